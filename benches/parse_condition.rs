@@ -0,0 +1,44 @@
+extern crate criterion;
+extern crate sqlparser_mysql;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use sqlparser_mysql::base::condition::ConditionExpression;
+
+/// `c0 = 0 AND c1 = 1 AND ... AND cN = N`
+fn chained_and(n: usize) -> String {
+    (0..n)
+        .map(|i| format!("c{} = {}", i, i))
+        .collect::<Vec<_>>()
+        .join(" AND ")
+}
+
+/// `((a = 1 AND b = 2) OR b = 2) AND b = 2 ...` nested `n` levels deep
+fn nested_brackets(n: usize) -> String {
+    (0..n).fold(String::from("a = 1"), |acc, i| {
+        let operator = if i % 2 == 0 { "AND" } else { "OR" };
+        format!("({} {} b = 2)", acc, operator)
+    })
+}
+
+fn parse_where_clauses(c: &mut Criterion) {
+    let mut group = c.benchmark_group("chained_and");
+    for n in [16, 64, 256, 1024].iter() {
+        let sql = chained_and(*n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &sql, |b, sql| {
+            b.iter(|| ConditionExpression::condition_expr(sql).unwrap())
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("nested_brackets");
+    for n in [4, 8, 16, 32].iter() {
+        let sql = nested_brackets(*n);
+        group.bench_with_input(BenchmarkId::from_parameter(n), &sql, |b, sql| {
+            b.iter(|| ConditionExpression::condition_expr(sql).unwrap())
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, parse_where_clauses);
+criterion_main!(benches);
@@ -0,0 +1,38 @@
+extern crate criterion;
+extern crate sqlparser_mysql;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use sqlparser_mysql::base::CommonParser;
+use sqlparser_mysql::dms::SelectStatement;
+
+fn parse_identifiers(c: &mut Criterion) {
+    let identifiers = [
+        "user_id",
+        "created_at",
+        "zone",
+        "account_balance",
+        "updated_by",
+        "status",
+        "name",
+        "order_items",
+    ];
+    c.bench_function("sql_identifier", |b| {
+        b.iter(|| {
+            for identifier in identifiers.iter() {
+                CommonParser::sql_identifier(identifier).unwrap();
+            }
+        })
+    });
+
+    let columns = (0..64)
+        .map(|i| format!("column_{}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let sql = format!("SELECT {} FROM wide_table WHERE column_0 = 1", columns);
+    c.bench_function("select_64_columns", |b| {
+        b.iter(|| SelectStatement::parse(&sql).unwrap())
+    });
+}
+
+criterion_group!(benches, parse_identifiers);
+criterion_main!(benches);
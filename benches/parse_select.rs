@@ -0,0 +1,93 @@
+extern crate criterion;
+extern crate sqlparser_mysql;
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use sqlparser_mysql::dms::SelectStatement;
+use sqlparser_mysql::{ParseConfig, Parser};
+
+/// counts every allocation so the benchmark can report allocations per query
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+const QUERY_COUNT: usize = 10_000;
+
+fn simple_selects() -> Vec<String> {
+    (0..QUERY_COUNT)
+        .map(|i| format!("SELECT id, name FROM users WHERE id = {}", i))
+        .collect()
+}
+
+fn report_allocations(name: &str, sqls: &[String], parse: impl Fn(&str)) {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    for sql in sqls {
+        parse(sql);
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - before;
+    println!(
+        "{}: {} allocations for {} queries ({} per query)",
+        name,
+        allocations,
+        sqls.len(),
+        allocations / sqls.len()
+    );
+}
+
+fn parse_simple_selects(c: &mut Criterion) {
+    let sqls = simple_selects();
+    let config = ParseConfig::default();
+
+    report_allocations("SelectStatement::parse", &sqls, |sql| {
+        SelectStatement::parse(sql).unwrap();
+    });
+    report_allocations("SelectStatement::nested_selection", &sqls, |sql| {
+        SelectStatement::nested_selection(sql).unwrap();
+    });
+    report_allocations("Parser::parse", &sqls, |sql| {
+        Parser::parse(&config, sql).unwrap();
+    });
+
+    c.bench_function("select_statement_parse_10k", |b| {
+        b.iter(|| {
+            for sql in &sqls {
+                SelectStatement::parse(sql).unwrap();
+            }
+        })
+    });
+    // the full grammar, which `SelectStatement::parse` skips for these simple
+    // single-table lookups
+    c.bench_function("nested_selection_10k", |b| {
+        b.iter(|| {
+            for sql in &sqls {
+                SelectStatement::nested_selection(sql).unwrap();
+            }
+        })
+    });
+    c.bench_function("parser_parse_10k", |b| {
+        b.iter(|| {
+            for sql in &sqls {
+                Parser::parse(&config, sql).unwrap();
+            }
+        })
+    });
+}
+
+criterion_group!(benches, parse_simple_selects);
+criterion_main!(benches);
@@ -52,10 +52,10 @@ impl AlgorithmType {
 impl Display for AlgorithmType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            AlgorithmType::Instant => write!(f, "ALGORITHM INSTANT"),
-            AlgorithmType::Default => write!(f, "ALGORITHM DEFAULT"),
-            AlgorithmType::Inplace => write!(f, "ALGORITHM INPLACE"),
-            AlgorithmType::Copy => write!(f, "ALGORITHM COPY"),
+            AlgorithmType::Instant => write_sql!(f, "ALGORITHM INSTANT"),
+            AlgorithmType::Default => write_sql!(f, "ALGORITHM DEFAULT"),
+            AlgorithmType::Inplace => write_sql!(f, "ALGORITHM INPLACE"),
+            AlgorithmType::Copy => write_sql!(f, "ALGORITHM COPY"),
         }
     }
 }
@@ -43,10 +43,10 @@ impl ArithmeticOperator {
 impl fmt::Display for ArithmeticOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ArithmeticOperator::Add => write!(f, "+"),
-            ArithmeticOperator::Subtract => write!(f, "-"),
-            ArithmeticOperator::Multiply => write!(f, "*"),
-            ArithmeticOperator::Divide => write!(f, "/"),
+            ArithmeticOperator::Add => write_sql!(f, "+"),
+            ArithmeticOperator::Subtract => write_sql!(f, "-"),
+            ArithmeticOperator::Multiply => write_sql!(f, "*"),
+            ArithmeticOperator::Divide => write_sql!(f, "/"),
         }
     }
 }
@@ -79,9 +79,9 @@ impl ArithmeticBase {
 impl fmt::Display for ArithmeticBase {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ArithmeticBase::Column(ref col) => write!(f, "{}", col),
-            ArithmeticBase::Scalar(ref lit) => write!(f, "{}", lit),
-            ArithmeticBase::Bracketed(ref ari) => write!(f, "({})", ari),
+            ArithmeticBase::Column(ref col) => write_sql!(f, "{}", col),
+            ArithmeticBase::Scalar(ref lit) => write_sql!(f, "{}", lit),
+            ArithmeticBase::Bracketed(ref ari) => write_sql!(f, "({})", ari),
         }
     }
 }
@@ -95,7 +95,7 @@ pub enum ArithmeticItem {
 impl ArithmeticItem {
     fn term(i: &str) -> IResult<&str, ArithmeticItem, ParseSQLError<&str>> {
         map(
-            pair(Self::arithmetic_cast, many0(Self::term_rest)),
+            CommonParser::chain(Self::arithmetic_cast, Self::term_rest),
             |(b, rs)| {
                 rs.into_iter()
                     .fold(ArithmeticItem::Base(b.0), |acc, (o, r)| {
@@ -121,7 +121,7 @@ impl ArithmeticItem {
 
     fn expr(i: &str) -> IResult<&str, ArithmeticItem, ParseSQLError<&str>> {
         map(
-            pair(ArithmeticItem::term, many0(Self::expr_rest)),
+            CommonParser::chain(ArithmeticItem::term, Self::expr_rest),
             |(item, rs)| {
                 rs.into_iter().fold(item, |acc, (o, r)| {
                     ArithmeticItem::Expr(Box::new(Arithmetic {
@@ -179,8 +179,8 @@ impl ArithmeticItem {
 impl fmt::Display for ArithmeticItem {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ArithmeticItem::Base(ref b) => write!(f, "{}", b),
-            ArithmeticItem::Expr(ref expr) => write!(f, "{}", expr),
+            ArithmeticItem::Base(ref b) => write_sql!(f, "{}", b),
+            ArithmeticItem::Expr(ref expr) => write_sql!(f, "{}", expr),
         }
     }
 }
@@ -193,8 +193,8 @@ pub struct Arithmetic {
 }
 
 impl Arithmetic {
-    fn parse(i: &str) -> IResult<&str, Arithmetic, ParseSQLError<&str>> {
-        let res = ArithmeticItem::expr(i)?;
+    pub(crate) fn parse(i: &str) -> IResult<&str, Arithmetic, ParseSQLError<&str>> {
+        let res = CommonParser::nested(ArithmeticItem::expr)(i)?;
         match res.1 {
             ArithmeticItem::Base(ArithmeticBase::Column(_))
             | ArithmeticItem::Base(ArithmeticBase::Scalar(_)) => {
@@ -217,7 +217,7 @@ impl Arithmetic {
 
 impl fmt::Display for Arithmetic {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} {}", self.left, self.op, self.right)
+        write_sql!(f, "{} {} {}", self.left, self.op, self.right)
     }
 }
 
@@ -260,8 +260,8 @@ impl ArithmeticExpression {
 impl fmt::Display for ArithmeticExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.alias {
-            Some(ref alias) => write!(f, "{} AS {}", self.ari, alias),
-            None => write!(f, "{}", self.ari),
+            Some(ref alias) => write_sql!(f, "{} AS {}", self.ari, alias),
+            None => write_sql!(f, "{}", self.ari),
         }
     }
 }
@@ -345,6 +345,7 @@ mod tests {
                     name: String::from("max(foo)"),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: Some(Box::new(FunctionExpression::Max(FunctionArgument::Column(
                         "foo".into(),
                     )))),
@@ -1,15 +1,18 @@
 use std::fmt;
 
+use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::{multispace0, multispace1};
-use nom::combinator::opt;
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
 use nom::sequence::{delimited, terminated, tuple};
 use nom::IResult;
 
+use base::arithmetic::Arithmetic;
 use base::column::Column;
 use base::condition::ConditionExpression;
 use base::error::ParseSQLError;
-use base::Literal;
+use base::{CommonParser, Literal};
 
 /// ```sql
 /// CASE expression
@@ -20,67 +23,96 @@ use base::Literal;
 /// ```
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct CaseWhenExpression {
-    pub condition: ConditionExpression,
-    pub then_expr: ColumnOrLiteral,
+    /// `WHEN condition THEN result` branches, in the order they are written
+    pub branches: Vec<(ConditionExpression, ColumnOrLiteral)>,
     pub else_expr: Option<ColumnOrLiteral>,
 }
 
 impl CaseWhenExpression {
     pub fn parse(i: &str) -> IResult<&str, CaseWhenExpression, ParseSQLError<&str>> {
-        let (input, (_, _, _, _, condition, _, _, _, column, _, else_val, _)) = tuple((
+        CommonParser::nested(Self::case_when)(i)
+    }
+
+    fn case_when(i: &str) -> IResult<&str, CaseWhenExpression, ParseSQLError<&str>> {
+        let (input, (_, _, branches, _, else_expr, _)) = tuple((
             tag_no_case("CASE"),
             multispace1,
-            tag_no_case("WHEN"),
-            multispace0,
-            ConditionExpression::condition_expr,
-            multispace0,
-            tag_no_case("THEN"),
-            multispace0,
-            Column::without_alias,
+            separated_list1(multispace1, Self::when_then),
             multispace0,
             opt(delimited(
                 terminated(tag_no_case("ELSE"), multispace0),
-                Literal::parse,
+                ColumnOrLiteral::parse,
                 multispace0,
             )),
             tag_no_case("END"),
         ))(i)?;
 
-        let then_expr = ColumnOrLiteral::Column(column);
-        let else_expr = else_val.map(ColumnOrLiteral::Literal);
-
         Ok((
             input,
             CaseWhenExpression {
-                condition,
-                then_expr,
+                branches,
                 else_expr,
             },
         ))
     }
+
+    /// `WHEN condition THEN result`
+    fn when_then(
+        i: &str,
+    ) -> IResult<&str, (ConditionExpression, ColumnOrLiteral), ParseSQLError<&str>> {
+        map(
+            tuple((
+                tag_no_case("WHEN"),
+                multispace0,
+                ConditionExpression::condition_expr,
+                multispace0,
+                tag_no_case("THEN"),
+                multispace0,
+                ColumnOrLiteral::parse,
+            )),
+            |(_, _, condition, _, _, _, result)| (condition, result),
+        )(i)
+    }
 }
 
 impl fmt::Display for CaseWhenExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "CASE WHEN {} THEN {}", self.condition, self.then_expr)?;
+        write_sql!(f, "CASE")?;
+        for (condition, result) in &self.branches {
+            write_sql!(f, " WHEN {} THEN {}", condition, result)?;
+        }
         if let Some(ref expr) = self.else_expr {
-            write!(f, " ELSE {}", expr)?;
+            write_sql!(f, " ELSE {}", expr)?;
         }
-        Ok(())
+        write_sql!(f, " END")
     }
 }
 
+/// a `THEN` or `ELSE` result of a [CaseWhenExpression]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum ColumnOrLiteral {
     Column(Column),
     Literal(Literal),
+    /// e.g. `b + 1`
+    Arithmetic(Arithmetic),
+}
+
+impl ColumnOrLiteral {
+    pub fn parse(i: &str) -> IResult<&str, ColumnOrLiteral, ParseSQLError<&str>> {
+        alt((
+            map(Arithmetic::parse, ColumnOrLiteral::Arithmetic),
+            map(Literal::parse, ColumnOrLiteral::Literal),
+            map(Column::without_alias, ColumnOrLiteral::Column),
+        ))(i)
+    }
 }
 
 impl fmt::Display for ColumnOrLiteral {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ColumnOrLiteral::Column(ref c) => write!(f, "{}", c)?,
-            ColumnOrLiteral::Literal(ref l) => write!(f, "{}", l)?,
+            ColumnOrLiteral::Column(ref c) => write_sql!(f, "{}", c)?,
+            ColumnOrLiteral::Literal(ref l) => write_sql!(f, "{}", l)?,
+            ColumnOrLiteral::Arithmetic(ref a) => write_sql!(f, "{}", a)?,
         }
         Ok(())
     }
@@ -101,26 +133,43 @@ mod tests {
         let res = CaseWhenExpression::parse(str);
 
         let exp = CaseWhenExpression {
-            condition: ComparisonOp(ConditionTree {
-                operator: Greater,
-                left: Box::new(Base(Field(Column {
-                    name: "age".to_string(),
+            branches: vec![(
+                ComparisonOp(ConditionTree {
+                    operator: Greater,
+                    left: Box::new(Base(Field(Column {
+                        name: "age".to_string(),
+                        alias: None,
+                        table: None,
+                        schema: None,
+                        function: None,
+                    }))),
+                    right: Box::new(Base(Literal(Integer(10)))),
+                }),
+                ColumnOrLiteral::Column(Column {
+                    name: "col_name".to_string(),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: None,
-                }))),
-                right: Box::new(Base(Literal(Integer(10)))),
-            }),
-            then_expr: ColumnOrLiteral::Column(Column {
-                name: "col_name".to_string(),
-                alias: None,
-                table: None,
-                function: None,
-            }),
+                }),
+            )],
             else_expr: Some(ColumnOrLiteral::Literal(Integer(22))),
         };
 
         assert!(res.is_ok());
         assert_eq!(res.unwrap().1, exp);
     }
+
+    #[test]
+    fn parse_case_branches() {
+        let str = "CASE WHEN a = 1 THEN 'one' WHEN a = 2 THEN b + 1 ELSE b * (c - 1) END";
+        let res = CaseWhenExpression::parse(str).unwrap().1;
+        assert_eq!(res.branches.len(), 2);
+        assert!(matches!(res.branches[1].1, ColumnOrLiteral::Arithmetic(_)));
+        assert!(matches!(
+            res.else_expr,
+            Some(ColumnOrLiteral::Arithmetic(_))
+        ));
+        assert_eq!(res.to_string(), str);
+    }
 }
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
 use std::fmt::{self, Display};
 use std::str;
@@ -5,14 +6,17 @@ use std::str::FromStr;
 
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_until};
-use nom::character::complete::{alphanumeric1, digit1, multispace0, multispace1};
-use nom::combinator::{map, opt};
-use nom::multi::{many0, separated_list0};
+use nom::character::complete::{alphanumeric1, digit1, multispace0, multispace1, satisfy};
+use nom::combinator::{map, map_res, not, opt, peek, verify};
+use nom::multi::{many0, separated_list0, separated_list1};
 use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use base::error::ParseSQLErrorKind;
-use base::{CaseWhenExpression, CommonParser, DataType, DisplayUtil, Literal, ParseSQLError, Real};
+use base::{
+    CaseWhenExpression, CommonParser, DataType, DisplayUtil, Literal, OrderClause, ParseSQLError,
+    Real, ReferenceDefinition, WindowFrame, WindowSpec,
+};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum FunctionExpression {
@@ -23,13 +27,54 @@ pub enum FunctionExpression {
     Max(FunctionArgument),
     Min(FunctionArgument),
     GroupConcat(FunctionArgument, String),
+    /// `VALUES(col_name)` in `ON DUPLICATE KEY UPDATE`
+    Values(Column),
+    /// `CASE WHEN ... END`, which may stand wherever a column does, e.g. as an
+    /// `ORDER BY` key or a comparison operand
+    Case(CaseWhenExpression),
+    /// `col->'path'`, short for `JSON_EXTRACT(col, 'path')`. Being part of the
+    /// operand, it binds tighter than any comparison or arithmetic operator.
+    JsonExtract(Column, String),
+    /// `col->>'path'`, short for `JSON_UNQUOTE(JSON_EXTRACT(col, 'path'))`
+    JsonUnquoteExtract(Column, String),
     Generic(String, FunctionArguments),
+    /// `func OVER {window_name | ([window_name] [PARTITION BY ...] [ORDER BY ...]
+    /// [frame_clause])}`. `OVER w` refers to a named window and leaves every
+    /// other part empty, while `OVER ()` spans the whole result.
+    Window {
+        func: Box<FunctionExpression>,
+        window_name: Option<String>,
+        partition_by: Vec<Column>,
+        order_by: Option<OrderClause>,
+        frame: Option<WindowFrame>,
+    },
 }
 
 impl FunctionExpression {
     pub fn parse(i: &str) -> IResult<&str, FunctionExpression, ParseSQLError<&str>> {
+        CommonParser::nested(Self::function_expr)(i)
+    }
+
+    fn function_expr(i: &str) -> IResult<&str, FunctionExpression, ParseSQLError<&str>> {
+        map(
+            pair(Self::function_call, opt(Self::over_clause)),
+            |(func, over)| match over {
+                Some((window_name, partition_by, order_by, frame)) => FunctionExpression::Window {
+                    func: Box::new(func),
+                    window_name,
+                    partition_by,
+                    order_by,
+                    frame,
+                },
+                None => func,
+            },
+        )(i)
+    }
+
+    fn function_call(i: &str) -> IResult<&str, FunctionExpression, ParseSQLError<&str>> {
         let delim_group_concat_fx = delimited(tag("("), Self::group_concat_fx, tag(")"));
         alt((
+            Self::json_extract,
             map(tag_no_case("COUNT(*)"), |_| FunctionExpression::CountStar),
             map(
                 preceded(tag_no_case("COUNT"), FunctionArgument::delim_fx_args),
@@ -63,6 +108,18 @@ impl FunctionExpression {
                     FunctionExpression::GroupConcat(FunctionArgument::Column(col.clone()), sep)
                 },
             ),
+            map(
+                preceded(
+                    pair(tag_no_case("VALUES"), multispace0),
+                    delimited(
+                        pair(tag("("), multispace0),
+                        Column::without_alias,
+                        pair(multispace0, tag(")")),
+                    ),
+                ),
+                FunctionExpression::Values,
+            ),
+            map(CaseWhenExpression::parse, FunctionExpression::Case),
             map(
                 tuple((
                     CommonParser::sql_identifier,
@@ -85,6 +142,73 @@ impl FunctionExpression {
         ))(i)
     }
 
+    /// `OVER {window_name | (window_spec)}`, where window_spec is
+    /// `[window_name] [PARTITION BY expr [, expr] ...] [ORDER BY ...] [frame_clause]`
+    #[allow(clippy::type_complexity)]
+    fn over_clause(
+        i: &str,
+    ) -> IResult<
+        &str,
+        (
+            Option<String>,
+            Vec<Column>,
+            Option<OrderClause>,
+            Option<WindowFrame>,
+        ),
+        ParseSQLError<&str>,
+    > {
+        preceded(
+            tuple((
+                multispace0,
+                tag_no_case("OVER"),
+                not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+                multispace0,
+            )),
+            alt((
+                map(WindowSpec::parse, |spec| {
+                    (
+                        spec.window_name,
+                        spec.partition_by,
+                        spec.order_by,
+                        spec.frame,
+                    )
+                }),
+                map(WindowSpec::window_name, |window_name| {
+                    (Some(window_name), vec![], None, None)
+                }),
+            )),
+        )(i)
+    }
+
+    /// `[table.]col{->|->>}'path'`
+    fn json_extract(i: &str) -> IResult<&str, FunctionExpression, ParseSQLError<&str>> {
+        map(
+            tuple((
+                pair(
+                    opt(terminated(CommonParser::sql_identifier, tag("."))),
+                    CommonParser::sql_identifier,
+                ),
+                multispace0,
+                alt((tag("->>"), tag("->"))),
+                multispace0,
+                CommonParser::parse_quoted_string,
+            )),
+            |((table, name), _, arrow, _, path)| {
+                let column = Column {
+                    name: name.to_string(),
+                    alias: None,
+                    table: table.map(String::from),
+                    schema: None,
+                    function: None,
+                };
+                match arrow {
+                    "->>" => FunctionExpression::JsonUnquoteExtract(column, path),
+                    _ => FunctionExpression::JsonExtract(column, path),
+                }
+            },
+        )(i)
+    }
+
     fn group_concat_fx_helper(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
         let ws_sep = preceded(multispace0, tag_no_case("separator"));
         let (remaining_input, sep) = delimited(
@@ -104,19 +228,42 @@ impl FunctionExpression {
 impl Display for FunctionExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            FunctionExpression::Avg(ref col, d) if d => write!(f, "avg(distinct {})", col),
-            FunctionExpression::Count(ref col, d) if d => write!(f, "count(distinct {})", col),
-            FunctionExpression::Sum(ref col, d) if d => write!(f, "sum(distinct {})", col),
-            FunctionExpression::Avg(ref col, _) => write!(f, "avg({})", col),
-            FunctionExpression::Count(ref col, _) => write!(f, "count({})", col),
-            FunctionExpression::CountStar => write!(f, "count(*)"),
-            FunctionExpression::Sum(ref col, _) => write!(f, "sum({})", col),
-            FunctionExpression::Max(ref col) => write!(f, "max({})", col),
-            FunctionExpression::Min(ref col) => write!(f, "min({})", col),
+            FunctionExpression::Avg(ref col, d) if d => write_sql!(f, "avg(distinct {})", col),
+            FunctionExpression::Count(ref col, d) if d => write_sql!(f, "count(distinct {})", col),
+            FunctionExpression::Sum(ref col, d) if d => write_sql!(f, "sum(distinct {})", col),
+            FunctionExpression::Avg(ref col, _) => write_sql!(f, "avg({})", col),
+            FunctionExpression::Count(ref col, _) => write_sql!(f, "count({})", col),
+            FunctionExpression::CountStar => write_sql!(f, "count(*)"),
+            FunctionExpression::Sum(ref col, _) => write_sql!(f, "sum({})", col),
+            FunctionExpression::Max(ref col) => write_sql!(f, "max({})", col),
+            FunctionExpression::Min(ref col) => write_sql!(f, "min({})", col),
             FunctionExpression::GroupConcat(ref col, ref s) => {
-                write!(f, "group_concat({}, {})", col, s)
+                write_sql!(f, "group_concat({}, {})", col, s)
+            }
+            FunctionExpression::Values(ref col) => write_sql!(f, "VALUES({})", col),
+            FunctionExpression::Case(ref case_when) => write_sql!(f, "{}", case_when),
+            FunctionExpression::JsonExtract(ref col, ref path) => {
+                write_sql!(f, "{}->'{}'", col, path)
+            }
+            FunctionExpression::JsonUnquoteExtract(ref col, ref path) => {
+                write_sql!(f, "{}->>'{}'", col, path)
+            }
+            FunctionExpression::Generic(ref name, ref args) => write_sql!(f, "{}({})", name, args),
+            FunctionExpression::Window {
+                ref func,
+                ref window_name,
+                ref partition_by,
+                ref order_by,
+                ref frame,
+            } => {
+                write_sql!(f, "{} OVER ", func)?;
+                if let (Some(name), true, None, None) =
+                    (window_name, partition_by.is_empty(), order_by, frame)
+                {
+                    return write_sql!(f, "{}", DisplayUtil::escape_if_keyword(name));
+                }
+                WindowSpec::fmt_parts(f, window_name, partition_by, order_by, frame)
             }
-            FunctionExpression::Generic(ref name, ref args) => write!(f, "{}({})", name, args),
         }
     }
 }
@@ -128,14 +275,14 @@ pub struct FunctionArguments {
 
 impl Display for FunctionArguments {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
+        write_sql!(
             f,
             "{}",
             self.arguments
                 .iter()
                 .map(|arg| format!("{}", arg))
                 .collect::<Vec<String>>()
-                .join(",")
+                .join(", ")
         )?;
         Ok(())
     }
@@ -147,10 +294,12 @@ impl From<Vec<FunctionArgument>> for FunctionArguments {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum FunctionArgument {
     Column(Column),
     Conditional(CaseWhenExpression),
+    Literal(Literal),
 }
 
 impl FunctionArgument {
@@ -158,6 +307,7 @@ impl FunctionArgument {
     pub fn parse(i: &str) -> IResult<&str, FunctionArgument, ParseSQLError<&str>> {
         alt((
             map(CaseWhenExpression::parse, FunctionArgument::Conditional),
+            map(Literal::parse, FunctionArgument::Literal),
             map(Column::without_alias, FunctionArgument::Column),
         ))(i)
     }
@@ -179,10 +329,11 @@ impl FunctionArgument {
 impl Display for FunctionArgument {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            FunctionArgument::Column(ref col) => write!(f, "{}", col)?,
+            FunctionArgument::Column(ref col) => write_sql!(f, "{}", col)?,
             FunctionArgument::Conditional(ref e) => {
-                write!(f, "{}", e)?;
+                write_sql!(f, "{}", e)?;
             }
+            FunctionArgument::Literal(ref literal) => write_sql!(f, "{}", literal)?,
         }
         Ok(())
     }
@@ -193,6 +344,8 @@ pub struct Column {
     pub name: String,
     pub alias: Option<String>,
     pub table: Option<String>,
+    /// database of a `db_name.tbl_name.col_name` reference
+    pub schema: Option<String>,
     pub function: Option<Box<FunctionExpression>>,
 }
 
@@ -218,22 +371,28 @@ impl Column {
 
     // Parses a SQL column identifier in the column format
     pub fn without_alias(i: &str) -> IResult<&str, Column, ParseSQLError<&str>> {
-        let table_parser = pair(
+        let table_parser = tuple((
+            opt(terminated(CommonParser::sql_identifier, tag("."))),
             opt(terminated(CommonParser::sql_identifier, tag("."))),
             CommonParser::sql_identifier,
-        );
+        ));
         alt((
             map(FunctionExpression::parse, |f| Column {
                 name: format!("{}", f),
                 alias: None,
                 table: None,
+                schema: None,
                 function: Some(Box::new(f)),
             }),
-            map(table_parser, |tup| Column {
-                name: tup.1.to_string(),
-                alias: None,
-                table: tup.0.map(|t| t.to_string()),
-                function: None,
+            map(table_parser, |(first, second, name)| {
+                let (schema, table) = Self::schema_and_table(first, second);
+                Column {
+                    name: name.to_string(),
+                    alias: None,
+                    table,
+                    schema,
+                    function: None,
+                }
             }),
         ))(i)
     }
@@ -245,46 +404,67 @@ impl Column {
             |tup| Column {
                 name: match tup.1 {
                     None => format!("{}", tup.0),
-                    Some(a) => String::from(a),
+                    Some(ref a) => a.to_string(),
                 },
                 alias: tup.1.map(String::from),
                 table: None,
+                schema: None,
                 function: Some(Box::new(tup.0)),
             },
         );
         let col_w_table = map(
             tuple((
+                opt(terminated(CommonParser::sql_identifier, tag("."))),
                 opt(terminated(CommonParser::sql_identifier, tag("."))),
                 CommonParser::sql_identifier,
                 opt(CommonParser::as_alias),
             )),
-            |tup| Column {
-                name: tup.1.to_string(),
-                alias: tup.2.map(String::from),
-                table: tup.0.map(|t| t.to_string()),
-                function: None,
+            |(first, second, name, alias)| {
+                let (schema, table) = Self::schema_and_table(first, second);
+                Column {
+                    name: name.to_string(),
+                    alias: alias.map(String::from),
+                    table,
+                    schema,
+                    function: None,
+                }
             },
         );
         alt((col_func_no_table, col_w_table))(i)
     }
+
+    /// `db_name.` and `tbl_name.` of `[[db_name.]tbl_name.]col_name`, where a
+    /// single qualifier is the table
+    fn schema_and_table(
+        first: Option<Cow<str>>,
+        second: Option<Cow<str>>,
+    ) -> (Option<String>, Option<String>) {
+        match second {
+            Some(table) => (first.map(String::from), Some(String::from(table))),
+            None => (None, first.map(String::from)),
+        }
+    }
 }
 
 impl fmt::Display for Column {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref schema) = self.schema {
+            write_sql!(f, "{}.", DisplayUtil::escape_if_keyword(schema))?;
+        }
         if let Some(ref table) = self.table {
-            write!(
+            write_sql!(
                 f,
                 "{}.{}",
                 DisplayUtil::escape_if_keyword(table),
                 DisplayUtil::escape_if_keyword(&self.name)
             )?;
         } else if let Some(ref function) = self.function {
-            write!(f, "{}", *function)?;
+            write_sql!(f, "{}", *function)?;
         } else {
-            write!(f, "{}", DisplayUtil::escape_if_keyword(&self.name))?;
+            write_sql!(f, "{}", DisplayUtil::escape_if_keyword(&self.name))?;
         }
         if let Some(ref alias) = self.alias {
-            write!(f, " AS {}", DisplayUtil::escape_if_keyword(alias))?;
+            write_sql!(f, " AS {}", DisplayUtil::escape_if_keyword(alias))?;
         }
         Ok(())
     }
@@ -292,38 +472,26 @@ impl fmt::Display for Column {
 
 impl From<String> for Column {
     fn from(value: String) -> Self {
-        match value.find('.') {
-            None => Column {
-                name: value,
-                alias: None,
-                table: None,
-                function: None,
-            },
-            Some(i) => Column {
-                name: String::from(&value[i + 1..]),
-                alias: None,
-                table: Some(String::from(&value[0..i])),
-                function: None,
-            },
-        }
+        Column::from(value.as_str())
     }
 }
 
-impl<'a> From<&'a str> for Column {
+/// Split `[[db_name.]tbl_name.]col_name` at the dots outside backquotes,
+/// dropping the backquotes, e.g. `` `db`.t.`a.b` `` is column `a.b` of `db.t`.
+impl From<&str> for Column {
     fn from(c: &str) -> Column {
-        match c.find('.') {
-            None => Column {
-                name: String::from(c),
-                alias: None,
-                table: None,
-                function: None,
-            },
-            Some(i) => Column {
-                name: String::from(&c[i + 1..]),
-                alias: None,
-                table: Some(String::from(&c[0..i])),
-                function: None,
+        let mut parts = CommonParser::split_qualified_name(c);
+        let name = parts.pop().unwrap_or_default();
+        let table = parts.pop();
+        Column {
+            name,
+            alias: None,
+            table,
+            schema: match parts.is_empty() {
+                true => None,
+                false => Some(parts.join(".")),
             },
+            function: None,
         }
     }
 }
@@ -368,6 +536,9 @@ pub enum ColumnConstraint {
     PrimaryKey,
     Unique,
     OnUpdate(Literal),
+    /// inline `REFERENCES tbl_name (key_part,...)`, which MySQL parses but
+    /// does not enforce as a foreign key
+    Reference(ReferenceDefinition),
 }
 
 impl ColumnConstraint {
@@ -388,16 +559,28 @@ impl ColumnConstraint {
             delimited(multispace0, tag_no_case("AUTO_INCREMENT"), multispace0),
             |_| Some(ColumnConstraint::AutoIncrement),
         );
+        // `[PRIMARY] KEY`
         let primary_key = map(
             delimited(
                 multispace0,
-                tuple((tag_no_case("PRIMARY"), multispace1, tag_no_case("KEY"))),
+                pair(
+                    opt(pair(tag_no_case("PRIMARY"), multispace1)),
+                    tag_no_case("KEY"),
+                ),
                 multispace0,
             ),
             |_| Some(ColumnConstraint::PrimaryKey),
         );
+        // `UNIQUE [KEY]`
         let unique = map(
-            delimited(multispace0, tag_no_case("UNIQUE"), multispace0),
+            delimited(
+                multispace0,
+                pair(
+                    tag_no_case("UNIQUE"),
+                    opt(preceded(multispace1, tag_no_case("KEY"))),
+                ),
+                multispace0,
+            ),
             |_| Some(ColumnConstraint::Unique),
         );
         let character_set = map(
@@ -414,7 +597,7 @@ impl ColumnConstraint {
                 )),
             ),
             |cs| {
-                let char_set = cs.to_owned();
+                let char_set = cs.into_owned();
                 Some(ColumnConstraint::CharacterSet(char_set))
             },
         );
@@ -428,7 +611,7 @@ impl ColumnConstraint {
                 )),
             ),
             |cs| {
-                let char_set = cs.to_owned();
+                let char_set = cs.into_owned();
                 Some(ColumnConstraint::CharacterSet(char_set))
             },
         );
@@ -442,7 +625,7 @@ impl ColumnConstraint {
                 )),
             ),
             |c| {
-                let collation = c.to_owned();
+                let collation = c.into_owned();
                 Some(ColumnConstraint::Collation(collation))
             },
         );
@@ -455,9 +638,12 @@ impl ColumnConstraint {
                 tag_no_case("UPDATE"),
                 multispace1,
                 tag_no_case("CURRENT_TIMESTAMP"),
-                opt(CommonParser::delim_digit),
+                opt(map_res(CommonParser::delim_digit, u8::from_str)),
             )),
-            |_| Some(ColumnConstraint::OnUpdate(Literal::CurrentTimestamp)),
+            |(_, _, _, _, _, fsp)| {
+                let timestamp = Literal::CurrentTimestamp(fsp);
+                Some(ColumnConstraint::OnUpdate(timestamp))
+            },
         );
 
         alt((
@@ -471,6 +657,9 @@ impl ColumnConstraint {
             charset,
             collate,
             on_update,
+            map(ReferenceDefinition::parse, |reference| {
+                Some(ColumnConstraint::Reference(reference))
+            }),
         ))(i)
     }
 
@@ -480,37 +669,38 @@ impl ColumnConstraint {
             tag_no_case("DEFAULT"),
             multispace1,
             alt((
+                Literal::bit_literal,
                 map(delimited(tag("'"), take_until("'"), tag("'")), |s| {
                     Literal::String(String::from(s))
                 }),
                 map(delimited(tag("\""), take_until("\""), tag("\"")), |s| {
                     Literal::String(String::from(s))
                 }),
-                map(tuple((digit1, tag("."), digit1)), |(i, _, f)| {
-                    Literal::FixedPoint(Real {
-                        integral: i32::from_str(i).unwrap(),
-                        fractional: i32::from_str(f).unwrap(),
-                    })
-                }),
-                map(tuple((opt(tag("-")), digit1)), |d: (Option<&str>, &str)| {
-                    let d_i64: i64 = d.1.parse().unwrap();
-                    if d.0.is_some() {
-                        Literal::Integer(-d_i64)
-                    } else {
-                        Literal::Integer(d_i64)
-                    }
-                }),
-                map(tag("''"), |_| Literal::String(String::from(""))),
-                map(tag_no_case("NULL"), |_| Literal::Null),
-                map(tag_no_case("FALSE"), |_| Literal::Bool(false)),
-                map(tag_no_case("TRUE"), |_| Literal::Bool(true)),
                 map(
                     tuple((
-                        tag_no_case("CURRENT_TIMESTAMP"),
-                        opt(CommonParser::delim_digit),
+                        map_res(digit1, i32::from_str),
+                        tag("."),
+                        map_res(digit1, i32::from_str),
                     )),
-                    |_| Literal::CurrentTimestamp,
+                    |(integral, _, fractional)| {
+                        Literal::FixedPoint(Real {
+                            integral,
+                            fractional,
+                        })
+                    },
+                ),
+                map(
+                    tuple((opt(tag("-")), map_res(digit1, i64::from_str))),
+                    |(sign, d_i64)| match sign {
+                        Some(_) => Literal::Integer(-d_i64),
+                        None => Literal::Integer(d_i64),
+                    },
                 ),
+                map(tag("''"), |_| Literal::String(String::from(""))),
+                map(tag_no_case("NULL"), |_| Literal::Null),
+                map(tag_no_case("FALSE"), |_| Literal::Bool(false)),
+                map(tag_no_case("TRUE"), |_| Literal::Bool(true)),
+                Literal::keyword_function,
             )),
             multispace0,
         ))(i)?;
@@ -522,17 +712,20 @@ impl ColumnConstraint {
 impl fmt::Display for ColumnConstraint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ColumnConstraint::NotNull => write!(f, "NOT NULL"),
-            ColumnConstraint::Null => write!(f, "NULL"),
-            ColumnConstraint::CharacterSet(ref charset) => write!(f, "CHARACTER SET {}", charset),
-            ColumnConstraint::Collation(ref collation) => write!(f, "COLLATE {}", collation),
+            ColumnConstraint::NotNull => write_sql!(f, "NOT NULL"),
+            ColumnConstraint::Null => write_sql!(f, "NULL"),
+            ColumnConstraint::CharacterSet(ref charset) => {
+                write_sql!(f, "CHARACTER SET {}", charset)
+            }
+            ColumnConstraint::Collation(ref collation) => write_sql!(f, "COLLATE {}", collation),
             ColumnConstraint::DefaultValue(ref literal) => {
-                write!(f, "DEFAULT {}", literal)
+                write_sql!(f, "DEFAULT {}", literal)
             }
-            ColumnConstraint::AutoIncrement => write!(f, "AutoIncrement"),
-            ColumnConstraint::PrimaryKey => write!(f, "PRIMARY KEY"),
-            ColumnConstraint::Unique => write!(f, "UNIQUE"),
-            ColumnConstraint::OnUpdate(ref ts) => write!(f, "ON UPDATE CURRENT_TIMESTAMP"),
+            ColumnConstraint::AutoIncrement => write_sql!(f, "AUTO_INCREMENT"),
+            ColumnConstraint::PrimaryKey => write_sql!(f, "PRIMARY KEY"),
+            ColumnConstraint::Unique => write_sql!(f, "UNIQUE"),
+            ColumnConstraint::OnUpdate(ref ts) => write_sql!(f, "ON UPDATE {}", ts),
+            ColumnConstraint::Reference(ref reference) => write_sql!(f, "{}", reference),
         }
     }
 }
@@ -566,13 +759,13 @@ impl ColumnPosition {
 impl Display for ColumnPosition {
     fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
         match self {
-            ColumnPosition::First => Ok(write!(f, "FIRST")?),
+            ColumnPosition::First => Ok(write_sql!(f, "FIRST")?),
             ColumnPosition::After(column) => {
                 let column_name = match &column.table {
                     Some(table) => format!("{}.{}", table, &column.name),
                     None => column.name.to_string(),
                 };
-                Ok(write!(f, "AFTER {column_name}")?)
+                Ok(write_sql!(f, "AFTER {}", column_name)?)
             }
         }
     }
@@ -602,14 +795,17 @@ impl ColumnSpecification {
                 DataType::type_identifier,
                 multispace0,
             )),
-            many0(ColumnConstraint::parse),
-            opt(CommonParser::parse_comment),
+            // MySQL accepts COMMENT anywhere among the column attributes
+            many0(alt((
+                map(ColumnConstraint::parse, |constraint| (constraint, None)),
+                map(CommonParser::parse_comment, |comment| (None, Some(comment))),
+            ))),
             opt(ColumnPosition::parse),
             opt(CommonParser::ws_sep_comma),
         ));
 
         match parser(i) {
-            Ok((input, (column, field_type, constraints, comment, position, _))) => {
+            Ok((input, (column, field_type, attributes, position, _))) => {
                 if field_type.is_none() {
                     let error = ParseSQLError {
                         errors: vec![(i, ParseSQLErrorKind::Context("data type is empty"))],
@@ -618,12 +814,20 @@ impl ColumnSpecification {
                 }
 
                 let sql_type = field_type.unwrap();
+                let mut constraints = vec![];
+                let mut comment = None;
+                for (constraint, attribute_comment) in attributes {
+                    constraints.extend(constraint);
+                    if attribute_comment.is_some() {
+                        comment = attribute_comment;
+                    }
+                }
                 Ok((
                     input,
                     ColumnSpecification {
                         column,
                         data_type: sql_type,
-                        constraints: constraints.into_iter().flatten().collect(),
+                        constraints,
                         comment,
                         position,
                     },
@@ -660,20 +864,20 @@ impl ColumnSpecification {
 
 impl fmt::Display for ColumnSpecification {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
+        write_sql!(
             f,
             "{} {}",
             DisplayUtil::escape_if_keyword(&self.column.name),
             self.data_type
         )?;
         for constraint in self.constraints.iter() {
-            write!(f, " {}", constraint)?;
+            write_sql!(f, " {}", constraint)?;
         }
         if let Some(ref comment) = self.comment {
-            write!(f, " COMMENT '{}'", comment)?;
+            write_sql!(f, " COMMENT '{}'", comment)?;
         }
         if let Some(ref position) = self.position {
-            write!(f, " {}", position)?;
+            write_sql!(f, " {}", position)?;
         }
         Ok(())
     }
@@ -694,9 +898,43 @@ mod tests {
                 name: String::from("col"),
                 alias: None,
                 table: Some(String::from("table")),
+                schema: None,
                 function: None,
             }
         );
+
+        let c = Column::from("db.tbl.col");
+        assert_eq!(c.schema, Some(String::from("db")));
+        assert_eq!(c.table, Some(String::from("tbl")));
+        assert_eq!(c.name, "col");
+        assert_eq!(c.to_string(), "db.tbl.col");
+
+        let c = Column::from("`my.db`.`tbl`.`a``b`");
+        assert_eq!(c.schema, Some(String::from("my.db")));
+        assert_eq!(c.table, Some(String::from("tbl")));
+        assert_eq!(c.name, "a`b");
+
+        assert_eq!(Column::from(String::from("`t`.c")), Column::from("t.c"));
+        assert_eq!(Column::from("col"), Column::from("`col`"));
+        assert_eq!(Column::from("col").table, None);
+    }
+
+    #[test]
+    fn parse_qualified_column() {
+        let res = Column::parse("db.tbl.col AS c").unwrap();
+        assert_eq!(res.0, "");
+        assert_eq!(
+            res.1,
+            Column {
+                alias: Some(String::from("c")),
+                ..Column::from("db.tbl.col")
+            }
+        );
+
+        let res = Column::without_alias("`db`.tbl.`col`").unwrap();
+        assert_eq!(res, ("", Column::from("db.tbl.col")));
+        let res = Column::without_alias("tbl.col").unwrap();
+        assert_eq!(res, ("", Column::from("tbl.col")));
     }
 
     #[test]
@@ -705,18 +943,21 @@ mod tests {
             name: "".into(), // must be present, but will be ignored
             alias: Some("foo".into()),
             table: None,
+            schema: None,
             function: Some(Box::new(FunctionExpression::CountStar)),
         };
         let c2 = Column {
             name: "".into(), // must be present, but will be ignored
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(FunctionExpression::CountStar)),
         };
         let c3 = Column {
             name: "".into(), // must be present, but will be ignored
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(FunctionExpression::Sum(
                 FunctionArgument::Column(Column::from("mytab.foo")),
                 false,
@@ -801,7 +1042,7 @@ mod tests {
             " AFTER foo ",
             "  AFTER  foo ",
         ];
-        let positions = vec![
+        let positions = [
             ColumnPosition::First,
             ColumnPosition::First,
             ColumnPosition::First,
@@ -846,4 +1087,65 @@ mod tests {
         assert!(res2.is_ok());
         assert_eq!(res2.unwrap().1, expected);
     }
+
+    #[test]
+    fn comment_after_parenthesized_type() {
+        let parts = [
+            "c ENUM('a','b') COMMENT 'choice'",
+            "d DECIMAL(10,2) COMMENT 'money, in cents'",
+        ];
+        let exps = [
+            ColumnSpecification {
+                column: "c".into(),
+                data_type: DataType::Enum(vec![
+                    Literal::String("a".to_string()),
+                    Literal::String("b".to_string()),
+                ]),
+                constraints: vec![],
+                comment: Some("choice".to_string()),
+                position: None,
+            },
+            ColumnSpecification {
+                column: "d".into(),
+                data_type: DataType::Decimal(10, 2),
+                constraints: vec![],
+                comment: Some("money, in cents".to_string()),
+                position: None,
+            },
+        ];
+        for i in 0..parts.len() {
+            let res = ColumnSpecification::parse(parts[i]);
+            assert_eq!(res.unwrap(), ("", exps[i].clone()));
+            let display = exps[i].to_string();
+            let round_trip = ColumnSpecification::parse(&display);
+            assert_eq!(round_trip.unwrap(), ("", exps[i].clone()));
+        }
+        assert_eq!(exps[0].to_string(), "c ENUM('a', 'b') COMMENT 'choice'");
+    }
+
+    #[test]
+    fn comment_among_constraints() {
+        let parts = [
+            "c INT NOT NULL COMMENT 'x' DEFAULT 0",
+            "c INT NOT NULL DEFAULT 0 COMMENT 'x'",
+            "c INT COMMENT 'x' NOT NULL DEFAULT 0",
+        ];
+        let exp = ColumnSpecification {
+            column: "c".into(),
+            data_type: DataType::Int(32),
+            constraints: vec![
+                ColumnConstraint::NotNull,
+                ColumnConstraint::DefaultValue(Literal::Integer(0)),
+            ],
+            comment: Some("x".to_string()),
+            position: None,
+        };
+        for part in parts {
+            let res = ColumnSpecification::parse(part);
+            assert_eq!(res.unwrap(), ("", exp.clone()));
+        }
+        let display = exp.to_string();
+        assert_eq!(display, "c INT(32) NOT NULL DEFAULT 0 COMMENT 'x'");
+        assert_eq!(ColumnSpecification::parse(&display).unwrap(), ("", exp));
+    }
 }
@@ -1,22 +1,214 @@
+use std::borrow::Cow;
+use std::cell::Cell;
 use std::str::FromStr;
 
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_until, take_while, take_while1};
 use nom::character::complete::{alpha1, digit1, line_ending, multispace0, multispace1};
 use nom::character::is_alphanumeric;
-use nom::combinator::{map, not, opt, peek, recognize};
+use nom::combinator::{map, map_res, not, opt, peek, recognize};
 use nom::error::{ErrorKind, ParseError};
+use nom::multi::many1;
 use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::{IResult, InputLength, Parser};
 
 use base::column::Column;
-use base::{DefaultOrZeroOrOne, OrderType, ParseSQLError};
+use base::{DefaultOrZeroOrOne, OrderType, ParseSQLError, ParseSQLErrorKind};
+use parser::ParseConfig;
+
+/// reserved keywords, sorted by byte order so they can be binary searched
+const SQL_KEYWORDS: [&str; 134] = [
+    "ABORT",
+    "ACTION",
+    "ADD",
+    "AFTER",
+    "ALL",
+    "ALTER",
+    "ANALYZE",
+    "AND",
+    "AS",
+    "ASC",
+    "ATTACH",
+    "AUTOINCREMENT",
+    "BEFORE",
+    "BEGIN",
+    "BETWEEN",
+    "BY",
+    "CASCADE",
+    "CASE",
+    "CAST",
+    "CHECK",
+    "COLLATE",
+    "COLUMN",
+    "COMMIT",
+    "CONFLICT",
+    "CONSTRAINT",
+    "CREATE",
+    "CROSS",
+    "CURRENT_DATE",
+    "CURRENT_TIME",
+    "CURRENT_TIMESTAMP",
+    "CURRENT_USER",
+    "DATABASE",
+    "DEFAULT",
+    "DEFERRABLE",
+    "DEFERRED",
+    "DELETE",
+    "DESC",
+    "DETACH",
+    "DISTINCT",
+    "DROP",
+    "EACH",
+    "ELSE",
+    "END",
+    "ESCAPE",
+    "EXCEPT",
+    "EXCLUSIVE",
+    "EXISTS",
+    "EXPLAIN",
+    "FAIL",
+    "FOR",
+    "FOREIGN",
+    "FROM",
+    "FULL",
+    "FULLTEXT",
+    "GLOB",
+    "GROUP",
+    "HAVING",
+    "IF",
+    "IGNORE",
+    "IMMEDIATE",
+    "IN",
+    "INDEX",
+    "INDEXED",
+    "INITIALLY",
+    "INNER",
+    "INSERT",
+    "INSTEAD",
+    "INTERSECT",
+    "INTO",
+    "IS",
+    "ISNULL",
+    "JOIN",
+    "KEY",
+    "LEFT",
+    "LIKE",
+    "LIMIT",
+    "LOCALTIME",
+    "LOCALTIMESTAMP",
+    "MATCH",
+    "NATURAL",
+    "NO",
+    "NOT",
+    "NOTNULL",
+    "NULL",
+    "OF",
+    "OFFSET",
+    "ON",
+    "OR",
+    "ORDER",
+    "OUTER",
+    "PLAN",
+    "PRAGMA",
+    "PRIMARY",
+    "PROCEDURE",
+    "QUERY",
+    "RAISE",
+    "RECURSIVE",
+    "REFERENCES",
+    "REGEXP",
+    "REINDEX",
+    "RELEASE",
+    "RENAME",
+    "REPLACE",
+    "RESTRICT",
+    "RIGHT",
+    "ROLLBACK",
+    "ROW",
+    "SAVEPOINT",
+    "SELECT",
+    "SET",
+    "SPATIAL",
+    "TABLE",
+    "TEMP",
+    "TEMPORARY",
+    "THEN",
+    "TO",
+    "TRANSACTION",
+    "TRIGGER",
+    "UNION",
+    "UNIQUE",
+    "UPDATE",
+    "USING",
+    "UTC_DATE",
+    "UTC_TIME",
+    "UTC_TIMESTAMP",
+    "VACUUM",
+    "VALUES",
+    "VIEW",
+    "VIRTUAL",
+    "WHEN",
+    "WHERE",
+    "WINDOW",
+    "WITH",
+    "WITHOUT",
+];
+
+thread_local! {
+    /// recursion levels and expression depth of the parse running on this thread
+    static NESTING: Cell<(usize, usize)> = const { Cell::new((0, 0)) };
+}
+
+/// Nesting charged by one parser, given back when it returns
+#[derive(Default)]
+struct Nesting {
+    levels: usize,
+    depth: usize,
+}
+
+impl Nesting {
+    fn charge<'a>(
+        &mut self,
+        i: &'a str,
+        levels: usize,
+        depth: usize,
+    ) -> Result<(), nom::Err<ParseSQLError<&'a str>>> {
+        self.levels += levels;
+        self.depth += depth;
+        let (levels, depth) = NESTING.with(|nesting| {
+            let (current_levels, current_depth) = nesting.get();
+            let charged = (current_levels + levels, current_depth + depth);
+            nesting.set(charged);
+            charged
+        });
+        if levels > crate::Parser::MAX_NESTING_DEPTH {
+            return Err(nom::Err::Failure(ParseSQLError {
+                errors: vec![(i, ParseSQLErrorKind::Context("nested too deeply"))],
+            }));
+        }
+        if depth > crate::Parser::MAX_EXPRESSION_DEPTH {
+            return Err(nom::Err::Failure(ParseSQLError {
+                errors: vec![(i, ParseSQLErrorKind::Context("expression too deep"))],
+            }));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for Nesting {
+    fn drop(&mut self) {
+        NESTING.with(|nesting| {
+            let (levels, depth) = nesting.get();
+            nesting.set((levels - self.levels, depth - self.depth));
+        });
+    }
+}
 
 /// collection of common used parsers
 pub struct CommonParser;
 
 impl CommonParser {
-    fn keyword_follow_char(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
+    fn keyword_follow_char<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, &'a str, E> {
         peek(alt((
             tag(" "),
             tag("\n"),
@@ -30,172 +222,278 @@ impl CommonParser {
         )))(i)
     }
 
-    fn keywords_part_1(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            terminated(tag_no_case("ABORT"), Self::keyword_follow_char),
-            terminated(tag_no_case("ACTION"), Self::keyword_follow_char),
-            terminated(tag_no_case("ADD"), Self::keyword_follow_char),
-            terminated(tag_no_case("AFTER"), Self::keyword_follow_char),
-            terminated(tag_no_case("ALL"), Self::keyword_follow_char),
-            terminated(tag_no_case("ALTER"), Self::keyword_follow_char),
-            terminated(tag_no_case("ANALYZE"), Self::keyword_follow_char),
-            terminated(tag_no_case("AND"), Self::keyword_follow_char),
-            terminated(tag_no_case("AS"), Self::keyword_follow_char),
-            terminated(tag_no_case("ASC"), Self::keyword_follow_char),
-            terminated(tag_no_case("ATTACH"), Self::keyword_follow_char),
-            terminated(tag_no_case("AUTOINCREMENT"), Self::keyword_follow_char),
-            terminated(tag_no_case("BEFORE"), Self::keyword_follow_char),
-            terminated(tag_no_case("BEGIN"), Self::keyword_follow_char),
-            terminated(tag_no_case("BETWEEN"), Self::keyword_follow_char),
-            terminated(tag_no_case("BY"), Self::keyword_follow_char),
-            terminated(tag_no_case("CASCADE"), Self::keyword_follow_char),
-            terminated(tag_no_case("CASE"), Self::keyword_follow_char),
-            terminated(tag_no_case("CAST"), Self::keyword_follow_char),
-            terminated(tag_no_case("CHECK"), Self::keyword_follow_char),
-            terminated(tag_no_case("COLLATE"), Self::keyword_follow_char),
-        ))(i)
+    // Matches any SQL reserved keyword
+    pub fn sql_keyword(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
+        Self::keyword(i)
     }
 
-    fn keywords_part_2(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            terminated(tag_no_case("COLUMN"), Self::keyword_follow_char),
-            terminated(tag_no_case("COMMIT"), Self::keyword_follow_char),
-            terminated(tag_no_case("CONFLICT"), Self::keyword_follow_char),
-            terminated(tag_no_case("CONSTRAINT"), Self::keyword_follow_char),
-            terminated(tag_no_case("CREATE"), Self::keyword_follow_char),
-            terminated(tag_no_case("CROSS"), Self::keyword_follow_char),
-            terminated(tag_no_case("CURRENT_DATE"), Self::keyword_follow_char),
-            terminated(tag_no_case("CURRENT_TIME"), Self::keyword_follow_char),
-            terminated(tag_no_case("CURRENT_TIMESTAMP"), Self::keyword_follow_char),
-            terminated(tag_no_case("DATABASE"), Self::keyword_follow_char),
-            terminated(tag_no_case("DEFAULT"), Self::keyword_follow_char),
-            terminated(tag_no_case("DEFERRABLE"), Self::keyword_follow_char),
-            terminated(tag_no_case("DEFERRED"), Self::keyword_follow_char),
-            terminated(tag_no_case("DELETE"), Self::keyword_follow_char),
-            terminated(tag_no_case("DESC"), Self::keyword_follow_char),
-            terminated(tag_no_case("DETACH"), Self::keyword_follow_char),
-            terminated(tag_no_case("DISTINCT"), Self::keyword_follow_char),
-            terminated(tag_no_case("DROP"), Self::keyword_follow_char),
-            terminated(tag_no_case("EACH"), Self::keyword_follow_char),
-            terminated(tag_no_case("ELSE"), Self::keyword_follow_char),
-            terminated(tag_no_case("END"), Self::keyword_follow_char),
-        ))(i)
+    /// Succeeds only if the input does not start with a SQL reserved keyword.
+    ///
+    /// The keyword lookup runs with the unit error type, so the many failing
+    /// alternatives tried for every identifier do not allocate.
+    fn not_sql_keyword(i: &str) -> IResult<&str, (), ParseSQLError<&str>> {
+        match Self::keyword::<()>(i) {
+            Ok(_) => Err(nom::Err::Error(ParseSQLError::from_error_kind(
+                i,
+                ErrorKind::Not,
+            ))),
+            Err(_) => Ok((i, ())),
+        }
     }
 
-    fn keywords_part_3(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            terminated(tag_no_case("ESCAPE"), Self::keyword_follow_char),
-            terminated(tag_no_case("EXCEPT"), Self::keyword_follow_char),
-            terminated(tag_no_case("EXCLUSIVE"), Self::keyword_follow_char),
-            terminated(tag_no_case("EXISTS"), Self::keyword_follow_char),
-            terminated(tag_no_case("EXPLAIN"), Self::keyword_follow_char),
-            terminated(tag_no_case("FAIL"), Self::keyword_follow_char),
-            terminated(tag_no_case("FOR"), Self::keyword_follow_char),
-            terminated(tag_no_case("FOREIGN"), Self::keyword_follow_char),
-            terminated(tag_no_case("FROM"), Self::keyword_follow_char),
-            terminated(tag_no_case("FULL"), Self::keyword_follow_char),
-            terminated(tag_no_case("FULLTEXT"), Self::keyword_follow_char),
-            terminated(tag_no_case("GLOB"), Self::keyword_follow_char),
-            terminated(tag_no_case("GROUP"), Self::keyword_follow_char),
-            terminated(tag_no_case("HAVING"), Self::keyword_follow_char),
-            terminated(tag_no_case("IF"), Self::keyword_follow_char),
-            terminated(tag_no_case("IGNORE"), Self::keyword_follow_char),
-            terminated(tag_no_case("IMMEDIATE"), Self::keyword_follow_char),
-            terminated(tag_no_case("IN"), Self::keyword_follow_char),
-            terminated(tag_no_case("INDEX"), Self::keyword_follow_char),
-            terminated(tag_no_case("INDEXED"), Self::keyword_follow_char),
-            terminated(tag_no_case("INITIALLY"), Self::keyword_follow_char),
-        ))(i)
+    /// Matches a reserved keyword by looking the leading word up in [SQL_KEYWORDS].
+    fn keyword<'a, E: ParseError<&'a str>>(i: &'a str) -> IResult<&'a str, &'a str, E> {
+        let (remaining_input, word) = terminated(
+            take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+            Self::keyword_follow_char,
+        )(i)?;
+        if Self::is_reserved_word(word) && !Self::is_allowed_identifier(word) {
+            Ok((remaining_input, word))
+        } else {
+            Err(nom::Err::Error(E::from_error_kind(i, ErrorKind::Tag)))
+        }
     }
 
-    fn keywords_part_4(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            terminated(tag_no_case("INNER"), Self::keyword_follow_char),
-            terminated(tag_no_case("INSERT"), Self::keyword_follow_char),
-            terminated(tag_no_case("INSTEAD"), Self::keyword_follow_char),
-            terminated(tag_no_case("INTERSECT"), Self::keyword_follow_char),
-            terminated(tag_no_case("INTO"), Self::keyword_follow_char),
-            terminated(tag_no_case("IS"), Self::keyword_follow_char),
-            terminated(tag_no_case("ISNULL"), Self::keyword_follow_char),
-            terminated(tag_no_case("ORDER"), Self::keyword_follow_char),
-            terminated(tag_no_case("JOIN"), Self::keyword_follow_char),
-            terminated(tag_no_case("KEY"), Self::keyword_follow_char),
-            terminated(tag_no_case("LEFT"), Self::keyword_follow_char),
-            terminated(tag_no_case("LIKE"), Self::keyword_follow_char),
-            terminated(tag_no_case("LIMIT"), Self::keyword_follow_char),
-            terminated(tag_no_case("MATCH"), Self::keyword_follow_char),
-            terminated(tag_no_case("NATURAL"), Self::keyword_follow_char),
-            terminated(tag_no_case("NO"), Self::keyword_follow_char),
-            terminated(tag_no_case("NOT"), Self::keyword_follow_char),
-            terminated(tag_no_case("NOTNULL"), Self::keyword_follow_char),
-            terminated(tag_no_case("NULL"), Self::keyword_follow_char),
-            terminated(tag_no_case("OF"), Self::keyword_follow_char),
-            terminated(tag_no_case("OFFSET"), Self::keyword_follow_char),
-        ))(i)
+    /// Whether `word` is in [SQL_KEYWORDS], even if the running
+    /// [crate::Parser::parse] accepts it as an identifier.
+    pub(crate) fn is_reserved_word(word: &str) -> bool {
+        SQL_KEYWORDS
+            .binary_search_by(|keyword| {
+                keyword
+                    .bytes()
+                    .cmp(word.bytes().map(|b| b.to_ascii_uppercase()))
+            })
+            .is_ok()
+            || ParseConfig::with_current(|config| {
+                (word.eq_ignore_ascii_case("QUALIFY") && config.qualify_clause)
+                    || (word.eq_ignore_ascii_case("TABLESAMPLE") && config.tablesample)
+            })
     }
 
-    fn keywords_part_5(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            terminated(tag_no_case("ON"), Self::keyword_follow_char),
-            terminated(tag_no_case("OR"), Self::keyword_follow_char),
-            terminated(tag_no_case("OUTER"), Self::keyword_follow_char),
-            terminated(tag_no_case("PLAN"), Self::keyword_follow_char),
-            terminated(tag_no_case("PRAGMA"), Self::keyword_follow_char),
-            terminated(tag_no_case("PRIMARY"), Self::keyword_follow_char),
-            terminated(tag_no_case("QUERY"), Self::keyword_follow_char),
-            terminated(tag_no_case("RAISE"), Self::keyword_follow_char),
-            terminated(tag_no_case("RECURSIVE"), Self::keyword_follow_char),
-            terminated(tag_no_case("REFERENCES"), Self::keyword_follow_char),
-            terminated(tag_no_case("REGEXP"), Self::keyword_follow_char),
-            terminated(tag_no_case("REINDEX"), Self::keyword_follow_char),
-            terminated(tag_no_case("RELEASE"), Self::keyword_follow_char),
-            terminated(tag_no_case("RENAME"), Self::keyword_follow_char),
-            terminated(tag_no_case("REPLACE"), Self::keyword_follow_char),
-            terminated(tag_no_case("RESTRICT"), Self::keyword_follow_char),
-            terminated(tag_no_case("RIGHT"), Self::keyword_follow_char),
-            terminated(tag_no_case("ROLLBACK"), Self::keyword_follow_char),
-            terminated(tag_no_case("ROW"), Self::keyword_follow_char),
-            terminated(tag_no_case("SAVEPOINT"), Self::keyword_follow_char),
-            terminated(tag_no_case("SELECT"), Self::keyword_follow_char),
-        ))(i)
+    fn is_allowed_identifier(word: &str) -> bool {
+        ParseConfig::with_current(|config| {
+            config
+                .allow_identifiers
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(word))
+        })
     }
 
-    fn keywords_part_6(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            terminated(tag_no_case("SET"), Self::keyword_follow_char),
-            terminated(tag_no_case("SPATIAL"), Self::keyword_follow_char),
-            terminated(tag_no_case("TABLE"), Self::keyword_follow_char),
-            terminated(tag_no_case("TEMP"), Self::keyword_follow_char),
-            terminated(tag_no_case("TEMPORARY"), Self::keyword_follow_char),
-            terminated(tag_no_case("THEN"), Self::keyword_follow_char),
-            terminated(tag_no_case("TO"), Self::keyword_follow_char),
-            terminated(tag_no_case("TRANSACTION"), Self::keyword_follow_char),
-            terminated(tag_no_case("TRIGGER"), Self::keyword_follow_char),
-            terminated(tag_no_case("UNION"), Self::keyword_follow_char),
-            terminated(tag_no_case("UNIQUE"), Self::keyword_follow_char),
-            terminated(tag_no_case("UPDATE"), Self::keyword_follow_char),
-            terminated(tag_no_case("USING"), Self::keyword_follow_char),
-            terminated(tag_no_case("VACUUM"), Self::keyword_follow_char),
-            terminated(tag_no_case("VALUES"), Self::keyword_follow_char),
-            terminated(tag_no_case("VIEW"), Self::keyword_follow_char),
-            terminated(tag_no_case("VIRTUAL"), Self::keyword_follow_char),
-            terminated(tag_no_case("WHEN"), Self::keyword_follow_char),
-            terminated(tag_no_case("WHERE"), Self::keyword_follow_char),
-            terminated(tag_no_case("WITH"), Self::keyword_follow_char),
-            terminated(tag_no_case("WITHOUT"), Self::keyword_follow_char),
-        ))(i)
+    /// Replace every `/* ... */` comment outside quoted strings and identifiers
+    /// with a single space, so optimizer hints such as `UPDATE /*+ NO_MERGE() */ t`
+    /// are skipped wherever whitespace is allowed. An unterminated comment is
+    /// left in place and fails to parse.
+    ///
+    /// The body of an executable comment such as `/*!50100 PARTITION BY ... */`
+    /// is kept, only its `/*!` marker, version and closing `*/` are replaced.
+    pub(crate) fn strip_comments(i: &str) -> Cow<'_, str> {
+        Self::replace_comments(i, false)
     }
 
-    // Matches any SQL reserved keyword
-    pub fn sql_keyword(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
-        alt((
-            Self::keywords_part_1,
-            Self::keywords_part_2,
-            Self::keywords_part_3,
-            Self::keywords_part_4,
-            Self::keywords_part_5,
-            Self::keywords_part_6,
-        ))(i)
+    /// Like [CommonParser::strip_comments], but a comment is replaced with as
+    /// many spaces as it has bytes, so offsets into the result are offsets
+    /// into `i` too.
+    pub(crate) fn blank_comments(i: &str) -> Cow<'_, str> {
+        Self::replace_comments(i, true)
+    }
+
+    fn replace_comments(i: &str, keep_offsets: bool) -> Cow<'_, str> {
+        if !i.contains("/*") {
+            return Cow::Borrowed(i);
+        }
+        let blank = |out: &mut String, len: usize| match keep_offsets {
+            true => out.extend((0..len).map(|_| ' ')),
+            false => out.push(' '),
+        };
+        let mut out = String::with_capacity(i.len());
+        let mut rest = i;
+        let mut executable = false;
+        while let Some(c) = rest.chars().next() {
+            if executable && rest.starts_with("*/") {
+                blank(&mut out, 2);
+                rest = &rest[2..];
+                executable = false;
+                continue;
+            }
+            if rest.starts_with("/*!") && rest[3..].contains("*/") {
+                let version = rest[3..].bytes().take_while(u8::is_ascii_digit).count();
+                blank(&mut out, 3 + version);
+                rest = &rest[3 + version..];
+                executable = true;
+                continue;
+            }
+            if rest.starts_with("/*") {
+                match rest[2..].find("*/") {
+                    Some(end) => {
+                        blank(&mut out, end + 4);
+                        rest = &rest[end + 4..];
+                        continue;
+                    }
+                    None => {
+                        out.push_str(rest);
+                        break;
+                    }
+                }
+            }
+            if c == '\'' || c == '"' || c == '`' {
+                let mut end = c.len_utf8();
+                let mut chars = rest[end..].char_indices();
+                while let Some((idx, next)) = chars.next() {
+                    end = c.len_utf8() + idx + next.len_utf8();
+                    if next == '\\' && c != '`' {
+                        if let Some((idx, escaped)) = chars.next() {
+                            end = c.len_utf8() + idx + escaped.len_utf8();
+                        }
+                    } else if next == c {
+                        break;
+                    }
+                }
+                out.push_str(&rest[..end]);
+                rest = &rest[end..];
+                continue;
+            }
+            out.push(c);
+            rest = &rest[c.len_utf8()..];
+        }
+        Cow::Owned(out)
+    }
+
+    /// The parts of a dotted name such as `db.tbl` or `` `db`.`tbl`.col ``,
+    /// split at the dots outside backquotes, with the backquotes removed.
+    pub(crate) fn split_qualified_name(name: &str) -> Vec<String> {
+        let mut parts = vec![String::new()];
+        let mut quoted = false;
+        let mut chars = name.chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                // a doubled backquote inside a quoted name stands for one
+                '`' if quoted && chars.peek() == Some(&'`') => {
+                    chars.next();
+                    parts.last_mut().unwrap().push('`');
+                }
+                '`' => quoted = !quoted,
+                '.' if !quoted => parts.push(String::new()),
+                _ => parts.last_mut().unwrap().push(c),
+            }
+        }
+        parts
+    }
+
+    /// Run `parser` one level of recursion deeper, failing instead once the
+    /// recursive parsers are nested more than [Parser::MAX_NESTING_DEPTH]
+    /// levels, so hostile input gets an error rather than a stack overflow.
+    ///
+    /// [Parser::MAX_NESTING_DEPTH]: crate::Parser::MAX_NESTING_DEPTH
+    pub(crate) fn nested<'a, O, F>(
+        mut parser: F,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, O, ParseSQLError<&'a str>>
+    where
+        F: FnMut(&'a str) -> IResult<&'a str, O, ParseSQLError<&'a str>>,
+    {
+        move |i| {
+            let mut nesting = Nesting::default();
+            nesting.charge(i, 1, 1)?;
+            parser(i)
+        }
+    }
+
+    /// Run `parser` below `depth` more levels of an expression tree built in
+    /// a loop, such as a run of `NOT`s, see [CommonParser::chain].
+    pub(crate) fn deeper<'a, O, F>(
+        depth: usize,
+        mut parser: F,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, O, ParseSQLError<&'a str>>
+    where
+        F: FnMut(&'a str) -> IResult<&'a str, O, ParseSQLError<&'a str>>,
+    {
+        move |i| {
+            let mut nesting = Nesting::default();
+            nesting.charge(i, 0, depth)?;
+            parser(i)
+        }
+    }
+
+    /// Like `pair(first, many0(rest))`, for operator chains parsed in a loop
+    /// but folded into a nested tree, such as `a AND b AND c`. Each element
+    /// nests the tree one level deeper, which counts towards
+    /// [Parser::MAX_EXPRESSION_DEPTH] for the rest of the chain, as the
+    /// tree is dropped and written by recursion.
+    ///
+    /// [Parser::MAX_EXPRESSION_DEPTH]: crate::Parser::MAX_EXPRESSION_DEPTH
+    pub(crate) fn chain<'a, O1, O2, F, G>(
+        mut first: F,
+        mut rest: G,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, (O1, Vec<O2>), ParseSQLError<&'a str>>
+    where
+        F: FnMut(&'a str) -> IResult<&'a str, O1, ParseSQLError<&'a str>>,
+        G: FnMut(&'a str) -> IResult<&'a str, O2, ParseSQLError<&'a str>>,
+    {
+        move |i| {
+            let (mut i, head) = first(i)?;
+            let mut tail = vec![];
+            let mut nesting = Nesting::default();
+            loop {
+                match rest(i) {
+                    // like many0, stop rather than loop on a parser consuming nothing
+                    Ok((remaining_input, _)) if remaining_input.len() == i.len() => {
+                        return Ok((i, (head, tail)))
+                    }
+                    Ok((remaining_input, item)) => {
+                        tail.push(item);
+                        i = remaining_input;
+                        nesting.charge(i, 0, 1)?;
+                    }
+                    Err(nom::Err::Error(_)) => return Ok((i, (head, tail))),
+                    Err(err) => return Err(err),
+                }
+            }
+        }
+    }
+
+    /// Offsets just past every `;` outside quoted strings, identifiers and
+    /// comments, followed by `i.len()` if anything but whitespace comes after
+    /// the last one.
+    pub(crate) fn statement_ends(i: &str) -> Vec<usize> {
+        let mut ends = Vec::new();
+        let mut quote = None;
+        let mut chars = i.char_indices().peekable();
+        while let Some((idx, c)) = chars.next() {
+            match quote {
+                Some(q) if c == q => quote = None,
+                Some(q) if c == '\\' && q != '`' => {
+                    chars.next();
+                }
+                Some(_) => {}
+                None => {
+                    let comment_end = match c {
+                        '\'' | '"' | '`' => {
+                            quote = Some(c);
+                            None
+                        }
+                        ';' => {
+                            ends.push(idx + 1);
+                            None
+                        }
+                        '#' => Some(i[idx..].find('\n').map_or(i.len(), |n| idx + n)),
+                        '-' if i[idx..].starts_with("-- ") => {
+                            Some(i[idx..].find('\n').map_or(i.len(), |n| idx + n))
+                        }
+                        '/' if i[idx..].starts_with("/*") => {
+                            Some(i[idx + 2..].find("*/").map_or(i.len(), |n| idx + n + 4))
+                        }
+                        _ => None,
+                    };
+                    if let Some(comment_end) = comment_end {
+                        while chars.next_if(|&(next, _)| next < comment_end).is_some() {}
+                    }
+                }
+            }
+        }
+        let last = ends.last().copied().unwrap_or(0);
+        if !i[last..].trim().is_empty() {
+            ends.push(i.len());
+        }
+        ends
     }
 
     /// `[index_name]`
@@ -210,14 +508,15 @@ impl CommonParser {
     pub fn index_col_name(
         i: &str,
     ) -> IResult<&str, (Column, Option<u16>, Option<OrderType>), ParseSQLError<&str>> {
-        let (remaining_input, (column, len_u8, order)) = tuple((
+        tuple((
             terminated(Column::without_alias, multispace0),
-            opt(delimited(tag("("), digit1, tag(")"))),
+            opt(delimited(
+                tag("("),
+                map_res(digit1, u16::from_str),
+                tag(")"),
+            )),
             opt(OrderType::parse),
-        ))(i)?;
-        let len = len_u8.map(|l| u16::from_str(l).unwrap());
-
-        Ok((remaining_input, (column, len, order)))
+        ))(i)
     }
 
     #[inline]
@@ -250,15 +549,13 @@ impl CommonParser {
     }
 
     fn precision_helper(i: &str) -> IResult<&str, (u8, Option<u8>), ParseSQLError<&str>> {
-        let (remaining_input, (m, d)) = tuple((
-            digit1,
-            opt(preceded(tag(","), preceded(multispace0, digit1))),
-        ))(i)?;
-
-        Ok((
-            remaining_input,
-            (m.parse().unwrap(), d.map(|r| r.parse().unwrap())),
-        ))
+        tuple((
+            map_res(digit1, u8::from_str),
+            opt(preceded(
+                tag(","),
+                preceded(multispace0, map_res(digit1, u8::from_str)),
+            )),
+        ))(i)
     }
 
     pub fn precision(i: &str) -> IResult<&str, (u8, Option<u8>), ParseSQLError<&str>> {
@@ -269,25 +566,49 @@ impl CommonParser {
         delimited(tag("("), digit1, tag(")"))(i)
     }
 
-    pub fn sql_identifier(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
+    pub fn sql_identifier(i: &str) -> IResult<&str, Cow<'_, str>, ParseSQLError<&str>> {
         alt((
-            alt((
-                preceded(
-                    not(peek(CommonParser::sql_keyword)),
-                    recognize(pair(alpha1, take_while(Self::is_sql_identifier))),
-                ),
-                recognize(pair(tag("_"), take_while1(Self::is_sql_identifier))),
-                // variable only
-                recognize(pair(tag("@"), take_while1(Self::is_sql_identifier))),
-            )),
-            delimited(tag("`"), take_while1(Self::is_sql_identifier), tag("`")),
-            delimited(tag("["), take_while1(Self::is_sql_identifier), tag("]")),
+            map(
+                alt((
+                    preceded(
+                        CommonParser::not_sql_keyword,
+                        recognize(pair(alpha1, take_while(Self::is_sql_identifier))),
+                    ),
+                    recognize(pair(tag("_"), take_while1(Self::is_sql_identifier))),
+                    // variable only
+                    recognize(pair(tag("@"), take_while1(Self::is_sql_identifier))),
+                )),
+                Cow::Borrowed,
+            ),
+            Self::backquoted_identifier,
+            map(
+                delimited(tag("["), take_while1(|c| c != ']'), tag("]")),
+                Cow::Borrowed,
+            ),
         ))(i)
     }
 
+    /// A backquoted identifier, which may contain any character; a doubled
+    /// backquote stands for one.
+    pub(crate) fn backquoted_identifier(
+        i: &str,
+    ) -> IResult<&str, Cow<'_, str>, ParseSQLError<&str>> {
+        map(
+            delimited(
+                tag("`"),
+                recognize(many1(alt((take_while1(|c| c != '`'), tag("``"))))),
+                tag("`"),
+            ),
+            |quoted: &str| match quoted.contains("``") {
+                true => Cow::Owned(quoted.replace("``", "`")),
+                false => Cow::Borrowed(quoted),
+            },
+        )(i)
+    }
+
     // Parse an unsigned integer.
     pub fn unsigned_number(i: &str) -> IResult<&str, u64, ParseSQLError<&str>> {
-        map(digit1, |d| FromStr::from_str(d).unwrap())(i)
+        map_res(digit1, u64::from_str)(i)
     }
 
     pub fn eof<I: Copy + InputLength, E: ParseError<I>>(input: I) -> IResult<I, I, E> {
@@ -302,14 +623,153 @@ impl CommonParser {
     pub fn statement_terminator(i: &str) -> IResult<&str, (), ParseSQLError<&str>> {
         let (remaining_input, _) = delimited(
             multispace0,
-            alt((tag(";"), line_ending, CommonParser::eof)),
+            alt((
+                tag(";"),
+                Self::vertical_terminator,
+                line_ending,
+                CommonParser::eof,
+            )),
             multispace0,
         )(i)?;
         Ok((remaining_input, ()))
     }
 
+    /// `\G` or `\g`, used by the mysql client instead of `;` to end a statement,
+    /// `\G` printing the result vertically.
+    pub fn vertical_terminator(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
+        tag_no_case("\\G")(i)
+    }
+
+    /// `DEFINER = user`, kept as written, e.g. `` `root`@`localhost` ``
+    pub(crate) fn definer(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+        map(
+            preceded(
+                pair(tag_no_case("DEFINER"), Self::ws_sep_equals),
+                take_while1(|c: char| !c.is_whitespace()),
+            ),
+            String::from,
+        )(i)
+    }
+
+    /// The rest of the statement as raw text, up to the first `;` outside
+    /// quoted strings, identifiers and comments, e.g. the body of a stored
+    /// routine or event.
+    pub(crate) fn statement_body(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+        let end = Self::statement_ends(i).first().copied().unwrap_or(i.len());
+        let body = i[..end].trim_end();
+        let body = [";", "\\G", "\\g"]
+            .iter()
+            .find_map(|terminator| body.strip_suffix(terminator))
+            .unwrap_or(body)
+            .trim_end();
+        if body.is_empty() {
+            return Err(nom::Err::Error(ParseSQLError {
+                errors: vec![(i, ParseSQLErrorKind::Context("missing statement body"))],
+            }));
+        }
+        Ok((&i[body.len()..], String::from(body)))
+    }
+
+    /// A `BEGIN ... END` compound statement as raw text, e.g. the body of a
+    /// stored routine or event, up to the `END` matching its `BEGIN`. Nested
+    /// `BEGIN` and `CASE` blocks are balanced, `END IF`, `END LOOP`,
+    /// `END REPEAT` and `END WHILE` close none of them, and quoted strings and
+    /// comments are skipped.
+    pub(crate) fn begin_end_block(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
+        let error = |kind| {
+            Err(nom::Err::Error(ParseSQLError {
+                errors: vec![(i, ParseSQLErrorKind::Context(kind))],
+            }))
+        };
+        let words = Self::words(i);
+        let keyword = |(start, end): (usize, usize)| i[start..end].to_ascii_uppercase();
+        match words.first() {
+            Some(&(0, end)) if keyword((0, end)) == "BEGIN" => {}
+            _ => return error("expected BEGIN"),
+        }
+        let mut blocks = 0usize;
+        let mut words = words.into_iter().peekable();
+        while let Some(word) = words.next() {
+            match keyword(word).as_str() {
+                "BEGIN" | "CASE" => blocks += 1,
+                "END" => {
+                    let mut end = word.1;
+                    let closes = words
+                        .peek()
+                        .filter(|next| i[end..next.0].trim().is_empty())
+                        .map(|&next| (next, keyword(next)));
+                    match closes {
+                        Some((_, ref kw))
+                            if kw == "IF" || kw == "LOOP" || kw == "REPEAT" || kw == "WHILE" =>
+                        {
+                            words.next();
+                            continue;
+                        }
+                        Some((next, ref kw)) if kw == "CASE" => {
+                            words.next();
+                            end = next.1;
+                        }
+                        _ => {}
+                    }
+                    blocks -= 1;
+                    if blocks == 0 {
+                        return Ok((&i[end..], &i[..end]));
+                    }
+                }
+                _ => {}
+            }
+        }
+        error("unterminated BEGIN ... END block")
+    }
+
+    /// Offsets of the words outside quoted strings, identifiers and comments.
+    fn words(i: &str) -> Vec<(usize, usize)> {
+        let mut words = Vec::new();
+        let mut word_start = None;
+        let mut quote = None;
+        let mut chars = i.char_indices().peekable();
+        while let Some((idx, c)) = chars.next() {
+            match quote {
+                Some(q) if c == q => quote = None,
+                Some(q) if c == '\\' && q != '`' => {
+                    chars.next();
+                }
+                Some(_) => {}
+                None if c.is_alphanumeric() || c == '_' || c == '$' => {
+                    word_start.get_or_insert(idx);
+                }
+                None => {
+                    if let Some(start) = word_start.take() {
+                        words.push((start, idx));
+                    }
+                    let comment_end = match c {
+                        '\'' | '"' | '`' => {
+                            quote = Some(c);
+                            None
+                        }
+                        '#' => Some(i[idx..].find('\n').map_or(i.len(), |n| idx + n)),
+                        '-' if i[idx..].starts_with("-- ") => {
+                            Some(i[idx..].find('\n').map_or(i.len(), |n| idx + n))
+                        }
+                        '/' if i[idx..].starts_with("/*") => {
+                            Some(i[idx + 2..].find("*/").map_or(i.len(), |n| idx + n + 4))
+                        }
+                        _ => None,
+                    };
+                    if let Some(comment_end) = comment_end {
+                        while chars.next_if(|&(next, _)| next < comment_end).is_some() {}
+                    }
+                }
+            }
+        }
+        if let Some(start) = word_start {
+            words.push((start, i.len()));
+        }
+        words
+    }
+
     // Parse rule for AS-based aliases for SQL entities.
-    pub fn as_alias(i: &str) -> IResult<&str, &str, ParseSQLError<&str>> {
+    pub fn as_alias(i: &str) -> IResult<&str, Cow<'_, str>, ParseSQLError<&str>> {
         map(
             tuple((
                 multispace1,
@@ -429,7 +889,7 @@ impl CommonParser {
     ) -> IResult<&str, String, ParseSQLError<&str>> {
         alt((
             map(
-                tuple((tag_no_case(key.as_str()), multispace1, Self::sql_identifier)),
+                tuple((tag_no_case(key.as_str()), multispace1, digit1)),
                 |(_, _, value)| String::from(value),
             ),
             map(
@@ -475,11 +935,33 @@ impl CommonParser {
 
 #[cfg(test)]
 mod tests {
+    use std::borrow::Cow;
+
     use nom::bytes::complete::tag;
     use nom::IResult;
 
+    use base::common_parser::SQL_KEYWORDS;
     use base::CommonParser;
 
+    #[test]
+    fn sql_keywords_are_sorted() {
+        assert!(SQL_KEYWORDS.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn parse_sql_keyword() {
+        let parts = ["select ", "Current_Timestamp", "WHERE(", "in;", "order,"];
+        for part in parts.iter() {
+            assert!(CommonParser::sql_keyword(part).is_ok());
+        }
+
+        let not_keywords = ["selected", "index_name", "in.x", "orders ", "current_dated"];
+        for part in not_keywords.iter() {
+            assert!(CommonParser::sql_keyword(part).is_err());
+        }
+        assert_eq!(CommonParser::sql_keyword("ASC b"), Ok((" b", "ASC")));
+    }
+
     #[test]
     fn parse_sql_identifiers() {
         let id1 = "foo";
@@ -497,6 +979,23 @@ mod tests {
         assert!(CommonParser::sql_identifier(id6).is_ok());
     }
 
+    #[test]
+    fn parse_backquoted_identifier_with_escaped_backquote() {
+        assert_eq!(
+            CommonParser::sql_identifier("`a``b` c"),
+            Ok((" c", Cow::Owned("a`b".to_string())))
+        );
+        assert_eq!(
+            CommonParser::sql_identifier("````"),
+            Ok(("", Cow::Owned("`".to_string())))
+        );
+        assert_eq!(
+            CommonParser::sql_identifier("`a b`"),
+            Ok(("", Cow::Borrowed("a b")))
+        );
+        assert!(CommonParser::sql_identifier("``").is_err());
+    }
+
     fn test_opt_delimited_fn_call(i: &str) -> IResult<&str, &str> {
         CommonParser::opt_delimited(tag("("), tag("abc"), tag(")"))(i)
     }
@@ -524,4 +1023,74 @@ mod tests {
         let res = CommonParser::statement_terminator("   ;  ");
         assert_eq!(res, Ok(("", ())));
     }
+
+    #[test]
+    fn strip_comments() {
+        let res = CommonParser::strip_comments("UPDATE /*+ NO_MERGE(t) */ t SET a='/* x */'");
+        assert_eq!(res, "UPDATE   t SET a='/* x */'");
+        let res = CommonParser::strip_comments("SELECT `a/*b`, 'it''s', \"\\\"/*\" FROM/**/t");
+        assert_eq!(res, "SELECT `a/*b`, 'it''s', \"\\\"/*\" FROM t");
+        let res = CommonParser::strip_comments("SELECT a /* unterminated");
+        assert_eq!(res, "SELECT a /* unterminated");
+        let res = CommonParser::strip_comments("SELECT /*! STRAIGHT_JOIN */ a, /*!50100 '*/' */ b");
+        assert_eq!(res, "SELECT   STRAIGHT_JOIN   a,   '*/'   b");
+    }
+
+    #[test]
+    fn begin_end_block() {
+        let body = "BEGIN\n  IF a > 0 THEN\n    SET b = CASE WHEN a > 1 THEN 'end' END;\n  \
+            END IF;\n  -- END of the IF\n  BEGIN SELECT 1; END;\nEND";
+        let str = format!("{}; SELECT 2", body);
+        let res = CommonParser::begin_end_block(&str);
+        assert_eq!(res.unwrap(), ("; SELECT 2", body));
+
+        let res = CommonParser::begin_end_block("begin case x when 1 then select 1; end case; end");
+        assert_eq!(
+            res.unwrap(),
+            ("", "begin case x when 1 then select 1; end case; end")
+        );
+
+        assert!(CommonParser::begin_end_block("BEGIN SELECT 1; END IF;").is_err());
+        assert!(CommonParser::begin_end_block("BEGINNING END").is_err());
+        assert!(CommonParser::begin_end_block("SELECT 1").is_err());
+    }
+
+    #[test]
+    fn blank_comments() {
+        let sql = "UPDATE /*+ NO_MERGE(t) */ t SET a='/* x */'";
+        let res = CommonParser::blank_comments(sql);
+        assert_eq!(res, format!("UPDATE {} t SET a='/* x */'", " ".repeat(18)));
+        assert_eq!(res.len(), sql.len());
+
+        let sql = "SELECT /*!50100 a */ FROM t";
+        let res = CommonParser::blank_comments(sql);
+        assert_eq!(res, format!("SELECT {} a {} FROM t", " ".repeat(8), "  "));
+        assert_eq!(res.len(), sql.len());
+    }
+
+    #[test]
+    fn statement_ends() {
+        assert_eq!(CommonParser::statement_ends(""), Vec::<usize>::new());
+        assert_eq!(CommonParser::statement_ends("SELECT 1;\n"), vec![9]);
+        assert_eq!(
+            CommonParser::statement_ends("SELECT ';'; SELECT `;`"),
+            vec![11, 22]
+        );
+        assert_eq!(
+            CommonParser::statement_ends("SELECT 1 -- ;\n/* ; */ # ;\n;"),
+            vec![27]
+        );
+    }
+
+    #[test]
+    fn statement_body() {
+        let res = CommonParser::statement_body("RETURN ';' /* ; */; SELECT 2");
+        assert_eq!(
+            res.unwrap(),
+            ("; SELECT 2", "RETURN ';' /* ; */".to_string())
+        );
+        let res = CommonParser::statement_body("DELETE FROM t \\G");
+        assert_eq!(res.unwrap(), (" \\G", "DELETE FROM t".to_string()));
+        assert!(CommonParser::statement_body(" ;").is_err());
+    }
 }
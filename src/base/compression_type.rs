@@ -19,9 +19,9 @@ pub enum CompressionType {
 impl Display for CompressionType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            CompressionType::ZLIB => write!(f, "COMPRESSION 'ZLIB'"),
-            CompressionType::LZ4 => write!(f, "COMPRESSION 'LZ4'"),
-            CompressionType::NONE => write!(f, "COMPRESSION 'NONE'"),
+            CompressionType::ZLIB => write_sql!(f, "COMPRESSION {}", "'ZLIB'"),
+            CompressionType::LZ4 => write_sql!(f, "COMPRESSION {}", "'LZ4'"),
+            CompressionType::NONE => write_sql!(f, "COMPRESSION {}", "'NONE'"),
         }
     }
 }
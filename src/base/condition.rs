@@ -6,29 +6,34 @@ use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{multispace0, multispace1};
 use nom::combinator::{map, opt};
+use nom::multi::{many0, many1, separated_list1};
 use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
 
 use base::arithmetic::ArithmeticExpression;
 use base::column::Column;
-use base::error::ParseSQLError;
-use base::{Literal, Operator};
+use base::error::{ParseSQLError, ParseSQLErrorKind};
+use base::visit::ContainsWindowFunction;
+use base::{CommonParser, Literal, Operator};
 use dms::{BetweenAndClause, SelectStatement};
+use parser::ParseConfig;
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum ConditionBase {
     Field(Column),
     Literal(Literal),
     LiteralList(Vec<Literal>),
+    /// value list of `IN (...)` that is not made of literals only, e.g. `(1, col, NOW())`
+    ExpressionList(Vec<ConditionExpression>),
     NestedSelect(Box<SelectStatement>),
 }
 
 impl fmt::Display for ConditionBase {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ConditionBase::Field(ref col) => write!(f, "{}", col),
-            ConditionBase::Literal(ref literal) => write!(f, "{}", literal),
-            ConditionBase::LiteralList(ref ll) => write!(
+            ConditionBase::Field(ref col) => write_sql!(f, "{}", col),
+            ConditionBase::Literal(ref literal) => write_sql!(f, "{}", literal),
+            ConditionBase::LiteralList(ref ll) => write_sql!(
                 f,
                 "({})",
                 ll.iter()
@@ -36,7 +41,16 @@ impl fmt::Display for ConditionBase {
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
-            ConditionBase::NestedSelect(ref select) => write!(f, "{}", select),
+            ConditionBase::ExpressionList(ref exprs) => write_sql!(
+                f,
+                "({})",
+                exprs
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            ConditionBase::NestedSelect(ref select) => write_sql!(f, "({})", select),
         }
     }
 }
@@ -51,23 +65,20 @@ pub struct ConditionTree {
 impl<'a> ConditionTree {
     pub fn contained_columns(&'a self) -> HashSet<&'a Column> {
         let mut s = HashSet::new();
-        let mut q = VecDeque::<&'a ConditionTree>::new();
-        q.push_back(self);
-        while let Some(ct) = q.pop_front() {
-            match *ct.left.as_ref() {
+        let mut q = VecDeque::<&'a ConditionExpression>::new();
+        q.push_back(&self.left);
+        q.push_back(&self.right);
+        while let Some(expr) = q.pop_front() {
+            match *expr {
                 ConditionExpression::Base(ConditionBase::Field(ref c)) => {
                     s.insert(c);
                 }
                 ConditionExpression::LogicalOp(ref ct)
-                | ConditionExpression::ComparisonOp(ref ct) => q.push_back(ct),
-                _ => (),
-            }
-            match *ct.right.as_ref() {
-                ConditionExpression::Base(ConditionBase::Field(ref c)) => {
-                    s.insert(c);
+                | ConditionExpression::ComparisonOp(ref ct) => {
+                    q.push_back(&ct.left);
+                    q.push_back(&ct.right);
                 }
-                ConditionExpression::LogicalOp(ref ct)
-                | ConditionExpression::ComparisonOp(ref ct) => q.push_back(ct),
+                ConditionExpression::LogicalChain(_, ref operands) => q.extend(operands),
                 _ => (),
             }
         }
@@ -77,9 +88,40 @@ impl<'a> ConditionTree {
 
 impl fmt::Display for ConditionTree {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.left)?;
-        write!(f, " {} ", self.operator)?;
-        write!(f, "{}", self.right)
+        let (left, right) = match self.operator {
+            Operator::Or => (OR_PRECEDENCE, OR_PRECEDENCE),
+            Operator::And => (AND_PRECEDENCE, AND_PRECEDENCE),
+            // comparisons are left-associative
+            _ => (COMPARISON_PRECEDENCE, OPERAND_PRECEDENCE),
+        };
+        write_sql!(f, "{}", Operand(&self.left, left))?;
+        write_sql!(f, " {} ", self.operator)?;
+        write_sql!(f, "{}", Operand(&self.right, right))
+    }
+}
+
+const OR_PRECEDENCE: u8 = 1;
+const AND_PRECEDENCE: u8 = 2;
+const NOT_PRECEDENCE: u8 = 3;
+const BETWEEN_PRECEDENCE: u8 = 4;
+const COMPARISON_PRECEDENCE: u8 = 5;
+const OPERAND_PRECEDENCE: u8 = 6;
+
+/// [fmt::Display] of an operand that needs at least the given precedence to
+/// be written without parentheses. [ConditionExpression::Bracketed] only
+/// records the parentheses of the input, so they are dropped where they do
+/// not change the meaning, e.g. `((a = 1))` is written as `a = 1` and
+/// `(a OR b) AND c` keeps its parentheses.
+struct Operand<'a>(&'a ConditionExpression, u8);
+
+impl<'a> fmt::Display for Operand<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let expr = self.0.without_brackets();
+        if expr.precedence() < self.1 {
+            write_sql!(f, "({})", expr)
+        } else {
+            write_sql!(f, "{}", expr)
+        }
     }
 }
 
@@ -88,12 +130,21 @@ impl fmt::Display for ConditionTree {
 pub enum ConditionExpression {
     ComparisonOp(ConditionTree),
     LogicalOp(ConditionTree),
+    /// `expr op expr op expr ...` with a single `AND` or `OR` operator, built
+    /// instead of nested [ConditionExpression::LogicalOp]s when
+    /// [crate::ParseConfig::flatten_logical_ops] is set
+    LogicalChain(Operator, Vec<ConditionExpression>),
     NegationOp(Box<ConditionExpression>),
     ExistsOp(Box<SelectStatement>),
     Base(ConditionBase),
     Arithmetic(Box<ArithmeticExpression>),
+    /// parentheses of the input, written back only where they change the meaning
     Bracketed(Box<ConditionExpression>),
-    BetweenAnd(BetweenAndClause),
+    BetweenAnd(Box<BetweenAndClause>),
+    /// `BINARY expr`
+    Binary(Box<ConditionExpression>),
+    /// `ROW(expr, expr [, expr] ...)`
+    Row(Vec<ConditionExpression>),
 }
 
 impl ConditionExpression {
@@ -104,6 +155,11 @@ impl ConditionExpression {
             multispace1,
             Self::condition_expr,
         ))(i)?;
+        Self::reject_window_function(
+            i,
+            &where_condition,
+            "window functions are not allowed in WHERE",
+        )?;
 
         Ok((remaining_input, where_condition))
     }
@@ -115,47 +171,81 @@ impl ConditionExpression {
             multispace1,
             Self::condition_expr,
         ))(i)?;
+        Self::reject_window_function(i, &ce, "window functions are not allowed in HAVING")?;
 
         Ok((remaining_input, ce))
     }
 
+    /// MySQL only allows window functions in the select list and `ORDER BY`,
+    /// so fail with `message` when `expr` holds one.
+    pub(crate) fn reject_window_function<'a, T: ContainsWindowFunction>(
+        i: &'a str,
+        expr: &T,
+        message: &'static str,
+    ) -> Result<(), nom::Err<ParseSQLError<&'a str>>> {
+        if expr.contains_window_function() {
+            return Err(nom::Err::Failure(ParseSQLError {
+                errors: vec![(i, ParseSQLErrorKind::Context(message))],
+            }));
+        }
+        Ok(())
+    }
+
     // Parse a conditional expression into a condition tree structure
     pub fn condition_expr(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
-        let cond = map(
-            separated_pair(
-                Self::and_expr,
-                delimited(multispace0, tag_no_case("OR"), multispace1),
-                Self::condition_expr,
-            ),
-            |p| {
-                ConditionExpression::LogicalOp(ConditionTree {
-                    operator: Operator::Or,
-                    left: Box::new(p.0),
-                    right: Box::new(p.1),
-                })
-            },
-        );
-
-        alt((cond, Self::and_expr))(i)
+        CommonParser::nested(|i| Self::logical_chain(i, Operator::Or, "OR", Self::and_expr))(i)
     }
 
     fn and_expr(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
-        let cond = map(
-            separated_pair(
-                Self::parenthetical_expr,
-                delimited(multispace0, tag_no_case("AND"), multispace1),
-                Self::and_expr,
-            ),
-            |p| {
-                ConditionExpression::LogicalOp(ConditionTree {
-                    operator: Operator::And,
-                    left: Box::new(p.0),
-                    right: Box::new(p.1),
-                })
-            },
+        Self::logical_chain(i, Operator::And, "AND", Self::parenthetical_expr)
+    }
+
+    /// `operand [keyword operand] ...`, which nests one level per operand
+    /// unless flattened into a [ConditionExpression::LogicalChain]
+    fn logical_chain<'a>(
+        i: &'a str,
+        operator: Operator,
+        keyword: &'static str,
+        operand: fn(&str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>>,
+    ) -> IResult<&'a str, ConditionExpression, ParseSQLError<&'a str>> {
+        let rest = preceded(
+            delimited(multispace0, tag_no_case(keyword), multispace1),
+            operand,
         );
+        let (remaining_input, (first, rest)) =
+            if ParseConfig::with_current(|config| config.flatten_logical_ops) {
+                pair(operand, many0(rest))(i)?
+            } else {
+                CommonParser::chain(operand, rest)(i)?
+            };
+        Ok((
+            remaining_input,
+            Self::fold_logical_op(operator, first, rest),
+        ))
+    }
 
-        alt((cond, Self::parenthetical_expr))(i)
+    /// Build a right-nested tree from `first op rest[0] op rest[1] ...`, so every
+    /// operand is parsed only once instead of being re-parsed on backtracking.
+    /// With flattening on, build a single [ConditionExpression::LogicalChain].
+    fn fold_logical_op(
+        operator: Operator,
+        first: ConditionExpression,
+        rest: Vec<ConditionExpression>,
+    ) -> ConditionExpression {
+        let mut operands = rest;
+        operands.insert(0, first);
+        if operands.len() > 1 && ParseConfig::with_current(|config| config.flatten_logical_ops) {
+            return ConditionExpression::LogicalChain(operator, operands);
+        }
+        let mut tree = operands.pop().unwrap();
+        while let Some(left) = operands.pop() {
+            tree = ConditionExpression::LogicalOp(ConditionTree {
+                operator: operator.clone(),
+                left: Box::new(left),
+                right: Box::new(tree),
+            });
+        }
+        tree
     }
 
     fn parenthetical_expr_helper(
@@ -199,15 +289,20 @@ impl ConditionExpression {
         ))(i)
     }
 
+    /// A run of `NOT`s is collected in a loop rather than by recursion, so a
+    /// long run does not exhaust the stack while parsing.
     fn not_expr(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
         alt((
-            map(
-                preceded(
-                    pair(tag_no_case("NOT"), multispace1),
-                    Self::parenthetical_expr,
-                ),
-                |right| ConditionExpression::NegationOp(Box::new(right)),
-            ),
+            |i| {
+                let (remaining_input, nots) =
+                    many1(terminated(tag_no_case("NOT"), multispace1))(i)?;
+                let (remaining_input, right) =
+                    CommonParser::deeper(nots.len(), Self::parenthetical_expr)(remaining_input)?;
+                let negated = nots.iter().fold(right, |expr, _| {
+                    ConditionExpression::NegationOp(Box::new(expr))
+                });
+                Ok((remaining_input, negated))
+            },
             Self::boolean_primary,
         ))(i)
     }
@@ -221,15 +316,13 @@ impl ConditionExpression {
             tag_no_case("NULL"),
         ))(i)?;
 
-        // XXX(malte): bit of a hack; would consumers ever need to know
-        // about "IS NULL" vs. "= NULL"?
         Ok((
             remaining_input,
             (
                 if not.is_some() {
-                    Operator::NotEqual
+                    Operator::IsNot
                 } else {
-                    Operator::Equal
+                    Operator::Is
                 },
                 ConditionExpression::Base(ConditionBase::Literal(Literal::Null)),
             ),
@@ -251,6 +344,14 @@ impl ConditionExpression {
                     map(delimited(tag("("), Literal::value_list, tag(")")), |vs| {
                         ConditionBase::LiteralList(vs)
                     }),
+                    map(
+                        delimited(
+                            pair(tag("("), multispace0),
+                            separated_list1(CommonParser::ws_sep_comma, Self::simple_expr),
+                            pair(multispace0, tag(")")),
+                        ),
+                        ConditionBase::ExpressionList,
+                    ),
                 )),
             ),
             |p| {
@@ -274,20 +375,24 @@ impl ConditionExpression {
         ))(i)
     }
 
+    /// Comparisons are left-associative as in MySQL, so `a < b < c` parses
+    /// as `(a < b) < c`.
     fn boolean_primary(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
-        alt((
-            map(
-                separated_pair(Self::predicate, multispace0, Self::boolean_primary_rest),
-                |e: (ConditionExpression, (Operator, ConditionExpression))| {
+        map(
+            CommonParser::chain(
+                Self::predicate,
+                preceded(multispace0, Self::boolean_primary_rest),
+            ),
+            |(first, rest)| {
+                rest.into_iter().fold(first, |left, (operator, right)| {
                     ConditionExpression::ComparisonOp(ConditionTree {
-                        operator: (e.1).0,
-                        left: Box::new(e.0),
-                        right: Box::new((e.1).1),
+                        operator,
+                        left: Box::new(left),
+                        right: Box::new(right),
                     })
-                },
-            ),
-            Self::predicate,
-        ))(i)
+                })
+            },
+        )(i)
     }
 
     fn predicate(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
@@ -316,6 +421,8 @@ impl ConditionExpression {
 
     pub fn simple_expr(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
         let simple_expr = alt((
+            Self::binary_expr,
+            map(Self::row_constructor, ConditionExpression::Row),
             map(
                 delimited(
                     terminated(tag("("), multispace0),
@@ -346,24 +453,104 @@ impl ConditionExpression {
         alt((Self::between_and, simple_expr))(i)
     }
 
+    fn binary_expr(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
+        map(
+            preceded(pair(tag_no_case("BINARY"), multispace1), Self::simple_expr),
+            |expr| ConditionExpression::Binary(Box::new(expr)),
+        )(i)
+    }
+
+    /// parse `ROW(expr, expr [, expr] ...)`
+    pub fn row_constructor(
+        i: &str,
+    ) -> IResult<&str, Vec<ConditionExpression>, ParseSQLError<&str>> {
+        preceded(
+            pair(tag_no_case("ROW"), multispace0),
+            delimited(
+                pair(tag("("), multispace0),
+                separated_list1(CommonParser::ws_sep_comma, Self::simple_expr),
+                pair(multispace0, tag(")")),
+            ),
+        )(i)
+    }
+
     fn between_and(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
         map(BetweenAndClause::parse, |x| {
-            ConditionExpression::BetweenAnd(x)
+            ConditionExpression::BetweenAnd(Box::new(x))
         })(i)
     }
+
+    /// The expression inside any number of parentheses.
+    fn without_brackets(&self) -> &ConditionExpression {
+        let mut expr = self;
+        while let ConditionExpression::Bracketed(ref inner) = *expr {
+            expr = inner;
+        }
+        expr
+    }
+
+    /// How tightly the expression binds when written without parentheses,
+    /// from `OR` to the operands of comparisons.
+    fn precedence(&self) -> u8 {
+        match *self {
+            ConditionExpression::LogicalOp(ConditionTree {
+                operator: Operator::Or,
+                ..
+            })
+            | ConditionExpression::LogicalChain(Operator::Or, _) => OR_PRECEDENCE,
+            ConditionExpression::LogicalOp(_) | ConditionExpression::LogicalChain(..) => {
+                AND_PRECEDENCE
+            }
+            ConditionExpression::NegationOp(_) => NOT_PRECEDENCE,
+            ConditionExpression::BetweenAnd(_) => BETWEEN_PRECEDENCE,
+            ConditionExpression::ComparisonOp(_) => COMPARISON_PRECEDENCE,
+            ConditionExpression::Bracketed(ref inner) => inner.precedence(),
+            _ => OPERAND_PRECEDENCE,
+        }
+    }
 }
 
 impl fmt::Display for ConditionExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ConditionExpression::ComparisonOp(ref tree) => write!(f, "{}", tree),
-            ConditionExpression::LogicalOp(ref tree) => write!(f, "{}", tree),
-            ConditionExpression::NegationOp(ref expr) => write!(f, "NOT {}", expr),
-            ConditionExpression::ExistsOp(ref expr) => write!(f, "EXISTS {}", expr),
-            ConditionExpression::Bracketed(ref expr) => write!(f, "({})", expr),
-            ConditionExpression::Base(ref base) => write!(f, "{}", base),
-            ConditionExpression::Arithmetic(ref expr) => write!(f, "{}", expr),
-            ConditionExpression::BetweenAnd(ref expr) => write!(f, "{}", expr),
+            ConditionExpression::ComparisonOp(ref tree) => write_sql!(f, "{}", tree),
+            ConditionExpression::LogicalOp(ref tree) => write_sql!(f, "{}", tree),
+            ConditionExpression::LogicalChain(ref operator, ref operands) => {
+                let precedence = match *operator {
+                    Operator::Or => OR_PRECEDENCE,
+                    _ => AND_PRECEDENCE,
+                };
+                write_sql!(
+                    f,
+                    "{}",
+                    operands
+                        .iter()
+                        .map(|e| Operand(e, precedence).to_string())
+                        .collect::<Vec<_>>()
+                        .join(&format!(" {} ", operator))
+                )
+            }
+            ConditionExpression::NegationOp(ref expr) => {
+                write_sql!(f, "NOT {}", Operand(expr, NOT_PRECEDENCE))
+            }
+            ConditionExpression::ExistsOp(ref expr) => write_sql!(f, "EXISTS ({})", expr),
+            // a whole condition, e.g. of `WHERE`, needs no parentheses
+            ConditionExpression::Bracketed(ref expr) => write_sql!(f, "{}", expr),
+            ConditionExpression::Base(ref base) => write_sql!(f, "{}", base),
+            ConditionExpression::Arithmetic(ref expr) => write_sql!(f, "{}", expr),
+            ConditionExpression::BetweenAnd(ref expr) => write_sql!(f, "{}", expr),
+            ConditionExpression::Binary(ref expr) => {
+                write_sql!(f, "BINARY {}", Operand(expr, OPERAND_PRECEDENCE))
+            }
+            ConditionExpression::Row(ref exprs) => write_sql!(
+                f,
+                "ROW({})",
+                exprs
+                    .iter()
+                    .map(|e| Operand(e, OPERAND_PRECEDENCE).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
         }
     }
 }
@@ -371,7 +558,8 @@ impl fmt::Display for ConditionExpression {
 #[cfg(test)]
 mod tests {
     use base::arithmetic::{ArithmeticBase, ArithmeticExpression, ArithmeticOperator};
-    use base::condition::ConditionBase::{Field, LiteralList, NestedSelect};
+    use base::column::{FunctionArguments, FunctionExpression};
+    use base::condition::ConditionBase::{ExpressionList, Field, LiteralList, NestedSelect};
     use base::condition::ConditionExpression::{
         Base, Bracketed, ComparisonOp, LogicalOp, NegationOp,
     };
@@ -750,7 +938,7 @@ mod tests {
         let res = ConditionExpression::condition_expr(cond);
 
         let nested_select = Box::new(SelectStatement {
-            tables: vec![Table::from("foo")],
+            tables: vec![Table::from("foo").into()],
             fields: FieldDefinitionExpression::from_column_str(&["col"]),
             ..Default::default()
         });
@@ -774,7 +962,7 @@ mod tests {
         let res = ConditionExpression::condition_expr(cond);
 
         let nested_select = Box::new(SelectStatement {
-            tables: vec![Table::from("foo")],
+            tables: vec![Table::from("foo").into()],
             fields: FieldDefinitionExpression::from_column_str(&["col"]),
             ..Default::default()
         });
@@ -794,7 +982,7 @@ mod tests {
         let res = ConditionExpression::condition_expr(cond);
 
         let nested_select = Box::new(SelectStatement {
-            tables: vec![Table::from("foo")],
+            tables: vec![Table::from("foo").into()],
             fields: FieldDefinitionExpression::from_column_str(&["col"]),
             ..Default::default()
         });
@@ -815,7 +1003,7 @@ mod tests {
         let res = ConditionExpression::condition_expr(cond);
 
         let nested_select = Box::new(SelectStatement {
-            tables: vec![Table::from("PaperConflict")],
+            tables: vec![Table::from("PaperConflict").into()],
             fields: FieldDefinitionExpression::from_column_str(&["paperId"]),
             ..Default::default()
         });
@@ -864,7 +1052,7 @@ mod tests {
 
         let res = ConditionExpression::condition_expr(cond);
         let expected = flat_condition_tree(
-            Operator::Equal,
+            Operator::Is,
             Field("bar".into()),
             ConditionBase::Literal(Literal::Null),
         );
@@ -874,11 +1062,22 @@ mod tests {
 
         let res = ConditionExpression::condition_expr(cond);
         let expected = flat_condition_tree(
-            Operator::NotEqual,
+            Operator::IsNot,
             Field("bar".into()),
             ConditionBase::Literal(Literal::Null),
         );
         assert_eq!(res.unwrap().1, expected);
+
+        // a comparison with NULL is not an `IS NULL` test
+        for cond in [
+            "bar IS NULL",
+            "bar = NULL",
+            "bar IS NOT NULL",
+            "bar != NULL",
+        ] {
+            let res = ConditionExpression::condition_expr(cond).unwrap().1;
+            assert_eq!(res.to_string(), cond);
+        }
     }
 
     #[test]
@@ -931,7 +1130,7 @@ mod tests {
                                     ConditionExpression::LogicalOp(ConditionTree {
                                         operator: Operator::And,
                                         left: Box::new(flat_condition_tree(
-                                            Operator::Equal,
+                                            Operator::Is,
                                             Field("parent_comments.user_id".into()),
                                             ConditionBase::Literal(Literal::Null),
                                         )),
@@ -950,7 +1149,7 @@ mod tests {
                                 ConditionExpression::LogicalOp(ConditionTree {
                                     operator: Operator::Or,
                                     left: Box::new(flat_condition_tree(
-                                        Operator::Equal,
+                                        Operator::Is,
                                         Field("parent_comments.id".into()),
                                         ConditionBase::Literal(Literal::Null),
                                     )),
@@ -993,4 +1192,225 @@ mod tests {
         let expected1 = "id NOT IN (1, 2)";
         assert_eq!(format!("{}", c1), expected1);
     }
+
+    #[test]
+    fn not_in_subquery_round_trip() {
+        use base::table::Table;
+
+        let qs = "a NOT IN (SELECT b FROM t)";
+        let res = ConditionExpression::condition_expr(qs).unwrap().1;
+        let nested_select = Box::new(SelectStatement {
+            tables: vec![Table::from("t").into()],
+            fields: FieldDefinitionExpression::from_column_str(&["b"]),
+            ..Default::default()
+        });
+        let expected = flat_condition_tree(
+            Operator::NotIn,
+            Field("a".into()),
+            NestedSelect(nested_select),
+        );
+        assert_eq!(res, expected);
+        assert_eq!(res.to_string(), qs);
+        assert_eq!(
+            ConditionExpression::condition_expr(&res.to_string())
+                .unwrap()
+                .1,
+            res
+        );
+    }
+
+    #[test]
+    fn in_mixed_expression_list() {
+        let qs1 = "a IN (1, b, NOW())";
+        let res1 = ConditionExpression::condition_expr(qs1);
+
+        let c1 = res1.unwrap().1;
+        let expected1 = flat_condition_tree(
+            Operator::In,
+            Field("a".into()),
+            ExpressionList(vec![
+                Base(ConditionBase::Literal(1.into())),
+                Base(Field("b".into())),
+                Base(Field(Column {
+                    name: "NOW()".to_string(),
+                    alias: None,
+                    table: None,
+                    schema: None,
+                    function: Some(Box::new(FunctionExpression::Generic(
+                        "NOW".to_string(),
+                        FunctionArguments { arguments: vec![] },
+                    ))),
+                })),
+            ]),
+        );
+        assert_eq!(c1, expected1);
+        assert_eq!(format!("{}", c1), qs1);
+    }
+
+    #[test]
+    fn binary_comparison() {
+        let qs1 = "BINARY name = 'X'";
+        let res1 = ConditionExpression::condition_expr(qs1);
+
+        let c1 = res1.unwrap().1;
+        let expected1 = ConditionExpression::ComparisonOp(ConditionTree {
+            operator: Operator::Equal,
+            left: Box::new(ConditionExpression::Binary(Box::new(Base(Field(
+                "name".into(),
+            ))))),
+            right: Box::new(Base(ConditionBase::Literal("X".into()))),
+        });
+        assert_eq!(c1, expected1);
+        assert_eq!(format!("{}", c1), qs1);
+    }
+
+    #[test]
+    fn row_comparison() {
+        let qs1 = "ROW(a, 1) = ROW(b, 2)";
+        let res1 = ConditionExpression::condition_expr(qs1);
+
+        let c1 = res1.unwrap().1;
+        let expected1 = ConditionExpression::ComparisonOp(ConditionTree {
+            operator: Operator::Equal,
+            left: Box::new(ConditionExpression::Row(vec![
+                Base(Field("a".into())),
+                Base(ConditionBase::Literal(1.into())),
+            ])),
+            right: Box::new(ConditionExpression::Row(vec![
+                Base(Field("b".into())),
+                Base(ConditionBase::Literal(2.into())),
+            ])),
+        });
+        assert_eq!(c1, expected1);
+        assert_eq!(format!("{}", c1), qs1);
+    }
+
+    #[test]
+    fn long_and_chain_is_right_nested() {
+        let qs = "a = 1 AND b = 2 AND c = 3";
+        let res = ConditionExpression::condition_expr(qs);
+
+        let a = flat_condition_tree(
+            Operator::Equal,
+            Field("a".into()),
+            ConditionBase::Literal(1.into()),
+        );
+        let b = flat_condition_tree(
+            Operator::Equal,
+            Field("b".into()),
+            ConditionBase::Literal(2.into()),
+        );
+        let c = flat_condition_tree(
+            Operator::Equal,
+            Field("c".into()),
+            ConditionBase::Literal(3.into()),
+        );
+        let expected = LogicalOp(ConditionTree {
+            operator: Operator::And,
+            left: Box::new(a),
+            right: Box::new(LogicalOp(ConditionTree {
+                operator: Operator::And,
+                left: Box::new(b),
+                right: Box::new(c),
+            })),
+        });
+        assert_eq!(res.unwrap().1, expected);
+    }
+
+    #[test]
+    fn comparison_chain_is_left_nested() {
+        let qs = "a < b < c";
+        let res = ConditionExpression::condition_expr(qs);
+
+        let expected = ComparisonOp(ConditionTree {
+            operator: Operator::Less,
+            left: Box::new(flat_condition_tree(
+                Operator::Less,
+                Field("a".into()),
+                Field("b".into()),
+            )),
+            right: Box::new(Base(Field("c".into()))),
+        });
+        let res = res.unwrap();
+        assert_eq!(res.0, "");
+        assert_eq!(res.1, expected);
+        assert_eq!(format!("{}", res.1), qs);
+
+        let qs = "a = 1 IS NULL";
+        let res = ConditionExpression::condition_expr(qs).unwrap().1;
+        match res {
+            ComparisonOp(ConditionTree { ref left, .. }) => {
+                assert!(matches!(**left, ComparisonOp(_)))
+            }
+            ref other => panic!("unexpected expression {:?}", other),
+        }
+        assert_eq!(format!("{}", res), qs);
+    }
+
+    #[test]
+    fn deeply_nested_brackets() {
+        let qs = (0..24).fold(String::from("a = 1"), |acc, _| {
+            format!("({} AND b = 2)", acc)
+        });
+        let res = ConditionExpression::condition_expr(&qs);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap().0, "");
+    }
+
+    #[test]
+    fn redundant_brackets_are_dropped() {
+        let cases = [
+            ("(a=1)", "a = 1"),
+            ("((a = 1))", "a = 1"),
+            ("(a) = 1", "a = 1"),
+            ("(a + 1) = 2", "a + 1 = 2"),
+            ("((a = 1) AND (b = 2)) OR c = 3", "a = 1 AND b = 2 OR c = 3"),
+            ("a = 1 OR (b = 2 OR (c = 3))", "a = 1 OR b = 2 OR c = 3"),
+            ("NOT (a = 1)", "NOT a = 1"),
+            ("(a IS NULL) AND (NOT (b = 1))", "a IS NULL AND NOT b = 1"),
+            // necessary brackets are kept
+            ("((a = 1 OR b = 2)) AND c = 3", "(a = 1 OR b = 2) AND c = 3"),
+            ("a = 1 AND (b = 2 OR c = 3)", "a = 1 AND (b = 2 OR c = 3)"),
+            ("NOT ((a = 1 AND b = 2))", "NOT (a = 1 AND b = 2)"),
+            (
+                "(a BETWEEN 1 AND 2) AND b = 1",
+                "a BETWEEN 1 AND 2 AND b = 1",
+            ),
+            ("(a BETWEEN 1 AND 2) = 1", "(a BETWEEN 1 AND 2) = 1"),
+        ];
+        for (redundant, canonical) in cases {
+            let res = ConditionExpression::condition_expr(redundant).unwrap();
+            assert_eq!(res.0, "");
+            assert_eq!(res.1.to_string(), canonical);
+            let original = unbracketed(&res.1);
+
+            let res = ConditionExpression::condition_expr(canonical).unwrap();
+            assert_eq!(res.0, "");
+            assert_eq!(res.1.to_string(), canonical);
+            assert_eq!(unbracketed(&res.1), original, "{}", canonical);
+        }
+    }
+
+    /// `expr` without the parentheses recorded from the input, which
+    /// [ConditionExpression::Bracketed] keeps even where they are redundant
+    fn unbracketed(expr: &ConditionExpression) -> ConditionExpression {
+        let tree = |tree: &ConditionTree| ConditionTree {
+            operator: tree.operator.clone(),
+            left: Box::new(unbracketed(&tree.left)),
+            right: Box::new(unbracketed(&tree.right)),
+        };
+        match *expr {
+            Bracketed(ref inner) => unbracketed(inner),
+            ComparisonOp(ref t) => ComparisonOp(tree(t)),
+            LogicalOp(ref t) => LogicalOp(tree(t)),
+            NegationOp(ref inner) => NegationOp(Box::new(unbracketed(inner))),
+            ConditionExpression::LogicalChain(ref operator, ref operands) => {
+                ConditionExpression::LogicalChain(
+                    operator.clone(),
+                    operands.iter().map(unbracketed).collect(),
+                )
+            }
+            ref other => other.clone(),
+        }
+    }
 }
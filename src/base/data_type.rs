@@ -3,8 +3,8 @@ use std::str::FromStr;
 
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case};
-use nom::character::complete::multispace0;
-use nom::combinator::{map, opt};
+use nom::character::complete::{digit1, multispace0};
+use nom::combinator::{map, map_res, opt};
 use nom::sequence::{delimited, preceded, terminated, tuple};
 use nom::IResult;
 
@@ -40,6 +40,7 @@ pub enum DataType {
     Timestamp,
     Binary(u16),
     Varbinary(u16),
+    Bit(u16),
     Enum(Vec<Literal>),
     Decimal(u8, u8),
 }
@@ -47,35 +48,44 @@ pub enum DataType {
 impl fmt::Display for DataType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            DataType::Bool => write!(f, "BOOL"),
-            DataType::Char(len) => write!(f, "CHAR({})", len),
-            DataType::Varchar(len) => write!(f, "VARCHAR({})", len),
-            DataType::Int(len) => write!(f, "INT({})", len),
-            DataType::UnsignedInt(len) => write!(f, "INT({}) UNSIGNED", len),
-            DataType::Bigint(len) => write!(f, "BIGINT({})", len),
-            DataType::UnsignedBigint(len) => write!(f, "BIGINT({}) UNSIGNED", len),
-            DataType::Tinyint(len) => write!(f, "TINYINT({})", len),
-            DataType::UnsignedTinyint(len) => write!(f, "TINYINT({}) UNSIGNED", len),
-            DataType::Blob => write!(f, "BLOB"),
-            DataType::Longblob => write!(f, "LONGBLOB"),
-            DataType::Mediumblob => write!(f, "MEDIUMBLOB"),
-            DataType::Tinyblob => write!(f, "TINYBLOB"),
-            DataType::Double => write!(f, "DOUBLE"),
-            DataType::Float => write!(f, "FLOAT"),
-            DataType::Real => write!(f, "REAL"),
-            DataType::Tinytext => write!(f, "TINYTEXT"),
-            DataType::Mediumtext => write!(f, "MEDIUMTEXT"),
-            DataType::Longtext => write!(f, "LONGTEXT"),
-            DataType::Text => write!(f, "TEXT"),
-            DataType::Json => write!(f, "JSON"),
-            DataType::Uuid => write!(f, "UUID"),
-            DataType::Date => write!(f, "DATE"),
-            DataType::DateTime(len) => write!(f, "DATETIME({})", len),
-            DataType::Timestamp => write!(f, "TIMESTAMP"),
-            DataType::Binary(len) => write!(f, "BINARY({})", len),
-            DataType::Varbinary(len) => write!(f, "VARBINARY({})", len),
-            DataType::Enum(_) => write!(f, "ENUM(...)"),
-            DataType::Decimal(m, d) => write!(f, "DECIMAL({}, {})", m, d),
+            DataType::Bool => write_sql!(f, "BOOL"),
+            DataType::Char(len) => write_sql!(f, "CHAR({})", len),
+            DataType::Varchar(len) => write_sql!(f, "VARCHAR({})", len),
+            DataType::Int(len) => write_sql!(f, "INT({})", len),
+            DataType::UnsignedInt(len) => write_sql!(f, "INT({}) UNSIGNED", len),
+            DataType::Bigint(len) => write_sql!(f, "BIGINT({})", len),
+            DataType::UnsignedBigint(len) => write_sql!(f, "BIGINT({}) UNSIGNED", len),
+            DataType::Tinyint(len) => write_sql!(f, "TINYINT({})", len),
+            DataType::UnsignedTinyint(len) => write_sql!(f, "TINYINT({}) UNSIGNED", len),
+            DataType::Blob => write_sql!(f, "BLOB"),
+            DataType::Longblob => write_sql!(f, "LONGBLOB"),
+            DataType::Mediumblob => write_sql!(f, "MEDIUMBLOB"),
+            DataType::Tinyblob => write_sql!(f, "TINYBLOB"),
+            DataType::Double => write_sql!(f, "DOUBLE"),
+            DataType::Float => write_sql!(f, "FLOAT"),
+            DataType::Real => write_sql!(f, "REAL"),
+            DataType::Tinytext => write_sql!(f, "TINYTEXT"),
+            DataType::Mediumtext => write_sql!(f, "MEDIUMTEXT"),
+            DataType::Longtext => write_sql!(f, "LONGTEXT"),
+            DataType::Text => write_sql!(f, "TEXT"),
+            DataType::Json => write_sql!(f, "JSON"),
+            DataType::Uuid => write_sql!(f, "UUID"),
+            DataType::Date => write_sql!(f, "DATE"),
+            DataType::DateTime(len) => write_sql!(f, "DATETIME({})", len),
+            DataType::Timestamp => write_sql!(f, "TIMESTAMP"),
+            DataType::Binary(len) => write_sql!(f, "BINARY({})", len),
+            DataType::Varbinary(len) => write_sql!(f, "VARBINARY({})", len),
+            DataType::Bit(len) => write_sql!(f, "BIT({})", len),
+            DataType::Enum(ref values) => write_sql!(
+                f,
+                "ENUM({})",
+                values
+                    .iter()
+                    .map(|v| v.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            DataType::Decimal(m, d) => write_sql!(f, "DECIMAL({}, {})", m, d),
         }
     }
 }
@@ -100,20 +110,15 @@ impl DataType {
             map(
                 tuple((
                     tag_no_case("CHAR"),
-                    CommonParser::delim_digit,
+                    Self::delim_len,
                     multispace0,
                     opt(tag_no_case("BINARY")),
                 )),
-                |t| DataType::Char(Self::len_as_u16(t.1)),
+                |t| DataType::Char(t.1),
             ),
             map(
-                preceded(tag_no_case("DATETIME"), opt(CommonParser::delim_digit)),
-                |fsp| {
-                    DataType::DateTime(match fsp {
-                        Some(fsp) => Self::len_as_u16(fsp),
-                        None => 0,
-                    })
-                },
+                preceded(tag_no_case("DATETIME"), opt(Self::delim_len)),
+                |fsp| DataType::DateTime(fsp.unwrap_or(0)),
             ),
             map(tag_no_case("DATE"), |_| DataType::Date),
             map(
@@ -147,21 +152,17 @@ impl DataType {
             map(tag_no_case("JSON"), |_| DataType::Json),
             map(tag_no_case("UUID"), |_| DataType::Uuid),
             map(
-                tuple((
-                    tag_no_case("TIMESTAMP"),
-                    opt(CommonParser::delim_digit),
-                    multispace0,
-                )),
+                tuple((tag_no_case("TIMESTAMP"), opt(Self::delim_len), multispace0)),
                 |_| DataType::Timestamp,
             ),
             map(
                 tuple((
                     tag_no_case("VARCHAR"),
-                    CommonParser::delim_digit,
+                    Self::delim_len,
                     multispace0,
                     opt(tag_no_case("BINARY")),
                 )),
-                |t| DataType::Varchar(Self::len_as_u16(t.1)),
+                |t| DataType::Varchar(t.1),
             ),
             Self::decimal_or_numeric,
         ))(i)
@@ -170,12 +171,8 @@ impl DataType {
     fn type_identifier_second_half(i: &str) -> IResult<&str, DataType, ParseSQLError<&str>> {
         alt((
             map(
-                tuple((
-                    tag_no_case("BINARY"),
-                    CommonParser::delim_digit,
-                    multispace0,
-                )),
-                |t| DataType::Binary(Self::len_as_u16(t.1)),
+                tuple((tag_no_case("BINARY"), Self::delim_len, multispace0)),
+                |t| DataType::Binary(t.1),
             ),
             map(tag_no_case("BLOB"), |_| DataType::Blob),
             map(tag_no_case("LONGBLOB"), |_| DataType::Longblob),
@@ -185,13 +182,13 @@ impl DataType {
             map(tag_no_case("TINYBLOB"), |_| DataType::Tinyblob),
             map(tag_no_case("TINYTEXT"), |_| DataType::Tinytext),
             map(
-                tuple((
-                    tag_no_case("VARBINARY"),
-                    CommonParser::delim_digit,
-                    multispace0,
-                )),
-                |t| DataType::Varbinary(Self::len_as_u16(t.1)),
+                tuple((tag_no_case("VARBINARY"), Self::delim_len, multispace0)),
+                |t| DataType::Varbinary(t.1),
             ),
+            // BIT is BIT(1)
+            map(preceded(tag_no_case("BIT"), opt(Self::delim_len)), |len| {
+                DataType::Bit(len.unwrap_or(1))
+            }),
         ))(i)
     }
 
@@ -201,7 +198,7 @@ impl DataType {
         let (remaining_input, (_, _, len, _, signed)) = tuple((
             tag_no_case("TINYINT"),
             multispace0,
-            opt(CommonParser::delim_digit),
+            opt(Self::delim_len),
             multispace0,
             Self::opt_signed,
         ))(i)?;
@@ -209,21 +206,12 @@ impl DataType {
         match signed {
             Some(sign) => {
                 if sign.eq_ignore_ascii_case("UNSIGNED") {
-                    Ok((
-                        remaining_input,
-                        DataType::UnsignedTinyint(len.map(Self::len_as_u16).unwrap_or(1)),
-                    ))
+                    Ok((remaining_input, DataType::UnsignedTinyint(len.unwrap_or(1))))
                 } else {
-                    Ok((
-                        remaining_input,
-                        DataType::Tinyint(len.map(Self::len_as_u16).unwrap_or(1)),
-                    ))
+                    Ok((remaining_input, DataType::Tinyint(len.unwrap_or(1))))
                 }
             }
-            None => Ok((
-                remaining_input,
-                DataType::Tinyint(len.map(Self::len_as_u16).unwrap_or(1)),
-            )),
+            None => Ok((remaining_input, DataType::Tinyint(len.unwrap_or(1)))),
         }
     }
 
@@ -233,7 +221,7 @@ impl DataType {
         let (remaining_input, (_, _, len, _, signed)) = tuple((
             tag_no_case("BIGINT"),
             multispace0,
-            opt(CommonParser::delim_digit),
+            opt(Self::delim_len),
             multispace0,
             Self::opt_signed,
         ))(i)?;
@@ -241,21 +229,12 @@ impl DataType {
         match signed {
             Some(sign) => {
                 if sign.eq_ignore_ascii_case("UNSIGNED") {
-                    Ok((
-                        remaining_input,
-                        DataType::UnsignedBigint(len.map(Self::len_as_u16).unwrap_or(1)),
-                    ))
+                    Ok((remaining_input, DataType::UnsignedBigint(len.unwrap_or(1))))
                 } else {
-                    Ok((
-                        remaining_input,
-                        DataType::Bigint(len.map(Self::len_as_u16).unwrap_or(1)),
-                    ))
+                    Ok((remaining_input, DataType::Bigint(len.unwrap_or(1))))
                 }
             }
-            None => Ok((
-                remaining_input,
-                DataType::Bigint(len.map(Self::len_as_u16).unwrap_or(1)),
-            )),
+            None => Ok((remaining_input, DataType::Bigint(len.unwrap_or(1)))),
         }
     }
 
@@ -269,7 +248,7 @@ impl DataType {
                 tag_no_case("SMALLINT"),
             )),
             multispace0,
-            opt(CommonParser::delim_digit),
+            opt(Self::delim_len),
             multispace0,
             Self::opt_signed,
         ))(i)?;
@@ -277,21 +256,12 @@ impl DataType {
         match signed {
             Some(sign) => {
                 if sign.eq_ignore_ascii_case("UNSIGNED") {
-                    Ok((
-                        remaining_input,
-                        DataType::UnsignedInt(len.map(Self::len_as_u16).unwrap_or(32)),
-                    ))
+                    Ok((remaining_input, DataType::UnsignedInt(len.unwrap_or(32))))
                 } else {
-                    Ok((
-                        remaining_input,
-                        DataType::Int(len.map(Self::len_as_u16).unwrap_or(32)),
-                    ))
+                    Ok((remaining_input, DataType::Int(len.unwrap_or(32))))
                 }
             }
-            None => Ok((
-                remaining_input,
-                DataType::Int(len.map(Self::len_as_u16).unwrap_or(32)),
-            )),
+            None => Ok((remaining_input, DataType::Int(len.unwrap_or(32)))),
         }
     }
 
@@ -316,12 +286,9 @@ impl DataType {
         opt(alt((tag_no_case("UNSIGNED"), tag_no_case("SIGNED"))))(i)
     }
 
-    #[inline]
-    fn len_as_u16(len: &str) -> u16 {
-        match u16::from_str(len) {
-            Ok(v) => v,
-            Err(e) => panic!("{}", e),
-        }
+    /// `(length)`, rejecting lengths that do not fit in a `u16`
+    fn delim_len(i: &str) -> IResult<&str, u16, ParseSQLError<&str>> {
+        delimited(tag("("), map_res(digit1, u16::from_str), tag(")"))(i)
     }
 }
 
@@ -331,7 +298,7 @@ mod tests {
 
     #[test]
     fn sql_types() {
-        let ok = ["bool", "integer(16)", "datetime(16)"];
+        let ok = ["bool", "integer(16)", "datetime(16)", "bit", "BIT(8)"];
         let res_ok: Vec<_> = ok
             .iter()
             .map(|t| DataType::type_identifier(t).unwrap().1)
@@ -339,7 +306,13 @@ mod tests {
 
         assert_eq!(
             res_ok,
-            vec![DataType::Bool, DataType::Int(16), DataType::DateTime(16)]
+            vec![
+                DataType::Bool,
+                DataType::Int(16),
+                DataType::DateTime(16),
+                DataType::Bit(1),
+                DataType::Bit(8)
+            ]
         );
 
         let not_ok = ["varchar"];
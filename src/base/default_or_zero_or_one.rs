@@ -29,9 +29,9 @@ impl DefaultOrZeroOrOne {
 impl fmt::Display for DefaultOrZeroOrOne {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            DefaultOrZeroOrOne::Default => write!(f, "DEFAULT")?,
-            DefaultOrZeroOrOne::Zero => write!(f, "1")?,
-            DefaultOrZeroOrOne::One => write!(f, "0")?,
+            DefaultOrZeroOrOne::Default => write_sql!(f, "DEFAULT")?,
+            DefaultOrZeroOrOne::Zero => write_sql!(f, "0")?,
+            DefaultOrZeroOrOne::One => write_sql!(f, "1")?,
         }
         Ok(())
     }
@@ -1,21 +1,137 @@
+use std::cell::Cell;
+use std::fmt;
+
 use base::CommonParser;
+use parser::{DisplayConfig, KeywordCase};
+
+/// `write!` for the `Display` impls of the AST: the text of the format string,
+/// i.e. keywords and punctuation, is written in the keyword case of the
+/// current [DisplayConfig], while the arguments are written as they write
+/// themselves, so names and values keep their case.
+macro_rules! write_sql {
+    ($f:expr, $fmt:literal $(, $arg:expr)* $(,)?) => {
+        $crate::base::DisplayUtil::write_sql(
+            $f,
+            format_args!($fmt $(, $crate::base::DisplayUtil::verbatim(&$arg))*),
+        )
+    };
+}
+
+/// `format!` for SQL text, with keywords cased as by [write_sql].
+macro_rules! format_sql {
+    ($($arg:tt)*) => {{
+        let mut sql = String::new();
+        // writing to a String cannot fail
+        let _ = write_sql!(&mut sql, $($arg)*);
+        sql
+    }};
+}
+
+thread_local! {
+    /// whether the text being written comes from the format string of a
+    /// [write_sql] rather than from one of its arguments
+    static KEYWORD_TEXT: Cell<bool> = const { Cell::new(false) };
+}
+
+/// restores whether keyword text is being written, even on panic
+struct KeywordTextGuard(bool);
+
+impl KeywordTextGuard {
+    fn set(keyword_text: bool) -> KeywordTextGuard {
+        KeywordTextGuard(KEYWORD_TEXT.with(|current| current.replace(keyword_text)))
+    }
+}
+
+impl Drop for KeywordTextGuard {
+    fn drop(&mut self) {
+        KEYWORD_TEXT.with(|current| current.set(self.0));
+    }
+}
+
+/// An argument of [write_sql], written as is.
+pub(crate) struct Verbatim<'a, T: ?Sized>(&'a T);
+
+impl<'a, T: fmt::Display + ?Sized> fmt::Display for Verbatim<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let _guard = KeywordTextGuard::set(false);
+        self.0.fmt(f)
+    }
+}
+
+impl<'a, T: fmt::Debug + ?Sized> fmt::Debug for Verbatim<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let _guard = KeywordTextGuard::set(false);
+        self.0.fmt(f)
+    }
+}
+
+/// Lowercases the keyword text written through it.
+struct LowercaseKeywords<'a, W: ?Sized>(&'a mut W);
+
+impl<'a, W: fmt::Write + ?Sized> fmt::Write for LowercaseKeywords<'a, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if KEYWORD_TEXT.with(Cell::get) && s.bytes().any(|b| b.is_ascii_uppercase()) {
+            self.0.write_str(&s.to_ascii_lowercase())
+        } else {
+            self.0.write_str(s)
+        }
+    }
+}
 
 pub struct DisplayUtil;
 
 impl DisplayUtil {
-    /// add `` to string if string is a MySQL keyword
+    /// See [write_sql].
+    pub(crate) fn write_sql<W: fmt::Write + ?Sized>(
+        f: &mut W,
+        args: fmt::Arguments,
+    ) -> fmt::Result {
+        match DisplayConfig::with_current(|config| config.keyword_case) {
+            KeywordCase::Upper => f.write_fmt(args),
+            KeywordCase::Lower => {
+                let _guard = KeywordTextGuard::set(true);
+                fmt::write(&mut LowercaseKeywords(f), args)
+            }
+        }
+    }
+
+    /// Write a keyword chosen at run time, e.g. by a `match`, in the keyword
+    /// case of the current [DisplayConfig].
+    pub(crate) fn write_keyword(f: &mut fmt::Formatter, keyword: &str) -> fmt::Result {
+        Self::write_sql(f, format_args!("{}", keyword))
+    }
+
+    /// See [write_sql].
+    pub(crate) fn verbatim<T: ?Sized>(arg: &T) -> Verbatim<'_, T> {
+        Verbatim(arg)
+    }
+
+    /// add `` to string if string is a MySQL keyword or cannot be written as
+    /// a plain identifier, e.g. it starts with a digit; a backquote inside is
+    /// doubled
     pub fn escape_if_keyword(s: &str) -> String {
-        if CommonParser::sql_keyword(s).is_ok() {
-            format!("`{}`", s)
+        if CommonParser::sql_keyword(s).is_ok() || !Self::is_plain_identifier(s) {
+            format!("`{}`", s.replace('`', "``"))
         } else {
             s.to_owned()
         }
     }
+
+    fn is_plain_identifier(s: &str) -> bool {
+        let body = s.strip_prefix('@').unwrap_or(s);
+        match body.chars().next() {
+            Some(first) if !first.is_ascii_digit() => body
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$'),
+            _ => false,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use crate::{ParseConfig, Parser};
+    use base::DisplayUtil;
 
     #[test]
     fn escaped_keyword() {
@@ -32,4 +148,31 @@ mod tests {
         assert_eq!(expected0, format!("{}", res0.unwrap()));
         assert_eq!(expected1, format!("{}", res1.unwrap()));
     }
+
+    #[test]
+    fn quoted_identifier_round_trip() {
+        let str0 = "SELECT `select`, `order`.`desc` FROM `order`";
+        let str1 = "SELECT `name`, `2fa` FROM users";
+
+        let expected0 = "SELECT `select`, `order`.`desc` FROM `order`";
+        let expected1 = "SELECT name, `2fa` FROM users";
+        let config = ParseConfig::default();
+        let res0 = Parser::parse(&config, str0);
+        let res1 = Parser::parse(&config, str1);
+        assert!(res0.is_ok());
+        assert!(res1.is_ok());
+        assert_eq!(expected0, format!("{}", res0.unwrap()));
+        assert_eq!(expected1, format!("{}", res1.unwrap()));
+    }
+
+    #[test]
+    fn escape_backquote_in_identifier() {
+        assert_eq!(DisplayUtil::escape_if_keyword("a`b"), "`a``b`");
+
+        let str = "SELECT `a``b` FROM `we``ird`";
+        let config = ParseConfig::default();
+        let res = Parser::parse(&config, str).unwrap();
+        assert_eq!(str, format!("{}", res));
+        assert_eq!(Parser::parse(&config, &res.to_string()).unwrap(), res);
+    }
 }
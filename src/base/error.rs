@@ -23,6 +23,8 @@ where
 pub enum ParseSQLErrorKind {
     /// Static string added by the `context` function
     Context(&'static str),
+    /// Message built while parsing, e.g. to quote counts found in the input
+    Message(String),
     /// Indicates which character was expected by the `char` function
     Char(char),
     /// Error kind given by various nom parsers
@@ -81,6 +83,7 @@ impl<I: fmt::Display + InputLength> fmt::Display for ParseSQLError<I> {
                 ParseSQLErrorKind::Nom(e) => writeln!(f, "{:?} at: {}", e, input)?,
                 ParseSQLErrorKind::Char(c) => writeln!(f, "expected '{}' at: {}", c, input)?,
                 ParseSQLErrorKind::Context(s) => writeln!(f, "in section '{}', at: {}", s, input)?,
+                ParseSQLErrorKind::Message(s) => writeln!(f, "{}, at: {}", s, input)?,
             }
         }
 
@@ -4,17 +4,19 @@ use std::fmt::Display;
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::multispace0;
-use nom::combinator::{map, opt};
+use nom::combinator::{map, opt, verify};
 use nom::multi::{many0, many1};
-use nom::sequence::{delimited, separated_pair, terminated};
+use nom::sequence::{delimited, pair, separated_pair, terminated};
 use nom::IResult;
 
-use base::arithmetic::ArithmeticExpression;
+use base::arithmetic::{Arithmetic, ArithmeticExpression};
 use base::column::Column;
+use base::condition::ConditionExpression;
 use base::error::ParseSQLError;
 use base::literal::LiteralExpression;
 use base::table::Table;
 use base::{CommonParser, DisplayUtil, Literal};
+use dms::SelectStatement;
 
 #[derive(Default, Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum FieldDefinitionExpression {
@@ -23,6 +25,8 @@ pub enum FieldDefinitionExpression {
     AllInTable(String),
     Col(Column),
     Value(FieldValueExpression),
+    /// A scalar subquery, represented as (query, alias).
+    Subquery(Box<SelectStatement>, Option<String>),
 }
 
 impl FieldDefinitionExpression {
@@ -34,6 +38,28 @@ impl FieldDefinitionExpression {
                 map(terminated(Table::table_reference, tag(".*")), |t| {
                     FieldDefinitionExpression::AllInTable(t.name.clone())
                 }),
+                map(
+                    pair(
+                        delimited(
+                            pair(tag("("), multispace0),
+                            SelectStatement::nested_selection,
+                            pair(multispace0, tag(")")),
+                        ),
+                        opt(CommonParser::as_alias),
+                    ),
+                    |(select, alias)| {
+                        FieldDefinitionExpression::Subquery(
+                            Box::new(select),
+                            alias.map(String::from),
+                        )
+                    },
+                ),
+                map(AssignmentExpression::parse, |assignment| {
+                    FieldDefinitionExpression::Value(FieldValueExpression::Assignment(assignment))
+                }),
+                map(ConditionExpression::row_constructor, |exprs| {
+                    FieldDefinitionExpression::Value(FieldValueExpression::Row(exprs))
+                }),
                 map(ArithmeticExpression::parse, |expr| {
                     FieldDefinitionExpression::Value(FieldValueExpression::Arithmetic(expr))
                 }),
@@ -56,12 +82,19 @@ impl FieldDefinitionExpression {
 impl Display for FieldDefinitionExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            FieldDefinitionExpression::All => write!(f, "*"),
+            FieldDefinitionExpression::All => write_sql!(f, "*"),
             FieldDefinitionExpression::AllInTable(ref table) => {
-                write!(f, "{}.*", DisplayUtil::escape_if_keyword(table))
+                write_sql!(f, "{}.*", DisplayUtil::escape_if_keyword(table))
+            }
+            FieldDefinitionExpression::Col(ref col) => write_sql!(f, "{}", col),
+            FieldDefinitionExpression::Value(ref val) => write_sql!(f, "{}", val),
+            FieldDefinitionExpression::Subquery(ref select, ref alias) => {
+                write_sql!(f, "({})", select)?;
+                if let Some(ref alias) = *alias {
+                    write_sql!(f, " AS {}", alias)?;
+                }
+                Ok(())
             }
-            FieldDefinitionExpression::Col(ref col) => write!(f, "{}", col),
-            FieldDefinitionExpression::Value(ref val) => write!(f, "{}", val),
         }
     }
 }
@@ -70,20 +103,30 @@ impl Display for FieldDefinitionExpression {
 pub enum FieldValueExpression {
     Arithmetic(ArithmeticExpression),
     Literal(LiteralExpression),
+    Row(Vec<ConditionExpression>),
+    /// another column or a function call, e.g. `JSON_SET(data, '$.k', 1)`
+    Column(Column),
+    /// a user variable assignment, e.g. `@x := 2`
+    Assignment(AssignmentExpression),
 }
 
 impl FieldValueExpression {
-    fn parse(i: &str) -> IResult<&str, FieldValueExpression, ParseSQLError<&str>> {
+    pub fn parse(i: &str) -> IResult<&str, FieldValueExpression, ParseSQLError<&str>> {
         alt((
+            map(
+                AssignmentExpression::without_alias,
+                FieldValueExpression::Assignment,
+            ),
+            map(ArithmeticExpression::parse, |ae| {
+                FieldValueExpression::Arithmetic(ae)
+            }),
             map(Literal::parse, |l| {
                 FieldValueExpression::Literal(LiteralExpression {
                     value: l,
                     alias: None,
                 })
             }),
-            map(ArithmeticExpression::parse, |ae| {
-                FieldValueExpression::Arithmetic(ae)
-            }),
+            map(Column::without_alias, FieldValueExpression::Column),
         ))(i)
     }
 
@@ -110,9 +153,81 @@ impl FieldValueExpression {
 impl Display for FieldValueExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            FieldValueExpression::Arithmetic(ref expr) => write!(f, "{}", expr),
-            FieldValueExpression::Literal(ref lit) => write!(f, "{}", lit),
+            FieldValueExpression::Arithmetic(ref expr) => write_sql!(f, "{}", expr),
+            FieldValueExpression::Literal(ref lit) => write_sql!(f, "{}", lit),
+            FieldValueExpression::Column(ref col) => write_sql!(f, "{}", col),
+            FieldValueExpression::Assignment(ref assignment) => write_sql!(f, "{}", assignment),
+            FieldValueExpression::Row(ref exprs) => write_sql!(
+                f,
+                "ROW({})",
+                exprs
+                    .iter()
+                    .map(|e| e.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+/// `@var := expr`, assigning a user variable inside an expression
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentExpression {
+    pub variable: String,
+    pub value: Box<FieldValueExpression>,
+    pub alias: Option<String>,
+}
+
+impl AssignmentExpression {
+    pub fn parse(i: &str) -> IResult<&str, AssignmentExpression, ParseSQLError<&str>> {
+        map(
+            pair(Self::without_alias, opt(CommonParser::as_alias)),
+            |(assignment, alias)| AssignmentExpression {
+                alias: alias.map(String::from),
+                ..assignment
+            },
+        )(i)
+    }
+
+    pub fn without_alias(i: &str) -> IResult<&str, AssignmentExpression, ParseSQLError<&str>> {
+        map(
+            separated_pair(
+                verify(CommonParser::sql_identifier, |v: &str| {
+                    v.starts_with('@') && !v.starts_with("@@")
+                }),
+                delimited(multispace0, tag(":="), multispace0),
+                Self::value,
+            ),
+            |(variable, value)| AssignmentExpression {
+                variable: String::from(variable),
+                value: Box::new(value),
+                alias: None,
+            },
+        )(i)
+    }
+
+    // the assigned value, leaving any alias to the assignment itself
+    fn value(i: &str) -> IResult<&str, FieldValueExpression, ParseSQLError<&str>> {
+        alt((
+            map(Self::without_alias, FieldValueExpression::Assignment),
+            map(Arithmetic::parse, |ari| {
+                FieldValueExpression::Arithmetic(ArithmeticExpression { ari, alias: None })
+            }),
+            map(Literal::parse, |value| {
+                FieldValueExpression::Literal(LiteralExpression { value, alias: None })
+            }),
+            map(Column::without_alias, FieldValueExpression::Column),
+        ))(i)
+    }
+}
+
+impl Display for AssignmentExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "{} := {}", self.variable, self.value)?;
+        if let Some(ref alias) = self.alias {
+            write_sql!(f, " AS {}", DisplayUtil::escape_if_keyword(alias))?;
         }
+        Ok(())
     }
 }
 
@@ -120,8 +235,8 @@ impl Display for FieldValueExpression {
 mod tests {
     use base::algorithm_type::AlgorithmType;
     use base::arithmetic::ArithmeticBase;
-    use base::arithmetic::ArithmeticExpression;
     use base::arithmetic::ArithmeticOperator::{Add, Multiply};
+    use base::arithmetic::{Arithmetic, ArithmeticExpression};
     use base::{FieldDefinitionExpression, FieldValueExpression, Literal};
     use std::vec;
 
@@ -16,8 +16,8 @@ pub enum FulltextOrSpatialType {
 impl Display for FulltextOrSpatialType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            FulltextOrSpatialType::Fulltext => write!(f, "FULLTEXT"),
-            FulltextOrSpatialType::Spatial => write!(f, "SPATIAL"),
+            FulltextOrSpatialType::Fulltext => write_sql!(f, "FULLTEXT"),
+            FulltextOrSpatialType::Spatial => write_sql!(f, "SPATIAL"),
         }
     }
 }
@@ -36,17 +36,17 @@ pub enum IndexOption {
 impl Display for IndexOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            IndexOption::KeyBlockSize(ref val) => write!(f, "KEY_BLOCK_SIZE {}", val),
-            IndexOption::IndexType(ref val) => write!(f, "{}", val),
-            IndexOption::WithParser(ref val) => write!(f, "WITH PARSER {}", val),
-            IndexOption::Comment(ref val) => write!(f, "COMMENT '{}'", val),
+            IndexOption::KeyBlockSize(ref val) => write_sql!(f, "KEY_BLOCK_SIZE {}", val),
+            IndexOption::IndexType(ref val) => write_sql!(f, "{}", val),
+            IndexOption::WithParser(ref val) => write_sql!(f, "WITH PARSER {}", val),
+            IndexOption::Comment(ref val) => write_sql!(f, "COMMENT '{}'", val),
             IndexOption::VisibleType(ref val) => match *val {
-                VisibleType::Visible => write!(f, "VISIBLE"),
-                VisibleType::Invisible => write!(f, "INVISIBLE"),
+                VisibleType::Visible => write_sql!(f, "VISIBLE"),
+                VisibleType::Invisible => write_sql!(f, "INVISIBLE"),
             },
-            IndexOption::EngineAttribute(ref val) => write!(f, "ENGINE_ATTRIBUTE {}", val),
+            IndexOption::EngineAttribute(ref val) => write_sql!(f, "ENGINE_ATTRIBUTE {}", val),
             IndexOption::SecondaryEngineAttribute(ref val) => {
-                write!(f, "SECONDARY_ENGINE_ATTRIBUTE {}", val)
+                write_sql!(f, "SECONDARY_ENGINE_ATTRIBUTE {}", val)
             }
         }
     }
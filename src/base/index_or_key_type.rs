@@ -1,6 +1,8 @@
 use nom::branch::alt;
-use nom::bytes::complete::tag_no_case;
-use nom::combinator::map;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{alphanumeric1, multispace1};
+use nom::combinator::{map, not, opt};
+use nom::sequence::{preceded, terminated};
 use nom::IResult;
 use std::fmt::{Display, Formatter};
 
@@ -16,8 +18,8 @@ pub enum IndexOrKeyType {
 impl Display for IndexOrKeyType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            IndexOrKeyType::Index => write!(f, "INDEX"),
-            IndexOrKeyType::Key => write!(f, "KEY"),
+            IndexOrKeyType::Index => write_sql!(f, "INDEX"),
+            IndexOrKeyType::Key => write_sql!(f, "KEY"),
         }
     }
 }
@@ -30,6 +32,18 @@ impl IndexOrKeyType {
             map(tag_no_case("INDEX"), |_| IndexOrKeyType::Index),
         ))(i)
     }
+
+    /// `[INDEX | KEY]` after whitespace, which is absent in e.g. `FULLTEXT(col)`
+    pub fn opt_index_or_key(i: &str) -> IResult<&str, Option<IndexOrKeyType>, ParseSQLError<&str>> {
+        opt(preceded(
+            multispace1,
+            terminated(
+                IndexOrKeyType::parse,
+                // not the prefix of an index name such as `key_idx`
+                not(alt((alphanumeric1, tag("_")))),
+            ),
+        ))(i)
+    }
 }
 
 #[cfg(test)]
@@ -48,4 +62,16 @@ mod tests {
         assert!(res2.is_ok());
         assert_eq!(res2.unwrap().1, IndexOrKeyType::Key);
     }
+
+    #[test]
+    fn parse_opt_index_or_key() {
+        let res = IndexOrKeyType::opt_index_or_key(" INDEX idx");
+        assert_eq!(res.unwrap(), (" idx", Some(IndexOrKeyType::Index)));
+
+        let res = IndexOrKeyType::opt_index_or_key("(col)");
+        assert_eq!(res.unwrap(), ("(col)", None));
+
+        let res = IndexOrKeyType::opt_index_or_key(" key_idx (col)");
+        assert_eq!(res.unwrap(), (" key_idx (col)", None));
+    }
 }
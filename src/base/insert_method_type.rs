@@ -19,9 +19,9 @@ pub enum InsertMethodType {
 impl Display for InsertMethodType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            InsertMethodType::No => write!(f, "INSERT_METHOD NO"),
-            InsertMethodType::First => write!(f, "INSERT_METHOD FIRST"),
-            InsertMethodType::Last => write!(f, "INSERT_METHOD LAST"),
+            InsertMethodType::No => write_sql!(f, "INSERT_METHOD NO"),
+            InsertMethodType::First => write_sql!(f, "INSERT_METHOD FIRST"),
+            InsertMethodType::Last => write_sql!(f, "INSERT_METHOD LAST"),
         }
     }
 }
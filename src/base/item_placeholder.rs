@@ -14,9 +14,9 @@ pub enum ItemPlaceholder {
 impl Display for ItemPlaceholder {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            ItemPlaceholder::QuestionMark => write!(f, "?"),
-            ItemPlaceholder::DollarNumber(ref i) => write!(f, "${}", i),
-            ItemPlaceholder::ColonNumber(ref i) => write!(f, ":{}", i),
+            ItemPlaceholder::QuestionMark => write_sql!(f, "?"),
+            ItemPlaceholder::DollarNumber(ref i) => write_sql!(f, "${}", i),
+            ItemPlaceholder::ColonNumber(ref i) => write_sql!(f, ":{}", i),
         }
     }
 }
@@ -48,9 +48,9 @@ impl JoinClause {
 
 impl fmt::Display for JoinClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.operator)?;
-        write!(f, " {}", self.right)?;
-        write!(f, " {}", self.constraint)?;
+        write_sql!(f, "{}", self.operator)?;
+        write_sql!(f, " {}", self.right)?;
+        write_sql!(f, " {}", self.constraint)?;
         Ok(())
     }
 }
@@ -70,6 +70,10 @@ pub enum JoinRightSide {
 
 impl JoinRightSide {
     pub fn parse(i: &str) -> IResult<&str, JoinRightSide, ParseSQLError<&str>> {
+        CommonParser::nested(Self::right_side)(i)
+    }
+
+    fn right_side(i: &str) -> IResult<&str, JoinRightSide, ParseSQLError<&str>> {
         let nested_select = map(
             tuple((
                 delimited(tag("("), SelectStatement::nested_selection, tag(")")),
@@ -91,15 +95,23 @@ impl JoinRightSide {
 impl fmt::Display for JoinRightSide {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            JoinRightSide::Table(ref t) => write!(f, "{}", t)?,
+            JoinRightSide::Table(ref t) => write_sql!(f, "{}", t)?,
             JoinRightSide::NestedSelect(ref q, ref a) => {
-                write!(f, "({})", q)?;
+                write_sql!(f, "({})", q)?;
                 if a.is_some() {
-                    write!(f, " AS {}", a.as_ref().unwrap())?;
+                    write_sql!(f, " AS {}", a.as_ref().unwrap())?;
                 }
             }
-            JoinRightSide::NestedJoin(ref jc) => write!(f, "({})", jc)?,
-            _ => unimplemented!(),
+            JoinRightSide::NestedJoin(ref jc) => write_sql!(f, "({})", jc)?,
+            JoinRightSide::Tables(ref tables) => write_sql!(
+                f,
+                "({})",
+                tables
+                    .iter()
+                    .map(|t| t.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?,
         }
         Ok(())
     }
@@ -163,13 +175,13 @@ impl JoinOperator {
 impl fmt::Display for JoinOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            JoinOperator::Join => write!(f, "JOIN")?,
-            JoinOperator::LeftJoin => write!(f, "LEFT JOIN")?,
-            JoinOperator::LeftOuterJoin => write!(f, "LEFT OUTER JOIN")?,
-            JoinOperator::RightJoin => write!(f, "RIGHT JOIN")?,
-            JoinOperator::InnerJoin => write!(f, "INNER JOIN")?,
-            JoinOperator::CrossJoin => write!(f, "CROSS JOIN")?,
-            JoinOperator::StraightJoin => write!(f, "STRAIGHT JOIN")?,
+            JoinOperator::Join => write_sql!(f, "JOIN")?,
+            JoinOperator::LeftJoin => write_sql!(f, "LEFT JOIN")?,
+            JoinOperator::LeftOuterJoin => write_sql!(f, "LEFT OUTER JOIN")?,
+            JoinOperator::RightJoin => write_sql!(f, "RIGHT JOIN")?,
+            JoinOperator::InnerJoin => write_sql!(f, "INNER JOIN")?,
+            JoinOperator::CrossJoin => write_sql!(f, "CROSS JOIN")?,
+            JoinOperator::StraightJoin => write_sql!(f, "STRAIGHT JOIN")?,
         }
         Ok(())
     }
@@ -199,14 +211,13 @@ impl JoinConstraint {
             |t| JoinConstraint::Using(t.2),
         );
 
-        let on_condition = alt((
-            delimited(
-                terminated(tag("("), multispace0),
-                ConditionExpression::condition_expr,
-                preceded(multispace0, tag(")")),
-            ),
-            ConditionExpression::condition_expr,
-        ));
+        // parse the full expression, so that `ON (a OR b) AND c` is not cut off
+        // after the parenthesized part, and drop the parentheses around a
+        // fully bracketed condition
+        let on_condition = map(ConditionExpression::condition_expr, |cond| match cond {
+            ConditionExpression::Bracketed(inner) => *inner,
+            cond => cond,
+        });
         let on_clause = map(tuple((tag_no_case("ON"), multispace1, on_condition)), |t| {
             JoinConstraint::On(t.2)
         });
@@ -218,8 +229,8 @@ impl JoinConstraint {
 impl fmt::Display for JoinConstraint {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            JoinConstraint::On(ref ce) => write!(f, "ON {}", ce)?,
-            JoinConstraint::Using(ref columns) => write!(
+            JoinConstraint::On(ref ce) => write_sql!(f, "ON {}", ce)?,
+            JoinConstraint::Using(ref columns) => write_sql!(
                 f,
                 "USING ({})",
                 columns
@@ -263,4 +274,62 @@ mod tests {
         assert_eq!(clause, join);
         assert_eq!(str, format!("{}", clause));
     }
+
+    #[test]
+    fn parse_join_using_columns() {
+        let str = "JOIN b USING (x, y)";
+        let res = JoinClause::parse(str);
+
+        let join = JoinClause {
+            operator: JoinOperator::Join,
+            right: JoinRightSide::Table(Table::from("b")),
+            constraint: JoinConstraint::Using(vec![Column::from("x"), Column::from("y")]),
+        };
+
+        let clause = res.unwrap().1;
+        assert_eq!(clause, join);
+        assert_eq!(str, format!("{}", clause));
+    }
+
+    #[test]
+    fn parse_join_on_multiple_conditions() {
+        let equal = |l: &str, r: &str| {
+            ConditionExpression::ComparisonOp(ConditionTree {
+                left: Box::new(Base(Field(Column::from(l)))),
+                right: Box::new(Base(Field(Column::from(r)))),
+                operator: Operator::Equal,
+            })
+        };
+        let logical = |operator, left, right| {
+            ConditionExpression::LogicalOp(ConditionTree {
+                left: Box::new(left),
+                right: Box::new(right),
+                operator,
+            })
+        };
+
+        let str = "JOIN b ON a.x = b.x AND a.y = b.y OR a.z = b.z";
+        let clause = JoinClause::parse(str).unwrap().1;
+        let exp = logical(
+            Operator::Or,
+            logical(Operator::And, equal("a.x", "b.x"), equal("a.y", "b.y")),
+            equal("a.z", "b.z"),
+        );
+        assert_eq!(clause.constraint, JoinConstraint::On(exp));
+        assert_eq!(str, format!("{}", clause));
+
+        let str = "JOIN b ON (a.x = b.x OR a.y = b.y) AND a.z = b.z";
+        let clause = JoinClause::parse(str).unwrap().1;
+        let exp = logical(
+            Operator::And,
+            ConditionExpression::Bracketed(Box::new(logical(
+                Operator::Or,
+                equal("a.x", "b.x"),
+                equal("a.y", "b.y"),
+            ))),
+            equal("a.z", "b.z"),
+        );
+        assert_eq!(clause.constraint, JoinConstraint::On(exp));
+        assert_eq!(str, format!("{}", clause));
+    }
 }
@@ -0,0 +1,327 @@
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::condition::ConditionExpression;
+use base::error::ParseSQLError;
+use base::{CommonParser, DataType, Literal};
+
+/// parse `JSON_TABLE(expr, path COLUMNS (column_list))`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct JsonTable {
+    pub expr: ConditionExpression,
+    pub path: String,
+    pub columns: Vec<JsonTableColumn>,
+}
+
+impl JsonTable {
+    pub fn parse(i: &str) -> IResult<&str, JsonTable, ParseSQLError<&str>> {
+        map(
+            tuple((
+                tag_no_case("JSON_TABLE"),
+                multispace0,
+                tag("("),
+                multispace0,
+                ConditionExpression::simple_expr,
+                CommonParser::ws_sep_comma,
+                json_path,
+                multispace1,
+                JsonTableColumn::column_list,
+                multispace0,
+                tag(")"),
+            )),
+            |(_, _, _, _, expr, _, path, _, columns, _, _)| JsonTable {
+                expr,
+                path,
+                columns,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for JsonTable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(
+            f,
+            "JSON_TABLE({}, {} {})",
+            self.expr,
+            quote(&self.path),
+            JsonTableColumn::format_list(&self.columns)
+        )
+    }
+}
+
+/// column definition of [JsonTable]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum JsonTableColumn {
+    /// `name FOR ORDINALITY`
+    ForOrdinality { name: String },
+
+    /// `name type PATH string_path [on_empty] [on_error]`
+    Path {
+        name: String,
+        data_type: DataType,
+        path: String,
+        on_empty: Option<JsonTableOnResponse>,
+        on_error: Option<JsonTableOnResponse>,
+    },
+
+    /// `name type EXISTS PATH string_path`
+    Exists {
+        name: String,
+        data_type: DataType,
+        path: String,
+    },
+
+    /// `NESTED [PATH] path COLUMNS (column_list)`
+    Nested {
+        path: String,
+        columns: Vec<JsonTableColumn>,
+    },
+}
+
+impl JsonTableColumn {
+    /// `COLUMNS (column_list)`
+    fn column_list(i: &str) -> IResult<&str, Vec<JsonTableColumn>, ParseSQLError<&str>> {
+        preceded(
+            pair(tag_no_case("COLUMNS"), multispace0),
+            delimited(
+                pair(tag("("), multispace0),
+                separated_list1(CommonParser::ws_sep_comma, Self::parse),
+                pair(multispace0, tag(")")),
+            ),
+        )(i)
+    }
+
+    pub fn parse(i: &str) -> IResult<&str, JsonTableColumn, ParseSQLError<&str>> {
+        alt((Self::nested, Self::for_ordinality, Self::exists, Self::path))(i)
+    }
+
+    pub fn format_list(list: &[JsonTableColumn]) -> String {
+        format_sql!(
+            "COLUMNS ({})",
+            list.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+
+    fn nested(i: &str) -> IResult<&str, JsonTableColumn, ParseSQLError<&str>> {
+        map(
+            tuple((
+                tag_no_case("NESTED"),
+                multispace1,
+                opt(terminated(tag_no_case("PATH"), multispace1)),
+                json_path,
+                multispace1,
+                Self::column_list,
+            )),
+            |(_, _, _, path, _, columns)| JsonTableColumn::Nested { path, columns },
+        )(i)
+    }
+
+    fn for_ordinality(i: &str) -> IResult<&str, JsonTableColumn, ParseSQLError<&str>> {
+        map(
+            tuple((
+                CommonParser::sql_identifier,
+                multispace1,
+                tag_no_case("FOR"),
+                multispace1,
+                tag_no_case("ORDINALITY"),
+            )),
+            |(name, _, _, _, _)| JsonTableColumn::ForOrdinality {
+                name: String::from(name),
+            },
+        )(i)
+    }
+
+    fn exists(i: &str) -> IResult<&str, JsonTableColumn, ParseSQLError<&str>> {
+        map(
+            tuple((
+                CommonParser::sql_identifier,
+                multispace1,
+                DataType::type_identifier,
+                multispace0,
+                tag_no_case("EXISTS"),
+                multispace1,
+                tag_no_case("PATH"),
+                multispace1,
+                json_path,
+            )),
+            |(name, _, data_type, _, _, _, _, _, path)| JsonTableColumn::Exists {
+                name: String::from(name),
+                data_type,
+                path,
+            },
+        )(i)
+    }
+
+    fn path(i: &str) -> IResult<&str, JsonTableColumn, ParseSQLError<&str>> {
+        map(
+            tuple((
+                CommonParser::sql_identifier,
+                multispace1,
+                DataType::type_identifier,
+                multispace0,
+                tag_no_case("PATH"),
+                multispace1,
+                json_path,
+                opt(preceded(
+                    multispace1,
+                    JsonTableOnResponse::parse_on("EMPTY"),
+                )),
+                opt(preceded(
+                    multispace1,
+                    JsonTableOnResponse::parse_on("ERROR"),
+                )),
+            )),
+            |(name, _, data_type, _, _, _, path, on_empty, on_error)| JsonTableColumn::Path {
+                name: String::from(name),
+                data_type,
+                path,
+                on_empty,
+                on_error,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for JsonTableColumn {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonTableColumn::ForOrdinality { ref name } => {
+                write_sql!(f, "{} FOR ORDINALITY", name)
+            }
+            JsonTableColumn::Path {
+                ref name,
+                ref data_type,
+                ref path,
+                ref on_empty,
+                ref on_error,
+            } => {
+                write_sql!(f, "{} {} PATH {}", name, data_type, quote(path))?;
+                if let Some(on_empty) = on_empty {
+                    write_sql!(f, " {} ON EMPTY", on_empty)?;
+                }
+                if let Some(on_error) = on_error {
+                    write_sql!(f, " {} ON ERROR", on_error)?;
+                }
+                Ok(())
+            }
+            JsonTableColumn::Exists {
+                ref name,
+                ref data_type,
+                ref path,
+            } => write_sql!(f, "{} {} EXISTS PATH {}", name, data_type, quote(path)),
+            JsonTableColumn::Nested {
+                ref path,
+                ref columns,
+            } => write_sql!(
+                f,
+                "NESTED PATH {} {}",
+                quote(path),
+                JsonTableColumn::format_list(columns)
+            ),
+        }
+    }
+}
+
+/// `{NULL | DEFAULT json_string | ERROR}` of `ON EMPTY` and `ON ERROR`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum JsonTableOnResponse {
+    Null,
+    Default(String),
+    Error,
+}
+
+impl JsonTableOnResponse {
+    /// parse `{NULL | DEFAULT json_string | ERROR} ON {EMPTY | ERROR}`
+    fn parse_on(
+        event: &'static str,
+    ) -> impl FnMut(&str) -> IResult<&str, JsonTableOnResponse, ParseSQLError<&str>> {
+        move |i| {
+            terminated(
+                alt((
+                    map(tag_no_case("NULL"), |_| JsonTableOnResponse::Null),
+                    map(tag_no_case("ERROR"), |_| JsonTableOnResponse::Error),
+                    map(
+                        preceded(pair(tag_no_case("DEFAULT"), multispace1), json_path),
+                        JsonTableOnResponse::Default,
+                    ),
+                )),
+                tuple((
+                    multispace1,
+                    tag_no_case("ON"),
+                    multispace1,
+                    tag_no_case(event),
+                )),
+            )(i)
+        }
+    }
+}
+
+impl fmt::Display for JsonTableOnResponse {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            JsonTableOnResponse::Null => write_sql!(f, "NULL"),
+            JsonTableOnResponse::Default(ref value) => write_sql!(f, "DEFAULT {}", quote(value)),
+            JsonTableOnResponse::Error => write_sql!(f, "ERROR"),
+        }
+    }
+}
+
+/// quoted JSON path or JSON string value
+fn json_path(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+    map(Literal::string_literal, |lit| match lit {
+        Literal::String(s) => s,
+        _ => unreachable!(),
+    })(i)
+}
+
+fn quote(s: &str) -> String {
+    format!("'{}'", s.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod tests {
+    use base::column::Column;
+    use base::condition::{ConditionBase, ConditionExpression};
+    use base::json_table::{JsonTable, JsonTableColumn, JsonTableOnResponse};
+    use base::DataType;
+
+    #[test]
+    fn parse_json_table() {
+        let str = "JSON_TABLE(t.doc, '$[*]' COLUMNS (id FOR ORDINALITY, \
+            name VARCHAR(40) PATH '$.name' DEFAULT '\"n/a\"' ON EMPTY NULL ON ERROR))";
+        let res = JsonTable::parse(str);
+        let exp = JsonTable {
+            expr: ConditionExpression::Base(ConditionBase::Field(Column::from("t.doc"))),
+            path: "$[*]".to_string(),
+            columns: vec![
+                JsonTableColumn::ForOrdinality {
+                    name: "id".to_string(),
+                },
+                JsonTableColumn::Path {
+                    name: "name".to_string(),
+                    data_type: DataType::Varchar(40),
+                    path: "$.name".to_string(),
+                    on_empty: Some(JsonTableOnResponse::Default("\"n/a\"".to_string())),
+                    on_error: Some(JsonTableOnResponse::Null),
+                },
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(
+            exp.to_string(),
+            "JSON_TABLE(t.doc, '$[*]' COLUMNS (id FOR ORDINALITY, \
+            name VARCHAR(40) PATH '$.name' DEFAULT '\"n/a\"' ON EMPTY NULL ON ERROR))"
+        );
+    }
+}
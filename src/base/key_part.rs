@@ -1,7 +1,7 @@
 use nom::branch::alt;
 use nom::bytes::complete::tag;
 use nom::character::complete::{anychar, digit1, multispace0, multispace1};
-use nom::combinator::{map, opt, recognize};
+use nom::combinator::{map, map_res, opt, recognize};
 use nom::multi::many1;
 use nom::sequence::{delimited, preceded, terminated, tuple};
 use nom::IResult;
@@ -19,9 +19,9 @@ pub struct KeyPart {
 
 impl Display for KeyPart {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.r#type);
+        write_sql!(f, "{}", self.r#type);
         if let Some(order) = &self.order {
-            write!(f, " {}", order);
+            write_sql!(f, " {}", order);
         }
         Ok(())
     }
@@ -56,7 +56,8 @@ impl KeyPart {
             tuple((
                 KeyPartType::parse,
                 opt(map(
-                    tuple((multispace1, OrderType::parse, multispace0)),
+                    // col_name [(length)] already consumes the whitespace before the order
+                    tuple((multispace0, OrderType::parse, multispace0)),
                     |(_, order, _)| order,
                 )),
             )),
@@ -64,6 +65,14 @@ impl KeyPart {
         )(i)
     }
 
+    /// the column name of a `col_name [(length)]` key part, `None` for `(expr)`
+    pub fn column_name(&self) -> Option<String> {
+        match self.r#type {
+            KeyPartType::ColumnNameWithLength { ref col_name, .. } => Some(col_name.clone()),
+            KeyPartType::Expr { .. } => None,
+        }
+    }
+
     pub fn format_list(key_parts: &[KeyPart]) -> String {
         let key_parts = key_parts
             .iter()
@@ -94,12 +103,12 @@ impl Display for KeyPartType {
                 ref length,
             } => {
                 if let Some(length) = length {
-                    write!(f, "{}({})", col_name, length)
+                    write_sql!(f, "{}({})", col_name, length)
                 } else {
-                    write!(f, "{}", col_name)
+                    write_sql!(f, "{}", col_name)
                 }
             }
-            KeyPartType::Expr { ref expr } => write!(f, "({})", expr),
+            KeyPartType::Expr { ref expr } => write_sql!(f, "({})", expr),
         }
     }
 }
@@ -112,9 +121,7 @@ impl KeyPartType {
             multispace0,
             opt(delimited(
                 tag("("),
-                map(digit1, |digit_str: &str| {
-                    digit_str.parse::<usize>().unwrap()
-                }),
+                map_res(digit1, |digit_str: &str| digit_str.parse::<usize>()),
                 tag(")"),
             )),
         ));
@@ -140,7 +147,7 @@ impl KeyPartType {
 
 #[cfg(test)]
 mod tests {
-    use base::{KeyPart, KeyPartType};
+    use base::{KeyPart, KeyPartType, OrderType};
 
     #[test]
     fn parse_key_part_type() {
@@ -169,5 +176,25 @@ mod tests {
         }];
         assert!(res1.is_ok());
         assert_eq!(res1.unwrap().1, exp);
+
+        let str2 = "(col_1 DESC, col_2(10) ASC)";
+        let res2 = KeyPart::parse(str2);
+        let exp = vec![
+            KeyPart {
+                r#type: KeyPartType::ColumnNameWithLength {
+                    col_name: "col_1".to_string(),
+                    length: None,
+                },
+                order: Some(OrderType::Desc),
+            },
+            KeyPart {
+                r#type: KeyPartType::ColumnNameWithLength {
+                    col_name: "col_2".to_string(),
+                    length: Some(10),
+                },
+                order: Some(OrderType::Asc),
+            },
+        ];
+        assert_eq!(res2.unwrap().1, exp);
     }
 }
@@ -3,15 +3,15 @@ use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use nom::branch::alt;
-use nom::bytes::complete::{is_not, tag, tag_no_case, take};
-use nom::character::complete::{digit1, multispace0};
-use nom::combinator::{map, opt};
+use nom::bytes::complete::{is_not, tag, tag_no_case, take, take_while, take_while1};
+use nom::character::complete::{digit1, multispace0, satisfy};
+use nom::combinator::{map, map_res, not, opt, peek};
 use nom::multi::{fold_many0, many0};
-use nom::sequence::{delimited, pair, preceded, tuple};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use base::error::ParseSQLError;
-use base::{CommonParser, ItemPlaceholder};
+use base::{CommonParser, ItemPlaceholder, SystemVariable};
 
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum Literal {
@@ -22,40 +22,56 @@ pub enum Literal {
     FixedPoint(Real),
     String(String),
     Blob(Vec<u8>),
-    CurrentTime,
+    /// bit-value literal `b'01'` or `0b01`, keeping its binary digits
+    BitString(String),
+    /// `CURRENT_TIME[(fsp)]`, keeping the fractional seconds precision
+    CurrentTime(Option<u8>),
     CurrentDate,
-    CurrentTimestamp,
+    /// `CURRENT_TIMESTAMP[(fsp)]`
+    CurrentTimestamp(Option<u8>),
+    CurrentUser,
+    /// `LOCALTIME[(fsp)]`
+    LocalTime(Option<u8>),
+    /// `LOCALTIMESTAMP[(fsp)]`
+    LocalTimestamp(Option<u8>),
+    UtcDate,
+    /// `UTC_TIME[(fsp)]`
+    UtcTime(Option<u8>),
+    /// `UTC_TIMESTAMP[(fsp)]`
+    UtcTimestamp(Option<u8>),
     Placeholder(ItemPlaceholder),
+    /// `@@[GLOBAL. | SESSION.]system_var_name`
+    SystemVariable(SystemVariable),
 }
 
 impl Literal {
     // Integer literal value
     pub fn integer_literal(i: &str) -> IResult<&str, Literal, ParseSQLError<&str>> {
-        map(pair(opt(tag("-")), digit1), |tup| {
-            let mut intval = i64::from_str(tup.1).unwrap();
-            if (tup.0).is_some() {
-                intval *= -1;
-            }
-            Literal::Integer(intval)
-        })(i)
-    }
-
-    fn unpack(v: &str) -> i32 {
-        i32::from_str(v).unwrap()
+        map(
+            pair(opt(tag("-")), map_res(digit1, i64::from_str)),
+            |(sign, intval)| match sign {
+                Some(_) => Literal::Integer(-intval),
+                None => Literal::Integer(intval),
+            },
+        )(i)
     }
 
     // Floating point literal value
     pub fn float_literal(i: &str) -> IResult<&str, Literal, ParseSQLError<&str>> {
-        map(tuple((opt(tag("-")), digit1, tag("."), digit1)), |tup| {
-            Literal::FixedPoint(Real {
-                integral: if (tup.0).is_some() {
-                    -Self::unpack(tup.1)
-                } else {
-                    Self::unpack(tup.1)
-                },
-                fractional: Self::unpack(tup.3),
-            })
-        })(i)
+        map(
+            tuple((
+                opt(tag("-")),
+                map_res(digit1, i32::from_str),
+                tag("."),
+                map_res(digit1, i32::from_str),
+            )),
+            |(sign, integral, _, fractional)| {
+                Literal::FixedPoint(Real {
+                    integral: if sign.is_some() { -integral } else { integral },
+                    fractional,
+                })
+            },
+        )(i)
     }
 
     /// String literal value
@@ -108,6 +124,21 @@ impl Literal {
         Self::raw_string_quoted(i, false)
     }
 
+    /// `b'val'`, `B'val'` or `0bval`, where val is made of binary digits
+    pub fn bit_literal(i: &str) -> IResult<&str, Literal, ParseSQLError<&str>> {
+        let binary_digits = |i| take_while(|c: char| c == '0' || c == '1')(i);
+        map(
+            alt((
+                delimited(tag_no_case("b'"), binary_digits, tag("'")),
+                terminated(
+                    preceded(tag("0b"), take_while1(|c: char| c == '0' || c == '1')),
+                    not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+                ),
+            )),
+            |digits: &str| Literal::BitString(String::from(digits)),
+        )(i)
+    }
+
     pub fn string_literal(i: &str) -> IResult<&str, Literal, ParseSQLError<&str>> {
         map(
             alt((
@@ -121,29 +152,95 @@ impl Literal {
     // Any literal value.
     pub fn parse(i: &str) -> IResult<&str, Literal, ParseSQLError<&str>> {
         alt((
+            Self::bit_literal,
             Self::float_literal,
             Self::integer_literal,
             Self::string_literal,
-            map(tag_no_case("NULL"), |_| Literal::Null),
-            map(tag_no_case("CURRENT_TIMESTAMP"), |_| {
-                Literal::CurrentTimestamp
-            }),
-            map(tag_no_case("CURRENT_DATE"), |_| Literal::CurrentDate),
-            map(tag_no_case("CURRENT_TIME"), |_| Literal::CurrentTime),
+            map(
+                terminated(
+                    tag_no_case("NULL"),
+                    not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+                ),
+                |_| Literal::Null,
+            ),
+            Self::keyword_function,
+            map(SystemVariable::parse, Literal::SystemVariable),
             map(tag("?"), |_| {
                 Literal::Placeholder(ItemPlaceholder::QuestionMark)
             }),
-            map(preceded(tag(":"), digit1), |num| {
-                let value = i32::from_str(num).unwrap();
-                Literal::Placeholder(ItemPlaceholder::ColonNumber(value))
+            map(
+                preceded(tag(":"), map_res(digit1, i32::from_str)),
+                |value| Literal::Placeholder(ItemPlaceholder::ColonNumber(value)),
+            ),
+            map(
+                preceded(tag("$"), map_res(digit1, i32::from_str)),
+                |value| Literal::Placeholder(ItemPlaceholder::DollarNumber(value)),
+            ),
+        ))(i)
+    }
+
+    /// Functions that can be called without parentheses, e.g. `CURRENT_USER` or `LOCALTIME(3)`.
+    /// The fractional seconds precision is kept for the time functions.
+    pub fn keyword_function(i: &str) -> IResult<&str, Literal, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(tag_no_case("CURRENT_TIMESTAMP"), Self::fsp),
+                Literal::CurrentTimestamp,
+            ),
+            map(preceded(tag_no_case("CURRENT_DATE"), Self::fsp), |_| {
+                Literal::CurrentDate
             }),
-            map(preceded(tag("$"), digit1), |num| {
-                let value = i32::from_str(num).unwrap();
-                Literal::Placeholder(ItemPlaceholder::DollarNumber(value))
+            map(
+                preceded(tag_no_case("CURRENT_TIME"), Self::fsp),
+                Literal::CurrentTime,
+            ),
+            map(preceded(tag_no_case("CURRENT_USER"), Self::fsp), |_| {
+                Literal::CurrentUser
+            }),
+            map(
+                preceded(tag_no_case("LOCALTIMESTAMP"), Self::fsp),
+                Literal::LocalTimestamp,
+            ),
+            map(
+                preceded(tag_no_case("LOCALTIME"), Self::fsp),
+                Literal::LocalTime,
+            ),
+            map(
+                preceded(tag_no_case("UTC_TIMESTAMP"), Self::fsp),
+                Literal::UtcTimestamp,
+            ),
+            map(preceded(tag_no_case("UTC_DATE"), Self::fsp), |_| {
+                Literal::UtcDate
             }),
+            map(
+                preceded(tag_no_case("UTC_TIME"), Self::fsp),
+                Literal::UtcTime,
+            ),
         ))(i)
     }
 
+    // the optional `(fsp)` after a keyword function, `()` giving no precision
+    fn fsp(i: &str) -> IResult<&str, Option<u8>, ParseSQLError<&str>> {
+        map(
+            preceded(
+                not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+                opt(delimited(
+                    pair(tag("("), multispace0),
+                    opt(map_res(digit1, u8::from_str)),
+                    pair(multispace0, tag(")")),
+                )),
+            ),
+            Option::flatten,
+        )(i)
+    }
+
+    fn write_fsp(f: &mut Formatter<'_>, fsp: Option<u8>) -> fmt::Result {
+        match fsp {
+            Some(fsp) => write_sql!(f, "({})", fsp),
+            None => Ok(()),
+        }
+    }
+
     // Parse a list of values (e.g., for INSERT syntax).
     pub fn value_list(i: &str) -> IResult<&str, Vec<Literal>, ParseSQLError<&str>> {
         many0(delimited(
@@ -193,18 +290,18 @@ impl<'a> From<&'a str> for Literal {
 impl Display for Literal {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match *self {
-            Literal::Null => write!(f, "NULL"),
+            Literal::Null => write_sql!(f, "NULL"),
             Literal::Bool(ref value) => {
                 if *value {
-                    write!(f, "TRUE")
+                    write_sql!(f, "TRUE")
                 } else {
-                    write!(f, "FALSE")
+                    write_sql!(f, "FALSE")
                 }
             }
-            Literal::Integer(ref i) => write!(f, "{}", i),
-            Literal::UnsignedInteger(ref i) => write!(f, "{}", i),
-            Literal::FixedPoint(ref fp) => write!(f, "{}.{}", fp.integral, fp.fractional),
-            Literal::String(ref s) => write!(f, "'{}'", s.replace('\'', "''")),
+            Literal::Integer(ref i) => write_sql!(f, "{}", i),
+            Literal::UnsignedInteger(ref i) => write_sql!(f, "{}", i),
+            Literal::FixedPoint(ref fp) => write_sql!(f, "{}.{}", fp.integral, fp.fractional),
+            Literal::String(ref s) => write_sql!(f, "'{}'", s.replace('\'', "''")),
             Literal::Blob(ref bv) => {
                 let val = bv
                     .iter()
@@ -212,12 +309,38 @@ impl Display for Literal {
                     .collect::<Vec<String>>()
                     .join(" ")
                     .to_string();
-                write!(f, "{}", val)
+                write_sql!(f, "{}", val)
+            }
+            Literal::BitString(ref digits) => write_sql!(f, "b'{}'", digits),
+            Literal::CurrentTime(fsp) => {
+                write_sql!(f, "CURRENT_TIME")?;
+                Self::write_fsp(f, fsp)
+            }
+            Literal::CurrentDate => write_sql!(f, "CURRENT_DATE"),
+            Literal::CurrentTimestamp(fsp) => {
+                write_sql!(f, "CURRENT_TIMESTAMP")?;
+                Self::write_fsp(f, fsp)
+            }
+            Literal::CurrentUser => write_sql!(f, "CURRENT_USER"),
+            Literal::LocalTime(fsp) => {
+                write_sql!(f, "LOCALTIME")?;
+                Self::write_fsp(f, fsp)
             }
-            Literal::CurrentTime => write!(f, "CURRENT_TIME"),
-            Literal::CurrentDate => write!(f, "CURRENT_DATE"),
-            Literal::CurrentTimestamp => write!(f, "CURRENT_TIMESTAMP"),
-            Literal::Placeholder(ref item) => write!(f, "{}", item),
+            Literal::LocalTimestamp(fsp) => {
+                write_sql!(f, "LOCALTIMESTAMP")?;
+                Self::write_fsp(f, fsp)
+            }
+            Literal::UtcDate => write_sql!(f, "UTC_DATE"),
+            Literal::UtcTime(fsp) => {
+                write_sql!(f, "UTC_TIME")?;
+                Self::write_fsp(f, fsp)
+            }
+            Literal::UtcTimestamp(fsp) => {
+                write_sql!(f, "UTC_TIMESTAMP")?;
+                Self::write_fsp(f, fsp)
+            }
+            Literal::Placeholder(ref item) => write_sql!(f, "{}", item),
+            Literal::SystemVariable(ref variable) => write_sql!(f, "{}", variable),
         }
     }
 }
@@ -255,8 +378,8 @@ impl From<Literal> for LiteralExpression {
 impl fmt::Display for LiteralExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.alias {
-            Some(ref alias) => write!(f, "{} AS {}", self.value, alias),
-            None => write!(f, "{}", self.value),
+            Some(ref alias) => write_sql!(f, "{} AS {}", self.value, alias),
+            None => write_sql!(f, "{}", self.value),
         }
     }
 }
@@ -269,13 +392,13 @@ pub struct Real {
 
 impl Display for Real {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "{}.{}", self.integral, self.fractional)
+        write_sql!(f, "{}.{}", self.integral, self.fractional)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use base::Literal;
+    use base::{ItemPlaceholder, Literal};
 
     #[test]
     #[allow(clippy::redundant_slicing)]
@@ -300,6 +423,67 @@ mod tests {
         assert_eq!(res.unwrap().1, expected);
     }
 
+    #[test]
+    fn value_list_with_null_and_placeholders() {
+        let res = Literal::value_list("1, NULL, ?, :2, 'a'");
+        let exp = vec![
+            Literal::Integer(1),
+            Literal::Null,
+            Literal::Placeholder(ItemPlaceholder::QuestionMark),
+            Literal::Placeholder(ItemPlaceholder::ColonNumber(2)),
+            Literal::String("a".to_string()),
+        ];
+        assert_eq!(res.unwrap(), ("", exp));
+
+        // NULL is a whole word, not the prefix of an identifier
+        assert!(Literal::parse("nullable").is_err());
+    }
+
+    #[test]
+    fn bit_literal() {
+        let bits = |digits: &str| Literal::BitString(digits.to_string());
+        assert_eq!(Literal::parse("b'0101'").unwrap(), ("", bits("0101")));
+        assert_eq!(Literal::parse("B''").unwrap(), ("", bits("")));
+        assert_eq!(Literal::parse("0b0101").unwrap(), ("", bits("0101")));
+        assert_eq!(bits("0101").to_string(), "b'0101'");
+
+        assert!(Literal::bit_literal("b'012'").is_err());
+        assert!(Literal::bit_literal("0b012").is_err());
+        assert!(Literal::bit_literal("0bx").is_err());
+    }
+
+    #[test]
+    fn parse_keyword_function() {
+        let parts = [
+            "CURRENT_USER",
+            "current_date",
+            "CURRENT_TIMESTAMP(6)",
+            "LOCALTIME",
+            "LOCALTIMESTAMP()",
+            "UTC_TIMESTAMP",
+        ];
+        let exps = [
+            Literal::CurrentUser,
+            Literal::CurrentDate,
+            Literal::CurrentTimestamp(Some(6)),
+            Literal::LocalTime(None),
+            Literal::LocalTimestamp(None),
+            Literal::UtcTimestamp(None),
+        ];
+        for i in 0..parts.len() {
+            let res = Literal::parse(parts[i]);
+            assert!(res.is_ok());
+            assert_eq!(res.unwrap().1, exps[i]);
+        }
+
+        assert!(Literal::keyword_function("localtime_col").is_err());
+        assert_eq!(
+            Literal::CurrentTimestamp(Some(6)).to_string(),
+            "CURRENT_TIMESTAMP(6)"
+        );
+        assert_eq!(Literal::UtcTime(None).to_string(), "UTC_TIME");
+    }
+
     #[test]
     fn literal_string_double_quote() {
         let res = Literal::string_literal(r#""a""b""#);
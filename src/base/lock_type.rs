@@ -21,10 +21,10 @@ pub enum LockType {
 impl Display for LockType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            LockType::Default => write!(f, "LOCK DEFAULT"),
-            LockType::None => write!(f, "LOCK NONE"),
-            LockType::Shared => write!(f, "LOCK SHARED"),
-            LockType::Exclusive => write!(f, "LOCK EXCLUSIVE"),
+            LockType::Default => write_sql!(f, "LOCK DEFAULT"),
+            LockType::None => write_sql!(f, "LOCK NONE"),
+            LockType::Shared => write_sql!(f, "LOCK SHARED"),
+            LockType::Exclusive => write_sql!(f, "LOCK EXCLUSIVE"),
         }
     }
 }
@@ -19,9 +19,9 @@ pub enum MatchType {
 impl Display for MatchType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            MatchType::Full => write!(f, "MATCH FULL"),
-            MatchType::Partial => write!(f, "MATCH PARTIAL"),
-            MatchType::Simple => write!(f, "MATCH SIMPLE"),
+            MatchType::Full => write_sql!(f, "MATCH FULL"),
+            MatchType::Partial => write_sql!(f, "MATCH PARTIAL"),
+            MatchType::Simple => write_sql!(f, "MATCH SIMPLE"),
         }
     }
 }
@@ -6,24 +6,31 @@ pub use self::data_type::DataType;
 pub use self::default_or_zero_or_one::DefaultOrZeroOrOne;
 pub use self::display_util::DisplayUtil;
 pub use self::error::*;
-pub use self::field::{FieldDefinitionExpression, FieldValueExpression};
+pub use self::field::{AssignmentExpression, FieldDefinitionExpression, FieldValueExpression};
 pub use self::insert_method_type::InsertMethodType;
 pub use self::item_placeholder::ItemPlaceholder;
 pub use self::join::JoinClause;
 pub use self::join::{JoinConstraint, JoinOperator, JoinRightSide};
+pub use self::json_table::{JsonTable, JsonTableColumn, JsonTableOnResponse};
 pub use self::key_part::{KeyPart, KeyPartType};
 pub use self::literal::{Literal, LiteralExpression, Real};
 pub use self::match_type::MatchType;
 pub use self::operator::Operator;
 pub use self::order::OrderClause;
 pub use self::order::OrderType;
-pub use self::partition_definition::PartitionDefinition;
+pub use self::partition_definition::{PartitionDefinition, PartitionValues};
+pub use self::partition_options::{PartitionBy, PartitionKey, PartitionOptions};
 pub use self::reference_definition::ReferenceDefinition;
 pub use self::row_format_type::RowFormatType;
+pub use self::system_variable::SystemVariable;
 pub use self::table::Table;
 pub use self::table_option::CheckConstraintDefinition;
 pub use self::tablespace_type::TablespaceType;
 pub use self::trigger::Trigger;
+pub use self::window::{WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec};
+
+#[macro_use]
+mod display_util;
 
 pub mod column;
 pub mod table;
@@ -48,6 +55,7 @@ pub mod match_type;
 pub mod operator;
 pub mod reference_type;
 pub mod row_format_type;
+pub mod system_variable;
 pub mod tablespace_type;
 pub mod visible_type;
 
@@ -56,14 +64,17 @@ pub mod arithmetic;
 pub mod index_option;
 mod key_part;
 mod partition_definition;
+mod partition_options;
 mod reference_definition;
 pub mod table_option;
 
 pub mod condition;
 
 mod order;
+mod window;
 
 pub mod case;
 
-mod display_util;
 mod join;
+mod json_table;
+pub(crate) mod visit;
@@ -7,6 +7,7 @@ use nom::combinator::map;
 use nom::IResult;
 
 use base::error::ParseSQLError;
+use base::DisplayUtil;
 
 /// Parse binary comparison operators
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -25,6 +26,7 @@ pub enum Operator {
     In,
     NotIn,
     Is,
+    IsNot,
 }
 
 impl Operator {
@@ -61,7 +63,8 @@ impl Display for Operator {
             Operator::In => "IN",
             Operator::NotIn => "NOT IN",
             Operator::Is => "IS",
+            Operator::IsNot => "IS NOT",
         };
-        write!(f, "{}", op)
+        DisplayUtil::write_keyword(f, op)
     }
 }
@@ -4,10 +4,10 @@ use std::str;
 
 use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
-use nom::character::complete::{multispace0, multispace1};
-use nom::combinator::{map, opt};
+use nom::character::complete::{multispace0, multispace1, satisfy};
+use nom::combinator::{map, not, opt, peek};
 use nom::multi::many0;
-use nom::sequence::{preceded, tuple};
+use nom::sequence::{preceded, terminated, tuple};
 use nom::IResult;
 
 use base::column::Column;
@@ -50,13 +50,13 @@ impl OrderClause {
 
 impl fmt::Display for OrderClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ORDER BY ")?;
-        write!(
+        write_sql!(f, "ORDER BY ")?;
+        write_sql!(
             f,
             "{}",
             self.columns
                 .iter()
-                .map(|(c, o)| format!("{} {}", DisplayUtil::escape_if_keyword(&c.name), o))
+                .map(|(c, o)| format!("{} {}", c, o))
                 .collect::<Vec<_>>()
                 .join(", ")
         )
@@ -72,18 +72,22 @@ pub enum OrderType {
 
 impl OrderType {
     pub fn parse(i: &str) -> IResult<&str, OrderType, ParseSQLError<&str>> {
-        alt((
-            map(tag_no_case("DESC"), |_| OrderType::Desc),
-            map(tag_no_case("ASC"), |_| OrderType::Asc),
-        ))(i)
+        terminated(
+            alt((
+                map(tag_no_case("DESC"), |_| OrderType::Desc),
+                map(tag_no_case("ASC"), |_| OrderType::Asc),
+            )),
+            // `ORDER BY description` must not split into `DESC` + `ription`
+            not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+        )(i)
     }
 }
 
 impl Display for OrderType {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match *self {
-            OrderType::Asc => write!(f, "ASC"),
-            OrderType::Desc => write!(f, "DESC"),
+            OrderType::Asc => write_sql!(f, "ASC"),
+            OrderType::Desc => write_sql!(f, "DESC"),
         }
     }
 }
@@ -130,5 +134,22 @@ mod tests {
         let res2 = OrderType::parse(str2);
         assert!(res2.is_ok());
         assert_eq!(res2.unwrap().1, OrderType::Desc);
+
+        assert!(OrderType::parse("description").is_err());
+        assert!(OrderType::parse("ascii_name").is_err());
+    }
+
+    #[test]
+    fn order_by_column_starting_with_order_type() {
+        let str = "order by description, ascent desc, ascii_name";
+        let expected = OrderClause {
+            columns: vec![
+                ("description".into(), OrderType::Asc),
+                ("ascent".into(), OrderType::Desc),
+                ("ascii_name".into(), OrderType::Asc),
+            ],
+        };
+        let res = OrderClause::parse(str);
+        assert_eq!(res.unwrap().1, expected);
     }
 }
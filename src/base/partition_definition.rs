@@ -1,3 +1,201 @@
-// TODO support partition
+use std::fmt::{Display, Formatter};
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil, FieldValueExpression};
+
+/// partition_definition:
+///     `PARTITION partition_name
+///         [VALUES
+///             {LESS THAN {(expr | value_list) | MAXVALUE}
+///             |
+///             IN (value_list)}]
+///         [[STORAGE] ENGINE [=] engine_name]
+///         [COMMENT [=] 'string' ]`
+///
+/// `MAXVALUE` is kept as a value of a `LESS THAN` list, so `LESS THAN MAXVALUE`
+/// is written back as `LESS THAN (MAXVALUE)`.
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-pub struct PartitionDefinition {}
+pub struct PartitionDefinition {
+    pub name: String,
+    pub values: Option<PartitionValues>,
+    pub engine: Option<String>,
+    pub comment: Option<String>,
+}
+
+impl Display for PartitionDefinition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_sql!(
+            f,
+            "PARTITION {}",
+            DisplayUtil::escape_if_keyword(&self.name)
+        )?;
+        if let Some(ref values) = self.values {
+            write_sql!(f, " {}", values)?;
+        }
+        if let Some(ref engine) = self.engine {
+            write_sql!(f, " ENGINE = {}", engine)?;
+        }
+        if let Some(ref comment) = self.comment {
+            write_sql!(f, " COMMENT = '{}'", comment)?;
+        }
+        Ok(())
+    }
+}
+
+impl PartitionDefinition {
+    pub fn parse(i: &str) -> IResult<&str, PartitionDefinition, ParseSQLError<&str>> {
+        map(
+            tuple((
+                tag_no_case("PARTITION"),
+                multispace1,
+                map(CommonParser::sql_identifier, String::from),
+                opt(preceded(multispace1, PartitionValues::parse)),
+                opt(preceded(
+                    tuple((
+                        multispace1,
+                        opt(pair(tag_no_case("STORAGE"), multispace1)),
+                        tag_no_case("ENGINE"),
+                        alt((CommonParser::ws_sep_equals, multispace1)),
+                    )),
+                    map(CommonParser::sql_identifier, String::from),
+                )),
+                opt(preceded(
+                    tuple((
+                        multispace1,
+                        tag_no_case("COMMENT"),
+                        alt((CommonParser::ws_sep_equals, multispace1)),
+                    )),
+                    CommonParser::parse_quoted_string,
+                )),
+            )),
+            |(_, _, name, values, engine, comment)| PartitionDefinition {
+                name,
+                values,
+                engine,
+                comment,
+            },
+        )(i)
+    }
+
+    /// `(partition_definition [, partition_definition] ...)`
+    pub fn parse_list(i: &str) -> IResult<&str, Vec<PartitionDefinition>, ParseSQLError<&str>> {
+        delimited(
+            pair(tag("("), multispace0),
+            separated_list1(CommonParser::ws_sep_comma, Self::parse),
+            pair(multispace0, tag(")")),
+        )(i)
+    }
+
+    pub fn format_list(list: &[PartitionDefinition]) -> String {
+        format!(
+            "({})",
+            list.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+/// `VALUES {LESS THAN {(value_list) | MAXVALUE} | IN (value_list)}`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum PartitionValues {
+    LessThan(Vec<FieldValueExpression>),
+    In(Vec<FieldValueExpression>),
+}
+
+impl Display for PartitionValues {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (keyword, values) = match *self {
+            PartitionValues::LessThan(ref values) => ("LESS THAN", values),
+            PartitionValues::In(ref values) => ("IN", values),
+        };
+        let values = values
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        write_sql!(f, "VALUES ")?;
+        DisplayUtil::write_keyword(f, keyword)?;
+        write_sql!(f, " ({})", values)
+    }
+}
+
+impl PartitionValues {
+    pub fn parse(i: &str) -> IResult<&str, PartitionValues, ParseSQLError<&str>> {
+        let value_list = || {
+            delimited(
+                pair(tag("("), multispace0),
+                separated_list1(CommonParser::ws_sep_comma, FieldValueExpression::parse),
+                pair(multispace0, tag(")")),
+            )
+        };
+        preceded(
+            pair(tag_no_case("VALUES"), multispace1),
+            alt((
+                map(
+                    preceded(
+                        tuple((
+                            tag_no_case("LESS"),
+                            multispace1,
+                            tag_no_case("THAN"),
+                            multispace0,
+                        )),
+                        alt((
+                            value_list(),
+                            map(FieldValueExpression::parse, |value| vec![value]),
+                        )),
+                    ),
+                    PartitionValues::LessThan,
+                ),
+                map(
+                    preceded(pair(tag_no_case("IN"), multispace0), value_list()),
+                    PartitionValues::In,
+                ),
+            )),
+        )(i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::partition_definition::{PartitionDefinition, PartitionValues};
+    use base::{Column, FieldValueExpression, Literal};
+
+    #[test]
+    fn parse_partition_definition() {
+        let str = "PARTITION p0 VALUES LESS THAN (1991) ENGINE=InnoDB COMMENT 'old'";
+        let res = PartitionDefinition::parse(str);
+        let exp = PartitionDefinition {
+            name: "p0".to_string(),
+            values: Some(PartitionValues::LessThan(vec![
+                FieldValueExpression::Literal(Literal::Integer(1991).into()),
+            ])),
+            engine: Some("InnoDB".to_string()),
+            comment: Some("old".to_string()),
+        };
+        assert_eq!(res.unwrap(), ("", exp));
+
+        let str = "PARTITION p3 VALUES LESS THAN MAXVALUE";
+        let res = PartitionDefinition::parse(str).unwrap().1;
+        assert_eq!(
+            res.values,
+            Some(PartitionValues::LessThan(vec![
+                FieldValueExpression::Column(Column::from("MAXVALUE"))
+            ]))
+        );
+        assert_eq!(res.to_string(), "PARTITION p3 VALUES LESS THAN (MAXVALUE)");
+
+        let str = "PARTITION pEast VALUES IN (1, 2, 3)";
+        let res = PartitionDefinition::parse(str).unwrap().1;
+        assert_eq!(res.to_string(), str);
+    }
+}
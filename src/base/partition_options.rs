@@ -0,0 +1,276 @@
+use std::fmt::{Display, Formatter};
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1, one_of};
+use nom::combinator::{map, opt};
+use nom::multi::separated_list0;
+use nom::sequence::{delimited, pair, preceded, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil, FieldValueExpression, PartitionDefinition};
+
+/// partition_options:
+///     `PARTITION BY
+///         { [LINEAR] HASH(expr)
+///         | [LINEAR] KEY [ALGORITHM={1 | 2}] (column_list)
+///         | RANGE{(expr) | COLUMNS(column_list)}
+///         | LIST{(expr) | COLUMNS(column_list)} }
+///     [PARTITIONS num]
+///     [(partition_definition [, partition_definition] ...)]`
+///
+/// Subpartitioning is not supported.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct PartitionOptions {
+    pub partition_by: PartitionBy,
+    pub partitions: Option<u64>,
+    pub definitions: Vec<PartitionDefinition>,
+}
+
+impl Display for PartitionOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write_sql!(f, "PARTITION BY {}", self.partition_by)?;
+        if let Some(partitions) = self.partitions {
+            write_sql!(f, " PARTITIONS {}", partitions)?;
+        }
+        if !self.definitions.is_empty() {
+            write_sql!(
+                f,
+                " {}",
+                PartitionDefinition::format_list(&self.definitions)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl PartitionOptions {
+    pub fn parse(i: &str) -> IResult<&str, PartitionOptions, ParseSQLError<&str>> {
+        map(
+            tuple((
+                tag_no_case("PARTITION"),
+                multispace1,
+                tag_no_case("BY"),
+                multispace1,
+                PartitionBy::parse,
+                opt(preceded(
+                    tuple((multispace1, tag_no_case("PARTITIONS"), multispace1)),
+                    CommonParser::unsigned_number,
+                )),
+                opt(preceded(multispace0, PartitionDefinition::parse_list)),
+            )),
+            |(_, _, _, _, partition_by, partitions, definitions)| PartitionOptions {
+                partition_by,
+                partitions,
+                definitions: definitions.unwrap_or_default(),
+            },
+        )(i)
+    }
+}
+
+/// how rows are assigned to partitions, see [PartitionOptions]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum PartitionBy {
+    Hash {
+        linear: bool,
+        expr: FieldValueExpression,
+    },
+    Key {
+        linear: bool,
+        algorithm: Option<u8>,
+        columns: Vec<String>,
+    },
+    Range(PartitionKey),
+    List(PartitionKey),
+}
+
+impl Display for PartitionBy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PartitionBy::Hash { linear, ref expr } => {
+                if linear {
+                    write_sql!(f, "LINEAR ")?;
+                }
+                write_sql!(f, "HASH ({})", expr)
+            }
+            PartitionBy::Key {
+                linear,
+                algorithm,
+                ref columns,
+            } => {
+                if linear {
+                    write_sql!(f, "LINEAR ")?;
+                }
+                write_sql!(f, "KEY")?;
+                if let Some(algorithm) = algorithm {
+                    write_sql!(f, " ALGORITHM = {}", algorithm)?;
+                }
+                write_sql!(f, " ({})", format_columns(columns))
+            }
+            PartitionBy::Range(ref key) => write_sql!(f, "RANGE {}", key),
+            PartitionBy::List(ref key) => write_sql!(f, "LIST {}", key),
+        }
+    }
+}
+
+impl PartitionBy {
+    pub fn parse(i: &str) -> IResult<&str, PartitionBy, ParseSQLError<&str>> {
+        let linear = || {
+            map(opt(pair(tag_no_case("LINEAR"), multispace1)), |x| {
+                x.is_some()
+            })
+        };
+        alt((
+            map(
+                tuple((
+                    linear(),
+                    tag_no_case("HASH"),
+                    multispace0,
+                    delimited(
+                        pair(tag("("), multispace0),
+                        FieldValueExpression::parse,
+                        pair(multispace0, tag(")")),
+                    ),
+                )),
+                |(linear, _, _, expr)| PartitionBy::Hash { linear, expr },
+            ),
+            map(
+                tuple((
+                    linear(),
+                    tag_no_case("KEY"),
+                    opt(preceded(
+                        tuple((
+                            multispace1,
+                            tag_no_case("ALGORITHM"),
+                            CommonParser::ws_sep_equals,
+                        )),
+                        map(one_of("12"), |x| x as u8 - b'0'),
+                    )),
+                    multispace0,
+                    column_list,
+                )),
+                |(linear, _, algorithm, _, columns)| PartitionBy::Key {
+                    linear,
+                    algorithm,
+                    columns,
+                },
+            ),
+            map(
+                preceded(pair(tag_no_case("RANGE"), multispace0), PartitionKey::parse),
+                PartitionBy::Range,
+            ),
+            map(
+                preceded(pair(tag_no_case("LIST"), multispace0), PartitionKey::parse),
+                PartitionBy::List,
+            ),
+        ))(i)
+    }
+}
+
+/// `{(expr) | COLUMNS(column_list)}` of `RANGE` and `LIST` partitioning
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum PartitionKey {
+    Expression(FieldValueExpression),
+    Columns(Vec<String>),
+}
+
+impl Display for PartitionKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PartitionKey::Expression(ref expr) => write_sql!(f, "({})", expr),
+            PartitionKey::Columns(ref columns) => {
+                write_sql!(f, "COLUMNS ({})", format_columns(columns))
+            }
+        }
+    }
+}
+
+impl PartitionKey {
+    pub fn parse(i: &str) -> IResult<&str, PartitionKey, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(pair(tag_no_case("COLUMNS"), multispace0), column_list),
+                PartitionKey::Columns,
+            ),
+            map(
+                delimited(
+                    pair(tag("("), multispace0),
+                    FieldValueExpression::parse,
+                    pair(multispace0, tag(")")),
+                ),
+                PartitionKey::Expression,
+            ),
+        ))(i)
+    }
+}
+
+/// `(column_list)`, which is empty for `KEY()` partitioning on the primary key
+fn column_list(i: &str) -> IResult<&str, Vec<String>, ParseSQLError<&str>> {
+    delimited(
+        pair(tag("("), multispace0),
+        separated_list0(
+            CommonParser::ws_sep_comma,
+            map(CommonParser::sql_identifier, String::from),
+        ),
+        pair(multispace0, tag(")")),
+    )(i)
+}
+
+fn format_columns(columns: &[String]) -> String {
+    columns
+        .iter()
+        .map(|x| DisplayUtil::escape_if_keyword(x))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use base::partition_options::{PartitionBy, PartitionKey, PartitionOptions};
+    use base::{Column, FieldValueExpression};
+
+    #[test]
+    fn parse_partition_options() {
+        let str = "PARTITION BY RANGE (YEAR(hired)) (\
+            PARTITION p0 VALUES LESS THAN (1991), \
+            PARTITION p1 VALUES LESS THAN (MAXVALUE))";
+        let res = PartitionOptions::parse(str).unwrap();
+        assert_eq!(res.0, "");
+        assert!(matches!(
+            res.1.partition_by,
+            PartitionBy::Range(PartitionKey::Expression(FieldValueExpression::Column(_)))
+        ));
+        assert_eq!(res.1.definitions.len(), 2);
+        assert_eq!(
+            res.1.to_string(),
+            "PARTITION BY RANGE (YEAR(hired)) (\
+            PARTITION p0 VALUES LESS THAN (1991), \
+            PARTITION p1 VALUES LESS THAN (MAXVALUE))"
+        );
+
+        let str = "PARTITION BY LINEAR HASH (id) PARTITIONS 4";
+        let res = PartitionOptions::parse(str).unwrap().1;
+        assert_eq!(
+            res.partition_by,
+            PartitionBy::Hash {
+                linear: true,
+                expr: FieldValueExpression::Column(Column::from("id")),
+            }
+        );
+        assert_eq!(res.partitions, Some(4));
+        assert_eq!(res.to_string(), str);
+
+        let parts = [
+            "PARTITION BY KEY ALGORITHM = 2 (a, b) PARTITIONS 2",
+            "PARTITION BY KEY () PARTITIONS 2",
+            "PARTITION BY LIST COLUMNS (region) (PARTITION pEast VALUES IN ('NY', 'MA'))",
+        ];
+        for part in parts {
+            let res = PartitionOptions::parse(part).unwrap();
+            assert_eq!(res.0, "");
+            assert_eq!(res.1.to_string(), part);
+        }
+    }
+}
@@ -1,11 +1,12 @@
+use nom::branch::alt;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::{multispace0, multispace1};
 use nom::combinator::{map, opt};
-use nom::sequence::tuple;
+use nom::sequence::{pair, preceded, tuple};
 use nom::IResult;
 use std::fmt::{Display, Formatter};
 
-use base::error::ParseSQLError;
+use base::error::{ParseSQLError, ParseSQLErrorKind};
 use base::reference_type::ReferenceType;
 use base::{CommonParser, KeyPart, MatchType};
 
@@ -25,21 +26,20 @@ pub struct ReferenceDefinition {
 
 impl Display for ReferenceDefinition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        let key_part = self
-            .key_part
-            .iter()
-            .map(|x| x.to_string())
-            .collect::<Vec<String>>()
-            .join(" ");
-        write!(f, "REFERENCES {} ({})", self.tbl_name, key_part);
+        write_sql!(
+            f,
+            "REFERENCES {} {}",
+            self.tbl_name,
+            KeyPart::format_list(&self.key_part)
+        );
         if let Some(match_type) = &self.match_type {
-            write!(f, " {}", match_type);
+            write_sql!(f, " {}", match_type);
         }
         if let Some(on_delete) = &self.on_delete {
-            write!(f, " {}", on_delete);
+            write_sql!(f, " ON DELETE {}", on_delete);
         }
         if let Some(on_update) = &self.on_update {
-            write!(f, " {}", on_update);
+            write_sql!(f, " ON UPDATE {}", on_update);
         }
 
         Ok(())
@@ -48,43 +48,20 @@ impl Display for ReferenceDefinition {
 
 impl ReferenceDefinition {
     pub fn parse(i: &str) -> IResult<&str, ReferenceDefinition, ParseSQLError<&str>> {
-        let opt_on_delete = opt(map(
-            tuple((
-                tag_no_case("ON"),
-                multispace1,
-                tag_no_case("DELETE"),
-                multispace1,
-                ReferenceType::parse,
-            )),
-            |(_, _, _, _, reference_type)| reference_type,
-        ));
-        let opt_on_update = opt(map(
-            tuple((
-                tag_no_case("ON"),
-                multispace1,
-                tag_no_case("UPDATE"),
-                multispace1,
-                ReferenceType::parse,
-            )),
-            |(_, _, _, _, reference_type)| reference_type,
-        ));
-
         map(
             tuple((
                 tuple((multispace0, tag_no_case("REFERENCES"), multispace1)),
                 // tbl_name
                 map(CommonParser::sql_identifier, String::from),
                 multispace0,
-                KeyPart::parse, // (key_part,...)
+                Self::key_part, // (key_part,...)
                 multispace0,
                 opt(MatchType::parse), // [MATCH FULL | MATCH PARTIAL | MATCH SIMPLE]
                 multispace0,
-                opt_on_delete,
-                multispace0,
-                opt_on_update,
+                Self::on_delete_and_on_update,
                 multispace0,
             )),
-            |(_, tbl_name, _, key_part, _, match_type, _, on_delete, _, on_update, _)| {
+            |(_, tbl_name, _, key_part, _, match_type, _, (on_delete, on_update), _)| {
                 ReferenceDefinition {
                     tbl_name,
                     key_part,
@@ -95,6 +72,59 @@ impl ReferenceDefinition {
             },
         )(i)
     }
+
+    /// `[ON DELETE reference_option] [ON UPDATE reference_option]`, in either order
+    #[allow(clippy::type_complexity)]
+    fn on_delete_and_on_update(
+        i: &str,
+    ) -> IResult<&str, (Option<ReferenceType>, Option<ReferenceType>), ParseSQLError<&str>> {
+        alt((
+            map(
+                pair(
+                    Self::on_action("UPDATE"),
+                    opt(preceded(multispace1, Self::on_action("DELETE"))),
+                ),
+                |(on_update, on_delete)| (on_delete, Some(on_update)),
+            ),
+            map(
+                pair(
+                    opt(Self::on_action("DELETE")),
+                    opt(preceded(multispace1, Self::on_action("UPDATE"))),
+                ),
+                |(on_delete, on_update)| (on_delete, on_update),
+            ),
+        ))(i)
+    }
+
+    /// `ON {DELETE | UPDATE} reference_option`
+    fn on_action<'a>(
+        action: &'static str,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, ReferenceType, ParseSQLError<&'a str>> {
+        map(
+            tuple((
+                tag_no_case("ON"),
+                multispace1,
+                tag_no_case(action),
+                multispace1,
+                ReferenceType::parse,
+            )),
+            |(_, _, _, _, reference_type)| reference_type,
+        )
+    }
+
+    /// `(key_part,...)` of the referenced table, which MySQL does not allow to carry `ASC` or `DESC`
+    fn key_part(i: &str) -> IResult<&str, Vec<KeyPart>, ParseSQLError<&str>> {
+        let (remaining_input, key_part) = KeyPart::parse(i)?;
+        if key_part.iter().any(|x| x.order.is_some()) {
+            return Err(nom::Err::Failure(ParseSQLError {
+                errors: vec![(
+                    i,
+                    ParseSQLErrorKind::Context("ASC or DESC is not allowed in REFERENCES"),
+                )],
+            }));
+        }
+        Ok((remaining_input, key_part))
+    }
 }
 
 #[cfg(test)]
@@ -149,4 +179,16 @@ mod tests {
         assert!(res2.is_ok());
         assert_eq!(res2.unwrap().1, exp2);
     }
+
+    #[test]
+    fn reject_ordered_reference_key_part() {
+        let parts = [
+            "references tbl_name (col_name1 DESC)",
+            "references tbl_name (col_name1, col_name2 ASC)",
+        ];
+        for part in parts {
+            let res = ReferenceDefinition::parse(part);
+            assert!(matches!(res, Err(nom::Err::Failure(_))), "{}", part);
+        }
+    }
 }
@@ -22,11 +22,11 @@ pub enum ReferenceType {
 impl Display for ReferenceType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            ReferenceType::Restrict => write!(f, "RESTRICT"),
-            ReferenceType::Cascade => write!(f, "CASCADE"),
-            ReferenceType::SetNull => write!(f, "SET NULL"),
-            ReferenceType::NoAction => write!(f, "NO ACTION"),
-            ReferenceType::SetDefault => write!(f, "SET DEFAULT"),
+            ReferenceType::Restrict => write_sql!(f, "RESTRICT"),
+            ReferenceType::Cascade => write_sql!(f, "CASCADE"),
+            ReferenceType::SetNull => write_sql!(f, "SET NULL"),
+            ReferenceType::NoAction => write_sql!(f, "NO ACTION"),
+            ReferenceType::SetDefault => write_sql!(f, "SET DEFAULT"),
         }
     }
 }
@@ -22,12 +22,12 @@ pub enum RowFormatType {
 impl Display for RowFormatType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            RowFormatType::Default => write!(f, "ROW_FORMAT DEFAULT"),
-            RowFormatType::Dynamic => write!(f, "ROW_FORMAT DYNAMIC"),
-            RowFormatType::Fixed => write!(f, "ROW_FORMAT FIXED"),
-            RowFormatType::Compressed => write!(f, "ROW_FORMAT COMPRESSED"),
-            RowFormatType::Redundant => write!(f, "ROW_FORMAT REDUNDANT"),
-            RowFormatType::Compact => write!(f, "ROW_FORMAT COMPACT"),
+            RowFormatType::Default => write_sql!(f, "ROW_FORMAT DEFAULT"),
+            RowFormatType::Dynamic => write_sql!(f, "ROW_FORMAT DYNAMIC"),
+            RowFormatType::Fixed => write_sql!(f, "ROW_FORMAT FIXED"),
+            RowFormatType::Compressed => write_sql!(f, "ROW_FORMAT COMPRESSED"),
+            RowFormatType::Redundant => write_sql!(f, "ROW_FORMAT REDUNDANT"),
+            RowFormatType::Compact => write_sql!(f, "ROW_FORMAT COMPACT"),
         }
     }
 }
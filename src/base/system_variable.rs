@@ -0,0 +1,116 @@
+use std::fmt;
+use std::fmt::Display;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_while1};
+use nom::combinator::{consumed, map, opt};
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+
+use base::{CommonParser, ParseSQLError};
+use das::VariableScope;
+
+/// parse `@@[GLOBAL. | SESSION. | LOCAL. | PERSIST. | PERSIST_ONLY.]system_var_name`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SystemVariable {
+    /// the scope and its spelling as written, e.g. `global` in `@@global.x`
+    pub scope: Option<(VariableScope, String)>,
+    pub name: String,
+}
+
+impl SystemVariable {
+    pub fn parse(i: &str) -> IResult<&str, SystemVariable, ParseSQLError<&str>> {
+        map(
+            preceded(
+                tag("@@"),
+                tuple((
+                    opt(terminated(
+                        consumed(VariableScope::parse_with_persist),
+                        tag("."),
+                    )),
+                    alt((
+                        map(
+                            take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+                            String::from,
+                        ),
+                        map(CommonParser::backquoted_identifier, String::from),
+                    )),
+                )),
+            ),
+            |(scope, name)| SystemVariable {
+                scope: scope.map(|(spelling, scope)| (scope, String::from(spelling))),
+                name,
+            },
+        )(i)
+    }
+}
+
+impl Display for SystemVariable {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "@@")?;
+        if let Some((_, ref spelling)) = self.scope {
+            write_sql!(f, "{}.", spelling)?;
+        }
+        // keywords need no quotes after `@@`, only unusual characters do
+        if !self.name.is_empty()
+            && self
+                .name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_')
+        {
+            write_sql!(f, "{}", self.name)
+        } else {
+            write_sql!(f, "`{}`", self.name.replace('`', "``"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::SystemVariable;
+    use das::VariableScope;
+
+    #[test]
+    fn parse_system_variable() {
+        let res = SystemVariable::parse("@@version");
+        let exp = SystemVariable {
+            scope: None,
+            name: "version".to_string(),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), "@@version");
+
+        let res = SystemVariable::parse("@@global.max_connections");
+        let exp = SystemVariable {
+            scope: Some((VariableScope::Global, "global".to_string())),
+            name: "max_connections".to_string(),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), "@@global.max_connections");
+
+        let res = SystemVariable::parse("@@Session.autocommit").unwrap().1;
+        assert_eq!(
+            res.scope.as_ref().map(|(scope, _)| *scope),
+            Some(VariableScope::Session)
+        );
+        assert_eq!(res.to_string(), "@@Session.autocommit");
+
+        for (sql, scope) in [
+            ("@@LOCAL.sort_buffer_size", VariableScope::Local),
+            ("@@PERSIST.max_connections", VariableScope::Persist),
+            ("@@persist_only.back_log", VariableScope::PersistOnly),
+        ] {
+            let res = SystemVariable::parse(sql).unwrap().1;
+            assert_eq!(res.scope.as_ref().map(|(scope, _)| *scope), Some(scope));
+            assert_eq!(res.to_string(), sql);
+        }
+
+        let res = SystemVariable::parse("@@global.`sql_mode`").unwrap().1;
+        assert_eq!(res.name, "sql_mode");
+        assert_eq!(res.to_string(), "@@global.sql_mode");
+        let res = SystemVariable::parse("@@`my-var`").unwrap().1;
+        assert_eq!(res.to_string(), "@@`my-var`");
+
+        assert!(SystemVariable::parse("@var").is_err());
+    }
+}
@@ -1,15 +1,16 @@
 use std::fmt;
 use std::str;
 
-use nom::bytes::complete::{tag, tag_no_case};
+use nom::bytes::complete::{tag, tag_no_case, take_while, take_while1};
 use nom::character::complete::{multispace0, multispace1};
-use nom::combinator::{map, opt};
+use nom::combinator::{map, opt, recognize};
 use nom::multi::many0;
-use nom::sequence::{pair, terminated, tuple};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use base::error::ParseSQLError;
 use base::{CommonParser, DisplayUtil};
+use parser::ParseConfig;
 
 /// **Table Definition**
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -20,6 +21,9 @@ pub struct Table {
     pub alias: Option<String>,
     /// Optional schema/database name
     pub schema: Option<String>,
+    /// `TABLESAMPLE` clause of other dialects as written after the keyword,
+    /// e.g. `SYSTEM (10)`, only parsed when [crate::ParseConfig::tablesample] is set
+    pub tablesample: Option<String>,
 }
 
 impl Table {
@@ -39,11 +43,13 @@ impl Table {
                 opt(pair(CommonParser::sql_identifier, tag("."))),
                 CommonParser::sql_identifier,
                 opt(CommonParser::as_alias),
+                Self::tablesample,
             )),
             |tup| Table {
                 name: String::from(tup.1),
                 alias: tup.2.map(String::from),
                 schema: tup.0.map(|(schema, _)| String::from(schema)),
+                tablesample: tup.3,
             },
         )(i)
     }
@@ -51,15 +57,46 @@ impl Table {
     // Parse a reference to a named table, with an optional alias
     pub fn table_reference(i: &str) -> IResult<&str, Table, ParseSQLError<&str>> {
         map(
-            pair(CommonParser::sql_identifier, opt(CommonParser::as_alias)),
+            tuple((
+                CommonParser::sql_identifier,
+                opt(CommonParser::as_alias),
+                Self::tablesample,
+            )),
             |tup| Table {
                 name: String::from(tup.0),
                 alias: tup.1.map(String::from),
                 schema: None,
+                tablesample: tup.2,
             },
         )(i)
     }
 
+    /// `TABLESAMPLE method (size) [REPEATABLE (seed)]`, which MySQL lacks, so
+    /// it is skipped unless enabled
+    fn tablesample(i: &str) -> IResult<&str, Option<String>, ParseSQLError<&str>> {
+        if !ParseConfig::with_current(|config| config.tablesample) {
+            return Ok((i, None));
+        }
+        let parenthesized = || delimited(tag("("), take_while(|c| c != ')'), tag(")"));
+        opt(map(
+            preceded(
+                tuple((multispace1, tag_no_case("TABLESAMPLE"), multispace1)),
+                recognize(tuple((
+                    take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+                    multispace0,
+                    parenthesized(),
+                    opt(tuple((
+                        multispace1,
+                        tag_no_case("REPEATABLE"),
+                        multispace0,
+                        parenthesized(),
+                    ))),
+                ))),
+            ),
+            String::from,
+        ))(i)
+    }
+
     /// table alias not allowed in DROP/TRUNCATE/RENAME TABLE statement
     pub fn without_alias(i: &str) -> IResult<&str, Table, ParseSQLError<&str>> {
         map(
@@ -71,6 +108,7 @@ impl Table {
                 name: String::from(tup.1),
                 alias: None,
                 schema: tup.0.map(|(schema, _)| String::from(schema)),
+                tablesample: None,
             },
         )(i)
     }
@@ -81,11 +119,11 @@ impl Table {
     ) -> IResult<&str, (Table, Table), ParseSQLError<&str>> {
         map(
             tuple((
-                Self::schema_table_reference,
-                multispace0,
+                Self::without_alias,
+                multispace1,
                 tag_no_case("TO"),
                 multispace1,
-                Self::schema_table_reference,
+                Self::without_alias,
             )),
             |(from, _, _, _, to)| (from, to),
         )(i)
@@ -95,22 +133,33 @@ impl Table {
 impl fmt::Display for Table {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(ref schema) = self.schema {
-            write!(f, "{}.", DisplayUtil::escape_if_keyword(schema))?;
+            write_sql!(f, "{}.", DisplayUtil::escape_if_keyword(schema))?;
         }
-        write!(f, "{}", DisplayUtil::escape_if_keyword(&self.name))?;
+        write_sql!(f, "{}", DisplayUtil::escape_if_keyword(&self.name))?;
         if let Some(ref alias) = self.alias {
-            write!(f, " AS {}", DisplayUtil::escape_if_keyword(alias))?;
+            write_sql!(f, " AS {}", DisplayUtil::escape_if_keyword(alias))?;
+        }
+        if let Some(ref tablesample) = self.tablesample {
+            write_sql!(f, " TABLESAMPLE {}", tablesample)?;
         }
         Ok(())
     }
 }
 
-impl<'a> From<&'a str> for Table {
+/// Split `[db_name.]tbl_name` at the dot outside backquotes, dropping the
+/// backquotes, e.g. `` `my db`.t ``.
+impl From<&str> for Table {
     fn from(t: &str) -> Table {
+        let mut parts = CommonParser::split_qualified_name(t);
+        let name = parts.pop().unwrap_or_default();
         Table {
-            name: String::from(t),
+            name,
             alias: None,
-            schema: None,
+            schema: match parts.is_empty() {
+                true => None,
+                false => Some(parts.join(".")),
+            },
+            tablesample: None,
         }
     }
 }
@@ -121,6 +170,7 @@ impl<'a> From<(&'a str, &'a str)> for Table {
             name: String::from(t.1),
             alias: None,
             schema: Some(String::from(t.0)),
+            tablesample: None,
         }
     }
 }
@@ -137,6 +187,7 @@ mod tests {
             name: "tbl_name".to_string(),
             alias: None,
             schema: None,
+            tablesample: None,
         };
         assert!(res1.is_ok());
         assert_eq!(res1.unwrap().1, exp1);
@@ -147,6 +198,7 @@ mod tests {
             name: "tbl_name".to_string(),
             alias: None,
             schema: Some("foo".to_string()),
+            tablesample: None,
         };
         assert!(res2.is_ok());
         assert_eq!(res2.unwrap().1, exp2);
@@ -157,6 +209,7 @@ mod tests {
             name: "tbl_name".to_string(),
             alias: Some("bar".to_string()),
             schema: Some("foo".to_string()),
+            tablesample: None,
         };
         assert!(res3.is_ok());
         assert_eq!(res3.unwrap().1, exp3);
@@ -169,8 +222,18 @@ mod tests {
             name: "tbl_name".to_string(),
             alias: None,
             schema: None,
+            tablesample: None,
         };
         assert_eq!(trigger1, exp1);
+
+        let qualified = Table::from("db1.PaperTag");
+        assert_eq!(qualified, Table::from(("db1", "PaperTag")));
+        assert_eq!(qualified.to_string(), "db1.PaperTag");
+
+        let quoted = Table::from("`my.db`.`order`");
+        assert_eq!(quoted.schema, Some("my.db".to_string()));
+        assert_eq!(quoted.name, "order");
+        assert_eq!(Table::from("`tbl_name`"), Table::from("tbl_name"));
     }
 
     #[test]
@@ -180,7 +243,36 @@ mod tests {
             name: "tbl_name".to_string(),
             alias: None,
             schema: Some("foo".to_string()),
+            tablesample: None,
         };
         assert_eq!(table2, exp2);
     }
+
+    #[test]
+    fn display() {
+        let plain = Table::from("tbl_name");
+        assert_eq!(plain.to_string(), "tbl_name");
+
+        let qualified = Table::from(("foo", "tbl_name"));
+        assert_eq!(qualified.to_string(), "foo.tbl_name");
+
+        let aliased = Table {
+            name: "tbl_name".to_string(),
+            alias: Some("t".to_string()),
+            schema: Some("foo".to_string()),
+            tablesample: None,
+        };
+        assert_eq!(aliased.to_string(), "foo.tbl_name AS t");
+
+        let quoted = Table {
+            name: "order".to_string(),
+            alias: Some("my alias".to_string()),
+            schema: Some("my-db".to_string()),
+            tablesample: None,
+        };
+        let display = quoted.to_string();
+        assert_eq!(display, "`my-db`.`order` AS `my alias`");
+        let res = Table::schema_table_reference(&display);
+        assert_eq!(res.unwrap().1, quoted);
+    }
 }
@@ -1,16 +1,16 @@
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_until};
 use nom::character::complete::{digit1, multispace0, multispace1};
-use nom::combinator::{map, opt, value};
-use nom::sequence::{delimited, tuple};
+use nom::combinator::{map, map_res, opt, value};
+use nom::sequence::{delimited, pair, preceded, tuple};
 use nom::{IResult, Parser};
 use std::fmt::{write, Display, Formatter};
 
 use base::column::Column;
 use base::error::ParseSQLError;
 use base::{
-    CommonParser, CompressionType, DefaultOrZeroOrOne, InsertMethodType, RowFormatType,
-    TablespaceType,
+    CommonParser, CompressionType, DefaultOrZeroOrOne, DisplayUtil, InsertMethodType,
+    RowFormatType, TablespaceType,
 };
 
 /// table_option: `{
@@ -58,6 +58,8 @@ pub enum TableOption {
     IndexDirectory(String),
     DelayKeyWrite(u8),
     Encryption(bool),
+    /// the engine name as written, e.g. `InnoDB` or `innodb`; MySQL compares
+    /// engine names case-insensitively, so the casing is kept for the dump
     Engine(String),
     EngineAttribute(String),
     InsertMethod(InsertMethodType),
@@ -79,46 +81,49 @@ pub enum TableOption {
 impl Display for TableOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            TableOption::AutoextendSize(ref val) => write!(f, "AUTOEXTEND_SIZE {}", val),
-            TableOption::AutoIncrement(ref val) => write!(f, "AUTO_INCREMENT {}", val),
-            TableOption::AvgRowLength(ref val) => write!(f, "AVG_ROW_LENGTH {}", val),
-            TableOption::DefaultCharacterSet(ref val) => write!(f, "CHARACTER SET {}", val),
-            TableOption::DefaultCharset(ref val) => write!(f, "CHARSET {}", val),
-            TableOption::Checksum(ref val) => write!(f, "CHECKSUM {}", val),
-            TableOption::DefaultCollate(ref val) => write!(f, "COLLATE {}", val),
-            TableOption::Comment(ref val) => write!(f, "COMMENT '{}'", val),
-            TableOption::Compression(ref val) => write!(f, "COMPRESSION {}", val),
-            TableOption::Connection(ref val) => write!(f, "CONNECTION {}", val),
-            TableOption::DataDirectory(ref val) => write!(f, "DATA DIRECTORY '{}'", val),
-            TableOption::IndexDirectory(ref val) => write!(f, "INDEX DIRECTORY '{}'", val),
-            TableOption::DelayKeyWrite(ref val) => write!(f, "DELAY_KEY_WRITE {}", val),
-            TableOption::Encryption(ref val) => write!(f, "ENCRYPTION '{}'", val),
-            TableOption::Engine(ref val) => write!(f, "ENGINE {}", val),
-            TableOption::EngineAttribute(ref val) => write!(f, "ENGINE_ATTRIBUTE {}", val),
-            TableOption::InsertMethod(ref val) => write!(f, "INSERT_METHOD {}", val),
-            TableOption::KeyBlockSize(ref val) => write!(f, "KEY_BLOCK_SIZE {}", val),
-            TableOption::MaxRows(ref val) => write!(f, "MAX_ROWS {}", val),
-            TableOption::MinRows(ref val) => write!(f, "MIN_ROWS {}", val),
-            TableOption::PackKeys(ref val) => write!(f, "PACK_KEYS {}", val),
-            TableOption::Password(ref val) => write!(f, "PASSWORD '{}'", val),
-            TableOption::RowFormat(ref val) => write!(f, "ROW_FORMAT {}", val),
-            TableOption::StartTransaction => write!(f, "START TRANSACTION"),
+            TableOption::AutoextendSize(ref val) => write_sql!(f, "AUTOEXTEND_SIZE {}", val),
+            TableOption::AutoIncrement(ref val) => write_sql!(f, "AUTO_INCREMENT {}", val),
+            TableOption::AvgRowLength(ref val) => write_sql!(f, "AVG_ROW_LENGTH {}", val),
+            TableOption::DefaultCharacterSet(ref val) => write_sql!(f, "CHARACTER SET {}", val),
+            TableOption::DefaultCharset(ref val) => write_sql!(f, "CHARSET {}", val),
+            TableOption::Checksum(ref val) => write_sql!(f, "CHECKSUM {}", val),
+            TableOption::DefaultCollate(ref val) => write_sql!(f, "COLLATE {}", val),
+            TableOption::Comment(ref val) => write_sql!(f, "COMMENT '{}'", val),
+            TableOption::Compression(ref val) => write_sql!(f, "{}", val),
+            TableOption::Connection(ref val) => write_sql!(f, "CONNECTION '{}'", val),
+            TableOption::DataDirectory(ref val) => write_sql!(f, "DATA DIRECTORY '{}'", val),
+            TableOption::IndexDirectory(ref val) => write_sql!(f, "INDEX DIRECTORY '{}'", val),
+            TableOption::DelayKeyWrite(ref val) => write_sql!(f, "DELAY_KEY_WRITE {}", val),
+            TableOption::Encryption(val) => match val {
+                true => write_sql!(f, "ENCRYPTION {}", "'Y'"),
+                false => write_sql!(f, "ENCRYPTION {}", "'N'"),
+            },
+            TableOption::Engine(ref val) => write_sql!(f, "ENGINE {}", val),
+            TableOption::EngineAttribute(ref val) => write_sql!(f, "ENGINE_ATTRIBUTE '{}'", val),
+            TableOption::InsertMethod(ref val) => write_sql!(f, "{}", val),
+            TableOption::KeyBlockSize(ref val) => write_sql!(f, "KEY_BLOCK_SIZE {}", val),
+            TableOption::MaxRows(ref val) => write_sql!(f, "MAX_ROWS {}", val),
+            TableOption::MinRows(ref val) => write_sql!(f, "MIN_ROWS {}", val),
+            TableOption::PackKeys(ref val) => write_sql!(f, "PACK_KEYS {}", val),
+            TableOption::Password(ref val) => write_sql!(f, "PASSWORD '{}'", val),
+            TableOption::RowFormat(ref val) => write_sql!(f, "{}", val),
+            TableOption::StartTransaction => write_sql!(f, "START TRANSACTION"),
             TableOption::SecondaryEngineAttribute(ref val) => {
-                write!(f, "SECONDARY_ENGINE_ATTRIBUTE '{}'", val)
+                write_sql!(f, "SECONDARY_ENGINE_ATTRIBUTE '{}'", val)
             }
-            TableOption::StatsAutoRecalc(ref val) => write!(f, "STATS_AUTO_RECALC {}", val),
-            TableOption::StatsPersistent(ref val) => write!(f, "STATS_PERSISTENT {}", val),
-            TableOption::StatsSamplePages(ref val) => write!(f, "STATS_SAMPLE_PAGES {}", val),
+            TableOption::StatsAutoRecalc(ref val) => write_sql!(f, "STATS_AUTO_RECALC {}", val),
+            TableOption::StatsPersistent(ref val) => write_sql!(f, "STATS_PERSISTENT {}", val),
+            TableOption::StatsSamplePages(ref val) => write_sql!(f, "STATS_SAMPLE_PAGES {}", val),
             TableOption::Tablespace(ref tablespace_name, ref tbl_space_type) => {
-                write!(f, "TABLESPACE {}", tablespace_name);
+                write_sql!(f, "TABLESPACE {}", tablespace_name);
                 if let Some(tbl_space_type) = tbl_space_type {
-                    write!(f, " {}", tbl_space_type);
+                    write_sql!(f, " {}", tbl_space_type);
                 }
                 Ok(())
             }
             TableOption::Union(ref tbl_names) => {
-                let tbl_names = tbl_names.join(",");
-                write!(f, "UNION ({})", tbl_names)
+                let tbl_names = tbl_names.join(", ");
+                write_sql!(f, "UNION ({})", tbl_names)
             }
         }
     }
@@ -177,71 +182,70 @@ impl TableOption {
 
     /// parse `AUTOEXTEND_SIZE [=] value`
     fn autoextend_size(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
-            |x| CommonParser::parse_string_value_with_key(x, "AUTOEXTEND_SIZE".to_string()),
-            |value| TableOption::AutoextendSize(value.parse::<u64>().unwrap()),
+        map_res(
+            |x| CommonParser::parse_digit_value_with_key(x, "AUTOEXTEND_SIZE".to_string()),
+            |value| value.parse::<u64>().map(TableOption::AutoextendSize),
         )(i)
     }
 
     /// parse `AUTO_INCREMENT [=] value`
     fn auto_increment(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
+        map_res(
             |x| CommonParser::parse_digit_value_with_key(x, "AUTO_INCREMENT".to_string()),
-            |value| TableOption::AutoIncrement(value.parse::<u64>().unwrap()),
+            |value| value.parse::<u64>().map(TableOption::AutoIncrement),
         )(i)
     }
 
     /// parse `AVG_ROW_LENGTH [=] value`
     fn avg_row_length(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
-            |x| CommonParser::parse_string_value_with_key(x, "AVG_ROW_LENGTH".to_string()),
-            |value| TableOption::AvgRowLength(value.parse::<u64>().unwrap()),
+        map_res(
+            |x| CommonParser::parse_digit_value_with_key(x, "AVG_ROW_LENGTH".to_string()),
+            |value| value.parse::<u64>().map(TableOption::AvgRowLength),
         )(i)
     }
 
     /// parse `[DEFAULT] CHARACTER SET [=] charset_name`
     fn default_character_set(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        alt((
-            map(
-                tuple((
-                    opt(tag_no_case("DEFAULT ")),
-                    multispace0,
-                    tuple((
-                        tag_no_case("CHARACTER"),
-                        multispace1,
-                        tag_no_case("SET"),
-                        multispace1,
-                    )),
-                    map(CommonParser::sql_identifier, String::from),
-                )),
-                |(_, _, _, charset_name)| TableOption::DefaultCharacterSet(charset_name),
-            ),
-            map(
+        map(
+            preceded(
                 tuple((
-                    opt(tag_no_case("DEFAULT ")),
-                    multispace0,
-                    tuple((
-                        tag_no_case("CHARACTER"),
-                        multispace1,
-                        tag_no_case("SET"),
-                        multispace0,
-                        tag("="),
-                        multispace0,
-                    )),
-                    map(CommonParser::sql_identifier, String::from),
+                    opt(pair(tag_no_case("DEFAULT"), multispace1)),
+                    tag_no_case("CHARACTER"),
+                    multispace1,
+                    tag_no_case("SET"),
                 )),
-                |(_, _, _, charset_name)| TableOption::DefaultCharacterSet(charset_name),
+                Self::quotable_name,
             ),
-        ))(i)
+            TableOption::DefaultCharacterSet,
+        )(i)
     }
 
     /// parse `[DEFAULT] CHARSET [=] charset_name`
     fn default_charset(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
         map(
-            tuple((opt(tag_no_case("DEFAULT ")), multispace0, |x| {
-                CommonParser::parse_string_value_with_key(x, "CHARSET".to_string())
-            })),
-            |(_, _, charset_name)| TableOption::DefaultCharset(charset_name),
+            preceded(
+                pair(
+                    opt(pair(tag_no_case("DEFAULT"), multispace1)),
+                    tag_no_case("CHARSET"),
+                ),
+                Self::quotable_name,
+            ),
+            TableOption::DefaultCharset,
+        )(i)
+    }
+
+    /// `[=] name`, where the name may be quoted as in `CHARSET = 'utf8mb4'`
+    fn quotable_name(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+        map(
+            preceded(
+                alt((delimited(multispace0, tag("="), multispace0), multispace1)),
+                alt((
+                    CommonParser::sql_identifier,
+                    delimited(tag("'"), CommonParser::sql_identifier, tag("'")),
+                    delimited(tag("\""), CommonParser::sql_identifier, tag("\"")),
+                )),
+            ),
+            String::from,
         )(i)
     }
 
@@ -250,7 +254,7 @@ impl TableOption {
         alt((
             map(
                 tuple((
-                    tag_no_case("CHECKSUM "),
+                    tag_no_case("CHECKSUM"),
                     multispace1,
                     alt((map(tag("0"), |_| 0), map(tag("1"), |_| 1))),
                 )),
@@ -258,7 +262,7 @@ impl TableOption {
             ),
             map(
                 tuple((
-                    tag_no_case("CHECKSUM "),
+                    tag_no_case("CHECKSUM"),
                     multispace0,
                     tag("="),
                     multispace0,
@@ -272,10 +276,14 @@ impl TableOption {
     /// parse `[DEFAULT] COLLATE [=] collation_name`
     fn default_collate(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
         map(
-            tuple((opt(tag_no_case("DEFAULT ")), multispace0, |x| {
-                CommonParser::parse_string_value_with_key(x, "COLLATE".to_string())
-            })),
-            |(_, _, collation_name)| TableOption::DefaultCollate(collation_name),
+            preceded(
+                pair(
+                    opt(pair(tag_no_case("DEFAULT"), multispace1)),
+                    tag_no_case("COLLATE"),
+                ),
+                Self::quotable_name,
+            ),
+            TableOption::DefaultCollate,
         )(i)
     }
 
@@ -295,7 +303,7 @@ impl TableOption {
     /// parse `CONNECTION [=] 'connect_string'`
     fn connection(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
         map(
-            |x| CommonParser::parse_string_value_with_key(x, "CONNECTION".to_string()),
+            |x| CommonParser::parse_quoted_string_value_with_key(x, "CONNECTION".to_string()),
             TableOption::Connection,
         )(i)
     }
@@ -316,7 +324,7 @@ impl TableOption {
             tuple((tag_no_case("INDEX"), multispace1, |x| {
                 CommonParser::parse_quoted_string_value_with_key(x, "DIRECTORY".to_string())
             })),
-            |(_, _, path)| TableOption::DataDirectory(path),
+            |(_, _, path)| TableOption::IndexDirectory(path),
         )(i)
     }
 
@@ -325,21 +333,21 @@ impl TableOption {
         alt((
             map(
                 tuple((
-                    tag_no_case("DELAY_KEY_RITE"),
+                    tag_no_case("DELAY_KEY_WRITE"),
                     multispace1,
                     alt((map(tag("0"), |_| 0), map(tag("1"), |_| 1))),
                 )),
-                |(_, _, delay_key_rite)| TableOption::DelayKeyWrite(delay_key_rite),
+                |(_, _, delay_key_write)| TableOption::DelayKeyWrite(delay_key_write),
             ),
             map(
                 tuple((
-                    tag_no_case("DELAY_KEY_RITE"),
+                    tag_no_case("DELAY_KEY_WRITE"),
                     multispace0,
                     tag("="),
                     multispace0,
                     alt((map(tag("0"), |_| 0), map(tag("1"), |_| 1))),
                 )),
-                |(_, _, _, _, delay_key_rite)| TableOption::DelayKeyWrite(delay_key_rite),
+                |(_, _, _, _, delay_key_write)| TableOption::DelayKeyWrite(delay_key_write),
             ),
         ))(i)
     }
@@ -371,7 +379,7 @@ impl TableOption {
     /// parse `ENGINE [=] engine_name`
     fn engine(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
         map(
-            |x| CommonParser::parse_string_value_with_key(x, "ENGINE".to_string()),
+            preceded(tag_no_case("ENGINE"), Self::quotable_name),
             TableOption::Engine,
         )(i)
     }
@@ -391,25 +399,25 @@ impl TableOption {
 
     /// parse `KEY_BLOCK_SIZE [=] value`
     fn key_block_size(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
-            |x| CommonParser::parse_string_value_with_key(x, "KEY_BLOCK_SIZE".to_string()),
-            |value| TableOption::KeyBlockSize(value.parse::<u64>().unwrap()),
+        map_res(
+            |x| CommonParser::parse_digit_value_with_key(x, "KEY_BLOCK_SIZE".to_string()),
+            |value| value.parse::<u64>().map(TableOption::KeyBlockSize),
         )(i)
     }
 
     /// parse `MAX_ROWS [=] value`
     fn max_rows(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
-            |x| CommonParser::parse_string_value_with_key(x, "MAX_ROWS".to_string()),
-            |value| TableOption::MaxRows(value.parse::<u64>().unwrap()),
+        map_res(
+            |x| CommonParser::parse_digit_value_with_key(x, "MAX_ROWS".to_string()),
+            |value| value.parse::<u64>().map(TableOption::MaxRows),
         )(i)
     }
 
     /// parse `MIN_ROWS [=] value`
     fn min_rows(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
-            |x| CommonParser::parse_string_value_with_key(x, "MIN_ROWS".to_string()),
-            |value| TableOption::MinRows(value.parse::<u64>().unwrap()),
+        map_res(
+            |x| CommonParser::parse_digit_value_with_key(x, "MIN_ROWS".to_string()),
+            |value| value.parse::<u64>().map(TableOption::MinRows),
         )(i)
     }
 
@@ -471,16 +479,16 @@ impl TableOption {
     /// parse `STATS_PERSISTENT [=] {DEFAULT | 0 | 1}`
     fn stats_persistent(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
         map(
-            |x| CommonParser::parse_default_value_with_key(x, "STATS_AUTO_RECALC".to_string()),
-            TableOption::StatsAutoRecalc,
+            |x| CommonParser::parse_default_value_with_key(x, "STATS_PERSISTENT".to_string()),
+            TableOption::StatsPersistent,
         )(i)
     }
 
     /// parse `STATS_SAMPLE_PAGES [=] value`
     fn stats_sample_pages(i: &str) -> IResult<&str, TableOption, ParseSQLError<&str>> {
-        map(
-            |x| CommonParser::parse_string_value_with_key(x, "STATS_SAMPLE_PAGES".to_string()),
-            |value| TableOption::StatsSamplePages(value.parse::<u64>().unwrap()),
+        map_res(
+            |x| CommonParser::parse_digit_value_with_key(x, "STATS_SAMPLE_PAGES".to_string()),
+            |value| value.parse::<u64>().map(TableOption::StatsSamplePages),
         )(i)
     }
 
@@ -521,7 +529,7 @@ impl TableOption {
             ),
             map(
                 tuple((
-                    tag_no_case("UNION "),
+                    tag_no_case("UNION"),
                     multispace0,
                     tag("="),
                     multispace0,
@@ -553,13 +561,12 @@ pub struct CheckConstraintDefinition {
 
 impl Display for CheckConstraintDefinition {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "CONSTRAINT");
         if let Some(symbol) = &self.symbol {
-            write!(f, " {}", symbol);
+            write_sql!(f, "CONSTRAINT {} ", DisplayUtil::escape_if_keyword(symbol));
         }
-        write!(f, " CHECK {}", &self.expr);
+        write_sql!(f, "CHECK ({})", &self.expr);
         if !&self.enforced {
-            write!(f, " NOT ENFORCED");
+            write_sql!(f, " NOT ENFORCED");
         }
         Ok(())
     }
@@ -590,4 +597,51 @@ mod tests {
         assert!(res3.is_ok());
         assert_eq!(res3.unwrap().1, exp);
     }
+
+    #[test]
+    fn quoted_charset_and_collation() {
+        let parts = [
+            (
+                "DEFAULT CHARACTER SET = 'utf8mb4'",
+                TableOption::DefaultCharacterSet("utf8mb4".to_string()),
+                "CHARACTER SET utf8mb4",
+            ),
+            (
+                "character set \"utf8mb4\"",
+                TableOption::DefaultCharacterSet("utf8mb4".to_string()),
+                "CHARACTER SET utf8mb4",
+            ),
+            (
+                "DEFAULT CHARSET 'latin1'",
+                TableOption::DefaultCharset("latin1".to_string()),
+                "CHARSET latin1",
+            ),
+            (
+                "COLLATE='utf8mb4_bin'",
+                TableOption::DefaultCollate("utf8mb4_bin".to_string()),
+                "COLLATE utf8mb4_bin",
+            ),
+        ];
+        for (sql, exp, display) in parts {
+            let res = TableOption::parse(sql);
+            assert_eq!(res.unwrap().1, exp);
+            assert_eq!(exp.to_string(), display);
+        }
+    }
+
+    #[test]
+    fn engine_name_keeps_its_casing() {
+        let parts = [
+            ("ENGINE=innodb", "innodb"),
+            ("ENGINE = MyISAM", "MyISAM"),
+            ("ENGINE InnoDB", "InnoDB"),
+            ("ENGINE='InnoDB'", "InnoDB"),
+        ];
+        for (sql, name) in parts {
+            let res = TableOption::parse(sql).unwrap().1;
+            assert_eq!(res, TableOption::Engine(name.to_string()));
+            assert_eq!(res.to_string(), format!("ENGINE {}", name));
+            assert_eq!(TableOption::parse(&res.to_string()).unwrap().1, res);
+        }
+    }
 }
@@ -18,8 +18,8 @@ pub enum TablespaceType {
 impl Display for TablespaceType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            TablespaceType::StorageDisk => write!(f, "STORAGE DISK"),
-            TablespaceType::StorageMemory => write!(f, "STORAGE MEMORY"),
+            TablespaceType::StorageDisk => write_sql!(f, "STORAGE DISK"),
+            TablespaceType::StorageMemory => write_sql!(f, "STORAGE MEMORY"),
         }
     }
 }
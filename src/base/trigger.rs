@@ -33,14 +33,14 @@ impl Trigger {
 impl fmt::Display for Trigger {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if let Some(ref schema) = self.schema {
-            write!(f, "{}.", DisplayUtil::escape_if_keyword(schema))?;
+            write_sql!(f, "{}.", DisplayUtil::escape_if_keyword(schema))?;
         }
-        write!(f, "{}", DisplayUtil::escape_if_keyword(&self.name))?;
+        write_sql!(f, "{}", DisplayUtil::escape_if_keyword(&self.name))?;
         Ok(())
     }
 }
 
-impl<'a> From<&'a str> for Trigger {
+impl From<&str> for Trigger {
     fn from(t: &str) -> Trigger {
         Trigger {
             name: String::from(t),
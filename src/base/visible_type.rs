@@ -16,8 +16,8 @@ pub enum VisibleType {
 impl Display for VisibleType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            VisibleType::Visible => write!(f, "VISIBLE"),
-            VisibleType::Invisible => write!(f, "INVISIBLE"),
+            VisibleType::Visible => write_sql!(f, "VISIBLE"),
+            VisibleType::Invisible => write_sql!(f, "INVISIBLE"),
         }
     }
 }
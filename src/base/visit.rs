@@ -0,0 +1,527 @@
+use base::arithmetic::{Arithmetic, ArithmeticBase, ArithmeticExpression, ArithmeticItem};
+use base::column::{Column, FunctionArgument, FunctionExpression};
+use base::condition::{ConditionBase, ConditionExpression, ConditionTree};
+use base::{
+    CaseWhenExpression, ColumnOrLiteral, FieldDefinitionExpression, FieldValueExpression,
+    JoinClause, JoinConstraint, JoinRightSide, JsonTable, Literal, OrderClause, WindowFrameBound,
+    WindowSpec,
+};
+use dms::{
+    CompoundSelectOperand, CompoundSelectStatement, DeleteStatement, ExplainStatement,
+    ExplainTarget, GroupByClause, InsertStatement, SelectStatement, TableReference, TableSource,
+    UpdateStatement,
+};
+
+/// Rewrites the literals of an AST in place.
+///
+/// Literals are visited in the order they are written by `Display`, so a
+/// rewrite that collects values lines them up with the SQL text it produces.
+pub(crate) trait VisitLiteralsMut {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal));
+}
+
+impl<T: VisitLiteralsMut> VisitLiteralsMut for Option<T> {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        if let Some(inner) = self {
+            inner.visit_literals_mut(visitor);
+        }
+    }
+}
+
+impl<T: VisitLiteralsMut> VisitLiteralsMut for Box<T> {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        (**self).visit_literals_mut(visitor);
+    }
+}
+
+impl<T: VisitLiteralsMut> VisitLiteralsMut for Vec<T> {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        for item in self {
+            item.visit_literals_mut(visitor);
+        }
+    }
+}
+
+impl VisitLiteralsMut for Literal {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        visitor(self);
+    }
+}
+
+impl VisitLiteralsMut for Column {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.function.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for FunctionExpression {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            FunctionExpression::Avg(argument, _)
+            | FunctionExpression::Count(argument, _)
+            | FunctionExpression::Sum(argument, _)
+            | FunctionExpression::Max(argument)
+            | FunctionExpression::Min(argument)
+            | FunctionExpression::GroupConcat(argument, _) => argument.visit_literals_mut(visitor),
+            FunctionExpression::Case(case_when) => case_when.visit_literals_mut(visitor),
+            FunctionExpression::Generic(_, arguments) => {
+                arguments.arguments.visit_literals_mut(visitor)
+            }
+            FunctionExpression::Window {
+                func,
+                partition_by,
+                order_by,
+                frame,
+                ..
+            } => {
+                func.visit_literals_mut(visitor);
+                partition_by.visit_literals_mut(visitor);
+                order_by.visit_literals_mut(visitor);
+                if let Some(frame) = frame {
+                    frame.start.visit_literals_mut(visitor);
+                    frame.end.visit_literals_mut(visitor);
+                }
+            }
+            FunctionExpression::CountStar
+            | FunctionExpression::Values(_)
+            | FunctionExpression::JsonExtract(..)
+            | FunctionExpression::JsonUnquoteExtract(..) => {}
+        }
+    }
+}
+
+impl VisitLiteralsMut for WindowSpec {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.partition_by.visit_literals_mut(visitor);
+        self.order_by.visit_literals_mut(visitor);
+        if let Some(frame) = &mut self.frame {
+            frame.start.visit_literals_mut(visitor);
+            frame.end.visit_literals_mut(visitor);
+        }
+    }
+}
+
+impl VisitLiteralsMut for WindowFrameBound {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            WindowFrameBound::Preceding(value) | WindowFrameBound::Following(value) => {
+                value.visit_literals_mut(visitor)
+            }
+            WindowFrameBound::CurrentRow
+            | WindowFrameBound::UnboundedPreceding
+            | WindowFrameBound::UnboundedFollowing => {}
+        }
+    }
+}
+
+impl VisitLiteralsMut for FunctionArgument {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            FunctionArgument::Column(column) => column.visit_literals_mut(visitor),
+            FunctionArgument::Conditional(case_when) => case_when.visit_literals_mut(visitor),
+            FunctionArgument::Literal(literal) => visitor(literal),
+        }
+    }
+}
+
+impl VisitLiteralsMut for CaseWhenExpression {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        for (condition, result) in &mut self.branches {
+            condition.visit_literals_mut(visitor);
+            result.visit_literals_mut(visitor);
+        }
+        self.else_expr.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for ColumnOrLiteral {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            ColumnOrLiteral::Column(column) => column.visit_literals_mut(visitor),
+            ColumnOrLiteral::Literal(literal) => visitor(literal),
+            ColumnOrLiteral::Arithmetic(arithmetic) => arithmetic.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for ArithmeticExpression {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.ari.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for Arithmetic {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.left.visit_literals_mut(visitor);
+        self.right.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for ArithmeticItem {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            ArithmeticItem::Base(base) => base.visit_literals_mut(visitor),
+            ArithmeticItem::Expr(arithmetic) => arithmetic.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for ArithmeticBase {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            ArithmeticBase::Column(column) => column.visit_literals_mut(visitor),
+            ArithmeticBase::Scalar(literal) => visitor(literal),
+            ArithmeticBase::Bracketed(arithmetic) => arithmetic.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for ConditionExpression {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            ConditionExpression::ComparisonOp(tree) | ConditionExpression::LogicalOp(tree) => {
+                tree.visit_literals_mut(visitor)
+            }
+            ConditionExpression::LogicalChain(_, operands) | ConditionExpression::Row(operands) => {
+                operands.visit_literals_mut(visitor)
+            }
+            ConditionExpression::NegationOp(inner)
+            | ConditionExpression::Bracketed(inner)
+            | ConditionExpression::Binary(inner) => inner.visit_literals_mut(visitor),
+            ConditionExpression::ExistsOp(select) => select.visit_literals_mut(visitor),
+            ConditionExpression::Base(base) => base.visit_literals_mut(visitor),
+            ConditionExpression::Arithmetic(arithmetic) => arithmetic.visit_literals_mut(visitor),
+            // the bounds are kept as text
+            ConditionExpression::BetweenAnd(_) => {}
+        }
+    }
+}
+
+impl VisitLiteralsMut for ConditionTree {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.left.visit_literals_mut(visitor);
+        self.right.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for ConditionBase {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            ConditionBase::Field(column) => column.visit_literals_mut(visitor),
+            ConditionBase::Literal(literal) => visitor(literal),
+            ConditionBase::LiteralList(literals) => literals.visit_literals_mut(visitor),
+            ConditionBase::ExpressionList(expressions) => expressions.visit_literals_mut(visitor),
+            ConditionBase::NestedSelect(select) => select.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for FieldDefinitionExpression {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            FieldDefinitionExpression::All | FieldDefinitionExpression::AllInTable(_) => {}
+            FieldDefinitionExpression::Col(column) => column.visit_literals_mut(visitor),
+            FieldDefinitionExpression::Value(value) => value.visit_literals_mut(visitor),
+            FieldDefinitionExpression::Subquery(select, _) => select.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for FieldValueExpression {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            FieldValueExpression::Arithmetic(arithmetic) => arithmetic.visit_literals_mut(visitor),
+            FieldValueExpression::Literal(literal) => visitor(&mut literal.value),
+            FieldValueExpression::Row(expressions) => expressions.visit_literals_mut(visitor),
+            FieldValueExpression::Column(column) => column.visit_literals_mut(visitor),
+            FieldValueExpression::Assignment(assignment) => {
+                assignment.value.visit_literals_mut(visitor)
+            }
+        }
+    }
+}
+
+impl VisitLiteralsMut for JoinClause {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.right.visit_literals_mut(visitor);
+        if let JoinConstraint::On(ref mut condition) = self.constraint {
+            condition.visit_literals_mut(visitor);
+        }
+    }
+}
+
+impl VisitLiteralsMut for JoinRightSide {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            JoinRightSide::Table(_) | JoinRightSide::Tables(_) => {}
+            JoinRightSide::NestedSelect(select, _) => select.visit_literals_mut(visitor),
+            JoinRightSide::NestedJoin(join) => join.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for TableReference {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.source.visit_literals_mut(visitor);
+        self.join.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for TableSource {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self {
+            TableSource::Table(_) => {}
+            TableSource::Subquery(select, _) => select.visit_literals_mut(visitor),
+            TableSource::JsonTable(json_table, _) => json_table.visit_literals_mut(visitor),
+        }
+    }
+}
+
+impl VisitLiteralsMut for JsonTable {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.expr.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for GroupByClause {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        for column in &mut self.columns {
+            // `GROUP BY 1` refers to the first select field, it is not a value
+            if let ConditionExpression::Base(ConditionBase::Literal(
+                Literal::Integer(_) | Literal::UnsignedInteger(_),
+            )) = column
+            {
+                continue;
+            }
+            column.visit_literals_mut(visitor);
+        }
+        self.having.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for OrderClause {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        for (column, _) in &mut self.columns {
+            column.visit_literals_mut(visitor);
+        }
+    }
+}
+
+impl VisitLiteralsMut for SelectStatement {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.fields.visit_literals_mut(visitor);
+        self.tables.visit_literals_mut(visitor);
+        self.where_clause.visit_literals_mut(visitor);
+        self.group_by.visit_literals_mut(visitor);
+        for (_, spec) in &mut self.window {
+            spec.visit_literals_mut(visitor);
+        }
+        self.qualify.visit_literals_mut(visitor);
+        self.order.visit_literals_mut(visitor);
+        if let Some(ref mut procedure) = self.procedure {
+            procedure.arguments.visit_literals_mut(visitor);
+        }
+    }
+}
+
+impl VisitLiteralsMut for CompoundSelectStatement {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        for (_, operand) in &mut self.selects {
+            match operand {
+                CompoundSelectOperand::Select(select) => select.visit_literals_mut(visitor),
+                CompoundSelectOperand::Compound(compound) => compound.visit_literals_mut(visitor),
+            }
+        }
+        self.order.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for InsertStatement {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.select.visit_literals_mut(visitor);
+        self.data.visit_literals_mut(visitor);
+        if let Some(ref mut on_duplicate) = self.on_duplicate {
+            for (_, value) in on_duplicate {
+                value.visit_literals_mut(visitor);
+            }
+        }
+    }
+}
+
+impl VisitLiteralsMut for UpdateStatement {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        for (_, value) in &mut self.fields {
+            value.visit_literals_mut(visitor);
+        }
+        self.where_clause.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for DeleteStatement {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        self.where_clause.visit_literals_mut(visitor);
+    }
+}
+
+impl VisitLiteralsMut for ExplainStatement {
+    fn visit_literals_mut(&mut self, visitor: &mut dyn FnMut(&mut Literal)) {
+        match self.target {
+            ExplainTarget::Select(ref mut select) => select.visit_literals_mut(visitor),
+            ExplainTarget::CompoundSelect(ref mut compound) => compound.visit_literals_mut(visitor),
+            ExplainTarget::Insert(ref mut insert) => insert.visit_literals_mut(visitor),
+            ExplainTarget::Delete(ref mut delete) => delete.visit_literals_mut(visitor),
+            ExplainTarget::Update(ref mut update) => update.visit_literals_mut(visitor),
+            ExplainTarget::ForConnection(_) => {}
+        }
+    }
+}
+
+/// Finds window functions in an expression. MySQL only allows them in the
+/// select list and `ORDER BY` of their own query, so subqueries, which are
+/// queries of their own, are not searched.
+pub(crate) trait ContainsWindowFunction {
+    fn contains_window_function(&self) -> bool;
+}
+
+impl<T: ContainsWindowFunction> ContainsWindowFunction for Option<T> {
+    fn contains_window_function(&self) -> bool {
+        self.as_ref().is_some_and(T::contains_window_function)
+    }
+}
+
+impl<T: ContainsWindowFunction> ContainsWindowFunction for Box<T> {
+    fn contains_window_function(&self) -> bool {
+        (**self).contains_window_function()
+    }
+}
+
+impl<T: ContainsWindowFunction> ContainsWindowFunction for Vec<T> {
+    fn contains_window_function(&self) -> bool {
+        self.iter().any(T::contains_window_function)
+    }
+}
+
+impl ContainsWindowFunction for Column {
+    fn contains_window_function(&self) -> bool {
+        self.function.contains_window_function()
+    }
+}
+
+impl ContainsWindowFunction for FunctionExpression {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            FunctionExpression::Window { .. } => true,
+            FunctionExpression::Avg(argument, _)
+            | FunctionExpression::Count(argument, _)
+            | FunctionExpression::Sum(argument, _)
+            | FunctionExpression::Max(argument)
+            | FunctionExpression::Min(argument)
+            | FunctionExpression::GroupConcat(argument, _) => argument.contains_window_function(),
+            FunctionExpression::Case(case_when) => case_when.contains_window_function(),
+            FunctionExpression::Generic(_, arguments) => {
+                arguments.arguments.contains_window_function()
+            }
+            FunctionExpression::CountStar
+            | FunctionExpression::Values(_)
+            | FunctionExpression::JsonExtract(..)
+            | FunctionExpression::JsonUnquoteExtract(..) => false,
+        }
+    }
+}
+
+impl ContainsWindowFunction for FunctionArgument {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            FunctionArgument::Column(column) => column.contains_window_function(),
+            FunctionArgument::Conditional(case_when) => case_when.contains_window_function(),
+            FunctionArgument::Literal(_) => false,
+        }
+    }
+}
+
+impl ContainsWindowFunction for CaseWhenExpression {
+    fn contains_window_function(&self) -> bool {
+        self.branches.iter().any(|(condition, result)| {
+            condition.contains_window_function() || result.contains_window_function()
+        }) || self.else_expr.contains_window_function()
+    }
+}
+
+impl ContainsWindowFunction for ColumnOrLiteral {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            ColumnOrLiteral::Column(column) => column.contains_window_function(),
+            ColumnOrLiteral::Literal(_) => false,
+            ColumnOrLiteral::Arithmetic(arithmetic) => arithmetic.contains_window_function(),
+        }
+    }
+}
+
+impl ContainsWindowFunction for ArithmeticExpression {
+    fn contains_window_function(&self) -> bool {
+        self.ari.contains_window_function()
+    }
+}
+
+impl ContainsWindowFunction for Arithmetic {
+    fn contains_window_function(&self) -> bool {
+        self.left.contains_window_function() || self.right.contains_window_function()
+    }
+}
+
+impl ContainsWindowFunction for ArithmeticItem {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            ArithmeticItem::Base(base) => base.contains_window_function(),
+            ArithmeticItem::Expr(arithmetic) => arithmetic.contains_window_function(),
+        }
+    }
+}
+
+impl ContainsWindowFunction for ArithmeticBase {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            ArithmeticBase::Column(column) => column.contains_window_function(),
+            ArithmeticBase::Scalar(_) => false,
+            ArithmeticBase::Bracketed(arithmetic) => arithmetic.contains_window_function(),
+        }
+    }
+}
+
+impl ContainsWindowFunction for ConditionExpression {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            ConditionExpression::ComparisonOp(tree) | ConditionExpression::LogicalOp(tree) => {
+                tree.contains_window_function()
+            }
+            ConditionExpression::LogicalChain(_, operands) | ConditionExpression::Row(operands) => {
+                operands.contains_window_function()
+            }
+            ConditionExpression::NegationOp(inner)
+            | ConditionExpression::Bracketed(inner)
+            | ConditionExpression::Binary(inner) => inner.contains_window_function(),
+            ConditionExpression::Base(base) => base.contains_window_function(),
+            ConditionExpression::Arithmetic(arithmetic) => arithmetic.contains_window_function(),
+            ConditionExpression::BetweenAnd(between) => between.column.contains_window_function(),
+            ConditionExpression::ExistsOp(_) => false,
+        }
+    }
+}
+
+impl ContainsWindowFunction for ConditionTree {
+    fn contains_window_function(&self) -> bool {
+        self.left.contains_window_function() || self.right.contains_window_function()
+    }
+}
+
+impl ContainsWindowFunction for ConditionBase {
+    fn contains_window_function(&self) -> bool {
+        match self {
+            ConditionBase::Field(column) => column.contains_window_function(),
+            ConditionBase::ExpressionList(expressions) => expressions.contains_window_function(),
+            ConditionBase::Literal(_)
+            | ConditionBase::LiteralList(_)
+            | ConditionBase::NestedSelect(_) => false,
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::{Display, Formatter};
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1, satisfy};
+use nom::combinator::{map, not, opt, peek, verify};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{Column, CommonParser, DisplayUtil, Literal, OrderClause};
+
+/// window_spec:
+///     `([window_name] [PARTITION BY expr [, expr] ...] [ORDER BY ...] [frame_clause])`
+///
+/// Given in `OVER (...)` or, under a name, in the `WINDOW` clause of a query.
+/// `window_name` refers to a named window whose parts are inherited.
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WindowSpec {
+    pub window_name: Option<String>,
+    pub partition_by: Vec<Column>,
+    pub order_by: Option<OrderClause>,
+    pub frame: Option<WindowFrame>,
+}
+
+impl Display for WindowSpec {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        Self::fmt_parts(
+            f,
+            &self.window_name,
+            &self.partition_by,
+            &self.order_by,
+            &self.frame,
+        )
+    }
+}
+
+impl WindowSpec {
+    pub fn parse(i: &str) -> IResult<&str, WindowSpec, ParseSQLError<&str>> {
+        map(
+            delimited(
+                pair(tag("("), multispace0),
+                tuple((
+                    opt(terminated(Self::window_name, multispace0)),
+                    opt(terminated(
+                        preceded(
+                            tuple((
+                                tag_no_case("PARTITION"),
+                                multispace1,
+                                tag_no_case("BY"),
+                                multispace1,
+                            )),
+                            separated_list1(CommonParser::ws_sep_comma, Column::without_alias),
+                        ),
+                        multispace0,
+                    )),
+                    opt(terminated(OrderClause::parse, multispace0)),
+                    opt(terminated(WindowFrame::parse, multispace0)),
+                )),
+                tag(")"),
+            ),
+            |(window_name, partition_by, order_by, frame)| WindowSpec {
+                window_name,
+                partition_by: partition_by.unwrap_or_default(),
+                order_by,
+                frame,
+            },
+        )(i)
+    }
+
+    /// Write the parts of a window spec, shared with the `OVER (...)` of
+    /// [crate::base::column::FunctionExpression::Window]
+    pub(crate) fn fmt_parts(
+        f: &mut Formatter<'_>,
+        window_name: &Option<String>,
+        partition_by: &[Column],
+        order_by: &Option<OrderClause>,
+        frame: &Option<WindowFrame>,
+    ) -> std::fmt::Result {
+        let mut parts = vec![];
+        if let Some(ref name) = *window_name {
+            parts.push(DisplayUtil::escape_if_keyword(name));
+        }
+        if !partition_by.is_empty() {
+            let columns = partition_by
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+            parts.push(format_sql!("PARTITION BY {}", columns));
+        }
+        if let Some(ref order_by) = *order_by {
+            parts.push(order_by.to_string());
+        }
+        if let Some(ref frame) = *frame {
+            parts.push(frame.to_string());
+        }
+        write_sql!(f, "({})", parts.join(" "))
+    }
+
+    /// A window name, which must not swallow the keyword of the next part
+    pub fn window_name(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+        map(
+            verify(CommonParser::sql_identifier, |name: &str| {
+                !["PARTITION", "ORDER", "ROWS", "RANGE"]
+                    .iter()
+                    .any(|keyword| name.eq_ignore_ascii_case(keyword))
+            }),
+            String::from,
+        )(i)
+    }
+}
+
+/// frame_clause:
+///     `frame_units frame_extent`
+///
+/// frame_extent:
+///     `{frame_start | BETWEEN frame_start AND frame_end}`
+///
+/// Without `BETWEEN` only the start is given and the frame ends at the
+/// current row, so `end` is `None`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct WindowFrame {
+    pub units: WindowFrameUnits,
+    pub start: WindowFrameBound,
+    pub end: Option<WindowFrameBound>,
+}
+
+impl Display for WindowFrame {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.end {
+            Some(ref end) => write_sql!(f, "{} BETWEEN {} AND {}", self.units, self.start, end),
+            None => write_sql!(f, "{} {}", self.units, self.start),
+        }
+    }
+}
+
+impl WindowFrame {
+    pub fn parse(i: &str) -> IResult<&str, WindowFrame, ParseSQLError<&str>> {
+        map(
+            tuple((
+                WindowFrameUnits::parse,
+                multispace1,
+                alt((
+                    map(
+                        tuple((
+                            keyword("BETWEEN"),
+                            multispace1,
+                            WindowFrameBound::parse,
+                            multispace1,
+                            keyword("AND"),
+                            multispace1,
+                            WindowFrameBound::parse,
+                        )),
+                        |(_, _, start, _, _, _, end)| (start, Some(end)),
+                    ),
+                    map(WindowFrameBound::parse, |start| (start, None)),
+                )),
+            )),
+            |(units, _, (start, end))| WindowFrame { units, start, end },
+        )(i)
+    }
+}
+
+/// `{ROWS | RANGE}`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum WindowFrameUnits {
+    Rows,
+    Range,
+}
+
+impl Display for WindowFrameUnits {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            WindowFrameUnits::Rows => write_sql!(f, "ROWS"),
+            WindowFrameUnits::Range => write_sql!(f, "RANGE"),
+        }
+    }
+}
+
+impl WindowFrameUnits {
+    pub fn parse(i: &str) -> IResult<&str, WindowFrameUnits, ParseSQLError<&str>> {
+        alt((
+            map(keyword("ROWS"), |_| WindowFrameUnits::Rows),
+            map(keyword("RANGE"), |_| WindowFrameUnits::Range),
+        ))(i)
+    }
+}
+
+/// frame_start, frame_end:
+///     `{CURRENT ROW | UNBOUNDED PRECEDING | UNBOUNDED FOLLOWING
+///         | expr PRECEDING | expr FOLLOWING}`
+///
+/// `expr` is limited to a literal, which covers row counts and `?`
+/// placeholders but not `INTERVAL` ranges.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum WindowFrameBound {
+    CurrentRow,
+    UnboundedPreceding,
+    UnboundedFollowing,
+    Preceding(Literal),
+    Following(Literal),
+}
+
+impl Display for WindowFrameBound {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            WindowFrameBound::CurrentRow => write_sql!(f, "CURRENT ROW"),
+            WindowFrameBound::UnboundedPreceding => write_sql!(f, "UNBOUNDED PRECEDING"),
+            WindowFrameBound::UnboundedFollowing => write_sql!(f, "UNBOUNDED FOLLOWING"),
+            WindowFrameBound::Preceding(ref value) => write_sql!(f, "{} PRECEDING", value),
+            WindowFrameBound::Following(ref value) => write_sql!(f, "{} FOLLOWING", value),
+        }
+    }
+}
+
+impl WindowFrameBound {
+    pub fn parse(i: &str) -> IResult<&str, WindowFrameBound, ParseSQLError<&str>> {
+        alt((
+            map(
+                tuple((keyword("CURRENT"), multispace1, keyword("ROW"))),
+                |_| WindowFrameBound::CurrentRow,
+            ),
+            preceded(
+                pair(keyword("UNBOUNDED"), multispace1),
+                alt((
+                    map(keyword("PRECEDING"), |_| {
+                        WindowFrameBound::UnboundedPreceding
+                    }),
+                    map(keyword("FOLLOWING"), |_| {
+                        WindowFrameBound::UnboundedFollowing
+                    }),
+                )),
+            ),
+            map(
+                tuple((Literal::parse, multispace0, keyword("PRECEDING"))),
+                |(value, _, _)| WindowFrameBound::Preceding(value),
+            ),
+            map(
+                tuple((Literal::parse, multispace0, keyword("FOLLOWING"))),
+                |(value, _, _)| WindowFrameBound::Following(value),
+            ),
+        ))(i)
+    }
+}
+
+/// Matches `word` as a whole word, so `ROWS` does not match `ROWSET`.
+fn keyword<'a>(
+    word: &'static str,
+) -> impl FnMut(&'a str) -> IResult<&'a str, &'a str, ParseSQLError<&'a str>> {
+    terminated(
+        tag_no_case(word),
+        not(peek(satisfy(|c: char| c.is_alphanumeric() || c == '_'))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use base::window::{WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec};
+    use base::{Column, Literal};
+
+    #[test]
+    fn parse_window_spec() {
+        let str = "(w PARTITION BY a, b ROWS 1 PRECEDING)";
+        let res = WindowSpec::parse(str).unwrap().1;
+        assert_eq!(res.window_name, Some(String::from("w")));
+        assert_eq!(res.partition_by, vec![Column::from("a"), Column::from("b")]);
+        assert_eq!(res.order_by, None);
+        assert_eq!(res.to_string(), str);
+
+        assert_eq!(WindowSpec::parse("( )").unwrap().1, WindowSpec::default());
+        // a keyword opening the next part is not a window name
+        let res = WindowSpec::parse("(ORDER BY a DESC)").unwrap().1;
+        assert_eq!(res.window_name, None);
+        assert_eq!(res.to_string(), "(ORDER BY a DESC)");
+    }
+
+    #[test]
+    fn parse_window_frame() {
+        let str = "ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW";
+        let res = WindowFrame::parse(str).unwrap();
+        assert_eq!(
+            res,
+            (
+                "",
+                WindowFrame {
+                    units: WindowFrameUnits::Rows,
+                    start: WindowFrameBound::UnboundedPreceding,
+                    end: Some(WindowFrameBound::CurrentRow),
+                }
+            )
+        );
+        assert_eq!(res.1.to_string(), str);
+
+        let str = "range 2 preceding";
+        let res = WindowFrame::parse(str).unwrap().1;
+        assert_eq!(res.start, WindowFrameBound::Preceding(Literal::Integer(2)));
+        assert_eq!(res.end, None);
+        assert_eq!(res.to_string(), "RANGE 2 PRECEDING");
+
+        let frames = [
+            "ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING",
+            "RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING",
+        ];
+        for frame in frames {
+            assert_eq!(WindowFrame::parse(frame).unwrap().1.to_string(), frame);
+        }
+
+        assert!(WindowFrame::parse("ROWSET 1 PRECEDING").is_err());
+    }
+}
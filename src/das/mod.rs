@@ -1,3 +1,11 @@
 mod set_statement;
+mod set_transaction;
+mod show;
+mod variable_scope;
 
 pub use das::set_statement::SetStatement;
+pub use das::set_transaction::{
+    IsolationLevel, SetTransactionStatement, TransactionCharacteristic,
+};
+pub use das::show::{ShowFilter, ShowStatement};
+pub use das::variable_scope::VariableScope;
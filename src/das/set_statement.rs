@@ -1,12 +1,14 @@
 use std::{fmt, str};
 
-use nom::bytes::complete::tag_no_case;
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, recognize};
 use nom::sequence::tuple;
 use nom::IResult;
 
 use base::error::ParseSQLError;
-use base::{CommonParser, Literal};
+use base::{CommonParser, FieldValueExpression, Literal, LiteralExpression, SystemVariable};
 
 /// parse `SET variable = expr [, variable = expr] ...`
 ///
@@ -22,31 +24,54 @@ use base::{CommonParser, Literal};
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct SetStatement {
     pub variable: String,
-    pub value: Literal,
+    pub value: FieldValueExpression,
 }
 
 impl SetStatement {
     pub fn parse(i: &str) -> IResult<&str, SetStatement, ParseSQLError<&str>> {
-        let (remaining_input, (_, _, var, _, _, _, value, _)) = tuple((
+        let (remaining_input, (_, _, variable, _, _, _, value, _)) = tuple((
             tag_no_case("SET"),
             multispace1,
-            CommonParser::sql_identifier,
+            alt((
+                map(recognize(SystemVariable::parse), String::from),
+                map(CommonParser::sql_identifier, String::from),
+            )),
             multispace0,
-            tag_no_case("="),
+            alt((tag(":="), tag("="))),
             multispace0,
-            Literal::parse,
+            FieldValueExpression::parse,
             CommonParser::statement_terminator,
         ))(i)?;
-        let variable = String::from(var);
         Ok((remaining_input, SetStatement { variable, value }))
     }
+
+    /// The individual modes of a `SET sql_mode = 'MODE,MODE'` assignment,
+    /// also when written as `@@sql_mode` or with a scope such as `@@SESSION.sql_mode`.
+    pub fn sql_modes(&self) -> Option<Vec<&str>> {
+        let name = match self.variable.strip_prefix("@@") {
+            Some(name) => name.split_once('.').map_or(name, |(_, name)| name),
+            None => &self.variable,
+        };
+        let name = name.trim_matches('`');
+        match self.value {
+            FieldValueExpression::Literal(LiteralExpression {
+                value: Literal::String(ref modes),
+                ..
+            }) if name.eq_ignore_ascii_case("sql_mode") => Some(
+                modes
+                    .split(',')
+                    .map(|mode| mode.trim())
+                    .filter(|mode| !mode.is_empty())
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for SetStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SET ")?;
-        write!(f, "{} = {}", self.variable, self.value)?;
-        Ok(())
+        write_sql!(f, "SET {} = {}", self.variable, self.value)
     }
 }
 
@@ -60,7 +85,7 @@ mod tests {
         let res = SetStatement::parse(str);
         let exp = SetStatement {
             variable: "SQL_AUTO_IS_NULL".to_owned(),
-            value: 0.into(),
+            value: FieldValueExpression::Literal(Literal::from(0).into()),
         };
         assert_eq!(res.unwrap().1, exp);
     }
@@ -71,11 +96,28 @@ mod tests {
         let res = SetStatement::parse(str);
         let exp = SetStatement {
             variable: "@var".to_owned(),
-            value: 123.into(),
+            value: FieldValueExpression::Literal(Literal::from(123).into()),
         };
         assert_eq!(res.unwrap().1, exp);
     }
 
+    #[test]
+    fn assignment_operator() {
+        let res = SetStatement::parse("SET @n := 0").unwrap().1;
+        assert_eq!(res, SetStatement::parse("SET @n = 0").unwrap().1);
+        assert_eq!(res.to_string(), "SET @n = 0");
+    }
+
+    #[test]
+    fn expression_value() {
+        for str in ["SET @x = @y + 1", "SET @x = 1 + @y", "SET @x = @y"] {
+            let res = SetStatement::parse(str).unwrap().1;
+            assert_eq!(res.to_string(), str);
+        }
+        let res = SetStatement::parse("SET @x = @y + 1").unwrap().1;
+        assert!(matches!(res.value, FieldValueExpression::Arithmetic(_)));
+    }
+
     #[test]
     fn format_set() {
         let str = "set autocommit=1";
@@ -83,4 +125,35 @@ mod tests {
         let res = SetStatement::parse(str);
         assert_eq!(format!("{}", res.unwrap().1), expected);
     }
+
+    #[test]
+    fn sql_mode_flags() {
+        let str = "SET sql_mode = 'STRICT_TRANS_TABLES,NO_ZERO_DATE';";
+        let res = SetStatement::parse(str).unwrap().1;
+        assert_eq!(
+            res.sql_modes(),
+            Some(vec!["STRICT_TRANS_TABLES", "NO_ZERO_DATE"])
+        );
+        assert_eq!(
+            res.to_string(),
+            "SET sql_mode = 'STRICT_TRANS_TABLES,NO_ZERO_DATE'"
+        );
+
+        let empty = SetStatement::parse("SET SQL_MODE = ''").unwrap().1;
+        assert_eq!(empty.sql_modes(), Some(vec![]));
+        assert_eq!(empty.to_string(), "SET SQL_MODE = ''");
+
+        for str in [
+            "SET @@sql_mode = 'ANSI'",
+            "SET @@SESSION.sql_mode = 'ANSI'",
+            "SET @@persist.`sql_mode` = 'ANSI'",
+        ] {
+            let res = SetStatement::parse(str).unwrap().1;
+            assert_eq!(res.sql_modes(), Some(vec!["ANSI"]));
+            assert_eq!(res.to_string(), str);
+        }
+
+        let other = SetStatement::parse("SET names = 'utf8'").unwrap().1;
+        assert_eq!(other.sql_modes(), None);
+    }
 }
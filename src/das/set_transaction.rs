@@ -0,0 +1,196 @@
+use std::{fmt, str};
+
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt, value};
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::CommonParser;
+use das::VariableScope;
+
+/// parse `SET [GLOBAL | SESSION] TRANSACTION
+///     transaction_characteristic [, transaction_characteristic] ...`
+///
+/// `transaction_characteristic: {
+///     ISOLATION LEVEL level
+///   | access_mode
+/// }`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct SetTransactionStatement {
+    pub scope: Option<VariableScope>,
+    pub characteristics: Vec<TransactionCharacteristic>,
+}
+
+impl SetTransactionStatement {
+    pub fn parse(i: &str) -> IResult<&str, SetTransactionStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("SET"), multispace1),
+                opt(terminated(VariableScope::parse, multispace1)),
+                terminated(tag_no_case("TRANSACTION"), multispace1),
+                separated_list1(
+                    delimited(multispace0, tag_no_case(","), multispace0),
+                    TransactionCharacteristic::parse,
+                ),
+                CommonParser::statement_terminator,
+            )),
+            |(_, scope, _, characteristics, _)| SetTransactionStatement {
+                scope,
+                characteristics,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for SetTransactionStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "SET")?;
+        if let Some(ref scope) = self.scope {
+            write_sql!(f, " {}", scope)?;
+        }
+        let characteristics = self
+            .characteristics
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        write_sql!(f, " TRANSACTION {}", characteristics)
+    }
+}
+
+/// `ISOLATION LEVEL level | READ WRITE | READ ONLY`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum TransactionCharacteristic {
+    IsolationLevel(IsolationLevel),
+    ReadWrite,
+    ReadOnly,
+}
+
+impl TransactionCharacteristic {
+    fn parse(i: &str) -> IResult<&str, TransactionCharacteristic, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(
+                    tuple((
+                        tag_no_case("ISOLATION"),
+                        multispace1,
+                        tag_no_case("LEVEL"),
+                        multispace1,
+                    )),
+                    IsolationLevel::parse,
+                ),
+                TransactionCharacteristic::IsolationLevel,
+            ),
+            preceded(
+                terminated(tag_no_case("READ"), multispace1),
+                alt((
+                    value(TransactionCharacteristic::ReadWrite, tag_no_case("WRITE")),
+                    value(TransactionCharacteristic::ReadOnly, tag_no_case("ONLY")),
+                )),
+            ),
+        ))(i)
+    }
+}
+
+impl fmt::Display for TransactionCharacteristic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TransactionCharacteristic::IsolationLevel(ref level) => {
+                write_sql!(f, "ISOLATION LEVEL {}", level)
+            }
+            TransactionCharacteristic::ReadWrite => write_sql!(f, "READ WRITE"),
+            TransactionCharacteristic::ReadOnly => write_sql!(f, "READ ONLY"),
+        }
+    }
+}
+
+/// `REPEATABLE READ | READ COMMITTED | READ UNCOMMITTED | SERIALIZABLE`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum IsolationLevel {
+    RepeatableRead,
+    ReadCommitted,
+    ReadUncommitted,
+    Serializable,
+}
+
+impl IsolationLevel {
+    fn parse(i: &str) -> IResult<&str, IsolationLevel, ParseSQLError<&str>> {
+        alt((
+            value(
+                IsolationLevel::RepeatableRead,
+                tuple((tag_no_case("REPEATABLE"), multispace1, tag_no_case("READ"))),
+            ),
+            value(
+                IsolationLevel::ReadCommitted,
+                tuple((tag_no_case("READ"), multispace1, tag_no_case("COMMITTED"))),
+            ),
+            value(
+                IsolationLevel::ReadUncommitted,
+                tuple((tag_no_case("READ"), multispace1, tag_no_case("UNCOMMITTED"))),
+            ),
+            value(IsolationLevel::Serializable, tag_no_case("SERIALIZABLE")),
+        ))(i)
+    }
+}
+
+impl fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IsolationLevel::RepeatableRead => write_sql!(f, "REPEATABLE READ"),
+            IsolationLevel::ReadCommitted => write_sql!(f, "READ COMMITTED"),
+            IsolationLevel::ReadUncommitted => write_sql!(f, "READ UNCOMMITTED"),
+            IsolationLevel::Serializable => write_sql!(f, "SERIALIZABLE"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn isolation_levels() {
+        let levels = [
+            ("READ UNCOMMITTED", IsolationLevel::ReadUncommitted),
+            ("READ COMMITTED", IsolationLevel::ReadCommitted),
+            ("REPEATABLE READ", IsolationLevel::RepeatableRead),
+            ("SERIALIZABLE", IsolationLevel::Serializable),
+        ];
+        for (level, exp) in levels {
+            let str = format!("SET TRANSACTION ISOLATION LEVEL {}", level);
+            let res = SetTransactionStatement::parse(&str);
+            let exp = SetTransactionStatement {
+                scope: None,
+                characteristics: vec![TransactionCharacteristic::IsolationLevel(exp)],
+            };
+            assert_eq!(res.unwrap().1, exp);
+            assert_eq!(exp.to_string(), str);
+        }
+    }
+
+    #[test]
+    fn scope_and_access_mode() {
+        let str = "set global transaction read only, isolation level serializable;";
+        let res = SetTransactionStatement::parse(str);
+        let exp = SetTransactionStatement {
+            scope: Some(VariableScope::Global),
+            characteristics: vec![
+                TransactionCharacteristic::ReadOnly,
+                TransactionCharacteristic::IsolationLevel(IsolationLevel::Serializable),
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(
+            exp.to_string(),
+            "SET GLOBAL TRANSACTION READ ONLY, ISOLATION LEVEL SERIALIZABLE"
+        );
+
+        let str = "SET SESSION TRANSACTION READ WRITE";
+        let res = SetTransactionStatement::parse(str);
+        assert_eq!(res.unwrap().1.to_string(), str);
+    }
+}
@@ -0,0 +1,270 @@
+use std::{fmt, str};
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt, value};
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+
+use base::condition::ConditionExpression;
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil};
+use das::VariableScope;
+use dms::LimitClause;
+
+/// parse `SHOW` statements
+///
+/// - `SHOW [GLOBAL | SESSION] VARIABLES [LIKE 'pattern' | WHERE expr]`
+/// - `SHOW [GLOBAL | SESSION] STATUS [LIKE 'pattern' | WHERE expr]`
+/// - `SHOW WARNINGS [LIMIT [offset,] row_count]`
+/// - `SHOW ERRORS [LIMIT [offset,] row_count]`
+/// - `SHOW COUNT(*) WARNINGS`
+/// - `SHOW COUNT(*) ERRORS`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ShowStatement {
+    Variables {
+        scope: Option<VariableScope>,
+        filter: Option<ShowFilter>,
+    },
+    Status {
+        scope: Option<VariableScope>,
+        filter: Option<ShowFilter>,
+    },
+    Warnings {
+        limit: Option<LimitClause>,
+    },
+    Errors {
+        limit: Option<LimitClause>,
+    },
+    CountWarnings,
+    CountErrors,
+}
+
+impl ShowStatement {
+    pub fn parse(i: &str) -> IResult<&str, ShowStatement, ParseSQLError<&str>> {
+        terminated(
+            preceded(
+                terminated(tag_no_case("SHOW"), multispace1),
+                alt((
+                    Self::variables_or_status,
+                    Self::warnings_or_errors,
+                    Self::count_warnings_or_errors,
+                )),
+            ),
+            CommonParser::statement_terminator,
+        )(i)
+    }
+
+    /// `[GLOBAL | SESSION] {VARIABLES | STATUS} [LIKE 'pattern' | WHERE expr]`
+    fn variables_or_status(i: &str) -> IResult<&str, ShowStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                opt(terminated(VariableScope::parse, multispace1)),
+                alt((
+                    value(true, tag_no_case("VARIABLES")),
+                    value(false, tag_no_case("STATUS")),
+                )),
+                opt(preceded(multispace1, ShowFilter::parse)),
+            )),
+            |(scope, variables, filter)| match variables {
+                true => ShowStatement::Variables { scope, filter },
+                false => ShowStatement::Status { scope, filter },
+            },
+        )(i)
+    }
+
+    /// `{WARNINGS | ERRORS} [LIMIT [offset,] row_count]`
+    fn warnings_or_errors(i: &str) -> IResult<&str, ShowStatement, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(tag_no_case("WARNINGS"), opt(LimitClause::parse)),
+                |limit| ShowStatement::Warnings { limit },
+            ),
+            map(
+                preceded(tag_no_case("ERRORS"), opt(LimitClause::parse)),
+                |limit| ShowStatement::Errors { limit },
+            ),
+        ))(i)
+    }
+
+    /// `COUNT(*) {WARNINGS | ERRORS}`
+    fn count_warnings_or_errors(i: &str) -> IResult<&str, ShowStatement, ParseSQLError<&str>> {
+        preceded(
+            tuple((
+                tag_no_case("COUNT"),
+                multispace0,
+                tag("("),
+                multispace0,
+                tag("*"),
+                multispace0,
+                tag(")"),
+                multispace1,
+            )),
+            alt((
+                value(ShowStatement::CountWarnings, tag_no_case("WARNINGS")),
+                value(ShowStatement::CountErrors, tag_no_case("ERRORS")),
+            )),
+        )(i)
+    }
+
+    fn fmt_with_filter(
+        f: &mut fmt::Formatter,
+        scope: &Option<VariableScope>,
+        keyword: &str,
+        filter: &Option<ShowFilter>,
+    ) -> fmt::Result {
+        if let Some(ref scope) = scope {
+            write_sql!(f, " {}", scope)?;
+        }
+        write_sql!(f, " ")?;
+        DisplayUtil::write_keyword(f, keyword)?;
+        if let Some(ref filter) = filter {
+            write_sql!(f, " {}", filter)?;
+        }
+        Ok(())
+    }
+
+    /// SHOW WARNINGS and SHOW ERRORS only take the `LIMIT [offset,] row_count` form
+    fn fmt_with_limit(
+        f: &mut fmt::Formatter,
+        keyword: &str,
+        limit: &Option<LimitClause>,
+    ) -> fmt::Result {
+        write_sql!(f, " ")?;
+        DisplayUtil::write_keyword(f, keyword)?;
+        match *limit {
+            Some(LimitClause { limit, offset: 0 }) => write_sql!(f, " LIMIT {}", limit),
+            Some(LimitClause { limit, offset }) => write_sql!(f, " LIMIT {}, {}", offset, limit),
+            None => Ok(()),
+        }
+    }
+}
+
+impl fmt::Display for ShowStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "SHOW")?;
+        match *self {
+            ShowStatement::Variables {
+                ref scope,
+                ref filter,
+            } => Self::fmt_with_filter(f, scope, "VARIABLES", filter),
+            ShowStatement::Status {
+                ref scope,
+                ref filter,
+            } => Self::fmt_with_filter(f, scope, "STATUS", filter),
+            ShowStatement::Warnings { ref limit } => Self::fmt_with_limit(f, "WARNINGS", limit),
+            ShowStatement::Errors { ref limit } => Self::fmt_with_limit(f, "ERRORS", limit),
+            ShowStatement::CountWarnings => write_sql!(f, " COUNT(*) WARNINGS"),
+            ShowStatement::CountErrors => write_sql!(f, " COUNT(*) ERRORS"),
+        }
+    }
+}
+
+/// `LIKE 'pattern' | WHERE expr`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ShowFilter {
+    Like(String),
+    Where(ConditionExpression),
+}
+
+impl ShowFilter {
+    pub fn parse(i: &str) -> IResult<&str, ShowFilter, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(
+                    terminated(tag_no_case("LIKE"), multispace1),
+                    CommonParser::parse_quoted_string,
+                ),
+                ShowFilter::Like,
+            ),
+            map(ConditionExpression::parse, ShowFilter::Where),
+        ))(i)
+    }
+}
+
+impl fmt::Display for ShowFilter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ShowFilter::Like(ref pattern) => write_sql!(f, "LIKE '{}'", pattern),
+            ShowFilter::Where(ref expr) => write_sql!(f, "WHERE {}", expr),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::condition::ConditionBase::{Field, Literal};
+    use base::condition::ConditionExpression::{Base, ComparisonOp};
+    use base::condition::ConditionTree;
+    use base::{Column, Operator};
+
+    use super::*;
+
+    #[test]
+    fn show_variables_like() {
+        let str = "SHOW GLOBAL VARIABLES LIKE 'max%'";
+        let res = ShowStatement::parse(str);
+        let exp = ShowStatement::Variables {
+            scope: Some(VariableScope::Global),
+            filter: Some(ShowFilter::Like("max%".to_string())),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), str);
+
+        let str = "show session variables;";
+        let res = ShowStatement::parse(str);
+        assert_eq!(res.unwrap().1.to_string(), "SHOW SESSION VARIABLES");
+    }
+
+    #[test]
+    fn show_status_where() {
+        let str = "SHOW STATUS WHERE Value > 0";
+        let res = ShowStatement::parse(str);
+        let exp = ShowStatement::Status {
+            scope: None,
+            filter: Some(ShowFilter::Where(ComparisonOp(ConditionTree {
+                operator: Operator::Greater,
+                left: Box::new(Base(Field(Column::from("Value")))),
+                right: Box::new(Base(Literal(0.into()))),
+            }))),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), str);
+    }
+
+    #[test]
+    fn show_warnings_and_errors() {
+        let str = "SHOW WARNINGS LIMIT 5";
+        let res = ShowStatement::parse(str);
+        let exp = ShowStatement::Warnings {
+            limit: Some(LimitClause {
+                limit: 5,
+                offset: 0,
+            }),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), str);
+
+        let parts = ["SHOW ERRORS LIMIT 10, 5", "SHOW ERRORS", "SHOW WARNINGS"];
+        for part in parts {
+            let res = ShowStatement::parse(part);
+            assert_eq!(res.unwrap().1.to_string(), part);
+        }
+    }
+
+    #[test]
+    fn show_count_warnings_and_errors() {
+        let str = "SHOW COUNT(*) WARNINGS";
+        let res = ShowStatement::parse(str);
+        assert_eq!(res.unwrap().1, ShowStatement::CountWarnings);
+        assert_eq!(ShowStatement::CountWarnings.to_string(), str);
+
+        let res = ShowStatement::parse("show count( * ) errors;");
+        assert_eq!(res.unwrap().1, ShowStatement::CountErrors);
+        assert_eq!(
+            ShowStatement::CountErrors.to_string(),
+            "SHOW COUNT(*) ERRORS"
+        );
+    }
+}
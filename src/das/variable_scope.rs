@@ -0,0 +1,52 @@
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::combinator::value;
+use nom::IResult;
+
+use base::error::ParseSQLError;
+
+/// `GLOBAL | SESSION | LOCAL | PERSIST | PERSIST_ONLY`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum VariableScope {
+    Global,
+    Session,
+    /// synonym of `SESSION`
+    Local,
+    Persist,
+    PersistOnly,
+}
+
+impl VariableScope {
+    /// parse `GLOBAL | SESSION | LOCAL`, the scopes of `SHOW` and `SET TRANSACTION`
+    pub fn parse(i: &str) -> IResult<&str, VariableScope, ParseSQLError<&str>> {
+        alt((
+            value(VariableScope::Global, tag_no_case("GLOBAL")),
+            value(VariableScope::Session, tag_no_case("SESSION")),
+            value(VariableScope::Local, tag_no_case("LOCAL")),
+        ))(i)
+    }
+
+    /// parse any scope, including `PERSIST | PERSIST_ONLY` which only apply to
+    /// system variables
+    pub fn parse_with_persist(i: &str) -> IResult<&str, VariableScope, ParseSQLError<&str>> {
+        alt((
+            Self::parse,
+            value(VariableScope::PersistOnly, tag_no_case("PERSIST_ONLY")),
+            value(VariableScope::Persist, tag_no_case("PERSIST")),
+        ))(i)
+    }
+}
+
+impl fmt::Display for VariableScope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            VariableScope::Global => write_sql!(f, "GLOBAL"),
+            VariableScope::Session => write_sql!(f, "SESSION"),
+            VariableScope::Local => write_sql!(f, "LOCAL"),
+            VariableScope::Persist => write_sql!(f, "PERSIST"),
+            VariableScope::PersistOnly => write_sql!(f, "PERSIST_ONLY"),
+        }
+    }
+}
@@ -39,6 +39,7 @@ impl AlterDatabaseStatement {
                 map(CommonParser::sql_identifier, String::from),
                 multispace1,
                 many1(terminated(AlterDatabaseOption::parse, multispace0)),
+                CommonParser::statement_terminator,
             )),
             |x| AlterDatabaseStatement {
                 db_name: x.4,
@@ -50,11 +51,11 @@ impl AlterDatabaseStatement {
 
 impl fmt::Display for AlterDatabaseStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ALTER DATABASE")?;
+        write_sql!(f, "ALTER DATABASE")?;
         let database = self.db_name.clone();
-        write!(f, " {}", database)?;
+        write_sql!(f, " {}", database)?;
         for alter_option in self.alter_options.iter() {
-            write!(f, " {}", alter_option)?;
+            write_sql!(f, " {}", alter_option)?;
         }
         Ok(())
     }
@@ -79,8 +80,7 @@ impl AlterDatabaseOption {
         // [DEFAULT] CHARACTER SET [=] charset_name
         let character = map(
             tuple((
-                opt(tag_no_case("DEFAULT")),
-                multispace1,
+                opt(terminated(tag_no_case("DEFAULT"), multispace1)),
                 tuple((
                     tag_no_case("CHARACTER"),
                     multispace1,
@@ -92,14 +92,13 @@ impl AlterDatabaseOption {
                 map(CommonParser::sql_identifier, String::from),
                 multispace0,
             )),
-            |(_, _, _, charset_name, _)| AlterDatabaseOption::CharacterSet(charset_name),
+            |(_, _, charset_name, _)| AlterDatabaseOption::CharacterSet(charset_name),
         );
 
         // [DEFAULT] COLLATE [=] collation_name
         let collate = map(
             tuple((
-                opt(tag_no_case("DEFAULT")),
-                multispace1,
+                opt(terminated(tag_no_case("DEFAULT"), multispace1)),
                 map(
                     tuple((
                         tag_no_case("COLLATE"),
@@ -113,29 +112,28 @@ impl AlterDatabaseOption {
                 ),
                 multispace0,
             )),
-            |(_, _, collation_name, _)| AlterDatabaseOption::Collate(collation_name),
+            |(_, collation_name, _)| AlterDatabaseOption::Collate(collation_name),
         );
 
         // [DEFAULT] ENCRYPTION [=] {'Y' | 'N'}
         let encryption = map(
             tuple((
-                opt(tag_no_case("DEFAULT")),
-                multispace1,
+                opt(terminated(tag_no_case("DEFAULT"), multispace1)),
                 tag_no_case("ENCRYPTION"),
-                multispace1,
+                multispace0,
                 opt(tag("=")),
                 multispace0,
                 alt((map(tag("'Y'"), |_| true), map(tag("'N'"), |_| false))),
                 multispace0,
             )),
-            |x| AlterDatabaseOption::Encryption(x.6),
+            |x| AlterDatabaseOption::Encryption(x.5),
         );
 
         // READ ONLY [=] {DEFAULT | 0 | 1}
         let read_only = alt((
             map(
                 tuple((
-                    opt(tag_no_case("READ")),
+                    tag_no_case("READ"),
                     multispace1,
                     tag_no_case("ONLY"),
                     multispace1,
@@ -145,7 +143,7 @@ impl AlterDatabaseOption {
             ),
             map(
                 tuple((
-                    opt(tag_no_case("READ")),
+                    tag_no_case("READ"),
                     multispace1,
                     tag_no_case("ONLY"),
                     multispace0,
@@ -164,16 +162,16 @@ impl AlterDatabaseOption {
 impl fmt::Display for AlterDatabaseOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            AlterDatabaseOption::CharacterSet(str) => write!(f, " CHARACTER SET {}", str)?,
-            AlterDatabaseOption::Collate(str) => write!(f, " COLLATE {}", str)?,
+            AlterDatabaseOption::CharacterSet(str) => write_sql!(f, "CHARACTER SET {}", str)?,
+            AlterDatabaseOption::Collate(str) => write_sql!(f, "COLLATE {}", str)?,
             AlterDatabaseOption::Encryption(bl) => {
                 if *bl {
-                    write!(f, " ENCRYPTION 'Y'",)?
+                    write_sql!(f, "ENCRYPTION {}", "'Y'")?
                 } else {
-                    write!(f, " ENCRYPTION 'N'",)?
+                    write_sql!(f, "ENCRYPTION {}", "'N'")?
                 }
             }
-            AlterDatabaseOption::ReadOnly(val) => write!(f, " READ ONLY {}", val)?,
+            AlterDatabaseOption::ReadOnly(val) => write_sql!(f, "READ ONLY {}", val)?,
         }
         Ok(())
     }
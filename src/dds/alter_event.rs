@@ -0,0 +1,201 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::multispace1;
+use nom::combinator::{map, opt, verify};
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil};
+use dds::create_event::{CreateEventStatement, EventSchedule, EventStatus};
+
+/// parse `ALTER [DEFINER = user] EVENT event_name
+///     [ON SCHEDULE schedule]
+///     [ON COMPLETION [NOT] PRESERVE]
+///     [RENAME TO new_event_name]
+///     [ENABLE | DISABLE | DISABLE ON {REPLICA | SLAVE}]
+///     [COMMENT 'string']
+///     [DO event_body]`
+///
+/// At least one of the clauses must be given. The event body is kept as raw text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AlterEventStatement {
+    pub definer: Option<String>,
+    pub event_name: String,
+    pub schedule: Option<EventSchedule>,
+    /// `Some(true)` for `ON COMPLETION PRESERVE`, `Some(false)` for `ON COMPLETION NOT PRESERVE`
+    pub on_completion_preserve: Option<bool>,
+    pub rename_to: Option<String>,
+    pub status: Option<EventStatus>,
+    pub comment: Option<String>,
+    pub body: Option<String>,
+}
+
+impl AlterEventStatement {
+    pub fn parse(i: &str) -> IResult<&str, AlterEventStatement, ParseSQLError<&str>> {
+        verify(
+            map(
+                tuple((
+                    terminated(tag_no_case("ALTER"), multispace1),
+                    opt(terminated(CommonParser::definer, multispace1)),
+                    terminated(tag_no_case("EVENT"), multispace1),
+                    map(CommonParser::sql_identifier, String::from),
+                    opt(preceded(
+                        tuple((
+                            multispace1,
+                            tag_no_case("ON"),
+                            multispace1,
+                            tag_no_case("SCHEDULE"),
+                            multispace1,
+                        )),
+                        EventSchedule::parse,
+                    )),
+                    opt(CreateEventStatement::on_completion),
+                    opt(preceded(
+                        tuple((
+                            multispace1,
+                            tag_no_case("RENAME"),
+                            multispace1,
+                            tag_no_case("TO"),
+                            multispace1,
+                        )),
+                        map(CommonParser::sql_identifier, String::from),
+                    )),
+                    opt(preceded(multispace1, EventStatus::parse)),
+                    opt(CommonParser::parse_comment),
+                    opt(CreateEventStatement::event_body),
+                    CommonParser::statement_terminator,
+                )),
+                |(
+                    _,
+                    definer,
+                    _,
+                    event_name,
+                    schedule,
+                    on_completion_preserve,
+                    rename_to,
+                    status,
+                    comment,
+                    body,
+                    _,
+                )| AlterEventStatement {
+                    definer,
+                    event_name,
+                    schedule,
+                    on_completion_preserve,
+                    rename_to,
+                    status,
+                    comment,
+                    body,
+                },
+            ),
+            |alter| {
+                alter.schedule.is_some()
+                    || alter.on_completion_preserve.is_some()
+                    || alter.rename_to.is_some()
+                    || alter.status.is_some()
+                    || alter.comment.is_some()
+                    || alter.body.is_some()
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for AlterEventStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "ALTER")?;
+        if let Some(ref definer) = self.definer {
+            write_sql!(f, " DEFINER = {}", definer)?;
+        }
+        write_sql!(
+            f,
+            " EVENT {}",
+            DisplayUtil::escape_if_keyword(&self.event_name)
+        )?;
+        if let Some(ref schedule) = self.schedule {
+            write_sql!(f, " ON SCHEDULE {}", schedule)?;
+        }
+        match self.on_completion_preserve {
+            Some(true) => write_sql!(f, " ON COMPLETION PRESERVE")?,
+            Some(false) => write_sql!(f, " ON COMPLETION NOT PRESERVE")?,
+            None => {}
+        }
+        if let Some(ref rename_to) = self.rename_to {
+            write_sql!(
+                f,
+                " RENAME TO {}",
+                DisplayUtil::escape_if_keyword(rename_to)
+            )?;
+        }
+        if let Some(ref status) = self.status {
+            write_sql!(f, " {}", status)?;
+        }
+        if let Some(ref comment) = self.comment {
+            write_sql!(f, " COMMENT '{}'", comment)?;
+        }
+        if let Some(ref body) = self.body {
+            write_sql!(f, " DO {}", body)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Literal;
+    use dds::alter_event::AlterEventStatement;
+    use dds::create_event::{EventSchedule, EventStatus, Interval, IntervalUnit};
+
+    #[test]
+    fn parse_alter_event() {
+        let sql = "ALTER EVENT e DISABLE";
+        let res = AlterEventStatement::parse(sql);
+        let exp = AlterEventStatement {
+            definer: None,
+            event_name: "e".to_string(),
+            schedule: None,
+            on_completion_preserve: None,
+            rename_to: None,
+            status: Some(EventStatus::Disable),
+            comment: None,
+            body: None,
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), sql);
+
+        let sql = "ALTER DEFINER = `root`@`localhost` EVENT purge_sessions \
+            ON SCHEDULE EVERY 2 HOUR ON COMPLETION NOT PRESERVE RENAME TO purge \
+            ENABLE COMMENT 'hourly' DO DELETE FROM sessions";
+        let res = AlterEventStatement::parse(sql).unwrap().1;
+        assert_eq!(
+            res.schedule,
+            Some(EventSchedule::Every {
+                interval: Interval {
+                    quantity: Literal::Integer(2),
+                    unit: IntervalUnit::Hour,
+                },
+                starts: None,
+                ends: None,
+            })
+        );
+        assert_eq!(res.on_completion_preserve, Some(false));
+        assert_eq!(res.rename_to, Some("purge".to_string()));
+        assert_eq!(res.body, Some("DELETE FROM sessions".to_string()));
+        assert_eq!(res.to_string(), sql);
+
+        // nothing to alter
+        assert!(AlterEventStatement::parse("ALTER EVENT e").is_err());
+
+        let sql = "ALTER EVENT `order` RENAME TO `table` DO DELETE FROM t; SELECT 1";
+        let (rest, res) = AlterEventStatement::parse(sql).unwrap();
+        assert_eq!(rest, "SELECT 1");
+        assert_eq!(res.event_name, "order");
+        assert_eq!(res.body, Some("DELETE FROM t".to_string()));
+        assert_eq!(
+            res.to_string(),
+            "ALTER EVENT `order` RENAME TO `table` DO DELETE FROM t"
+        );
+    }
+}
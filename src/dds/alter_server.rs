@@ -0,0 +1,75 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::multispace1;
+use nom::combinator::map;
+use nom::sequence::{terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil};
+use dds::create_server::ServerOption;
+
+/// parse `ALTER SERVER server_name OPTIONS (option [, option] ...)`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct AlterServerStatement {
+    pub server_name: String,
+    pub options: Vec<ServerOption>,
+}
+
+impl AlterServerStatement {
+    pub fn parse(i: &str) -> IResult<&str, AlterServerStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("ALTER"), multispace1),
+                terminated(tag_no_case("SERVER"), multispace1),
+                map(CommonParser::sql_identifier, String::from),
+                multispace1,
+                ServerOption::parse_list,
+                CommonParser::statement_terminator,
+            )),
+            |(_, _, server_name, _, options, _)| AlterServerStatement {
+                server_name,
+                options,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for AlterServerStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(
+            f,
+            "ALTER SERVER {} {}",
+            DisplayUtil::escape_if_keyword(&self.server_name),
+            ServerOption::format_list(&self.options)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dds::alter_server::AlterServerStatement;
+    use dds::create_server::ServerOption;
+
+    #[test]
+    fn parse_alter_server() {
+        let sql = "ALTER SERVER s OPTIONS (USER 'sally', PORT 3307)";
+        let res = AlterServerStatement::parse(sql);
+        let exp = AlterServerStatement {
+            server_name: "s".to_string(),
+            options: vec![
+                ServerOption::User("sally".to_string()),
+                ServerOption::Port(3307),
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), sql);
+
+        let sql = "ALTER SERVER `from` OPTIONS (PORT 3307)";
+        let res = AlterServerStatement::parse(sql).unwrap().1;
+        assert_eq!(res.server_name, "from");
+        assert_eq!(res.to_string(), sql);
+    }
+}
@@ -5,10 +5,10 @@ use std::str::FromStr;
 use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case, take_until};
 use nom::character::complete::{alphanumeric1, anychar, digit1, multispace0, multispace1};
-use nom::combinator::{map, opt, recognize};
+use nom::combinator::{map, not, opt, peek, recognize};
 use nom::error::ParseError;
-use nom::multi::{many0, many1};
-use nom::sequence::{delimited, preceded, terminated, tuple};
+use nom::multi::{many0, many1, separated_list1};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::{IResult, Parser};
 
 use base::algorithm_type::AlgorithmType;
@@ -22,9 +22,10 @@ use base::table::Table;
 use base::table_option::TableOption;
 use base::visible_type::VisibleType;
 use base::{
-    CheckConstraintDefinition, CommonParser, KeyPart, ParseSQLError, PartitionDefinition,
-    ReferenceDefinition,
+    CheckConstraintDefinition, CommonParser, DisplayUtil, KeyPart, ParseSQLError,
+    ParseSQLErrorKind, PartitionDefinition, PartitionOptions, ReferenceDefinition,
 };
+use parser::ParseConfig;
 
 /// parse `ALTER TABLE tbl_name [alter_option [, alter_option] ...] [partition_options]`
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
@@ -36,12 +37,15 @@ pub struct AlterTableStatement {
 
 impl Display for AlterTableStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "ALTER TABLE {}", &self.table);
-        if let Some(alter_options) = &self.alter_options {
-            write!(f, " {}", AlterTableOption::format_list(alter_options));
+        write_sql!(f, "ALTER TABLE {}", &self.table);
+        match &self.alter_options {
+            Some(alter_options) if !alter_options.is_empty() => {
+                write_sql!(f, " {}", AlterTableOption::format_list(alter_options));
+            }
+            _ => {}
         }
         if let Some(partition_options) = &self.partition_options {
-            write!(
+            write_sql!(
                 f,
                 " {}",
                 AlterPartitionOption::format_list(partition_options)
@@ -66,16 +70,15 @@ impl AlterTableStatement {
             //
             opt(many0(map(
                 tuple((
+                    // `DROP PARTITION p0` would otherwise drop a column named `PARTITION`
+                    not(AlterPartitionOption::parse),
                     AlterTableOption::parse,
                     opt(CommonParser::ws_sep_comma),
                     multispace0,
                 )),
-                |x| x.0,
-            ))),
-            opt(many0(terminated(
-                AlterPartitionOption::parse,
-                opt(CommonParser::ws_sep_comma),
+                |x| x.1,
             ))),
+            opt(separated_list1(multispace1, AlterPartitionOption::parse)),
             CommonParser::statement_terminator,
         ));
         let (remaining_input, (_, table, _, alter_options, partition_options, _)) = parser(i)?;
@@ -101,8 +104,8 @@ pub enum CheckOrConstraintType {
 impl Display for CheckOrConstraintType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            CheckOrConstraintType::Check => write!(f, "CHECK"),
-            CheckOrConstraintType::Constraint => write!(f, "CONSTRAINT"),
+            CheckOrConstraintType::Check => write_sql!(f, "CHECK"),
+            CheckOrConstraintType::Constraint => write_sql!(f, "CONSTRAINT"),
         }
     }
 }
@@ -126,6 +129,9 @@ pub enum AlterTableOption {
     /// `ADD [COLUMN] col_name column_definition
     ///     [FIRST | AFTER col_name]`
     /// `ADD [COLUMN] (col_name column_definition,...)`
+    ///
+    /// Only more than one column is written in the parenthesized form, as
+    /// MySQL reads `ADD (c INT)` the same as `ADD c INT`.
     AddColumn {
         opt_column: bool, // [COLUMN]
         columns: Vec<ColumnSpecification>,
@@ -241,8 +247,13 @@ pub enum AlterTableOption {
     /// `{DISCARD | IMPORT} TABLESPACE`
     ImportTablespace,
 
-    /// `DROP [COLUMN] col_name`
-    DropColumn { col_name: String },
+    /// `DROP [COLUMN] col_name [RESTRICT | CASCADE]`, the MariaDB
+    /// `RESTRICT` or `CASCADE` only with [crate::ParseConfig::mariadb]
+    DropColumn {
+        col_name: String,
+        if_restrict: bool,
+        if_cascade: bool,
+    },
 
     /// `DROP {INDEX | KEY} index_name`
     DropIndexOrKey {
@@ -284,7 +295,7 @@ pub enum AlterTableOption {
     },
 
     /// `RENAME [TO | AS] new_tbl_name`
-    RenameTable { new_tbl_name: String },
+    RenameTable { new_tbl_name: Table },
 
     /// `{WITHOUT | WITH} VALIDATION`
     Validation { with_validation: bool },
@@ -294,21 +305,27 @@ impl Display for AlterTableOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
             AlterTableOption::TableOptions { ref table_options } => {
-                write!(f, " {}", TableOption::format_list(table_options))
+                write_sql!(f, "{}", TableOption::format_list(table_options))
             }
             AlterTableOption::AddColumn {
                 ref opt_column,
                 ref columns,
             } => {
+                write_sql!(f, "ADD");
                 if *opt_column {
-                    write!(f, " ADD COLUMN");
+                    write_sql!(f, " COLUMN");
+                }
+                // a single column keeps its FIRST/AFTER position, which is
+                // not allowed inside the parenthesized form
+                if let [column] = columns.as_slice() {
+                    return write_sql!(f, " {}", column);
                 }
                 let columns = columns
                     .iter()
                     .map(|x| x.to_string())
                     .collect::<Vec<String>>()
                     .join(", ");
-                write!(f, " ({})", columns);
+                write_sql!(f, " ({})", columns);
                 Ok(())
             }
             AlterTableOption::AddIndexOrKey {
@@ -318,16 +335,16 @@ impl Display for AlterTableOption {
                 ref key_part,
                 ref opt_index_option,
             } => {
-                write!(f, " ADD {}", index_or_key);
+                write_sql!(f, "ADD {}", index_or_key);
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
                 if let Some(opt_index_type) = opt_index_type {
-                    write!(f, " {}", opt_index_type);
+                    write_sql!(f, " {}", opt_index_type);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -338,16 +355,16 @@ impl Display for AlterTableOption {
                 ref key_part,
                 ref opt_index_option,
             } => {
-                write!(f, " ADD {}", fulltext_or_spatial);
+                write_sql!(f, "ADD {}", fulltext_or_spatial);
                 if let Some(opt_index_or_key) = opt_index_or_key {
-                    write!(f, " {}", opt_index_or_key);
+                    write_sql!(f, " {}", opt_index_or_key);
                 }
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -357,17 +374,21 @@ impl Display for AlterTableOption {
                 ref key_part,
                 ref opt_index_option,
             } => {
-                write!(f, "ADD");
+                write_sql!(f, "ADD");
                 if let Some(opt_symbol) = opt_symbol {
-                    write!(f, " CONSTRAINT {}", opt_symbol);
+                    write_sql!(
+                        f,
+                        " CONSTRAINT {}",
+                        DisplayUtil::escape_if_keyword(opt_symbol)
+                    );
                 }
-                write!(f, " PRIMARY KEY");
+                write_sql!(f, " PRIMARY KEY");
                 if let Some(opt_index_type) = opt_index_type {
-                    write!(f, " {}", opt_index_type);
+                    write_sql!(f, " {}", opt_index_type);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -379,23 +400,27 @@ impl Display for AlterTableOption {
                 ref key_part,
                 ref opt_index_option,
             } => {
-                write!(f, "ADD");
+                write_sql!(f, "ADD");
                 if let Some(opt_symbol) = opt_symbol {
-                    write!(f, " CONSTRAINT {}", opt_symbol);
+                    write_sql!(
+                        f,
+                        " CONSTRAINT {}",
+                        DisplayUtil::escape_if_keyword(opt_symbol)
+                    );
                 }
-                write!(f, " UNIQUE");
+                write_sql!(f, " UNIQUE");
                 if let Some(opt_index_or_key) = opt_index_or_key {
-                    write!(f, " {}", opt_index_or_key);
+                    write_sql!(f, " {}", opt_index_or_key);
                 }
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
                 if let Some(opt_index_type) = opt_index_type {
-                    write!(f, " {}", opt_index_type);
+                    write_sql!(f, " {}", opt_index_type);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -405,69 +430,93 @@ impl Display for AlterTableOption {
                 ref columns,
                 ref reference_definition,
             } => {
-                write!(f, "ADD");
+                write_sql!(f, "ADD");
                 if let Some(opt_symbol) = opt_symbol {
-                    write!(f, " CONSTRAINT {}", opt_symbol);
+                    write_sql!(
+                        f,
+                        " CONSTRAINT {}",
+                        DisplayUtil::escape_if_keyword(opt_symbol)
+                    );
                 }
-                write!(f, " FOREIGN KEY");
+                write_sql!(f, " FOREIGN KEY");
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
-                write!(f, " ({})", columns.join(", "));
-                write!(f, " {}", reference_definition);
+                write_sql!(f, " ({})", columns.join(", "));
+                write_sql!(f, " {}", reference_definition);
                 Ok(())
             }
             AlterTableOption::AddCheck {
                 ref check_constraint,
             } => {
-                write!(f, " ADD {}", check_constraint)
+                write_sql!(f, "ADD {}", check_constraint)
             }
             AlterTableOption::DropCheckOrConstraint {
                 ref check_or_constraint,
                 ref symbol,
             } => {
-                write!(f, "DROP {} {}", &check_or_constraint, &symbol)
+                write_sql!(
+                    f,
+                    "DROP {} {}",
+                    &check_or_constraint,
+                    DisplayUtil::escape_if_keyword(symbol)
+                )
             }
             AlterTableOption::AlterCheckOrConstraintEnforced {
                 ref check_or_constraint,
                 ref symbol,
                 ref enforced,
             } => {
-                write!(f, "DROP {} {}", &check_or_constraint, &symbol);
+                write_sql!(
+                    f,
+                    "ALTER {} {}",
+                    &check_or_constraint,
+                    DisplayUtil::escape_if_keyword(symbol)
+                );
                 if !*enforced {
-                    write!(f, " NOT");
+                    write_sql!(f, " NOT");
                 }
-                write!(f, " ENFORCED");
+                write_sql!(f, " ENFORCED");
                 Ok(())
             }
             AlterTableOption::Algorithm { ref algorithm } => {
-                write!(f, " {}", algorithm)
+                write_sql!(f, "{}", algorithm)
             }
             AlterTableOption::AlterColumn {
                 ref col_name,
                 ref alter_column_operation,
             } => {
-                write!(f, " ALTER {} {}", col_name, alter_column_operation)
+                write_sql!(
+                    f,
+                    "ALTER COLUMN {} {}",
+                    DisplayUtil::escape_if_keyword(col_name),
+                    alter_column_operation
+                )
             }
             AlterTableOption::AlterIndexVisibility {
                 ref index_name,
                 ref visible,
             } => {
-                write!(f, " ALTER INDEX {} {}", index_name, visible)
+                write_sql!(f, "ALTER INDEX {} {}", index_name, visible)
             }
             AlterTableOption::ChangeColumn {
                 ref old_col_name,
                 ref column_definition,
             } => {
-                write!(f, " CHANGE {} {}", old_col_name, column_definition)
+                write_sql!(
+                    f,
+                    "CHANGE {} {}",
+                    DisplayUtil::escape_if_keyword(old_col_name),
+                    column_definition
+                )
             }
             AlterTableOption::DefaultCharacterSet {
                 ref charset_name,
                 ref collation_name,
             } => {
-                write!(f, " CHARACTER SET {}", charset_name);
+                write_sql!(f, "CHARACTER SET {}", charset_name);
                 if let Some(collation_name) = collation_name {
-                    write!(f, " COLLATE {}", collation_name);
+                    write_sql!(f, " COLLATE {}", collation_name);
                 }
                 Ok(())
             }
@@ -475,83 +524,100 @@ impl Display for AlterTableOption {
                 ref charset_name,
                 ref collation_name,
             } => {
-                write!(f, " CONVERT TO CHARACTER SET {}", charset_name);
+                write_sql!(f, "CONVERT TO CHARACTER SET {}", charset_name);
                 if let Some(collation_name) = collation_name {
-                    write!(f, " COLLATE {}", collation_name);
+                    write_sql!(f, " COLLATE {}", collation_name);
                 }
                 Ok(())
             }
             AlterTableOption::DisableKeys => {
-                write!(f, " DISABLE KEYS")
+                write_sql!(f, "DISABLE KEYS")
             }
             AlterTableOption::EnableKeys => {
-                write!(f, " ENABLE KEYS")
+                write_sql!(f, "ENABLE KEYS")
             }
             AlterTableOption::DiscardTablespace => {
-                write!(f, " DISCARD TABLESPACE")
+                write_sql!(f, "DISCARD TABLESPACE")
             }
             AlterTableOption::ImportTablespace => {
-                write!(f, " IMPORT TABLESPACE")
+                write_sql!(f, "IMPORT TABLESPACE")
             }
-            AlterTableOption::DropColumn { ref col_name } => {
-                write!(f, " DROP {}", col_name)
+            AlterTableOption::DropColumn {
+                ref col_name,
+                if_restrict,
+                if_cascade,
+            } => {
+                write_sql!(f, "DROP COLUMN {}", col_name)?;
+                if if_restrict {
+                    write_sql!(f, " RESTRICT")?;
+                }
+                if if_cascade {
+                    write_sql!(f, " CASCADE")?;
+                }
+                Ok(())
             }
             AlterTableOption::DropIndexOrKey {
                 ref index_or_key,
                 ref index_name,
             } => {
-                write!(f, " DROP {} {}", index_or_key, index_name)
+                write_sql!(f, "DROP {} {}", index_or_key, index_name)
             }
             AlterTableOption::DropPrimaryKey => {
-                write!(f, " DROP PRIMARY KEY")
+                write_sql!(f, "DROP PRIMARY KEY")
             }
             AlterTableOption::DropForeignKey { ref fk_symbol } => {
-                write!(f, " DROP FOREIGN KEY {}", fk_symbol)
+                write_sql!(
+                    f,
+                    "DROP FOREIGN KEY {}",
+                    DisplayUtil::escape_if_keyword(fk_symbol)
+                )
             }
             AlterTableOption::Force => {
-                write!(f, " FORCE")
+                write_sql!(f, "FORCE")
             }
             AlterTableOption::Lock { ref lock_type } => {
-                write!(f, " LOCK {}", lock_type)
+                write_sql!(f, "{}", lock_type)
             }
             AlterTableOption::ModifyColumn {
                 ref column_definition,
             } => {
-                write!(f, " MODIFY {}", column_definition)
+                write_sql!(f, "MODIFY {}", column_definition)
             }
             AlterTableOption::OrderBy { ref columns } => {
                 let columns = columns.join(", ");
-                write!(f, " ORDER BY {}", columns)
+                write_sql!(f, "ORDER BY {}", columns)
             }
             AlterTableOption::RenameColumn {
                 ref old_col_name,
                 ref new_col_name,
             } => {
-                write!(f, " RENAME COLUMN {} {}", old_col_name, new_col_name)
+                write_sql!(f, "RENAME COLUMN {} TO {}", old_col_name, new_col_name)
             }
             AlterTableOption::RenameIndexOrKey {
                 ref index_or_key,
                 ref old_index_name,
                 ref new_index_name,
             } => {
-                write!(
+                write_sql!(
                     f,
-                    " RENAME {} {} TO {}",
-                    index_or_key, old_index_name, new_index_name
+                    "RENAME {} {} TO {}",
+                    index_or_key,
+                    old_index_name,
+                    new_index_name
                 )
             }
             AlterTableOption::RenameTable { ref new_tbl_name } => {
-                write!(f, " RENAME TO {}", new_tbl_name)
+                write_sql!(f, "RENAME TO {}", new_tbl_name)
             }
             AlterTableOption::Validation {
                 ref with_validation,
             } => {
                 if *with_validation {
-                    write!(f, " WITH");
+                    write_sql!(f, "WITH");
                 } else {
-                    write!(f, " WITHOUT");
+                    write_sql!(f, "WITHOUT");
                 }
-                write!(f, " VALIDATION");
+                write_sql!(f, " VALIDATION");
                 Ok(())
             }
         }
@@ -614,10 +680,10 @@ impl AlterTableOption {
             Self::convert_to_character_set,
             Self::disable_or_enable_keys,
             Self::discard_or_import_tablespace,
-            Self::drop_column,
             Self::drop_index_or_key,
             Self::drop_primary_key,
             Self::drop_foreign_key,
+            Self::drop_column,
             Self::force,
             Self::lock,
             Self::modify_column,
@@ -645,6 +711,28 @@ impl AlterTableOption {
         )(i)
     }
 
+    /// `(col_name column_definition, ...)`, where MySQL allows no `FIRST` or `AFTER`
+    fn parenthesized_columns(
+        i: &str,
+    ) -> IResult<&str, Vec<ColumnSpecification>, ParseSQLError<&str>> {
+        let (remaining_input, columns) = delimited(
+            pair(tag("("), multispace0),
+            many1(ColumnSpecification::parse),
+            pair(multispace0, tag(")")),
+        )(i)?;
+        if columns.iter().any(|column| column.position.is_some()) {
+            return Err(nom::Err::Failure(ParseSQLError {
+                errors: vec![(
+                    i,
+                    ParseSQLErrorKind::Context(
+                        "FIRST and AFTER are not allowed when adding parenthesized columns",
+                    ),
+                )],
+            }));
+        }
+        Ok((remaining_input, columns))
+    }
+
     /// `ADD [COLUMN] col_name column_definition
     ///     [FIRST | AFTER col_name]`
     /// `ADD [COLUMN] (col_name column_definition,...)`
@@ -668,28 +756,16 @@ impl AlterTableOption {
                             multispace1,
                             tag_no_case("COLUMN"),
                             multispace0,
-                            tag("("),
-                            multispace0,
-                            many1(ColumnSpecification::parse),
-                            multispace0,
-                            tag(")"),
+                            Self::parenthesized_columns,
                         )),
-                        |x| (true, x.5),
+                        |x| (true, x.3),
                     ),
                     map(tuple((multispace0, ColumnSpecification::parse)), |x| {
                         (false, vec![x.1])
                     }),
-                    map(
-                        tuple((
-                            multispace0,
-                            tag("("),
-                            multispace0,
-                            many1(ColumnSpecification::parse),
-                            multispace0,
-                            tag(")"),
-                        )),
-                        |x| (false, x.3),
-                    ),
+                    map(preceded(multispace0, Self::parenthesized_columns), |x| {
+                        (false, x)
+                    }),
                 )),
             )),
             |(_, (opt_column, columns))| AlterTableOption::AddColumn {
@@ -735,7 +811,7 @@ impl AlterTableOption {
                 // {FULLTEXT | SPATIAL}
                 FulltextOrSpatialType::parse,
                 // [INDEX | KEY]
-                preceded(multispace1, opt(IndexOrKeyType::parse)),
+                IndexOrKeyType::opt_index_or_key,
                 // [index_name]
                 CommonParser::opt_index_name,
                 // (key_part,...)
@@ -1134,21 +1210,53 @@ impl AlterTableOption {
         )(i)
     }
 
-    /// `DROP [COLUMN] col_name`
+    /// `DROP [COLUMN] col_name [RESTRICT | CASCADE]`
     fn drop_column(i: &str) -> IResult<&str, AlterTableOption, ParseSQLError<&str>> {
         map(
             tuple((
-                tag_no_case("DROP "),
-                multispace0,
-                opt(tag_no_case("COLUMN ")),
+                tag_no_case("DROP"),
+                multispace1,
+                // the other DROP targets are matched before, never read them as a column
+                not(peek(tuple((
+                    alt((
+                        tag_no_case("INDEX"),
+                        tag_no_case("KEY"),
+                        tag_no_case("PRIMARY"),
+                        tag_no_case("FOREIGN"),
+                        tag_no_case("CHECK"),
+                        tag_no_case("CONSTRAINT"),
+                    )),
+                    multispace1,
+                )))),
+                opt(tuple((tag_no_case("COLUMN"), multispace1))),
                 // col_name
-                map(
-                    tuple((multispace0, CommonParser::sql_identifier, multispace0)),
-                    |(_, col_name, _)| String::from(col_name),
-                ),
+                map(CommonParser::sql_identifier, String::from),
+                Self::restrict_or_cascade,
                 multispace0,
             )),
-            |(_, _, _, col_name, _)| AlterTableOption::DropColumn { col_name },
+            |(_, _, _, _, col_name, (if_restrict, if_cascade), _)| AlterTableOption::DropColumn {
+                col_name,
+                if_restrict,
+                if_cascade,
+            },
+        )(i)
+    }
+
+    /// MariaDB `[RESTRICT | CASCADE]` after a dropped column, never present when
+    /// parsing MySQL
+    fn restrict_or_cascade(i: &str) -> IResult<&str, (bool, bool), ParseSQLError<&str>> {
+        if !ParseConfig::with_current(|config| config.mariadb) {
+            return Ok((i, (false, false)));
+        }
+        map(
+            opt(preceded(
+                multispace1,
+                alt((
+                    map(tag_no_case("RESTRICT"), |_| (true, false)),
+                    map(tag_no_case("CASCADE"), |_| (false, true)),
+                )),
+            )),
+            Option::unwrap_or_default,
         )(i)
     }
 
@@ -1287,14 +1395,14 @@ impl AlterTableOption {
                 IndexOrKeyType::parse,
                 // old_index_name
                 map(
-                    tuple((multispace1, CommonParser::sql_identifier, multispace1)),
-                    |(_, index_name, _)| String::from(index_name),
+                    tuple((multispace1, CommonParser::sql_identifier)),
+                    |(_, index_name)| String::from(index_name),
                 ),
                 tuple((multispace1, tag_no_case("TO"))),
                 // new_index_name
                 map(
-                    tuple((multispace1, CommonParser::sql_identifier, multispace1)),
-                    |(_, index_name, _)| String::from(index_name),
+                    tuple((multispace1, CommonParser::sql_identifier)),
+                    |(_, index_name)| String::from(index_name),
                 ),
                 multispace0,
             )),
@@ -1312,17 +1420,16 @@ impl AlterTableOption {
     fn rename_table(i: &str) -> IResult<&str, AlterTableOption, ParseSQLError<&str>> {
         map(
             tuple((
-                tuple((tag_no_case("RENAME"), multispace1)),
-                // {INDEX | KEY}
-                alt((tag_no_case("TO"), tag_no_case("AS"))),
-                // new_tbl_name
-                map(
-                    tuple((multispace1, CommonParser::sql_identifier, multispace0)),
-                    |(_, index_name, _)| String::from(index_name),
-                ),
+                tag_no_case("RENAME"),
+                multispace1,
+                opt(terminated(
+                    alt((tag_no_case("TO"), tag_no_case("AS"))),
+                    multispace1,
+                )),
+                Table::without_alias,
                 multispace0,
             )),
-            |x| AlterTableOption::RenameTable { new_tbl_name: x.2 },
+            |(_, _, _, new_tbl_name, _)| AlterTableOption::RenameTable { new_tbl_name },
         )(i)
     }
 
@@ -1358,10 +1465,12 @@ pub enum AlertColumnOperation {
 impl Display for AlertColumnOperation {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            AlertColumnOperation::SetDefaultLiteral(ref val) => write!(f, "SET DEFAULT {}", val),
-            AlertColumnOperation::SetDefaultExpr(ref val) => write!(f, "SET DEFAULT ({})", val),
-            AlertColumnOperation::SetVisible(ref val) => write!(f, "SET {}", val),
-            AlertColumnOperation::DropDefault => write!(f, "DROP DEFAULT"),
+            AlertColumnOperation::SetDefaultLiteral(ref val) => {
+                write_sql!(f, "SET DEFAULT {}", val)
+            }
+            AlertColumnOperation::SetDefaultExpr(ref val) => write_sql!(f, "SET DEFAULT ({})", val),
+            AlertColumnOperation::SetVisible(ref val) => write_sql!(f, "SET {}", val),
+            AlertColumnOperation::DropDefault => write_sql!(f, "DROP DEFAULT"),
         }
     }
 }
@@ -1411,23 +1520,50 @@ impl AlertColumnOperation {
     }
 }
 
-////////////// TODO support alter partition parser
+/// partition_options:
+///     `partition_option [partition_option] ...`
+///
+/// partition_option: {
+///     PARTITION BY ...
+///   | ADD PARTITION (partition_definition)
+///   | DROP PARTITION partition_names
+///   | DISCARD PARTITION {partition_names | ALL} TABLESPACE
+///   | IMPORT PARTITION {partition_names | ALL} TABLESPACE
+///   | TRUNCATE PARTITION {partition_names | ALL}
+///   | COALESCE PARTITION number
+///   | REORGANIZE PARTITION partition_names INTO (partition_definitions)
+///   | EXCHANGE PARTITION partition_name WITH TABLE tbl_name [{WITH | WITHOUT} VALIDATION]
+///   | ANALYZE PARTITION {partition_names | ALL}
+///   | CHECK PARTITION {partition_names | ALL}
+///   | OPTIMIZE PARTITION {partition_names | ALL}
+///   | REBUILD PARTITION {partition_names | ALL}
+///   | REPAIR PARTITION {partition_names | ALL}
+///   | REMOVE PARTITIONING
+/// }
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum AlterPartitionOption {
-    None,
-    AddPartition(PartitionDefinition),
-    DropPartition(String),
-    DiscardPartition,
-    ImportPartition,
-    TruncatePartition,
-    CoalescePartition,
-    ReorganizePartitionInto,
-    ExchangePartitionWithTable,
-    AnalyzePartition,
-    CheckPartition,
-    OptimizePartition,
-    RebuildPartition,
-    RepairPartition,
+    /// repartitions the table, see [PartitionOptions]
+    PartitionBy(PartitionOptions),
+    AddPartition(Vec<PartitionDefinition>),
+    DropPartition(Vec<String>),
+    DiscardPartition(PartitionNames),
+    ImportPartition(PartitionNames),
+    TruncatePartition(PartitionNames),
+    CoalescePartition(u64),
+    ReorganizePartition {
+        partition_names: Vec<String>,
+        definitions: Vec<PartitionDefinition>,
+    },
+    ExchangePartition {
+        partition_name: String,
+        table: Table,
+        with_validation: Option<bool>,
+    },
+    AnalyzePartition(PartitionNames),
+    CheckPartition(PartitionNames),
+    OptimizePartition(PartitionNames),
+    RebuildPartition(PartitionNames),
+    RepairPartition(PartitionNames),
     RemovePartitioning,
 }
 
@@ -1436,32 +1572,276 @@ impl AlterPartitionOption {
         list.iter()
             .map(|x| x.to_string())
             .collect::<Vec<String>>()
-            .join("")
+            .join(" ")
     }
 }
 
 impl Display for AlterPartitionOption {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
+        match *self {
+            AlterPartitionOption::PartitionBy(ref options) => write_sql!(f, "{}", options),
+            AlterPartitionOption::AddPartition(ref definitions) => write_sql!(
+                f,
+                "ADD PARTITION {}",
+                PartitionDefinition::format_list(definitions)
+            ),
+            AlterPartitionOption::DropPartition(ref names) => {
+                write_sql!(f, "DROP PARTITION {}", names.join(", "))
+            }
+            AlterPartitionOption::DiscardPartition(ref names) => {
+                write_sql!(f, "DISCARD PARTITION {} TABLESPACE", names)
+            }
+            AlterPartitionOption::ImportPartition(ref names) => {
+                write_sql!(f, "IMPORT PARTITION {} TABLESPACE", names)
+            }
+            AlterPartitionOption::TruncatePartition(ref names) => {
+                write_sql!(f, "TRUNCATE PARTITION {}", names)
+            }
+            AlterPartitionOption::CoalescePartition(number) => {
+                write_sql!(f, "COALESCE PARTITION {}", number)
+            }
+            AlterPartitionOption::ReorganizePartition {
+                ref partition_names,
+                ref definitions,
+            } => write_sql!(
+                f,
+                "REORGANIZE PARTITION {} INTO {}",
+                partition_names.join(", "),
+                PartitionDefinition::format_list(definitions)
+            ),
+            AlterPartitionOption::ExchangePartition {
+                ref partition_name,
+                ref table,
+                with_validation,
+            } => {
+                write_sql!(
+                    f,
+                    "EXCHANGE PARTITION {} WITH TABLE {}",
+                    partition_name,
+                    table
+                )?;
+                match with_validation {
+                    Some(true) => write_sql!(f, " WITH VALIDATION"),
+                    Some(false) => write_sql!(f, " WITHOUT VALIDATION"),
+                    None => Ok(()),
+                }
+            }
+            AlterPartitionOption::AnalyzePartition(ref names) => {
+                write_sql!(f, "ANALYZE PARTITION {}", names)
+            }
+            AlterPartitionOption::CheckPartition(ref names) => {
+                write_sql!(f, "CHECK PARTITION {}", names)
+            }
+            AlterPartitionOption::OptimizePartition(ref names) => {
+                write_sql!(f, "OPTIMIZE PARTITION {}", names)
+            }
+            AlterPartitionOption::RebuildPartition(ref names) => {
+                write_sql!(f, "REBUILD PARTITION {}", names)
+            }
+            AlterPartitionOption::RepairPartition(ref names) => {
+                write_sql!(f, "REPAIR PARTITION {}", names)
+            }
+            AlterPartitionOption::RemovePartitioning => write_sql!(f, "REMOVE PARTITIONING"),
+        }
     }
 }
 
 impl AlterPartitionOption {
     pub fn parse(i: &str) -> IResult<&str, AlterPartitionOption, ParseSQLError<&str>> {
-        map(tag_no_case(""), |_| AlterPartitionOption::None)(i)
+        // `ACTION PARTITION {partition_names | ALL}`
+        let names_or_all = |action: &'static str| {
+            preceded(
+                tuple((
+                    tag_no_case(action),
+                    multispace1,
+                    tag_no_case("PARTITION"),
+                    multispace1,
+                )),
+                PartitionNames::parse,
+            )
+        };
+        let tablespace = || tuple((multispace1, tag_no_case("TABLESPACE")));
+        alt((
+            map(PartitionOptions::parse, AlterPartitionOption::PartitionBy),
+            map(
+                preceded(
+                    tuple((
+                        tag_no_case("ADD"),
+                        multispace1,
+                        tag_no_case("PARTITION"),
+                        multispace0,
+                    )),
+                    PartitionDefinition::parse_list,
+                ),
+                AlterPartitionOption::AddPartition,
+            ),
+            map(
+                preceded(
+                    tuple((
+                        tag_no_case("DROP"),
+                        multispace1,
+                        tag_no_case("PARTITION"),
+                        multispace1,
+                    )),
+                    PartitionNames::name_list,
+                ),
+                AlterPartitionOption::DropPartition,
+            ),
+            map(
+                terminated(names_or_all("DISCARD"), tablespace()),
+                AlterPartitionOption::DiscardPartition,
+            ),
+            map(
+                terminated(names_or_all("IMPORT"), tablespace()),
+                AlterPartitionOption::ImportPartition,
+            ),
+            map(
+                names_or_all("TRUNCATE"),
+                AlterPartitionOption::TruncatePartition,
+            ),
+            map(
+                preceded(
+                    tuple((
+                        tag_no_case("COALESCE"),
+                        multispace1,
+                        tag_no_case("PARTITION"),
+                        multispace1,
+                    )),
+                    CommonParser::unsigned_number,
+                ),
+                AlterPartitionOption::CoalescePartition,
+            ),
+            map(
+                tuple((
+                    tag_no_case("REORGANIZE"),
+                    multispace1,
+                    tag_no_case("PARTITION"),
+                    multispace1,
+                    PartitionNames::name_list,
+                    multispace1,
+                    tag_no_case("INTO"),
+                    multispace0,
+                    PartitionDefinition::parse_list,
+                )),
+                |x| AlterPartitionOption::ReorganizePartition {
+                    partition_names: x.4,
+                    definitions: x.8,
+                },
+            ),
+            map(
+                tuple((
+                    tag_no_case("EXCHANGE"),
+                    multispace1,
+                    tag_no_case("PARTITION"),
+                    multispace1,
+                    map(CommonParser::sql_identifier, String::from),
+                    multispace1,
+                    tag_no_case("WITH"),
+                    multispace1,
+                    tag_no_case("TABLE"),
+                    multispace1,
+                    Table::without_alias,
+                    opt(preceded(
+                        multispace1,
+                        terminated(
+                            alt((
+                                map(tag_no_case("WITHOUT"), |_| false),
+                                map(tag_no_case("WITH"), |_| true),
+                            )),
+                            pair(multispace1, tag_no_case("VALIDATION")),
+                        ),
+                    )),
+                )),
+                |x| AlterPartitionOption::ExchangePartition {
+                    partition_name: x.4,
+                    table: x.10,
+                    with_validation: x.11,
+                },
+            ),
+            map(
+                names_or_all("ANALYZE"),
+                AlterPartitionOption::AnalyzePartition,
+            ),
+            map(names_or_all("CHECK"), AlterPartitionOption::CheckPartition),
+            map(
+                names_or_all("OPTIMIZE"),
+                AlterPartitionOption::OptimizePartition,
+            ),
+            map(
+                names_or_all("REBUILD"),
+                AlterPartitionOption::RebuildPartition,
+            ),
+            map(
+                names_or_all("REPAIR"),
+                AlterPartitionOption::RepairPartition,
+            ),
+            map(
+                tuple((
+                    tag_no_case("REMOVE"),
+                    multispace1,
+                    tag_no_case("PARTITIONING"),
+                )),
+                |_| AlterPartitionOption::RemovePartitioning,
+            ),
+        ))(i)
+    }
+}
+
+/// `{partition_names | ALL}`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum PartitionNames {
+    All,
+    Names(Vec<String>),
+}
+
+impl Display for PartitionNames {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            PartitionNames::All => write_sql!(f, "ALL"),
+            PartitionNames::Names(ref names) => write_sql!(f, "{}", names.join(", ")),
+        }
+    }
+}
+
+impl PartitionNames {
+    fn parse(i: &str) -> IResult<&str, PartitionNames, ParseSQLError<&str>> {
+        alt((
+            map(
+                terminated(tag_no_case("ALL"), not(peek(CommonParser::sql_identifier))),
+                |_| PartitionNames::All,
+            ),
+            map(Self::name_list, PartitionNames::Names),
+        ))(i)
+    }
+
+    /// `partition_name [, partition_name] ...`
+    fn name_list(i: &str) -> IResult<&str, Vec<String>, ParseSQLError<&str>> {
+        separated_list1(
+            CommonParser::ws_sep_comma,
+            map(CommonParser::sql_identifier, String::from),
+        )(i)
     }
 }
-////////////// TODO support alter partition parser
 
 #[cfg(test)]
 mod tests {
+    use base::algorithm_type::AlgorithmType;
     use base::column::{ColumnConstraint, ColumnPosition, ColumnSpecification};
     use base::fulltext_or_spatial_type::FulltextOrSpatialType;
     use base::index_option::IndexOption;
     use base::index_or_key_type::IndexOrKeyType;
+    use base::lock_type::LockType;
+    use base::table_option::TableOption;
     use base::visible_type::VisibleType;
-    use base::{CheckConstraintDefinition, DataType, KeyPart, KeyPartType, Literal};
-    use dds::alter_table::AlterTableOption;
+    use base::PartitionBy;
+    use base::{
+        CheckConstraintDefinition, DataType, KeyPart, KeyPartType, Literal, TablespaceType,
+    };
+    use dds::alter_table::{
+        AlertColumnOperation, AlterPartitionOption, AlterTableOption, AlterTableStatement,
+        CheckOrConstraintType,
+    };
+    use {ParseConfig, Parser};
 
     #[test]
     fn parse_add_column() {
@@ -1501,8 +1881,8 @@ mod tests {
                     column: "column6".into(),
                     data_type: DataType::Timestamp,
                     constraints: vec![
-                        ColumnConstraint::DefaultValue(Literal::CurrentTimestamp),
-                        ColumnConstraint::OnUpdate(Literal::CurrentTimestamp),
+                        ColumnConstraint::DefaultValue(Literal::CurrentTimestamp(None)),
+                        ColumnConstraint::OnUpdate(Literal::CurrentTimestamp(None)),
                     ],
                     comment: None,
                     position: None,
@@ -1516,6 +1896,200 @@ mod tests {
         }
     }
 
+    #[test]
+    fn add_columns_keep_their_positions() {
+        let sql = "ALTER TABLE t ADD a INT FIRST, ADD COLUMN b INT AFTER a";
+        let column = |name: &str, position| AlterTableOption::AddColumn {
+            opt_column: name == "b",
+            columns: vec![ColumnSpecification {
+                column: name.into(),
+                data_type: DataType::Int(32),
+                constraints: vec![],
+                comment: None,
+                position: Some(position),
+            }],
+        };
+        let exp = vec![
+            column("a", ColumnPosition::First),
+            column("b", ColumnPosition::After("a".into())),
+        ];
+
+        let res = AlterTableStatement::parse(sql).unwrap().1;
+        assert_eq!(res.alter_options, Some(exp));
+
+        let round_trip = AlterTableStatement::parse(&res.to_string()).unwrap().1;
+        assert_eq!(round_trip, res);
+    }
+
+    #[test]
+    fn add_column_round_trip() {
+        let parts = [
+            ("ALTER TABLE t ADD COLUMN c INT", "ADD COLUMN c INT(32)", 1),
+            ("ALTER TABLE t ADD c INT", "ADD c INT(32)", 1),
+            (
+                "ALTER TABLE t ADD COLUMN (a INT, b VARCHAR(10))",
+                "ADD COLUMN (a INT(32), b VARCHAR(10))",
+                2,
+            ),
+            (
+                "ALTER TABLE t ADD (a INT, b INT)",
+                "ADD (a INT(32), b INT(32))",
+                2,
+            ),
+        ];
+        for (sql, display, count) in parts {
+            let res = AlterTableStatement::parse(sql).unwrap().1;
+            match res.alter_options.as_deref() {
+                Some([AlterTableOption::AddColumn { columns, .. }]) => {
+                    assert_eq!(columns.len(), count)
+                }
+                other => panic!("unexpected options {:?}", other),
+            }
+            assert!(res.to_string().ends_with(display), "{}", res);
+            let round_trip = AlterTableStatement::parse(&res.to_string()).unwrap().1;
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn add_column_position_round_trip() {
+        let parts = [
+            (
+                "ALTER TABLE t ADD COLUMN c INT AFTER x",
+                ColumnPosition::After("x".into()),
+            ),
+            (
+                "ALTER TABLE t ADD c VARCHAR(10) NOT NULL COMMENT 'c' FIRST",
+                ColumnPosition::First,
+            ),
+        ];
+        for (sql, position) in parts {
+            let res = AlterTableStatement::parse(sql).unwrap().1;
+            let display = res.to_string();
+            assert!(display.ends_with(&format!(" {}", position)), "{}", display);
+            let round_trip = AlterTableStatement::parse(&display).unwrap().1;
+            match round_trip.alter_options.as_deref() {
+                Some([AlterTableOption::AddColumn { columns, .. }]) => {
+                    assert_eq!(columns[0].position, Some(position))
+                }
+                other => panic!("unexpected options {:?}", other),
+            }
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn modify_column_position_round_trip() {
+        let parts = [
+            (
+                "ALTER TABLE t MODIFY COLUMN c INT FIRST",
+                ColumnPosition::First,
+            ),
+            (
+                "ALTER TABLE t MODIFY COLUMN c INT AFTER x",
+                ColumnPosition::After("x".into()),
+            ),
+        ];
+        for (sql, position) in parts {
+            let res = AlterTableStatement::parse(sql).unwrap().1;
+            let display = res.to_string();
+            assert!(display.ends_with(&format!(" {}", position)), "{}", display);
+            let round_trip = AlterTableStatement::parse(&display).unwrap().1;
+            match round_trip.alter_options.as_deref() {
+                Some([AlterTableOption::ModifyColumn { column_definition }]) => {
+                    assert_eq!(column_definition.position, Some(position))
+                }
+                other => panic!("unexpected options {:?}", other),
+            }
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn repartition_table() {
+        let sql = "ALTER TABLE employees PARTITION BY RANGE (YEAR(hired)) (\
+            PARTITION p0 VALUES LESS THAN (1991), \
+            PARTITION p1 VALUES LESS THAN (MAXVALUE))";
+        let res = AlterTableStatement::parse(sql).unwrap();
+        assert_eq!(res.0, "");
+        assert_eq!(res.1.alter_options, Some(vec![]));
+        match res.1.partition_options.as_deref() {
+            Some([AlterPartitionOption::PartitionBy(options)]) => {
+                assert!(matches!(options.partition_by, PartitionBy::Range(_)));
+                let names: Vec<&str> = options
+                    .definitions
+                    .iter()
+                    .map(|x| x.name.as_str())
+                    .collect();
+                assert_eq!(names, ["p0", "p1"]);
+            }
+            other => panic!("unexpected partition options {:?}", other),
+        }
+        assert_eq!(res.1.to_string(), sql);
+
+        let sql = "ALTER TABLE employees ADD PARTITION (PARTITION p2 VALUES LESS THAN (2002))";
+        let res = AlterTableStatement::parse(sql).unwrap().1;
+        match res.partition_options.as_deref() {
+            Some([AlterPartitionOption::AddPartition(definitions)]) => {
+                assert_eq!(definitions[0].name, "p2")
+            }
+            other => panic!("unexpected partition options {:?}", other),
+        }
+        assert_eq!(res.to_string(), sql);
+
+        let parts = [
+            "ALTER TABLE t ENGINE InnoDB PARTITION BY LINEAR HASH (id) PARTITIONS 4",
+            "ALTER TABLE t DROP PARTITION p0, p1",
+            "ALTER TABLE t TRUNCATE PARTITION ALL",
+            "ALTER TABLE t COALESCE PARTITION 2",
+            "ALTER TABLE t REORGANIZE PARTITION p0 INTO (\
+                PARTITION n0 VALUES LESS THAN (1980), PARTITION n1 VALUES LESS THAN (1991))",
+            "ALTER TABLE t EXCHANGE PARTITION p0 WITH TABLE t2 WITHOUT VALIDATION",
+            "ALTER TABLE t DISCARD PARTITION p0 TABLESPACE",
+            "ALTER TABLE t REMOVE PARTITIONING",
+        ];
+        for part in parts {
+            let res = AlterTableStatement::parse(part).unwrap();
+            assert_eq!(res.0, "");
+            assert!(res.1.partition_options.is_some(), "{}", part);
+            assert_eq!(
+                res.1.to_string(),
+                part.split_whitespace().collect::<Vec<_>>().join(" ")
+            );
+        }
+
+        // a column named like the keyword still works
+        let res = AlterTableStatement::parse("ALTER TABLE t DROP COLUMN `partition`").unwrap();
+        assert_eq!(res.1.partition_options, None);
+    }
+
+    #[test]
+    fn parenthesized_add_columns_reject_positions() {
+        let valid = [
+            "ALTER TABLE t ADD a INT FIRST",
+            "ALTER TABLE t ADD COLUMN a INT AFTER b",
+            "ALTER TABLE t ADD (a INT, b INT)",
+            "ALTER TABLE t ADD COLUMN (a INT, b INT)",
+        ];
+        for sql in valid {
+            assert!(AlterTableStatement::parse(sql).is_ok(), "{}", sql);
+        }
+
+        let invalid = [
+            "ALTER TABLE t ADD (a INT FIRST)",
+            "ALTER TABLE t ADD COLUMN (a INT, b INT AFTER a)",
+        ];
+        for sql in invalid {
+            assert!(AlterTableStatement::parse(sql).is_err(), "{}", sql);
+            let err = Parser::parse(&ParseConfig::default(), sql).unwrap_err();
+            assert!(
+                err.contains("FIRST and AFTER are not allowed"),
+                "unexpected error: {}",
+                err
+            );
+        }
+    }
+
     #[test]
     fn parse_add_index_or_key() {
         let parts = [
@@ -1607,6 +2181,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn add_fulltext_or_spatial_without_index_keyword() {
+        let key_part = vec![KeyPart {
+            r#type: KeyPartType::ColumnNameWithLength {
+                col_name: "col".to_string(),
+                length: None,
+            },
+            order: None,
+        }];
+        let option = |fulltext_or_spatial, opt_index_name: Option<&str>| {
+            AlterTableOption::AddFulltextOrSpatial {
+                fulltext_or_spatial,
+                opt_index_or_key: None,
+                opt_index_name: opt_index_name.map(String::from),
+                key_part: key_part.clone(),
+                opt_index_option: None,
+            }
+        };
+        let parts = [
+            (
+                "ADD FULLTEXT (col)",
+                option(FulltextOrSpatialType::Fulltext, None),
+            ),
+            (
+                "ADD FULLTEXT(col)",
+                option(FulltextOrSpatialType::Fulltext, None),
+            ),
+            (
+                "ADD SPATIAL (col)",
+                option(FulltextOrSpatialType::Spatial, None),
+            ),
+            (
+                "ADD FULLTEXT key_idx (col)",
+                option(FulltextOrSpatialType::Fulltext, Some("key_idx")),
+            ),
+        ];
+        for (part, exp) in parts {
+            let res = AlterTableOption::add_fulltext_or_spatial(part);
+            assert_eq!(res.unwrap().1, exp, "{}", part);
+        }
+
+        let res = AlterTableStatement::parse("ALTER TABLE t ADD FULLTEXT (col)");
+        assert!(res.is_ok());
+    }
+
     #[test]
     fn parse_add_unique() {
         let parts = ["ADD CONSTRAINT UNIQUE (col_19)"];
@@ -1702,4 +2321,272 @@ mod tests {
             assert_eq!(res.unwrap().1, exps[i]);
         }
     }
+
+    #[test]
+    fn parse_drop_targets() {
+        let parts = [
+            "DROP COLUMN col_1",
+            "DROP col_1",
+            "DROP `index`",
+            "DROP INDEX idx_1",
+            "DROP KEY idx_1",
+            "DROP PRIMARY KEY",
+            "DROP FOREIGN KEY fk_1",
+            "DROP CHECK chk_1",
+            "DROP CONSTRAINT chk_1",
+        ];
+        let drop_column = AlterTableOption::DropColumn {
+            col_name: "col_1".to_string(),
+            if_restrict: false,
+            if_cascade: false,
+        };
+        let exps = [
+            drop_column.clone(),
+            drop_column,
+            AlterTableOption::DropColumn {
+                col_name: "index".to_string(),
+                if_restrict: false,
+                if_cascade: false,
+            },
+            AlterTableOption::DropIndexOrKey {
+                index_or_key: IndexOrKeyType::Index,
+                index_name: "idx_1".to_string(),
+            },
+            AlterTableOption::DropIndexOrKey {
+                index_or_key: IndexOrKeyType::Key,
+                index_name: "idx_1".to_string(),
+            },
+            AlterTableOption::DropPrimaryKey,
+            AlterTableOption::DropForeignKey {
+                fk_symbol: "fk_1".to_string(),
+            },
+            AlterTableOption::DropCheckOrConstraint {
+                check_or_constraint: CheckOrConstraintType::Check,
+                symbol: "chk_1".to_string(),
+            },
+            AlterTableOption::DropCheckOrConstraint {
+                check_or_constraint: CheckOrConstraintType::Constraint,
+                symbol: "chk_1".to_string(),
+            },
+        ];
+        for i in 0..parts.len() {
+            let res = AlterTableOption::parse(parts[i]);
+            assert!(res.is_ok(), "{}", parts[i]);
+            let (remaining, option) = res.unwrap();
+            assert_eq!(remaining, "", "{}", parts[i]);
+            assert_eq!(option, exps[i]);
+        }
+    }
+
+    #[test]
+    fn parse_drop_column_restrict_or_cascade() {
+        let parts = [
+            "ALTER TABLE t DROP COLUMN c CASCADE",
+            "ALTER TABLE t DROP COLUMN c RESTRICT",
+        ];
+        for part in parts {
+            assert!(AlterTableStatement::parse(part).is_err(), "{}", part);
+        }
+
+        let config = ParseConfig::builder().mariadb(true).build();
+        let exps = [(false, true), (true, false)];
+        for (part, (if_restrict, if_cascade)) in parts.iter().zip(exps) {
+            let res = config.scope(|| AlterTableStatement::parse(part));
+            let statement = res.unwrap().1;
+            assert_eq!(
+                statement.alter_options,
+                Some(vec![AlterTableOption::DropColumn {
+                    col_name: "c".to_string(),
+                    if_restrict,
+                    if_cascade,
+                }])
+            );
+            assert_eq!(statement.to_string(), *part);
+        }
+    }
+
+    #[test]
+    fn quoted_constraint_symbol_with_space() {
+        let parts = [
+            "ALTER TABLE t ADD CONSTRAINT `fk 1` FOREIGN KEY (a) REFERENCES p (id)",
+            "ALTER TABLE t DROP FOREIGN KEY `fk 1`",
+        ];
+        for part in parts {
+            let res = AlterTableStatement::parse(part).unwrap().1;
+            let symbol = match res.alter_options.as_ref().unwrap()[0] {
+                AlterTableOption::AddForeignKey { ref opt_symbol, .. } => opt_symbol.clone(),
+                AlterTableOption::DropForeignKey { ref fk_symbol } => Some(fk_symbol.clone()),
+                _ => None,
+            };
+            assert_eq!(symbol, Some("fk 1".to_string()));
+
+            let display = res.to_string();
+            assert!(display.contains("`fk 1`"), "{}", display);
+            let round_trip = AlterTableStatement::parse(&display).unwrap().1;
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn rename_key_and_set_tablespace() {
+        let sql = "ALTER TABLE t RENAME KEY k1 TO k2";
+        let res = AlterTableStatement::parse(sql).unwrap().1;
+        assert_eq!(
+            res.alter_options,
+            Some(vec![AlterTableOption::RenameIndexOrKey {
+                index_or_key: IndexOrKeyType::Key,
+                old_index_name: "k1".to_string(),
+                new_index_name: "k2".to_string(),
+            }])
+        );
+        let round_trip = AlterTableStatement::parse(&res.to_string()).unwrap().1;
+        assert_eq!(round_trip, res);
+
+        let sql = "ALTER TABLE t RENAME INDEX k1 TO k2, TABLESPACE ts_1 STORAGE DISK";
+        let res = AlterTableStatement::parse(sql).unwrap().1;
+        assert_eq!(
+            res.alter_options,
+            Some(vec![
+                AlterTableOption::RenameIndexOrKey {
+                    index_or_key: IndexOrKeyType::Index,
+                    old_index_name: "k1".to_string(),
+                    new_index_name: "k2".to_string(),
+                },
+                AlterTableOption::TableOptions {
+                    table_options: vec![TableOption::Tablespace(
+                        "ts_1".to_string(),
+                        Some(TablespaceType::StorageDisk),
+                    )],
+                },
+            ])
+        );
+        let round_trip = AlterTableStatement::parse(&res.to_string()).unwrap().1;
+        assert_eq!(round_trip, res);
+    }
+
+    #[test]
+    fn change_and_modify_column_keep_attributes() {
+        let parts = [
+            "ALTER TABLE t MODIFY COLUMN c VARCHAR(10) NOT NULL DEFAULT 'x' COMMENT 'y' AFTER z",
+            "ALTER TABLE t CHANGE COLUMN `select` c INT UNSIGNED NULL DEFAULT 3 COMMENT 'y' FIRST",
+        ];
+        let column_definition = |data_type, constraints, position| ColumnSpecification {
+            column: "c".into(),
+            data_type,
+            constraints,
+            comment: Some("y".to_string()),
+            position: Some(position),
+        };
+        let exps = [
+            AlterTableOption::ModifyColumn {
+                column_definition: column_definition(
+                    DataType::Varchar(10),
+                    vec![
+                        ColumnConstraint::NotNull,
+                        ColumnConstraint::DefaultValue(Literal::String("x".to_string())),
+                    ],
+                    ColumnPosition::After("z".into()),
+                ),
+            },
+            AlterTableOption::ChangeColumn {
+                old_col_name: "select".to_string(),
+                column_definition: column_definition(
+                    DataType::UnsignedInt(32),
+                    vec![
+                        ColumnConstraint::Null,
+                        ColumnConstraint::DefaultValue(Literal::Integer(3)),
+                    ],
+                    ColumnPosition::First,
+                ),
+            },
+        ];
+        for i in 0..parts.len() {
+            let res = AlterTableStatement::parse(parts[i]).unwrap().1;
+            assert_eq!(res.alter_options, Some(vec![exps[i].clone()]));
+
+            let round_trip = AlterTableStatement::parse(&res.to_string()).unwrap().1;
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn alter_column_visibility() {
+        let parts = [
+            (
+                "ALTER TABLE t ALTER COLUMN c SET INVISIBLE",
+                VisibleType::Invisible,
+            ),
+            ("ALTER TABLE t ALTER c SET VISIBLE", VisibleType::Visible),
+        ];
+        for (sql, visible) in parts {
+            let res = AlterTableStatement::parse(sql).unwrap().1;
+            let exp = AlterTableOption::AlterColumn {
+                col_name: "c".to_string(),
+                alter_column_operation: AlertColumnOperation::SetVisible(visible.clone()),
+            };
+            assert_eq!(res.alter_options, Some(vec![exp]));
+            let display = res.to_string();
+            assert!(display.ends_with(&format!("ALTER COLUMN c SET {}", visible)));
+
+            let round_trip = AlterTableStatement::parse(&display).unwrap().1;
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn alter_check_or_constraint_enforced_round_trip() {
+        let parts = [
+            ("ALTER TABLE t ALTER CONSTRAINT chk ENFORCED", true),
+            ("ALTER TABLE t ALTER CONSTRAINT chk NOT ENFORCED", false),
+            ("ALTER TABLE t ALTER CHECK chk NOT ENFORCED", false),
+        ];
+        for (sql, enforced) in parts {
+            let res = AlterTableStatement::parse(sql).unwrap().1;
+            match res.alter_options.as_deref() {
+                Some(
+                    [AlterTableOption::AlterCheckOrConstraintEnforced {
+                        symbol,
+                        enforced: res_enforced,
+                        ..
+                    }],
+                ) => {
+                    assert_eq!(symbol, "chk");
+                    assert_eq!(*res_enforced, enforced);
+                }
+                other => panic!("unexpected options {:?}", other),
+            }
+            assert_eq!(res.to_string(), sql);
+            let round_trip = AlterTableStatement::parse(&res.to_string()).unwrap().1;
+            assert_eq!(round_trip, res);
+        }
+    }
+
+    #[test]
+    fn algorithm_and_lock_without_comma() {
+        let sql = "ALTER TABLE t ADD COLUMN c INT, ALGORITHM=INPLACE LOCK=NONE";
+        let res = AlterTableStatement::parse(sql).unwrap();
+        assert_eq!(res.0, "");
+        let options = res.1.alter_options.clone().unwrap();
+        assert_eq!(options.len(), 3);
+        assert_eq!(
+            options[1],
+            AlterTableOption::Algorithm {
+                algorithm: AlgorithmType::Inplace
+            }
+        );
+        assert_eq!(
+            options[2],
+            AlterTableOption::Lock {
+                lock_type: LockType::None
+            }
+        );
+        let display = res.1.to_string();
+        assert!(display.ends_with("ALGORITHM INPLACE, LOCK NONE"));
+        let round_trip = AlterTableStatement::parse(&display).unwrap().1;
+        assert_eq!(round_trip, res.1);
+
+        let res = AlterTableStatement::parse("ALTER TABLE t FORCE ALGORITHM=INPLACE").unwrap();
+        assert_eq!(res.0, "");
+        assert_eq!(res.1.alter_options.unwrap().len(), 2);
+    }
 }
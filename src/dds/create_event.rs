@@ -0,0 +1,468 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt, value};
+use nom::multi::many0;
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil, Literal};
+
+/// parse `CREATE [DEFINER = user] EVENT [IF NOT EXISTS] event_name
+///     ON SCHEDULE schedule
+///     [ON COMPLETION [NOT] PRESERVE]
+///     [ENABLE | DISABLE | DISABLE ON {REPLICA | SLAVE}]
+///     [COMMENT 'string']
+///     DO event_body`
+///
+/// The event body is kept as raw text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CreateEventStatement {
+    pub definer: Option<String>,
+    pub if_not_exists: bool,
+    pub event_name: String,
+    pub schedule: EventSchedule,
+    /// `Some(true)` for `ON COMPLETION PRESERVE`, `Some(false)` for `ON COMPLETION NOT PRESERVE`
+    pub on_completion_preserve: Option<bool>,
+    pub status: Option<EventStatus>,
+    pub comment: Option<String>,
+    pub body: String,
+}
+
+impl CreateEventStatement {
+    pub fn parse(i: &str) -> IResult<&str, CreateEventStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("CREATE"), multispace1),
+                opt(terminated(CommonParser::definer, multispace1)),
+                terminated(tag_no_case("EVENT"), multispace1),
+                opt(tuple((
+                    tag_no_case("IF"),
+                    multispace1,
+                    tag_no_case("NOT"),
+                    multispace1,
+                    tag_no_case("EXISTS"),
+                    multispace1,
+                ))),
+                map(CommonParser::sql_identifier, String::from),
+                tuple((
+                    multispace1,
+                    tag_no_case("ON"),
+                    multispace1,
+                    tag_no_case("SCHEDULE"),
+                    multispace1,
+                )),
+                EventSchedule::parse,
+                opt(Self::on_completion),
+                opt(preceded(multispace1, EventStatus::parse)),
+                opt(CommonParser::parse_comment),
+                Self::event_body,
+                CommonParser::statement_terminator,
+            )),
+            |(
+                _,
+                definer,
+                _,
+                if_not_exists,
+                event_name,
+                _,
+                schedule,
+                on_completion_preserve,
+                status,
+                comment,
+                body,
+                _,
+            )| CreateEventStatement {
+                definer,
+                if_not_exists: if_not_exists.is_some(),
+                event_name,
+                schedule,
+                on_completion_preserve,
+                status,
+                comment,
+                body,
+            },
+        )(i)
+    }
+
+    /// ` ON COMPLETION [NOT] PRESERVE`, `true` unless `NOT` is given
+    pub(crate) fn on_completion(i: &str) -> IResult<&str, bool, ParseSQLError<&str>> {
+        preceded(
+            tuple((
+                multispace1,
+                tag_no_case("ON"),
+                multispace1,
+                tag_no_case("COMPLETION"),
+                multispace1,
+            )),
+            map(
+                pair(
+                    opt(terminated(tag_no_case("NOT"), multispace1)),
+                    tag_no_case("PRESERVE"),
+                ),
+                |(not, _)| not.is_none(),
+            ),
+        )(i)
+    }
+
+    /// ` DO event_body`, where the body is a single statement or a `BEGIN ... END` block
+    pub(crate) fn event_body(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+        preceded(
+            tuple((multispace1, tag_no_case("DO"), multispace1)),
+            alt((
+                map(CommonParser::begin_end_block, String::from),
+                CommonParser::statement_body,
+            )),
+        )(i)
+    }
+}
+
+impl fmt::Display for CreateEventStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "CREATE")?;
+        if let Some(ref definer) = self.definer {
+            write_sql!(f, " DEFINER = {}", definer)?;
+        }
+        write_sql!(f, " EVENT")?;
+        if self.if_not_exists {
+            write_sql!(f, " IF NOT EXISTS")?;
+        }
+        write_sql!(
+            f,
+            " {} ON SCHEDULE {}",
+            DisplayUtil::escape_if_keyword(&self.event_name),
+            self.schedule
+        )?;
+        match self.on_completion_preserve {
+            Some(true) => write_sql!(f, " ON COMPLETION PRESERVE")?,
+            Some(false) => write_sql!(f, " ON COMPLETION NOT PRESERVE")?,
+            None => {}
+        }
+        if let Some(ref status) = self.status {
+            write_sql!(f, " {}", status)?;
+        }
+        if let Some(ref comment) = self.comment {
+            write_sql!(f, " COMMENT '{}'", comment)?;
+        }
+        write_sql!(f, " DO {}", self.body)
+    }
+}
+
+/// schedule: {
+///     AT timestamp [+ INTERVAL interval] ...
+///   | EVERY interval
+///     [STARTS timestamp [+ INTERVAL interval] ...]
+///     [ENDS timestamp [+ INTERVAL interval] ...]
+/// }
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum EventSchedule {
+    At(EventTimestamp),
+    Every {
+        interval: Interval,
+        starts: Option<EventTimestamp>,
+        ends: Option<EventTimestamp>,
+    },
+}
+
+impl EventSchedule {
+    pub(crate) fn parse(i: &str) -> IResult<&str, EventSchedule, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(pair(tag_no_case("AT"), multispace1), EventTimestamp::parse),
+                EventSchedule::At,
+            ),
+            map(
+                tuple((
+                    preceded(pair(tag_no_case("EVERY"), multispace1), Interval::parse),
+                    opt(preceded(
+                        tuple((multispace1, tag_no_case("STARTS"), multispace1)),
+                        EventTimestamp::parse,
+                    )),
+                    opt(preceded(
+                        tuple((multispace1, tag_no_case("ENDS"), multispace1)),
+                        EventTimestamp::parse,
+                    )),
+                )),
+                |(interval, starts, ends)| EventSchedule::Every {
+                    interval,
+                    starts,
+                    ends,
+                },
+            ),
+        ))(i)
+    }
+}
+
+impl fmt::Display for EventSchedule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            EventSchedule::At(ref timestamp) => write_sql!(f, "AT {}", timestamp),
+            EventSchedule::Every {
+                ref interval,
+                ref starts,
+                ref ends,
+            } => {
+                write_sql!(f, "EVERY {}", interval)?;
+                if let Some(ref starts) = starts {
+                    write_sql!(f, " STARTS {}", starts)?;
+                }
+                if let Some(ref ends) = ends {
+                    write_sql!(f, " ENDS {}", ends)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+/// `timestamp [+ INTERVAL interval] ...`, where timestamp is a literal such as
+/// `CURRENT_TIMESTAMP` or `'2024-01-01 00:00:00'`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct EventTimestamp {
+    pub timestamp: Literal,
+    pub intervals: Vec<Interval>,
+}
+
+impl EventTimestamp {
+    fn parse(i: &str) -> IResult<&str, EventTimestamp, ParseSQLError<&str>> {
+        map(
+            pair(
+                Literal::parse,
+                many0(preceded(
+                    tuple((
+                        multispace0,
+                        tag("+"),
+                        multispace0,
+                        tag_no_case("INTERVAL"),
+                        multispace1,
+                    )),
+                    Interval::parse,
+                )),
+            ),
+            |(timestamp, intervals)| EventTimestamp {
+                timestamp,
+                intervals,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for EventTimestamp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "{}", self.timestamp)?;
+        for interval in &self.intervals {
+            write_sql!(f, " + INTERVAL {}", interval)?;
+        }
+        Ok(())
+    }
+}
+
+/// `quantity unit`, e.g. `1 DAY` or `'1:30' HOUR_MINUTE`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct Interval {
+    pub quantity: Literal,
+    pub unit: IntervalUnit,
+}
+
+impl Interval {
+    fn parse(i: &str) -> IResult<&str, Interval, ParseSQLError<&str>> {
+        map(
+            tuple((Literal::parse, multispace1, IntervalUnit::parse)),
+            |(quantity, _, unit)| Interval { quantity, unit },
+        )(i)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "{} {}", self.quantity, self.unit)
+    }
+}
+
+/// unit of an [Interval]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum IntervalUnit {
+    Year,
+    Quarter,
+    Month,
+    Day,
+    Hour,
+    Minute,
+    Week,
+    Second,
+    YearMonth,
+    DayHour,
+    DayMinute,
+    DaySecond,
+    HourMinute,
+    HourSecond,
+    MinuteSecond,
+}
+
+impl IntervalUnit {
+    fn parse(i: &str) -> IResult<&str, IntervalUnit, ParseSQLError<&str>> {
+        // compound units first, they share a prefix with the simple ones
+        alt((
+            value(IntervalUnit::YearMonth, tag_no_case("YEAR_MONTH")),
+            value(IntervalUnit::DayHour, tag_no_case("DAY_HOUR")),
+            value(IntervalUnit::DayMinute, tag_no_case("DAY_MINUTE")),
+            value(IntervalUnit::DaySecond, tag_no_case("DAY_SECOND")),
+            value(IntervalUnit::HourMinute, tag_no_case("HOUR_MINUTE")),
+            value(IntervalUnit::HourSecond, tag_no_case("HOUR_SECOND")),
+            value(IntervalUnit::MinuteSecond, tag_no_case("MINUTE_SECOND")),
+            value(IntervalUnit::Year, tag_no_case("YEAR")),
+            value(IntervalUnit::Quarter, tag_no_case("QUARTER")),
+            value(IntervalUnit::Month, tag_no_case("MONTH")),
+            value(IntervalUnit::Day, tag_no_case("DAY")),
+            value(IntervalUnit::Hour, tag_no_case("HOUR")),
+            value(IntervalUnit::Minute, tag_no_case("MINUTE")),
+            value(IntervalUnit::Week, tag_no_case("WEEK")),
+            value(IntervalUnit::Second, tag_no_case("SECOND")),
+        ))(i)
+    }
+}
+
+impl fmt::Display for IntervalUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let unit = match *self {
+            IntervalUnit::Year => "YEAR",
+            IntervalUnit::Quarter => "QUARTER",
+            IntervalUnit::Month => "MONTH",
+            IntervalUnit::Day => "DAY",
+            IntervalUnit::Hour => "HOUR",
+            IntervalUnit::Minute => "MINUTE",
+            IntervalUnit::Week => "WEEK",
+            IntervalUnit::Second => "SECOND",
+            IntervalUnit::YearMonth => "YEAR_MONTH",
+            IntervalUnit::DayHour => "DAY_HOUR",
+            IntervalUnit::DayMinute => "DAY_MINUTE",
+            IntervalUnit::DaySecond => "DAY_SECOND",
+            IntervalUnit::HourMinute => "HOUR_MINUTE",
+            IntervalUnit::HourSecond => "HOUR_SECOND",
+            IntervalUnit::MinuteSecond => "MINUTE_SECOND",
+        };
+        DisplayUtil::write_keyword(f, unit)
+    }
+}
+
+/// `ENABLE | DISABLE | DISABLE ON {REPLICA | SLAVE}`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum EventStatus {
+    Enable,
+    Disable,
+    DisableOnReplica,
+    DisableOnSlave,
+}
+
+impl EventStatus {
+    pub(crate) fn parse(i: &str) -> IResult<&str, EventStatus, ParseSQLError<&str>> {
+        alt((
+            value(EventStatus::Enable, tag_no_case("ENABLE")),
+            preceded(
+                tag_no_case("DISABLE"),
+                map(
+                    opt(preceded(
+                        tuple((multispace1, tag_no_case("ON"), multispace1)),
+                        alt((
+                            value(EventStatus::DisableOnReplica, tag_no_case("REPLICA")),
+                            value(EventStatus::DisableOnSlave, tag_no_case("SLAVE")),
+                        )),
+                    )),
+                    |status| status.unwrap_or(EventStatus::Disable),
+                ),
+            ),
+        ))(i)
+    }
+}
+
+impl fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            EventStatus::Enable => write_sql!(f, "ENABLE"),
+            EventStatus::Disable => write_sql!(f, "DISABLE"),
+            EventStatus::DisableOnReplica => write_sql!(f, "DISABLE ON REPLICA"),
+            EventStatus::DisableOnSlave => write_sql!(f, "DISABLE ON SLAVE"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::Literal;
+    use dds::create_event::{
+        CreateEventStatement, EventSchedule, EventStatus, EventTimestamp, Interval, IntervalUnit,
+    };
+
+    #[test]
+    fn parse_recurring_event() {
+        let sql = "CREATE EVENT IF NOT EXISTS purge_sessions \
+            ON SCHEDULE EVERY 1 DAY STARTS CURRENT_TIMESTAMP + INTERVAL 1 HOUR \
+            ENDS '2030-01-01 00:00:00' \
+            ON COMPLETION PRESERVE ENABLE COMMENT 'nightly purge' \
+            DO DELETE FROM sessions WHERE expires_at < NOW();";
+        let res = CreateEventStatement::parse(sql);
+        let exp = CreateEventStatement {
+            definer: None,
+            if_not_exists: true,
+            event_name: "purge_sessions".to_string(),
+            schedule: EventSchedule::Every {
+                interval: Interval {
+                    quantity: Literal::Integer(1),
+                    unit: IntervalUnit::Day,
+                },
+                starts: Some(EventTimestamp {
+                    timestamp: Literal::CurrentTimestamp(None),
+                    intervals: vec![Interval {
+                        quantity: Literal::Integer(1),
+                        unit: IntervalUnit::Hour,
+                    }],
+                }),
+                ends: Some(EventTimestamp {
+                    timestamp: Literal::String("2030-01-01 00:00:00".to_string()),
+                    intervals: vec![],
+                }),
+            },
+            on_completion_preserve: Some(true),
+            status: Some(EventStatus::Enable),
+            comment: Some("nightly purge".to_string()),
+            body: "DELETE FROM sessions WHERE expires_at < NOW()".to_string(),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(
+            exp.to_string(),
+            "CREATE EVENT IF NOT EXISTS purge_sessions \
+            ON SCHEDULE EVERY 1 DAY STARTS CURRENT_TIMESTAMP + INTERVAL 1 HOUR \
+            ENDS '2030-01-01 00:00:00' \
+            ON COMPLETION PRESERVE ENABLE COMMENT 'nightly purge' \
+            DO DELETE FROM sessions WHERE expires_at < NOW()"
+        );
+    }
+
+    #[test]
+    fn parse_one_time_event() {
+        let sql = "CREATE DEFINER = `root`@`localhost` EVENT e ON SCHEDULE \
+            AT CURRENT_TIMESTAMP + INTERVAL '1:30' HOUR_MINUTE DISABLE ON REPLICA \
+            DO BEGIN UPDATE t SET a = 1; UPDATE u SET b = 2; END";
+        let res = CreateEventStatement::parse(sql);
+        let stmt = res.unwrap().1;
+        assert_eq!(stmt.status, Some(EventStatus::DisableOnReplica));
+        assert_eq!(stmt.to_string(), sql);
+    }
+
+    #[test]
+    fn parse_event_named_by_keyword() {
+        let sql = "CREATE EVENT `select` ON SCHEDULE EVERY 1 DAY DO DELETE FROM t; SELECT 1";
+        let (rest, stmt) = CreateEventStatement::parse(sql).unwrap();
+        assert_eq!(rest, "SELECT 1");
+        assert_eq!(stmt.event_name, "select");
+        assert_eq!(stmt.body, "DELETE FROM t");
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE EVENT `select` ON SCHEDULE EVERY 1 DAY DO DELETE FROM t"
+        );
+    }
+}
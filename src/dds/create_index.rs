@@ -13,6 +13,7 @@ use base::index_type::IndexType;
 use base::lock_type::LockType;
 use base::table::Table;
 use base::{CommonParser, KeyPart};
+use parser::ParseConfig;
 
 /// parse `CREATE [UNIQUE | FULLTEXT | SPATIAL] INDEX index_name
 ///     [index_type]
@@ -43,6 +44,8 @@ use base::{CommonParser, KeyPart};
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct CreateIndexStatement {
     pub opt_index: Option<Index>,
+    /// MariaDB `IF NOT EXISTS`, see [crate::ParseConfig::mariadb]
+    pub if_not_exists: bool,
     pub index_name: String,
     pub index_type: Option<IndexType>,
     pub table: Table,
@@ -54,24 +57,28 @@ pub struct CreateIndexStatement {
 
 impl Display for CreateIndexStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "CREATE");
+        write_sql!(f, "CREATE");
         if let Some(opt_index) = &self.opt_index {
-            write!(f, " {}", opt_index);
+            write_sql!(f, " {}", opt_index);
         }
-        write!(f, " INDEX {}", self.index_name);
+        write_sql!(f, " INDEX");
+        if self.if_not_exists {
+            write_sql!(f, " IF NOT EXISTS");
+        }
+        write_sql!(f, " {}", self.index_name);
         if let Some(index_type) = &self.index_type {
-            write!(f, " {}", index_type);
+            write_sql!(f, " {}", index_type);
         }
-        write!(f, " ON {}", self.table);
-        write!(f, " {}", KeyPart::format_list(&self.key_part));
+        write_sql!(f, " ON {}", self.table);
+        write_sql!(f, " {}", KeyPart::format_list(&self.key_part));
         if let Some(index_option) = &self.index_option {
-            write!(f, " {}", IndexOption::format_list(index_option));
+            write_sql!(f, " {}", IndexOption::format_list(index_option));
         }
         if let Some(algorithm_option) = &self.algorithm_option {
-            write!(f, " {}", algorithm_option);
+            write_sql!(f, " {}", algorithm_option);
         }
         if let Some(lock_option) = &self.lock_option {
-            write!(f, " {}", lock_option);
+            write_sql!(f, " {}", lock_option);
         }
         Ok(())
     }
@@ -84,6 +91,7 @@ impl CreateIndexStatement {
                 tuple((tag_no_case("CREATE"), multispace1)),
                 opt(terminated(Index::parse, multispace1)),
                 tuple((tag_no_case("INDEX"), multispace1)),
+                Self::if_not_exists,
                 map(tuple((CommonParser::sql_identifier, multispace1)), |x| {
                     String::from(x.0)
                 }),
@@ -101,6 +109,7 @@ impl CreateIndexStatement {
                 _,
                 opt_index,
                 _,
+                if_not_exists,
                 index_name,
                 index_type,
                 _,
@@ -113,6 +122,7 @@ impl CreateIndexStatement {
                 _,
             )| CreateIndexStatement {
                 opt_index,
+                if_not_exists,
                 index_name,
                 index_type,
                 table,
@@ -123,6 +133,24 @@ impl CreateIndexStatement {
             },
         )(i)
     }
+
+    /// MariaDB `[IF NOT EXISTS]`, never present when parsing MySQL
+    fn if_not_exists(i: &str) -> IResult<&str, bool, ParseSQLError<&str>> {
+        if !ParseConfig::with_current(|config| config.mariadb) {
+            return Ok((i, false));
+        }
+        map(
+            opt(tuple((
+                tag_no_case("IF"),
+                multispace1,
+                tag_no_case("NOT"),
+                multispace1,
+                tag_no_case("EXISTS"),
+                multispace1,
+            ))),
+            |x| x.is_some(),
+        )(i)
+    }
 }
 
 /// `[UNIQUE | FULLTEXT | SPATIAL]`
@@ -136,9 +164,9 @@ pub enum Index {
 impl Display for Index {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            Index::Unique => write!(f, "UNIQUE"),
-            Index::Fulltext => write!(f, "FULLTEXT"),
-            Index::Spatial => write!(f, "SPATIAL"),
+            Index::Unique => write_sql!(f, "UNIQUE"),
+            Index::Fulltext => write_sql!(f, "FULLTEXT"),
+            Index::Spatial => write_sql!(f, "SPATIAL"),
         }
     }
 }
@@ -167,6 +195,7 @@ mod tests {
         let exp_statements = [
             CreateIndexStatement {
                 opt_index: None,
+                if_not_exists: false,
                 index_name: "idx_1".to_string(),
                 index_type: None,
                 table: "tbl_foo".into(),
@@ -183,6 +212,7 @@ mod tests {
             },
             CreateIndexStatement {
                 opt_index: None,
+                if_not_exists: false,
                 index_name: "idx_2".to_string(),
                 index_type: None,
                 table: "tbl_bar".into(),
@@ -0,0 +1,91 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::multispace1;
+use nom::combinator::map;
+use nom::multi::many0;
+use nom::sequence::{preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::CommonParser;
+use dds::create_tablespace::TablespaceOption;
+
+/// parse `CREATE LOGFILE GROUP logfile_group
+///     ADD UNDOFILE 'undo_file'
+///     [INITIAL_SIZE [=] initial_size]
+///     [UNDO_BUFFER_SIZE [=] undo_buffer_size]
+///     [REDO_BUFFER_SIZE [=] redo_buffer_size]
+///     [NODEGROUP [=] nodegroup_id]
+///     [WAIT]
+///     [COMMENT [=] 'string']
+///     ENGINE [=] engine_name`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CreateLogfileGroupStatement {
+    pub logfile_group: String,
+    pub undo_file: String,
+    pub options: Vec<TablespaceOption>,
+}
+
+impl CreateLogfileGroupStatement {
+    pub fn parse(i: &str) -> IResult<&str, CreateLogfileGroupStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("CREATE"), multispace1),
+                terminated(tag_no_case("LOGFILE"), multispace1),
+                terminated(tag_no_case("GROUP"), multispace1),
+                map(CommonParser::sql_identifier, String::from),
+                multispace1,
+                tuple((tag_no_case("ADD"), multispace1, tag_no_case("UNDOFILE"))),
+                preceded(multispace1, CommonParser::parse_quoted_string),
+                many0(preceded(multispace1, TablespaceOption::parse)),
+                CommonParser::statement_terminator,
+            )),
+            |(_, _, _, logfile_group, _, _, undo_file, options, _)| CreateLogfileGroupStatement {
+                logfile_group,
+                undo_file,
+                options,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for CreateLogfileGroupStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(
+            f,
+            "CREATE LOGFILE GROUP {} ADD UNDOFILE '{}'",
+            self.logfile_group,
+            self.undo_file
+        )?;
+        for option in &self.options {
+            write_sql!(f, " {}", option)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dds::create_logfile_group::CreateLogfileGroupStatement;
+    use dds::create_tablespace::TablespaceOption;
+
+    #[test]
+    fn parse_create_logfile_group() {
+        let sql = "CREATE LOGFILE GROUP lg_1 ADD UNDOFILE 'undo_1.log' INITIAL_SIZE = 16M \
+            UNDO_BUFFER_SIZE = 2M ENGINE = NDB";
+        let res = CreateLogfileGroupStatement::parse(sql);
+        let exp = CreateLogfileGroupStatement {
+            logfile_group: "lg_1".to_string(),
+            undo_file: "undo_1.log".to_string(),
+            options: vec![
+                TablespaceOption::InitialSize("16M".to_string()),
+                TablespaceOption::UndoBufferSize("2M".to_string()),
+                TablespaceOption::Engine("NDB".to_string()),
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), sql);
+    }
+}
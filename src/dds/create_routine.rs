@@ -0,0 +1,433 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt, value};
+use nom::multi::{many0, separated_list0};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DataType, DisplayUtil};
+
+/// parse `CREATE [DEFINER = user] {PROCEDURE | FUNCTION} [IF NOT EXISTS] [db_name.]sp_name
+///     ([param[, ...]]) [RETURNS type] [characteristic ...] routine_body`
+///
+/// The routine body is kept as raw text.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CreateRoutineStatement {
+    pub definer: Option<String>,
+    pub routine_type: RoutineType,
+    pub if_not_exists: bool,
+    pub db_name: Option<String>,
+    pub sp_name: String,
+    pub params: Vec<RoutineParam>,
+    pub returns: Option<DataType>,
+    pub characteristics: Vec<RoutineCharacteristic>,
+    pub body: String,
+}
+
+impl CreateRoutineStatement {
+    pub fn parse(i: &str) -> IResult<&str, CreateRoutineStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("CREATE"), multispace1),
+                opt(terminated(CommonParser::definer, multispace1)),
+                terminated(RoutineType::parse, multispace1),
+                opt(tuple((
+                    tag_no_case("IF"),
+                    multispace1,
+                    tag_no_case("NOT"),
+                    multispace1,
+                    tag_no_case("EXISTS"),
+                    multispace1,
+                ))),
+                opt(map(
+                    terminated(CommonParser::sql_identifier, tag(".")),
+                    String::from,
+                )),
+                map(CommonParser::sql_identifier, String::from),
+                multispace0,
+                delimited(
+                    pair(tag("("), multispace0),
+                    separated_list0(CommonParser::ws_sep_comma, RoutineParam::parse),
+                    pair(multispace0, tag(")")),
+                ),
+                opt(preceded(
+                    tuple((multispace0, tag_no_case("RETURNS"), multispace1)),
+                    DataType::type_identifier,
+                )),
+                many0(preceded(multispace0, RoutineCharacteristic::parse)),
+                multispace0,
+                alt((
+                    map(CommonParser::begin_end_block, String::from),
+                    CommonParser::statement_body,
+                )),
+                CommonParser::statement_terminator,
+            )),
+            |(
+                _,
+                definer,
+                routine_type,
+                if_not_exists,
+                db_name,
+                sp_name,
+                _,
+                params,
+                returns,
+                characteristics,
+                _,
+                body,
+                _,
+            )| {
+                CreateRoutineStatement {
+                    definer,
+                    routine_type,
+                    if_not_exists: if_not_exists.is_some(),
+                    db_name,
+                    sp_name,
+                    params,
+                    returns,
+                    characteristics,
+                    body,
+                }
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for CreateRoutineStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "CREATE")?;
+        if let Some(ref definer) = self.definer {
+            write_sql!(f, " DEFINER = {}", definer)?;
+        }
+        write_sql!(f, " {}", self.routine_type)?;
+        if self.if_not_exists {
+            write_sql!(f, " IF NOT EXISTS")?;
+        }
+        write_sql!(f, " ")?;
+        if let Some(ref db_name) = self.db_name {
+            write_sql!(f, "{}.", DisplayUtil::escape_if_keyword(db_name))?;
+        }
+        write_sql!(
+            f,
+            "{}({})",
+            DisplayUtil::escape_if_keyword(&self.sp_name),
+            self.params
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )?;
+        if let Some(ref returns) = self.returns {
+            write_sql!(f, " RETURNS {}", returns)?;
+        }
+        for characteristic in &self.characteristics {
+            write_sql!(f, " {}", characteristic)?;
+        }
+        write_sql!(f, " {}", self.body)
+    }
+}
+
+/// `{PROCEDURE | FUNCTION}`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum RoutineType {
+    Procedure,
+    Function,
+}
+
+impl RoutineType {
+    fn parse(i: &str) -> IResult<&str, RoutineType, ParseSQLError<&str>> {
+        alt((
+            map(tag_no_case("PROCEDURE"), |_| RoutineType::Procedure),
+            map(tag_no_case("FUNCTION"), |_| RoutineType::Function),
+        ))(i)
+    }
+}
+
+impl fmt::Display for RoutineType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            RoutineType::Procedure => write_sql!(f, "PROCEDURE"),
+            RoutineType::Function => write_sql!(f, "FUNCTION"),
+        }
+    }
+}
+
+/// `[IN | OUT | INOUT] param_name type`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct RoutineParam {
+    pub mode: Option<ParamMode>,
+    pub name: String,
+    pub data_type: DataType,
+}
+
+impl RoutineParam {
+    fn parse(i: &str) -> IResult<&str, RoutineParam, ParseSQLError<&str>> {
+        map(
+            tuple((
+                opt(terminated(ParamMode::parse, multispace1)),
+                map(CommonParser::sql_identifier, String::from),
+                multispace1,
+                DataType::type_identifier,
+            )),
+            |(mode, name, _, data_type)| RoutineParam {
+                mode,
+                name,
+                data_type,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for RoutineParam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(ref mode) = self.mode {
+            write_sql!(f, "{} ", mode)?;
+        }
+        write_sql!(f, "{} {}", self.name, self.data_type)
+    }
+}
+
+/// `{IN | OUT | INOUT}` of a procedure parameter
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ParamMode {
+    In,
+    Out,
+    InOut,
+}
+
+impl ParamMode {
+    fn parse(i: &str) -> IResult<&str, ParamMode, ParseSQLError<&str>> {
+        alt((
+            map(tag_no_case("INOUT"), |_| ParamMode::InOut),
+            map(tag_no_case("IN"), |_| ParamMode::In),
+            map(tag_no_case("OUT"), |_| ParamMode::Out),
+        ))(i)
+    }
+}
+
+impl fmt::Display for ParamMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            ParamMode::In => write_sql!(f, "IN"),
+            ParamMode::Out => write_sql!(f, "OUT"),
+            ParamMode::InOut => write_sql!(f, "INOUT"),
+        }
+    }
+}
+
+/// characteristic: {
+///     COMMENT 'string'
+///   | LANGUAGE SQL
+///   | [NOT] DETERMINISTIC
+///   | { CONTAINS SQL | NO SQL | READS SQL DATA | MODIFIES SQL DATA }
+///   | SQL SECURITY { DEFINER | INVOKER }
+/// }
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum RoutineCharacteristic {
+    Comment(String),
+    LanguageSql,
+    Deterministic(bool),
+    ContainsSql,
+    NoSql,
+    ReadsSqlData,
+    ModifiesSqlData,
+    SqlSecurityDefiner,
+    SqlSecurityInvoker,
+}
+
+impl RoutineCharacteristic {
+    fn parse(i: &str) -> IResult<&str, RoutineCharacteristic, ParseSQLError<&str>> {
+        alt((
+            map(CommonParser::parse_comment, RoutineCharacteristic::Comment),
+            value(
+                RoutineCharacteristic::LanguageSql,
+                tuple((tag_no_case("LANGUAGE"), multispace1, tag_no_case("SQL"))),
+            ),
+            map(
+                pair(
+                    opt(terminated(tag_no_case("NOT"), multispace1)),
+                    tag_no_case("DETERMINISTIC"),
+                ),
+                |(not, _)| RoutineCharacteristic::Deterministic(not.is_none()),
+            ),
+            value(
+                RoutineCharacteristic::ContainsSql,
+                tuple((tag_no_case("CONTAINS"), multispace1, tag_no_case("SQL"))),
+            ),
+            value(
+                RoutineCharacteristic::NoSql,
+                tuple((tag_no_case("NO"), multispace1, tag_no_case("SQL"))),
+            ),
+            value(
+                RoutineCharacteristic::ReadsSqlData,
+                tuple((
+                    tag_no_case("READS"),
+                    multispace1,
+                    tag_no_case("SQL"),
+                    multispace1,
+                    tag_no_case("DATA"),
+                )),
+            ),
+            value(
+                RoutineCharacteristic::ModifiesSqlData,
+                tuple((
+                    tag_no_case("MODIFIES"),
+                    multispace1,
+                    tag_no_case("SQL"),
+                    multispace1,
+                    tag_no_case("DATA"),
+                )),
+            ),
+            preceded(
+                tuple((
+                    tag_no_case("SQL"),
+                    multispace1,
+                    tag_no_case("SECURITY"),
+                    multispace1,
+                )),
+                alt((
+                    value(
+                        RoutineCharacteristic::SqlSecurityDefiner,
+                        tag_no_case("DEFINER"),
+                    ),
+                    value(
+                        RoutineCharacteristic::SqlSecurityInvoker,
+                        tag_no_case("INVOKER"),
+                    ),
+                )),
+            ),
+        ))(i)
+    }
+}
+
+impl fmt::Display for RoutineCharacteristic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            RoutineCharacteristic::Comment(ref comment) => write_sql!(f, "COMMENT '{}'", comment),
+            RoutineCharacteristic::LanguageSql => write_sql!(f, "LANGUAGE SQL"),
+            RoutineCharacteristic::Deterministic(true) => write_sql!(f, "DETERMINISTIC"),
+            RoutineCharacteristic::Deterministic(false) => write_sql!(f, "NOT DETERMINISTIC"),
+            RoutineCharacteristic::ContainsSql => write_sql!(f, "CONTAINS SQL"),
+            RoutineCharacteristic::NoSql => write_sql!(f, "NO SQL"),
+            RoutineCharacteristic::ReadsSqlData => write_sql!(f, "READS SQL DATA"),
+            RoutineCharacteristic::ModifiesSqlData => write_sql!(f, "MODIFIES SQL DATA"),
+            RoutineCharacteristic::SqlSecurityDefiner => write_sql!(f, "SQL SECURITY DEFINER"),
+            RoutineCharacteristic::SqlSecurityInvoker => write_sql!(f, "SQL SECURITY INVOKER"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::DataType;
+    use dds::create_routine::{
+        CreateRoutineStatement, ParamMode, RoutineCharacteristic, RoutineParam, RoutineType,
+    };
+
+    #[test]
+    fn parse_create_procedure() {
+        let sql = "CREATE DEFINER=`root`@`localhost` PROCEDURE add_bonus(IN emp_id INT, \
+            INOUT total DECIMAL(10,2), OUT note VARCHAR(20))\n\
+            MODIFIES SQL DATA COMMENT 'raise'\n\
+            BEGIN\n  UPDATE emp SET bonus = bonus + 1 WHERE id = emp_id;\nEND;";
+        let res = CreateRoutineStatement::parse(sql);
+        let exp = CreateRoutineStatement {
+            definer: Some("`root`@`localhost`".to_string()),
+            routine_type: RoutineType::Procedure,
+            if_not_exists: false,
+            db_name: None,
+            sp_name: "add_bonus".to_string(),
+            params: vec![
+                RoutineParam {
+                    mode: Some(ParamMode::In),
+                    name: "emp_id".to_string(),
+                    data_type: DataType::Int(32),
+                },
+                RoutineParam {
+                    mode: Some(ParamMode::InOut),
+                    name: "total".to_string(),
+                    data_type: DataType::Decimal(10, 2),
+                },
+                RoutineParam {
+                    mode: Some(ParamMode::Out),
+                    name: "note".to_string(),
+                    data_type: DataType::Varchar(20),
+                },
+            ],
+            returns: None,
+            characteristics: vec![
+                RoutineCharacteristic::ModifiesSqlData,
+                RoutineCharacteristic::Comment("raise".to_string()),
+            ],
+            body: "BEGIN\n  UPDATE emp SET bonus = bonus + 1 WHERE id = emp_id;\nEND".to_string(),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(
+            exp.to_string(),
+            "CREATE DEFINER = `root`@`localhost` PROCEDURE add_bonus(IN emp_id INT(32), \
+            INOUT total DECIMAL(10, 2), OUT note VARCHAR(20)) MODIFIES SQL DATA COMMENT 'raise' \
+            BEGIN\n  UPDATE emp SET bonus = bonus + 1 WHERE id = emp_id;\nEND"
+        );
+    }
+
+    #[test]
+    fn parse_create_procedure_with_nested_blocks() {
+        let body = "BEGIN\n  IF n > 10 THEN\n    SET res = 'big';\n  ELSE\n    \
+            SET res = 'small; END';\n  END IF;\nEND";
+        let sql = format!(
+            "CREATE PROCEDURE classify(IN n INT, OUT res TEXT) {};",
+            body
+        );
+        let res = CreateRoutineStatement::parse(&sql).unwrap();
+        assert_eq!(res.0, "");
+        assert_eq!(res.1.body, body);
+
+        let sql = format!("CREATE PROCEDURE p() {}; SELECT 1", body);
+        let res = CreateRoutineStatement::parse(&sql).unwrap();
+        assert_eq!(res.0, "SELECT 1");
+        assert_eq!(res.1.body, body);
+    }
+
+    #[test]
+    fn parse_create_function() {
+        let sql = "CREATE FUNCTION IF NOT EXISTS hello(s CHAR(20)) RETURNS CHAR(50) \
+            DETERMINISTIC RETURN CONCAT('Hello, ', s, '!')";
+        let res = CreateRoutineStatement::parse(sql);
+        let exp = CreateRoutineStatement {
+            definer: None,
+            routine_type: RoutineType::Function,
+            if_not_exists: true,
+            db_name: None,
+            sp_name: "hello".to_string(),
+            params: vec![RoutineParam {
+                mode: None,
+                name: "s".to_string(),
+                data_type: DataType::Char(20),
+            }],
+            returns: Some(DataType::Char(50)),
+            characteristics: vec![RoutineCharacteristic::Deterministic(true)],
+            body: "RETURN CONCAT('Hello, ', s, '!')".to_string(),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), sql);
+
+        let res = CreateRoutineStatement::parse("CREATE FUNCTION f() RETURNS INT");
+        assert!(res.is_err());
+
+        let sql = "CREATE FUNCTION shop.`order`() RETURNS INT RETURN 1; SELECT 2";
+        let (rest, res) = CreateRoutineStatement::parse(sql).unwrap();
+        assert_eq!(rest, "SELECT 2");
+        assert_eq!(res.db_name, Some("shop".to_string()));
+        assert_eq!(res.sp_name, "order");
+        assert_eq!(res.body, "RETURN 1");
+        assert_eq!(
+            res.to_string(),
+            "CREATE FUNCTION shop.`order`() RETURNS INT(32) RETURN 1"
+        );
+    }
+}
@@ -0,0 +1,190 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, DisplayUtil};
+
+/// parse `CREATE SERVER server_name
+///     FOREIGN DATA WRAPPER wrapper_name
+///     OPTIONS (option [, option] ...)`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CreateServerStatement {
+    pub server_name: String,
+    pub wrapper_name: String,
+    pub options: Vec<ServerOption>,
+}
+
+impl CreateServerStatement {
+    pub fn parse(i: &str) -> IResult<&str, CreateServerStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("CREATE"), multispace1),
+                terminated(tag_no_case("SERVER"), multispace1),
+                map(CommonParser::sql_identifier, String::from),
+                multispace1,
+                tuple((
+                    tag_no_case("FOREIGN"),
+                    multispace1,
+                    tag_no_case("DATA"),
+                    multispace1,
+                    tag_no_case("WRAPPER"),
+                    multispace1,
+                )),
+                alt((
+                    CommonParser::parse_quoted_string,
+                    map(CommonParser::sql_identifier, String::from),
+                )),
+                multispace1,
+                ServerOption::parse_list,
+                CommonParser::statement_terminator,
+            )),
+            |(_, _, server_name, _, _, wrapper_name, _, options, _)| CreateServerStatement {
+                server_name,
+                wrapper_name,
+                options,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for CreateServerStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(
+            f,
+            "CREATE SERVER {} FOREIGN DATA WRAPPER {} {}",
+            DisplayUtil::escape_if_keyword(&self.server_name),
+            DisplayUtil::escape_if_keyword(&self.wrapper_name),
+            ServerOption::format_list(&self.options)
+        )
+    }
+}
+
+/// option: {
+///     HOST character-literal
+///   | DATABASE character-literal
+///   | USER character-literal
+///   | PASSWORD character-literal
+///   | SOCKET character-literal
+///   | OWNER character-literal
+///   | PORT numeric-literal
+/// }
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ServerOption {
+    Host(String),
+    Database(String),
+    User(String),
+    Password(String),
+    Socket(String),
+    Owner(String),
+    Port(u64),
+}
+
+impl ServerOption {
+    /// `OPTIONS (option [, option] ...)`
+    pub fn parse_list(i: &str) -> IResult<&str, Vec<ServerOption>, ParseSQLError<&str>> {
+        preceded(
+            pair(tag_no_case("OPTIONS"), multispace0),
+            delimited(
+                pair(tag("("), multispace0),
+                separated_list1(CommonParser::ws_sep_comma, Self::parse),
+                pair(multispace0, tag(")")),
+            ),
+        )(i)
+    }
+
+    pub fn parse(i: &str) -> IResult<&str, ServerOption, ParseSQLError<&str>> {
+        let string_option = |key: &'static str| {
+            preceded(
+                pair(tag_no_case(key), multispace1),
+                CommonParser::parse_quoted_string,
+            )
+        };
+        alt((
+            map(string_option("HOST"), ServerOption::Host),
+            map(string_option("DATABASE"), ServerOption::Database),
+            map(string_option("USER"), ServerOption::User),
+            map(string_option("PASSWORD"), ServerOption::Password),
+            map(string_option("SOCKET"), ServerOption::Socket),
+            map(string_option("OWNER"), ServerOption::Owner),
+            map(
+                preceded(
+                    pair(tag_no_case("PORT"), multispace1),
+                    CommonParser::unsigned_number,
+                ),
+                ServerOption::Port,
+            ),
+        ))(i)
+    }
+
+    pub fn format_list(list: &[ServerOption]) -> String {
+        format_sql!(
+            "OPTIONS ({})",
+            list.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+impl fmt::Display for ServerOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            ServerOption::Host(ref value) => write_sql!(f, "HOST '{}'", value),
+            ServerOption::Database(ref value) => write_sql!(f, "DATABASE '{}'", value),
+            ServerOption::User(ref value) => write_sql!(f, "USER '{}'", value),
+            ServerOption::Password(ref value) => write_sql!(f, "PASSWORD '{}'", value),
+            ServerOption::Socket(ref value) => write_sql!(f, "SOCKET '{}'", value),
+            ServerOption::Owner(ref value) => write_sql!(f, "OWNER '{}'", value),
+            ServerOption::Port(value) => write_sql!(f, "PORT {}", value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dds::create_server::{CreateServerStatement, ServerOption};
+
+    #[test]
+    fn parse_create_server() {
+        let sql = "CREATE SERVER s FOREIGN DATA WRAPPER mysql OPTIONS (HOST '198.51.100.106', \
+            DATABASE 'test', USER 'remote', PASSWORD 'secret', SOCKET '/tmp/mysql.sock', \
+            OWNER 'root', PORT 3306)";
+        let res = CreateServerStatement::parse(sql);
+        let exp = CreateServerStatement {
+            server_name: "s".to_string(),
+            wrapper_name: "mysql".to_string(),
+            options: vec![
+                ServerOption::Host("198.51.100.106".to_string()),
+                ServerOption::Database("test".to_string()),
+                ServerOption::User("remote".to_string()),
+                ServerOption::Password("secret".to_string()),
+                ServerOption::Socket("/tmp/mysql.sock".to_string()),
+                ServerOption::Owner("root".to_string()),
+                ServerOption::Port(3306),
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), sql);
+
+        let sql = "create server s foreign data wrapper 'mysql' options (user 'remote');";
+        let res = CreateServerStatement::parse(sql);
+        assert_eq!(
+            res.unwrap().1.to_string(),
+            "CREATE SERVER s FOREIGN DATA WRAPPER mysql OPTIONS (USER 'remote')"
+        );
+
+        let sql = "CREATE SERVER `key` FOREIGN DATA WRAPPER mysql OPTIONS (PORT 3306)";
+        let res = CreateServerStatement::parse(sql).unwrap().1;
+        assert_eq!(res.server_name, "key");
+        assert_eq!(res.to_string(), sql);
+    }
+}
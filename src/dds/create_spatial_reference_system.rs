@@ -0,0 +1,188 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete;
+use nom::character::complete::multispace1;
+use nom::combinator::{map, opt};
+use nom::multi::many1;
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::CommonParser;
+
+/// parse `CREATE [OR REPLACE] SPATIAL REFERENCE SYSTEM
+///     [IF NOT EXISTS]
+///     srid srs_attribute ...`
+///
+/// `srid: 32-bit unsigned integer`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CreateSpatialReferenceSystemStatement {
+    pub or_replace: bool,
+    pub if_not_exists: bool,
+    pub srid: u32,
+    pub attributes: Vec<SrsAttribute>,
+}
+
+impl CreateSpatialReferenceSystemStatement {
+    pub fn parse(
+        i: &str,
+    ) -> IResult<&str, CreateSpatialReferenceSystemStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("CREATE"), multispace1),
+                opt(tuple((
+                    tag_no_case("OR"),
+                    multispace1,
+                    tag_no_case("REPLACE"),
+                    multispace1,
+                ))),
+                terminated(tag_no_case("SPATIAL"), multispace1),
+                terminated(tag_no_case("REFERENCE"), multispace1),
+                terminated(tag_no_case("SYSTEM"), multispace1),
+                opt(tuple((
+                    tag_no_case("IF"),
+                    multispace1,
+                    tag_no_case("NOT"),
+                    multispace1,
+                    tag_no_case("EXISTS"),
+                    multispace1,
+                ))),
+                complete::u32,
+                many1(preceded(multispace1, SrsAttribute::parse)),
+                CommonParser::statement_terminator,
+            )),
+            |(_, or_replace, _, _, _, if_not_exists, srid, attributes, _)| {
+                CreateSpatialReferenceSystemStatement {
+                    or_replace: or_replace.is_some(),
+                    if_not_exists: if_not_exists.is_some(),
+                    srid,
+                    attributes,
+                }
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for CreateSpatialReferenceSystemStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "CREATE")?;
+        if self.or_replace {
+            write_sql!(f, " OR REPLACE")?;
+        }
+        write_sql!(f, " SPATIAL REFERENCE SYSTEM")?;
+        if self.if_not_exists {
+            write_sql!(f, " IF NOT EXISTS")?;
+        }
+        write_sql!(f, " {}", self.srid)?;
+        for attribute in &self.attributes {
+            write_sql!(f, " {}", attribute)?;
+        }
+        Ok(())
+    }
+}
+
+/// srs_attribute: {
+///     NAME 'srs_name'
+///   | DEFINITION 'definition'
+///   | ORGANIZATION 'org_name' IDENTIFIED BY org_id
+///   | DESCRIPTION 'description'
+/// }
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum SrsAttribute {
+    Name(String),
+    Definition(String),
+    Organization { name: String, id: u32 },
+    Description(String),
+}
+
+impl SrsAttribute {
+    fn parse(i: &str) -> IResult<&str, SrsAttribute, ParseSQLError<&str>> {
+        let string_attribute = |key: &'static str| {
+            preceded(
+                pair(tag_no_case(key), multispace1),
+                CommonParser::parse_quoted_string,
+            )
+        };
+        alt((
+            map(string_attribute("NAME"), SrsAttribute::Name),
+            map(string_attribute("DEFINITION"), SrsAttribute::Definition),
+            map(
+                tuple((
+                    string_attribute("ORGANIZATION"),
+                    multispace1,
+                    tag_no_case("IDENTIFIED"),
+                    multispace1,
+                    tag_no_case("BY"),
+                    multispace1,
+                    complete::u32,
+                )),
+                |(name, _, _, _, _, _, id)| SrsAttribute::Organization { name, id },
+            ),
+            map(string_attribute("DESCRIPTION"), SrsAttribute::Description),
+        ))(i)
+    }
+}
+
+impl fmt::Display for SrsAttribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            SrsAttribute::Name(ref name) => write_sql!(f, "NAME '{}'", name),
+            SrsAttribute::Definition(ref definition) => {
+                write_sql!(f, "DEFINITION '{}'", definition)
+            }
+            SrsAttribute::Organization { ref name, id } => {
+                write_sql!(f, "ORGANIZATION '{}' IDENTIFIED BY {}", name, id)
+            }
+            SrsAttribute::Description(ref description) => {
+                write_sql!(f, "DESCRIPTION '{}'", description)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dds::create_spatial_reference_system::{
+        CreateSpatialReferenceSystemStatement, SrsAttribute,
+    };
+
+    #[test]
+    fn parse_create_spatial_reference_system() {
+        let definition = "GEOGCS[\"WGS 84\",DATUM[\"World Geodetic System 1984\",\
+            SPHEROID[\"WGS 84\",6378137,298.257223563]],PRIMEM[\"Greenwich\",0],\
+            UNIT[\"degree\",0.017453292519943278],AXIS[\"Lat\",NORTH],AXIS[\"Lon\",EAST]]";
+        let sql = format!(
+            "CREATE OR REPLACE SPATIAL REFERENCE SYSTEM 4120 NAME 'Greek' \
+            DEFINITION '{}' ORGANIZATION 'EPSG' IDENTIFIED BY 4120",
+            definition
+        );
+        let res = CreateSpatialReferenceSystemStatement::parse(&sql);
+        let exp = CreateSpatialReferenceSystemStatement {
+            or_replace: true,
+            if_not_exists: false,
+            srid: 4120,
+            attributes: vec![
+                SrsAttribute::Name("Greek".to_string()),
+                SrsAttribute::Definition(definition.to_string()),
+                SrsAttribute::Organization {
+                    name: "EPSG".to_string(),
+                    id: 4120,
+                },
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), sql);
+
+        let sql = "CREATE SPATIAL REFERENCE SYSTEM IF NOT EXISTS 4120 NAME 'Greek';";
+        let res = CreateSpatialReferenceSystemStatement::parse(sql);
+        let stmt = res.unwrap().1;
+        assert!(stmt.if_not_exists);
+        assert_eq!(
+            stmt.to_string(),
+            "CREATE SPATIAL REFERENCE SYSTEM IF NOT EXISTS 4120 NAME 'Greek'"
+        );
+    }
+}
@@ -9,7 +9,7 @@ use nom::multi::many1;
 use nom::sequence::{delimited, preceded, terminated, tuple};
 use nom::IResult;
 
-use base::column::{Column, ColumnSpecification};
+use base::column::{Column, ColumnConstraint, ColumnSpecification};
 use base::error::ParseSQLError;
 use base::fulltext_or_spatial_type::FulltextOrSpatialType;
 use base::index_option::IndexOption;
@@ -17,7 +17,10 @@ use base::index_or_key_type::IndexOrKeyType;
 use base::index_type::IndexType;
 use base::table::Table;
 use base::table_option::TableOption;
-use base::{CheckConstraintDefinition, CommonParser, KeyPart, ReferenceDefinition};
+use base::{
+    CheckConstraintDefinition, CommonParser, DataType, DisplayUtil, KeyPart, KeyPartType, Literal,
+    PartitionOptions, ReferenceDefinition,
+};
 use dms::SelectStatement;
 
 /// **CreateTableStatement**
@@ -58,12 +61,16 @@ pub struct CreateTableStatement {
 
 impl Display for CreateTableStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "CREATE");
+        write_sql!(f, "CREATE");
         if self.temporary {
-            write!(f, " TEMPORARY");
+            write_sql!(f, " TEMPORARY");
         }
-        write!(f, " TABLE {}", &self.table);
-        write!(f, " {}", &self.create_type);
+        write_sql!(f, " TABLE");
+        if self.if_not_exists {
+            write_sql!(f, " IF NOT EXISTS");
+        }
+        write_sql!(f, " {}", &self.table);
+        write_sql!(f, " {}", &self.create_type);
         Ok(())
     }
 }
@@ -78,6 +85,199 @@ impl CreateTableStatement {
     }
 }
 
+impl CreateTableStatement {
+    /// move column-level `PRIMARY KEY` and `UNIQUE` into table keys appended
+    /// after the definitions, so that `id INT PRIMARY KEY` is structurally the
+    /// same as `id INT, PRIMARY KEY (id)`. The parser keeps them on the column
+    /// to round-trip the original text.
+    pub fn promote_column_keys(&mut self) {
+        let create_definition = match self.create_type {
+            CreateTableType::Simple {
+                ref mut create_definition,
+                ..
+            } => create_definition,
+            CreateTableType::AsQuery {
+                create_definition: Some(ref mut create_definition),
+                ..
+            } => create_definition,
+            _ => return,
+        };
+
+        let mut keys = Vec::new();
+        for definition in create_definition.iter_mut() {
+            if let CreateDefinition::ColumnDefinition {
+                ref mut column_definition,
+            } = *definition
+            {
+                let key_part = vec![KeyPart {
+                    r#type: KeyPartType::ColumnNameWithLength {
+                        col_name: column_definition.column.name.clone(),
+                        length: None,
+                    },
+                    order: None,
+                }];
+                column_definition
+                    .constraints
+                    .retain(|constraint| match *constraint {
+                        ColumnConstraint::PrimaryKey => {
+                            keys.push(CreateDefinition::PrimaryKey {
+                                opt_symbol: None,
+                                opt_index_type: None,
+                                key_part: key_part.clone(),
+                                opt_index_option: None,
+                            });
+                            false
+                        }
+                        ColumnConstraint::Unique => {
+                            keys.push(CreateDefinition::Unique {
+                                opt_symbol: None,
+                                opt_index_or_key: None,
+                                opt_index_name: None,
+                                opt_index_type: None,
+                                key_part: key_part.clone(),
+                                opt_index_option: None,
+                            });
+                            false
+                        }
+                        _ => true,
+                    });
+            }
+        }
+        create_definition.extend(keys);
+    }
+
+    /// the value of the `AUTO_INCREMENT [=] value` table option, i.e. the first
+    /// value of the auto increment column, the last one winning if repeated
+    pub fn auto_increment_start(&self) -> Option<u64> {
+        let table_options = match self.create_type {
+            CreateTableType::Simple {
+                ref table_options, ..
+            }
+            | CreateTableType::AsQuery {
+                ref table_options, ..
+            } => table_options.as_deref()?,
+            CreateTableType::LikeOldTable { .. } => return None,
+        };
+        table_options.iter().rev().find_map(|option| match *option {
+            TableOption::AutoIncrement(value) => Some(value),
+            _ => None,
+        })
+    }
+
+    /// summarize the columns and keys of the table, e.g. for schema documentation.
+    /// `CREATE TABLE ... LIKE` has no definitions, so only the table is filled in.
+    pub fn summary(&self) -> TableSummary {
+        let create_definition: &[CreateDefinition] = match self.create_type {
+            CreateTableType::Simple {
+                ref create_definition,
+                ..
+            } => create_definition,
+            CreateTableType::AsQuery {
+                ref create_definition,
+                ..
+            } => create_definition.as_deref().unwrap_or_default(),
+            CreateTableType::LikeOldTable { .. } => &[],
+        };
+
+        let mut primary_key = Vec::new();
+        let mut foreign_keys = Vec::new();
+        for definition in create_definition {
+            match definition {
+                CreateDefinition::ColumnDefinition { column_definition }
+                    if column_definition
+                        .constraints
+                        .contains(&ColumnConstraint::PrimaryKey) =>
+                {
+                    primary_key.push(column_definition.column.name.clone())
+                }
+                CreateDefinition::PrimaryKey { key_part, .. } => {
+                    primary_key.extend(key_part.iter().filter_map(KeyPart::column_name))
+                }
+                CreateDefinition::ForeignKey {
+                    opt_symbol,
+                    columns,
+                    reference_definition,
+                    ..
+                } => foreign_keys.push(ForeignKeySummary {
+                    name: opt_symbol.clone(),
+                    columns: columns.clone(),
+                    referenced_table: reference_definition.tbl_name.clone(),
+                    referenced_columns: reference_definition
+                        .key_part
+                        .iter()
+                        .filter_map(KeyPart::column_name)
+                        .collect(),
+                }),
+                _ => {}
+            }
+        }
+
+        let columns = create_definition
+            .iter()
+            .filter_map(|definition| match definition {
+                CreateDefinition::ColumnDefinition { column_definition } => Some(column_definition),
+                _ => None,
+            })
+            .map(|spec| {
+                // primary key columns are implicitly NOT NULL
+                let nullable = !spec.constraints.contains(&ColumnConstraint::NotNull)
+                    && !primary_key.contains(&spec.column.name);
+                let default = spec
+                    .constraints
+                    .iter()
+                    .find_map(|constraint| match constraint {
+                        ColumnConstraint::DefaultValue(literal) => Some(literal.clone()),
+                        _ => None,
+                    });
+                ColumnSummary {
+                    name: spec.column.name.clone(),
+                    data_type: spec.data_type.clone(),
+                    nullable,
+                    default,
+                    comment: spec.comment.clone(),
+                }
+            })
+            .collect();
+
+        TableSummary {
+            table: self.table.clone(),
+            columns,
+            primary_key,
+            foreign_keys,
+        }
+    }
+}
+
+/// structured summary of a [CreateTableStatement], see [CreateTableStatement::summary]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TableSummary {
+    pub table: Table,
+    pub columns: Vec<ColumnSummary>,
+    /// columns of the primary key, in key order
+    pub primary_key: Vec<String>,
+    pub foreign_keys: Vec<ForeignKeySummary>,
+}
+
+/// a column of a [TableSummary]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ColumnSummary {
+    pub name: String,
+    pub data_type: DataType,
+    pub nullable: bool,
+    pub default: Option<Literal>,
+    pub comment: Option<String>,
+}
+
+/// a `FOREIGN KEY` of a [TableSummary]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ForeignKeySummary {
+    /// `CONSTRAINT symbol`, if any
+    pub name: Option<String>,
+    pub columns: Vec<String>,
+    pub referenced_table: String,
+    pub referenced_columns: Vec<String>,
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum IgnoreOrReplaceType {
     Ignore,
@@ -87,8 +287,8 @@ pub enum IgnoreOrReplaceType {
 impl Display for IgnoreOrReplaceType {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match *self {
-            IgnoreOrReplaceType::Ignore => write!(f, "IGNORE"),
-            IgnoreOrReplaceType::Replace => write!(f, "REPLACE"),
+            IgnoreOrReplaceType::Ignore => write_sql!(f, "IGNORE"),
+            IgnoreOrReplaceType::Replace => write_sql!(f, "REPLACE"),
         }
     }
 }
@@ -102,6 +302,7 @@ impl IgnoreOrReplaceType {
     }
 }
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum CreateTableType {
     /// Simple Create
@@ -114,7 +315,7 @@ pub enum CreateTableType {
     Simple {
         create_definition: Vec<CreateDefinition>, // (create_definition,...)
         table_options: Option<Vec<TableOption>>,  // [table_options]
-        partition_options: Option<CreatePartitionOption>, // [partition_options]
+        partition_options: Option<PartitionOptions>, // [partition_options]
     },
 
     /// Select Create
@@ -129,7 +330,7 @@ pub enum CreateTableType {
     AsQuery {
         create_definition: Option<Vec<CreateDefinition>>, // (create_definition,...)
         table_options: Option<Vec<TableOption>>,          // [table_options]
-        partition_options: Option<CreatePartitionOption>, // [partition_options]
+        partition_options: Option<PartitionOptions>,      // [partition_options]
         opt_ignore_or_replace: Option<IgnoreOrReplaceType>, // [IGNORE | REPLACE]
         query_expression: SelectStatement,                // [AS] query_expression
     },
@@ -150,12 +351,12 @@ impl Display for CreateTableType {
                 ref table_options,
                 ref partition_options,
             } => {
-                write!(f, " {}", CreateDefinition::format_list(create_definition));
+                write_sql!(f, "({})", CreateDefinition::format_list(create_definition));
                 if let Some(table_options) = table_options {
-                    write!(f, " {}", TableOption::format_list(table_options));
+                    write_sql!(f, " {}", TableOption::format_list(table_options));
                 };
                 if let Some(partition_options) = partition_options {
-                    write!(f, " {}", partition_options);
+                    write_sql!(f, " {}", partition_options);
                 };
                 Ok(())
             }
@@ -167,21 +368,21 @@ impl Display for CreateTableType {
                 ref query_expression,
             } => {
                 if let Some(create_definition) = create_definition {
-                    write!(f, " {}", CreateDefinition::format_list(create_definition));
+                    write_sql!(f, "({}) ", CreateDefinition::format_list(create_definition));
                 }
                 if let Some(table_options) = table_options {
-                    write!(f, " {}", TableOption::format_list(table_options));
+                    write_sql!(f, "{} ", TableOption::format_list(table_options));
                 };
                 if let Some(partition_options) = partition_options {
-                    write!(f, " {}", partition_options);
+                    write_sql!(f, "{} ", partition_options);
                 };
                 if let Some(opt_ignore_or_replace) = opt_ignore_or_replace {
-                    write!(f, " {}", opt_ignore_or_replace);
+                    write_sql!(f, "{} ", opt_ignore_or_replace);
                 };
-                write!(f, " {}", query_expression);
+                write_sql!(f, "{}", query_expression);
                 Ok(())
             }
-            CreateTableType::LikeOldTable { ref table } => write!(f, "LIKE {}", table),
+            CreateTableType::LikeOldTable { ref table } => write_sql!(f, "LIKE {}", table),
         }
     }
 }
@@ -200,7 +401,7 @@ impl CreateTableType {
                 opt(Self::create_table_options),
                 multispace0,
                 // [partition_options]
-                opt(CreatePartitionOption::parse),
+                opt(PartitionOptions::parse),
                 CommonParser::statement_terminator,
             )),
             |(x)| {
@@ -235,7 +436,7 @@ impl CreateTableType {
                 opt(Self::create_table_options),
                 multispace0,
                 // [partition_options]
-                opt(CreatePartitionOption::parse),
+                opt(PartitionOptions::parse),
                 multispace0,
                 opt(IgnoreOrReplaceType::parse),
                 multispace0,
@@ -414,7 +615,7 @@ impl Display for CreateDefinition {
         match *self {
             CreateDefinition::ColumnDefinition {
                 ref column_definition,
-            } => write!(f, " {}", column_definition),
+            } => write_sql!(f, "{}", column_definition),
             CreateDefinition::IndexOrKey {
                 ref index_or_key,
                 ref opt_index_name,
@@ -422,16 +623,16 @@ impl Display for CreateDefinition {
                 ref key_part,
                 ref opt_index_option,
             } => {
-                write!(f, " {}", index_or_key);
+                write_sql!(f, "{}", index_or_key);
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
                 if let Some(opt_index_type) = opt_index_type {
-                    write!(f, " {}", opt_index_type);
+                    write_sql!(f, " {}", opt_index_type);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -442,16 +643,16 @@ impl Display for CreateDefinition {
                 ref key_part,
                 ref opt_index_option,
             } => {
-                write!(f, " {}", fulltext_or_spatial);
+                write_sql!(f, "{}", fulltext_or_spatial);
                 if let Some(opt_index_or_key) = opt_index_or_key {
-                    write!(f, " {}", opt_index_or_key);
+                    write_sql!(f, " {}", opt_index_or_key);
                 }
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -462,15 +663,19 @@ impl Display for CreateDefinition {
                 ref opt_index_option,
             } => {
                 if let Some(opt_symbol) = opt_symbol {
-                    write!(f, " CONSTRAINT {}", opt_symbol);
+                    write_sql!(
+                        f,
+                        "CONSTRAINT {} ",
+                        DisplayUtil::escape_if_keyword(opt_symbol)
+                    );
                 }
-                write!(f, " PRIMARY KEY");
+                write_sql!(f, "PRIMARY KEY");
                 if let Some(opt_index_type) = opt_index_type {
-                    write!(f, " {}", opt_index_type);
+                    write_sql!(f, " {}", opt_index_type);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -483,21 +688,25 @@ impl Display for CreateDefinition {
                 ref opt_index_option,
             } => {
                 if let Some(opt_symbol) = opt_symbol {
-                    write!(f, " CONSTRAINT {}", opt_symbol);
+                    write_sql!(
+                        f,
+                        "CONSTRAINT {} ",
+                        DisplayUtil::escape_if_keyword(opt_symbol)
+                    );
                 }
-                write!(f, " UNIQUE");
+                write_sql!(f, "UNIQUE");
                 if let Some(opt_index_or_key) = opt_index_or_key {
-                    write!(f, " {}", opt_index_or_key);
+                    write_sql!(f, " {}", opt_index_or_key);
                 }
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
                 if let Some(opt_index_type) = opt_index_type {
-                    write!(f, " {}", opt_index_type);
+                    write_sql!(f, " {}", opt_index_type);
                 }
-                write!(f, " {}", KeyPart::format_list(key_part));
+                write_sql!(f, " {}", KeyPart::format_list(key_part));
                 if let Some(opt_index_option) = opt_index_option {
-                    write!(f, " {}", IndexOption::format_list(opt_index_option));
+                    write_sql!(f, " {}", IndexOption::format_list(opt_index_option));
                 }
                 Ok(())
             }
@@ -508,19 +717,23 @@ impl Display for CreateDefinition {
                 ref reference_definition,
             } => {
                 if let Some(opt_symbol) = opt_symbol {
-                    write!(f, " CONSTRAINT {}", opt_symbol);
+                    write_sql!(
+                        f,
+                        "CONSTRAINT {} ",
+                        DisplayUtil::escape_if_keyword(opt_symbol)
+                    );
                 }
-                write!(f, " FOREIGN KEY");
+                write_sql!(f, "FOREIGN KEY");
                 if let Some(opt_index_name) = opt_index_name {
-                    write!(f, " {}", opt_index_name);
+                    write_sql!(f, " {}", opt_index_name);
                 }
-                write!(f, " ({})", columns.join(", "));
-                write!(f, " {}", reference_definition);
+                write_sql!(f, " ({})", columns.join(", "));
+                write_sql!(f, " {}", reference_definition);
                 Ok(())
             }
             CreateDefinition::Check {
                 ref check_constraint_definition,
-            } => write!(f, " {}", check_constraint_definition),
+            } => write_sql!(f, "{}", check_constraint_definition),
         }
     }
 }
@@ -619,7 +832,7 @@ impl CreateDefinition {
                 // {FULLTEXT | SPATIAL}
                 FulltextOrSpatialType::parse,
                 // [INDEX | KEY]
-                preceded(multispace1, opt(IndexOrKeyType::parse)),
+                IndexOrKeyType::opt_index_or_key,
                 // [index_name]
                 CommonParser::opt_index_name,
                 // (key_part,...)
@@ -757,7 +970,7 @@ impl CreateDefinition {
                 // [CONSTRAINT [symbol]]
                 Self::opt_constraint_with_opt_symbol,
                 // CHECK
-                tuple((multispace1, tag_no_case("CHECK"), multispace0)),
+                tuple((multispace0, tag_no_case("CHECK"), multispace0)),
                 // (expr)
                 delimited(tag("("), take_until(")"), tag(")")),
                 // [[NOT] ENFORCED]
@@ -798,35 +1011,19 @@ impl CreateDefinition {
     }
 }
 
-///////////////////// TODO support create partition parser
-#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
-pub enum CreatePartitionOption {
-    None,
-}
-
-impl Display for CreatePartitionOption {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "")
-    }
-}
-
-impl CreatePartitionOption {
-    fn parse(i: &str) -> IResult<&str, CreatePartitionOption, ParseSQLError<&str>> {
-        map(tag_no_case(""), |_| CreatePartitionOption::None)(i)
-    }
-}
-///////////////////// TODO support create partition parser
-
 #[cfg(test)]
 mod tests {
     use base::column::{ColumnConstraint, ColumnSpecification};
+    use base::index_or_key_type::IndexOrKeyType;
+    use base::reference_type::ReferenceType;
     use base::table_option::TableOption;
     use base::{
-        Column, DataType, FieldDefinitionExpression, KeyPart, KeyPartType, Literal,
-        ReferenceDefinition,
+        CheckConstraintDefinition, Column, DataType, FieldDefinitionExpression, KeyPart,
+        KeyPartType, Literal, MatchType, ReferenceDefinition, Table,
     };
     use dds::create_table::{
-        CreateDefinition, CreatePartitionOption, CreateTableStatement, CreateTableType,
+        ColumnSummary, CreateDefinition, CreateTableStatement, CreateTableType, ForeignKeySummary,
+        TableSummary,
     };
     use dms::SelectStatement;
 
@@ -888,7 +1085,7 @@ mod tests {
                         TableOption::DefaultCharset("utf8".to_string()),
                         TableOption::Comment("Admin Role Table".to_string()),
                     ]),
-                    partition_options: Some(CreatePartitionOption::None),
+                    partition_options: None,
                 },
             },
             CreateTableStatement {
@@ -908,6 +1105,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_create_with_partition_options() {
+        let sql = "CREATE TABLE t (a INT(32)) PARTITION BY HASH (a) PARTITIONS 4";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        match res.create_type {
+            CreateTableType::Simple {
+                ref partition_options,
+                ..
+            } => assert_eq!(partition_options.as_ref().unwrap().partitions, Some(4)),
+            _ => panic!("expected a simple CREATE TABLE"),
+        }
+        assert_eq!(res.to_string(), sql);
+    }
+
     #[test]
     fn parse_create_as_query() {
         let sqls = ["CREATE TABLE tbl_name AS SELECT * from other_tbl_name"];
@@ -918,17 +1129,19 @@ mod tests {
             create_type: CreateTableType::AsQuery {
                 create_definition: None,
                 table_options: None,
-                partition_options: Some(CreatePartitionOption::None),
+                partition_options: None,
                 opt_ignore_or_replace: None,
                 query_expression: SelectStatement {
-                    tables: vec!["other_tbl_name".into()],
+                    tables: vec![Table::from("other_tbl_name").into()],
                     distinct: false,
                     fields: vec![FieldDefinitionExpression::All],
-                    join: vec![],
                     where_clause: None,
                     group_by: None,
+                    window: vec![],
+                    qualify: None,
                     order: None,
                     limit: None,
+                    procedure: None,
                 },
             },
         }];
@@ -1024,4 +1237,277 @@ mod tests {
         assert!(res.is_ok());
         assert_eq!(res.unwrap().1, exp);
     }
+
+    #[test]
+    fn parse_interleaved_create_definition_list() {
+        let part = "(KEY idx_b (b), a INT, CHECK (a > 0), b INT)";
+        let column = |name: &str| CreateDefinition::ColumnDefinition {
+            column_definition: ColumnSpecification {
+                column: name.into(),
+                data_type: DataType::Int(32),
+                constraints: vec![],
+                comment: None,
+                position: None,
+            },
+        };
+        let exp = vec![
+            CreateDefinition::IndexOrKey {
+                index_or_key: IndexOrKeyType::Key,
+                opt_index_name: Some("idx_b".to_string()),
+                opt_index_type: None,
+                key_part: vec![KeyPart {
+                    r#type: KeyPartType::ColumnNameWithLength {
+                        col_name: "b".to_string(),
+                        length: None,
+                    },
+                    order: None,
+                }],
+                opt_index_option: None,
+            },
+            column("a"),
+            CreateDefinition::Check {
+                check_constraint_definition: CheckConstraintDefinition {
+                    symbol: None,
+                    expr: "a > 0".to_string(),
+                    enforced: true,
+                },
+            },
+            column("b"),
+        ];
+        let res = CreateDefinition::create_definition_list(part);
+        assert_eq!(res.unwrap().1, exp);
+    }
+
+    #[test]
+    fn promote_column_keys() {
+        let sql = "CREATE TABLE t (id INT PRIMARY KEY, email VARCHAR(64) UNIQUE KEY, name TEXT)";
+        let mut res = CreateTableStatement::parse(sql).unwrap().1;
+        assert_eq!(
+            CreateTableStatement::parse(&res.to_string()).unwrap().1,
+            res
+        );
+
+        res.promote_column_keys();
+        let exp = CreateTableStatement::parse(
+            "CREATE TABLE t (id INT, email VARCHAR(64), name TEXT, \
+            PRIMARY KEY (id), UNIQUE (email))",
+        )
+        .unwrap()
+        .1;
+        assert_eq!(res, exp);
+        assert_eq!(res.summary(), exp.summary());
+
+        // bare `KEY` on a column is the primary key too
+        let mut res = CreateTableStatement::parse("CREATE TABLE t (id INT KEY)")
+            .unwrap()
+            .1;
+        res.promote_column_keys();
+        let exp = CreateTableStatement::parse("CREATE TABLE t (id INT, PRIMARY KEY (id))")
+            .unwrap()
+            .1;
+        assert_eq!(res, exp);
+    }
+
+    #[test]
+    fn auto_increment_start() {
+        let sql = "CREATE TABLE t (id INT AUTO_INCREMENT, PRIMARY KEY (id)) \
+            ENGINE=InnoDB AUTO_INCREMENT=1000 DEFAULT CHARSET=utf8mb4";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        assert_eq!(res.auto_increment_start(), Some(1000));
+        let round_trip = CreateTableStatement::parse(&res.to_string()).unwrap().1;
+        assert_eq!(round_trip.auto_increment_start(), Some(1000));
+
+        let sql = "CREATE TABLE t (id INT AUTO_INCREMENT, PRIMARY KEY (id)) ENGINE=InnoDB";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        assert_eq!(res.auto_increment_start(), None);
+
+        let res = CreateTableStatement::parse("CREATE TABLE t LIKE u")
+            .unwrap()
+            .1;
+        assert_eq!(res.auto_increment_start(), None);
+    }
+
+    #[test]
+    fn summary() {
+        let sql = "CREATE TABLE orders (\
+            id BIGINT(20) UNSIGNED AUTO_INCREMENT COMMENT 'order id',\
+            user_id INT(11) NOT NULL,\
+            status VARCHAR(16) DEFAULT 'new' COMMENT 'order status',\
+            note VARCHAR(255),\
+            PRIMARY KEY (id),\
+            KEY idx_user (user_id),\
+            CONSTRAINT fk_user FOREIGN KEY (user_id) REFERENCES users (id) ON DELETE CASCADE\
+            ) ENGINE=InnoDB";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        let column =
+            |name: &str, data_type, nullable, default, comment: Option<&str>| ColumnSummary {
+                name: name.to_string(),
+                data_type,
+                nullable,
+                default,
+                comment: comment.map(String::from),
+            };
+        let exp = TableSummary {
+            table: "orders".into(),
+            columns: vec![
+                column(
+                    "id",
+                    DataType::UnsignedBigint(20),
+                    false,
+                    None,
+                    Some("order id"),
+                ),
+                column("user_id", DataType::Int(11), false, None, None),
+                column(
+                    "status",
+                    DataType::Varchar(16),
+                    true,
+                    Some(Literal::String("new".to_string())),
+                    Some("order status"),
+                ),
+                column("note", DataType::Varchar(255), true, None, None),
+            ],
+            primary_key: vec!["id".to_string()],
+            foreign_keys: vec![ForeignKeySummary {
+                name: Some("fk_user".to_string()),
+                columns: vec!["user_id".to_string()],
+                referenced_table: "users".to_string(),
+                referenced_columns: vec!["id".to_string()],
+            }],
+        };
+        assert_eq!(res.summary(), exp);
+
+        let res = CreateTableStatement::parse("CREATE TABLE t (a INT PRIMARY KEY, b INT)");
+        let summary = res.unwrap().1.summary();
+        assert_eq!(summary.primary_key, vec!["a".to_string()]);
+        assert!(!summary.columns[0].nullable);
+        assert!(summary.columns[1].nullable);
+
+        let res = CreateTableStatement::parse("CREATE TABLE t LIKE s");
+        let summary = res.unwrap().1.summary();
+        assert_eq!(summary.table, "t".into());
+        assert!(summary.columns.is_empty());
+    }
+
+    #[test]
+    fn inline_foreign_key_round_trip() {
+        let sql = "CREATE TABLE IF NOT EXISTS child (\
+            id INT(11) NOT NULL, \
+            parent_id INT(11), \
+            CONSTRAINT fk_parent FOREIGN KEY (parent_id) REFERENCES parent (id) \
+            MATCH FULL ON DELETE CASCADE ON UPDATE RESTRICT)";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        let exp = CreateDefinition::ForeignKey {
+            opt_symbol: Some("fk_parent".to_string()),
+            opt_index_name: None,
+            columns: vec!["parent_id".to_string()],
+            reference_definition: ReferenceDefinition {
+                tbl_name: "parent".to_string(),
+                key_part: vec![KeyPart {
+                    r#type: KeyPartType::ColumnNameWithLength {
+                        col_name: "id".to_string(),
+                        length: None,
+                    },
+                    order: None,
+                }],
+                match_type: Some(MatchType::Full),
+                on_delete: Some(ReferenceType::Cascade),
+                on_update: Some(ReferenceType::Restrict),
+            },
+        };
+        match res.create_type {
+            CreateTableType::Simple {
+                ref create_definition,
+                ..
+            } => assert_eq!(create_definition[2], exp),
+            ref other => panic!("unexpected create type {:?}", other),
+        }
+        assert_eq!(
+            res.to_string(),
+            "CREATE TABLE IF NOT EXISTS child (id INT(11) NOT NULL, parent_id INT(11), \
+            CONSTRAINT fk_parent FOREIGN KEY (parent_id) REFERENCES parent (id) \
+            MATCH FULL ON DELETE CASCADE ON UPDATE RESTRICT)"
+        );
+        assert_eq!(
+            CreateTableStatement::parse(&res.to_string()).unwrap().1,
+            res
+        );
+
+        // the referential actions may come in either order
+        let sql = "CREATE TABLE child (parent_id INT, \
+            FOREIGN KEY (parent_id) REFERENCES parent (id) ON UPDATE SET NULL ON DELETE NO ACTION)";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        let summary = res.summary();
+        assert_eq!(summary.foreign_keys[0].referenced_table, "parent");
+        assert!(res
+            .to_string()
+            .ends_with("REFERENCES parent (id) ON DELETE NO ACTION ON UPDATE SET NULL)"));
+        assert_eq!(
+            CreateTableStatement::parse(&res.to_string()).unwrap().1,
+            res
+        );
+    }
+
+    #[test]
+    fn column_level_references() {
+        let sql = "CREATE TABLE child (\
+            id INT(11) NOT NULL, \
+            parent_id INT(11) REFERENCES parent (id) ON DELETE SET NULL)";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        let exp = ColumnConstraint::Reference(ReferenceDefinition {
+            tbl_name: "parent".to_string(),
+            key_part: vec![KeyPart {
+                r#type: KeyPartType::ColumnNameWithLength {
+                    col_name: "id".to_string(),
+                    length: None,
+                },
+                order: None,
+            }],
+            match_type: None,
+            on_delete: Some(ReferenceType::SetNull),
+            on_update: None,
+        });
+        match res.create_type {
+            CreateTableType::Simple {
+                ref create_definition,
+                ..
+            } => match create_definition[1] {
+                CreateDefinition::ColumnDefinition {
+                    ref column_definition,
+                } => assert_eq!(column_definition.constraints, vec![exp]),
+                ref other => panic!("unexpected definition {:?}", other),
+            },
+            ref other => panic!("unexpected create type {:?}", other),
+        }
+        assert_eq!(res.to_string(), sql);
+        assert_eq!(
+            CreateTableStatement::parse(&res.to_string()).unwrap().1,
+            res
+        );
+    }
+
+    #[test]
+    fn bit_column_with_bit_literal_default() {
+        let sql = "CREATE TABLE t (flags BIT(8) NOT NULL DEFAULT b'10101010', f BIT DEFAULT 0b1)";
+        let res = CreateTableStatement::parse(sql).unwrap().1;
+        let summary = res.summary();
+        assert_eq!(summary.columns[0].data_type, DataType::Bit(8));
+        assert_eq!(
+            summary.columns[0].default,
+            Some(Literal::BitString("10101010".to_string()))
+        );
+        assert_eq!(summary.columns[1].data_type, DataType::Bit(1));
+        assert_eq!(
+            summary.columns[1].default,
+            Some(Literal::BitString("1".to_string()))
+        );
+        assert_eq!(
+            res.to_string(),
+            "CREATE TABLE t (flags BIT(8) NOT NULL DEFAULT b'10101010', f BIT(1) DEFAULT b'1')"
+        );
+        assert_eq!(
+            CreateTableStatement::parse(&res.to_string()).unwrap().1,
+            res
+        );
+    }
 }
@@ -0,0 +1,275 @@
+use core::fmt;
+use std::fmt::Formatter;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::{digit1, multispace1, one_of};
+use nom::combinator::{map, opt, recognize};
+use nom::multi::many0;
+use nom::sequence::{pair, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::CommonParser;
+
+/// parse `CREATE [UNDO] TABLESPACE tablespace_name [tablespace_option] ...`
+///
+/// InnoDB and NDB options are accepted alike; MySQL rejects the ones that
+/// don't apply to the engine.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct CreateTablespaceStatement {
+    pub undo: bool,
+    pub tablespace_name: String,
+    pub options: Vec<TablespaceOption>,
+}
+
+impl CreateTablespaceStatement {
+    pub fn parse(i: &str) -> IResult<&str, CreateTablespaceStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(tag_no_case("CREATE"), multispace1),
+                opt(terminated(tag_no_case("UNDO"), multispace1)),
+                terminated(tag_no_case("TABLESPACE"), multispace1),
+                map(CommonParser::sql_identifier, String::from),
+                many0(preceded(multispace1, TablespaceOption::parse)),
+                CommonParser::statement_terminator,
+            )),
+            |(_, undo, _, tablespace_name, options, _)| CreateTablespaceStatement {
+                undo: undo.is_some(),
+                tablespace_name,
+                options,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for CreateTablespaceStatement {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_sql!(f, "CREATE")?;
+        if self.undo {
+            write_sql!(f, " UNDO")?;
+        }
+        write_sql!(f, " TABLESPACE {}", self.tablespace_name)?;
+        for option in &self.options {
+            write_sql!(f, " {}", option)?;
+        }
+        Ok(())
+    }
+}
+
+/// option of `CREATE TABLESPACE` and `CREATE LOGFILE GROUP`
+///
+/// Sizes are kept as written, e.g. `16M`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum TablespaceOption {
+    /// `ADD DATAFILE 'file_name'`
+    AddDatafile(String),
+    /// `USE LOGFILE GROUP logfile_group`
+    UseLogfileGroup(String),
+    /// `AUTOEXTEND_SIZE [=] value`
+    AutoextendSize(String),
+    /// `FILE_BLOCK_SIZE [=] value`
+    FileBlockSize(String),
+    /// `ENCRYPTION [=] {'Y' | 'N'}`
+    Encryption(String),
+    /// `EXTENT_SIZE [=] extent_size`
+    ExtentSize(String),
+    /// `INITIAL_SIZE [=] initial_size`
+    InitialSize(String),
+    /// `MAX_SIZE [=] max_size`
+    MaxSize(String),
+    /// `UNDO_BUFFER_SIZE [=] undo_buffer_size`
+    UndoBufferSize(String),
+    /// `REDO_BUFFER_SIZE [=] redo_buffer_size`
+    RedoBufferSize(String),
+    /// `NODEGROUP [=] nodegroup_id`
+    Nodegroup(u64),
+    /// `WAIT`
+    Wait,
+    /// `COMMENT [=] 'string'`
+    Comment(String),
+    /// `ENGINE [=] engine_name`
+    Engine(String),
+    /// `ENGINE_ATTRIBUTE [=] 'string'`
+    EngineAttribute(String),
+}
+
+impl TablespaceOption {
+    pub fn parse(i: &str) -> IResult<&str, TablespaceOption, ParseSQLError<&str>> {
+        alt((
+            map(
+                preceded(
+                    tuple((tag_no_case("ADD"), multispace1, tag_no_case("DATAFILE"))),
+                    preceded(multispace1, CommonParser::parse_quoted_string),
+                ),
+                TablespaceOption::AddDatafile,
+            ),
+            map(
+                preceded(
+                    tuple((
+                        tag_no_case("USE"),
+                        multispace1,
+                        tag_no_case("LOGFILE"),
+                        multispace1,
+                        tag_no_case("GROUP"),
+                        multispace1,
+                    )),
+                    CommonParser::sql_identifier,
+                ),
+                |x| TablespaceOption::UseLogfileGroup(String::from(x)),
+            ),
+            map(
+                Self::key_value("AUTOEXTEND_SIZE", size),
+                TablespaceOption::AutoextendSize,
+            ),
+            map(
+                Self::key_value("FILE_BLOCK_SIZE", size),
+                TablespaceOption::FileBlockSize,
+            ),
+            map(
+                Self::key_value("ENCRYPTION", CommonParser::parse_quoted_string),
+                TablespaceOption::Encryption,
+            ),
+            map(
+                Self::key_value("EXTENT_SIZE", size),
+                TablespaceOption::ExtentSize,
+            ),
+            map(
+                Self::key_value("INITIAL_SIZE", size),
+                TablespaceOption::InitialSize,
+            ),
+            map(Self::key_value("MAX_SIZE", size), TablespaceOption::MaxSize),
+            map(
+                Self::key_value("UNDO_BUFFER_SIZE", size),
+                TablespaceOption::UndoBufferSize,
+            ),
+            map(
+                Self::key_value("REDO_BUFFER_SIZE", size),
+                TablespaceOption::RedoBufferSize,
+            ),
+            map(
+                Self::key_value("NODEGROUP", CommonParser::unsigned_number),
+                TablespaceOption::Nodegroup,
+            ),
+            map(tag_no_case("WAIT"), |_| TablespaceOption::Wait),
+            map(
+                Self::key_value("COMMENT", CommonParser::parse_quoted_string),
+                TablespaceOption::Comment,
+            ),
+            map(
+                Self::key_value("ENGINE_ATTRIBUTE", CommonParser::parse_quoted_string),
+                TablespaceOption::EngineAttribute,
+            ),
+            map(
+                Self::key_value("ENGINE", CommonParser::sql_identifier),
+                |x| TablespaceOption::Engine(String::from(x)),
+            ),
+        ))(i)
+    }
+
+    /// `key [=] value`
+    fn key_value<'a, O>(
+        key: &'static str,
+        value: impl FnMut(&'a str) -> IResult<&'a str, O, ParseSQLError<&'a str>>,
+    ) -> impl FnMut(&'a str) -> IResult<&'a str, O, ParseSQLError<&'a str>> {
+        preceded(
+            pair(
+                tag_no_case(key),
+                alt((CommonParser::ws_sep_equals, multispace1)),
+            ),
+            value,
+        )
+    }
+}
+
+impl fmt::Display for TablespaceOption {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            TablespaceOption::AddDatafile(ref file) => write_sql!(f, "ADD DATAFILE '{}'", file),
+            TablespaceOption::UseLogfileGroup(ref group) => {
+                write_sql!(f, "USE LOGFILE GROUP {}", group)
+            }
+            TablespaceOption::AutoextendSize(ref size) => {
+                write_sql!(f, "AUTOEXTEND_SIZE = {}", size)
+            }
+            TablespaceOption::FileBlockSize(ref size) => {
+                write_sql!(f, "FILE_BLOCK_SIZE = {}", size)
+            }
+            TablespaceOption::Encryption(ref value) => write_sql!(f, "ENCRYPTION = '{}'", value),
+            TablespaceOption::ExtentSize(ref size) => write_sql!(f, "EXTENT_SIZE = {}", size),
+            TablespaceOption::InitialSize(ref size) => write_sql!(f, "INITIAL_SIZE = {}", size),
+            TablespaceOption::MaxSize(ref size) => write_sql!(f, "MAX_SIZE = {}", size),
+            TablespaceOption::UndoBufferSize(ref size) => {
+                write_sql!(f, "UNDO_BUFFER_SIZE = {}", size)
+            }
+            TablespaceOption::RedoBufferSize(ref size) => {
+                write_sql!(f, "REDO_BUFFER_SIZE = {}", size)
+            }
+            TablespaceOption::Nodegroup(id) => write_sql!(f, "NODEGROUP = {}", id),
+            TablespaceOption::Wait => write_sql!(f, "WAIT"),
+            TablespaceOption::Comment(ref comment) => write_sql!(f, "COMMENT = '{}'", comment),
+            TablespaceOption::Engine(ref engine) => write_sql!(f, "ENGINE = {}", engine),
+            TablespaceOption::EngineAttribute(ref value) => {
+                write_sql!(f, "ENGINE_ATTRIBUTE = '{}'", value)
+            }
+        }
+    }
+}
+
+/// size such as `8192`, `16M` or `1G`
+fn size(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+    map(recognize(pair(digit1, opt(one_of("KkMmGg")))), String::from)(i)
+}
+
+#[cfg(test)]
+mod tests {
+    use dds::create_tablespace::{CreateTablespaceStatement, TablespaceOption};
+
+    #[test]
+    fn parse_create_ndb_tablespace() {
+        let sql = "CREATE TABLESPACE ts_1
+            ADD DATAFILE 'data_1.dat'
+            USE LOGFILE GROUP lg_1
+            EXTENT_SIZE 1M
+            INITIAL_SIZE = 32M
+            MAX_SIZE=64M
+            NODEGROUP 0
+            WAIT
+            COMMENT 'ndb data'
+            ENGINE NDB;";
+        let res = CreateTablespaceStatement::parse(sql);
+        let exp = CreateTablespaceStatement {
+            undo: false,
+            tablespace_name: "ts_1".to_string(),
+            options: vec![
+                TablespaceOption::AddDatafile("data_1.dat".to_string()),
+                TablespaceOption::UseLogfileGroup("lg_1".to_string()),
+                TablespaceOption::ExtentSize("1M".to_string()),
+                TablespaceOption::InitialSize("32M".to_string()),
+                TablespaceOption::MaxSize("64M".to_string()),
+                TablespaceOption::Nodegroup(0),
+                TablespaceOption::Wait,
+                TablespaceOption::Comment("ndb data".to_string()),
+                TablespaceOption::Engine("NDB".to_string()),
+            ],
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(
+            exp.to_string(),
+            "CREATE TABLESPACE ts_1 ADD DATAFILE 'data_1.dat' USE LOGFILE GROUP lg_1 \
+            EXTENT_SIZE = 1M INITIAL_SIZE = 32M MAX_SIZE = 64M NODEGROUP = 0 WAIT \
+            COMMENT = 'ndb data' ENGINE = NDB"
+        );
+    }
+
+    #[test]
+    fn parse_create_innodb_tablespace() {
+        let sql = "CREATE UNDO TABLESPACE undo_1 ADD DATAFILE 'undo_1.ibu' \
+            AUTOEXTEND_SIZE = 4M FILE_BLOCK_SIZE = 8192 ENCRYPTION = 'Y' ENGINE = InnoDB";
+        let res = CreateTablespaceStatement::parse(sql);
+        let stmt = res.unwrap().1;
+        assert!(stmt.undo);
+        assert_eq!(stmt.options.len(), 5);
+        assert_eq!(stmt.to_string(), sql);
+    }
+}
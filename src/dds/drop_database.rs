@@ -45,12 +45,12 @@ impl DropDatabaseStatement {
 
 impl fmt::Display for DropDatabaseStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP DATABASE")?;
+        write_sql!(f, "DROP DATABASE")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
         let database = self.name.clone();
-        write!(f, " {}", database)?;
+        write_sql!(f, " {}", database)?;
         Ok(())
     }
 }
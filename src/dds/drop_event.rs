@@ -38,11 +38,11 @@ impl DropEventStatement {
 
 impl fmt::Display for DropEventStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP EVENT")?;
+        write_sql!(f, "DROP EVENT")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
-        write!(f, " {}", self.event_name)?;
+        write_sql!(f, " {}", self.event_name)?;
         Ok(())
     }
 }
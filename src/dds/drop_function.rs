@@ -38,11 +38,11 @@ impl DropFunctionStatement {
 
 impl fmt::Display for DropFunctionStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP FUNCTION")?;
+        write_sql!(f, "DROP FUNCTION")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
-        write!(f, " {}", self.sp_name)?;
+        write_sql!(f, " {}", self.sp_name)?;
         Ok(())
     }
 }
@@ -10,6 +10,7 @@ use base::error::ParseSQLError;
 use base::lock_type::LockType;
 use base::table::Table;
 use base::CommonParser;
+use parser::ParseConfig;
 
 /// parse `DROP INDEX index_name ON tbl_name
 ///     [algorithm_option | lock_option] ...`
@@ -18,6 +19,8 @@ use base::CommonParser;
 /// lock_option: `LOCK [=] {DEFAULT | NONE | SHARED | EXCLUSIVE}`
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct DropIndexStatement {
+    /// MariaDB `IF EXISTS`, see [crate::ParseConfig::mariadb]
+    pub if_exists: bool,
     pub index_name: String,
     pub table: Table,
     pub algorithm_option: Option<AlgorithmType>,
@@ -26,12 +29,16 @@ pub struct DropIndexStatement {
 
 impl Display for DropIndexStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "DROP INDEX {} ON {}", &self.index_name, &self.table);
+        write_sql!(f, "DROP INDEX");
+        if self.if_exists {
+            write_sql!(f, " IF EXISTS");
+        }
+        write_sql!(f, " {} ON {}", &self.index_name, &self.table);
         if let Some(algorithm_option) = &self.algorithm_option {
-            write!(f, " {}", algorithm_option);
+            write_sql!(f, " {}", algorithm_option);
         }
         if let Some(lock_option) = &self.lock_option {
-            write!(f, " {}", lock_option);
+            write_sql!(f, " {}", lock_option);
         }
         Ok(())
     }
@@ -43,6 +50,7 @@ impl DropIndexStatement {
             tuple((
                 tuple((tag_no_case("DROP"), multispace1)),
                 tuple((tag_no_case("INDEX"), multispace1)),
+                Self::if_exists,
                 map(
                     tuple((
                         CommonParser::sql_identifier,
@@ -60,8 +68,9 @@ impl DropIndexStatement {
                 multispace0,
                 CommonParser::statement_terminator,
             )),
-            |(_, _, index_name, table, _, algorithm_option, _, lock_option, _, _)| {
+            |(_, _, if_exists, index_name, table, _, algorithm_option, _, lock_option, _, _)| {
                 DropIndexStatement {
+                    if_exists,
                     index_name,
                     table,
                     algorithm_option,
@@ -70,6 +79,22 @@ impl DropIndexStatement {
             },
         )(i)
     }
+
+    /// MariaDB `[IF EXISTS]`, never present when parsing MySQL
+    fn if_exists(i: &str) -> IResult<&str, bool, ParseSQLError<&str>> {
+        if !ParseConfig::with_current(|config| config.mariadb) {
+            return Ok((i, false));
+        }
+        map(
+            opt(tuple((
+                tag_no_case("IF"),
+                multispace1,
+                tag_no_case("EXISTS"),
+                multispace1,
+            ))),
+            |x| x.is_some(),
+        )(i)
+    }
 }
 
 #[cfg(test)]
@@ -89,24 +114,28 @@ mod tests {
         ];
         let exp_statements = [
             DropIndexStatement {
+                if_exists: false,
                 index_name: "agent_id_index".to_string(),
                 table: "tbl_name".into(),
                 algorithm_option: None,
                 lock_option: None,
             },
             DropIndexStatement {
+                if_exists: false,
                 index_name: "agent_id_index".to_string(),
                 table: ("db_name", "tbl_name").into(),
                 algorithm_option: Some(AlgorithmType::Copy),
                 lock_option: None,
             },
             DropIndexStatement {
+                if_exists: false,
                 index_name: "IX_brand_id".to_string(),
                 table: "tbl_name".into(),
                 algorithm_option: None,
                 lock_option: Some(LockType::Default),
             },
             DropIndexStatement {
+                if_exists: false,
                 index_name: "IX_brand_id".to_string(),
                 table: ("db_name", "tbl_name").into(),
                 algorithm_option: Some(AlgorithmType::Copy),
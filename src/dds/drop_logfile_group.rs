@@ -46,9 +46,9 @@ impl DropLogfileGroupStatement {
 
 impl fmt::Display for DropLogfileGroupStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP LOGFILE GROUP")?;
-        write!(f, " {}", self.logfile_group)?;
-        write!(f, " ENGINE = {}", self.engine_name)?;
+        write_sql!(f, "DROP LOGFILE GROUP")?;
+        write_sql!(f, " {}", self.logfile_group)?;
+        write_sql!(f, " ENGINE = {}", self.engine_name)?;
         Ok(())
     }
 }
@@ -80,4 +80,24 @@ mod tests {
             assert_eq!(res.unwrap().1, exp_statements[i])
         }
     }
+
+    #[test]
+    fn drop_logfile_group_engine_round_trip() {
+        let sqls = [
+            (
+                "DROP LOGFILE GROUP lg ENGINE = NDB",
+                "DROP LOGFILE GROUP lg ENGINE = NDB",
+            ),
+            (
+                "DROP LOGFILE GROUP lg ENGINE=NDB;",
+                "DROP LOGFILE GROUP lg ENGINE = NDB",
+            ),
+        ];
+        for (sql, exp) in sqls {
+            let res = DropLogfileGroupStatement::parse(sql);
+            assert_eq!(res.unwrap().1.to_string(), exp);
+        }
+        // the engine is required
+        assert!(DropLogfileGroupStatement::parse("DROP LOGFILE GROUP lg").is_err());
+    }
 }
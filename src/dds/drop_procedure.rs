@@ -38,11 +38,11 @@ impl DropProcedureStatement {
 
 impl fmt::Display for DropProcedureStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP PROCEDURE")?;
+        write_sql!(f, "DROP PROCEDURE")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
-        write!(f, " {}", self.sp_name)?;
+        write_sql!(f, " {}", self.sp_name)?;
         Ok(())
     }
 }
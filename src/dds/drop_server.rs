@@ -39,11 +39,11 @@ impl DropServerStatement {
 
 impl fmt::Display for DropServerStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP SERVER")?;
+        write_sql!(f, "DROP SERVER")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
-        write!(f, " {}", self.server_name)?;
+        write_sql!(f, " {}", self.server_name)?;
         Ok(())
     }
 }
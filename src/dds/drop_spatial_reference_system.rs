@@ -47,11 +47,11 @@ impl DropSpatialReferenceSystemStatement {
 
 impl fmt::Display for DropSpatialReferenceSystemStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP SPATIAL REFERENCE SYSTEM")?;
+        write_sql!(f, "DROP SPATIAL REFERENCE SYSTEM")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
-        write!(f, " {}", self.srid)?;
+        write_sql!(f, " {}", self.srid)?;
         Ok(())
     }
 }
@@ -79,28 +79,28 @@ impl DropTableStatement {
 
 impl fmt::Display for DropTableStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP")?;
+        write_sql!(f, "DROP")?;
         if self.if_temporary {
-            write!(f, "TEMPORARY ")?;
+            write_sql!(f, " TEMPORARY")?;
         }
-        write!(f, " TABLE")?;
+        write_sql!(f, " TABLE")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
 
         let table_name = self
             .tables
             .iter()
-            .map(|x| x.name.clone())
+            .map(|x| x.to_string())
             .collect::<Vec<String>>()
             .join(", ");
-        write!(f, " {}", table_name)?;
+        write_sql!(f, " {}", table_name)?;
 
         if self.if_restrict {
-            write!(f, " RESTRICT")?;
+            write_sql!(f, " RESTRICT")?;
         }
         if self.if_cascade {
-            write!(f, " CASCADE")?;
+            write_sql!(f, " CASCADE")?;
         }
         Ok(())
     }
@@ -291,4 +291,19 @@ mod tests {
             assert_eq!(res.unwrap().1, exp_statements[i]);
         }
     }
+
+    #[test]
+    fn display_drop_table() {
+        let sqls = [
+            (
+                "drop temporary table if exists foo.tbl_name1, `order` cascade",
+                "DROP TEMPORARY TABLE IF EXISTS foo.tbl_name1, `order` CASCADE",
+            ),
+            ("DROP TABLE tbl_name", "DROP TABLE tbl_name"),
+        ];
+        for (sql, exp) in sqls {
+            let res = DropTableStatement::parse(sql);
+            assert_eq!(res.unwrap().1.to_string(), exp);
+        }
+    }
 }
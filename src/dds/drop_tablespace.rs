@@ -3,9 +3,9 @@ use std::fmt::Formatter;
 use std::str;
 
 use nom::bytes::complete::tag_no_case;
-use nom::character::complete::multispace0;
+use nom::character::complete::multispace1;
 use nom::combinator::{map, opt};
-use nom::sequence::tuple;
+use nom::sequence::{preceded, terminated, tuple};
 use nom::IResult;
 
 use base::error::ParseSQLError;
@@ -22,19 +22,18 @@ pub struct DropTablespaceStatement {
 impl DropTablespaceStatement {
     pub fn parse(i: &str) -> IResult<&str, DropTablespaceStatement, ParseSQLError<&str>> {
         let mut parser = tuple((
-            tag_no_case("DROP "),
-            multispace0,
-            opt(tag_no_case("UNDO")),
-            multispace0,
-            tag_no_case("TABLESPACE "),
-            multispace0,
+            tag_no_case("DROP"),
+            multispace1,
+            opt(terminated(tag_no_case("UNDO"), multispace1)),
+            tag_no_case("TABLESPACE"),
+            multispace1,
             map(CommonParser::sql_identifier, String::from),
-            multispace0,
-            opt(|x| CommonParser::parse_string_value_with_key(x, "ENGINE".to_string())),
+            opt(preceded(multispace1, |x| {
+                CommonParser::parse_string_value_with_key(x, "ENGINE".to_string())
+            })),
             CommonParser::statement_terminator,
         ));
-        let (remaining_input, (_, _, opt_undo, _, _, _, tablespace_name, _, engine_name, _)) =
-            parser(i)?;
+        let (remaining_input, (_, _, opt_undo, _, _, tablespace_name, engine_name, _)) = parser(i)?;
 
         Ok((
             remaining_input,
@@ -49,14 +48,14 @@ impl DropTablespaceStatement {
 
 impl fmt::Display for DropTablespaceStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP")?;
+        write_sql!(f, "DROP")?;
         if self.undo {
-            write!(f, " UNDO")?;
+            write_sql!(f, " UNDO")?;
         }
-        write!(f, " TABLESPACE")?;
-        write!(f, " {}", self.tablespace_name)?;
+        write_sql!(f, " TABLESPACE")?;
+        write_sql!(f, " {}", self.tablespace_name)?;
         if let Some(ref engine_name) = self.engine_name {
-            write!(f, " ENGINE = {}", engine_name)?;
+            write_sql!(f, " ENGINE = {}", engine_name)?;
         }
         Ok(())
     }
@@ -103,5 +102,29 @@ mod tests {
             assert!(res.is_ok());
             assert_eq!(res.unwrap().1, exp_statements[i])
         }
+
+        assert!(DropTablespaceStatement::parse("DROP UNDOTABLESPACE tablespace_name").is_err());
+    }
+
+    #[test]
+    fn drop_tablespace_engine_round_trip() {
+        let sqls = [
+            (
+                "DROP TABLESPACE ts ENGINE = InnoDB",
+                "DROP TABLESPACE ts ENGINE = InnoDB",
+            ),
+            (
+                "DROP TABLESPACE ts ENGINE InnoDB",
+                "DROP TABLESPACE ts ENGINE = InnoDB",
+            ),
+            (
+                "DROP UNDO TABLESPACE ts ENGINE=InnoDB;",
+                "DROP UNDO TABLESPACE ts ENGINE = InnoDB",
+            ),
+        ];
+        for (sql, exp) in sqls {
+            let res = DropTablespaceStatement::parse(sql);
+            assert_eq!(res.unwrap().1.to_string(), exp);
+        }
     }
 }
@@ -44,11 +44,11 @@ impl DropTriggerStatement {
 
 impl fmt::Display for DropTriggerStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP TRIGGER")?;
+        write_sql!(f, "DROP TRIGGER")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
-        write!(f, " {}", self.trigger_name)?;
+        write_sql!(f, " {}", self.trigger_name)?;
         Ok(())
     }
 }
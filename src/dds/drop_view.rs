@@ -5,13 +5,13 @@ use std::str;
 use nom::bytes::complete::tag_no_case;
 use nom::character::complete::multispace0;
 use nom::character::complete::multispace1;
-use nom::combinator::{map, opt};
+use nom::combinator::opt;
 use nom::multi::many0;
 use nom::sequence::{delimited, terminated, tuple};
 use nom::IResult;
 
 use base::error::ParseSQLError;
-use base::CommonParser;
+use base::{CommonParser, Table};
 
 /// parse `DROP VIEW [IF EXISTS]
 ///     view_name [, view_name] ...
@@ -20,7 +20,7 @@ use base::CommonParser;
 pub struct DropViewStatement {
     pub if_exists: bool,
     /// A name of a table, view, custom type, etc., possibly multipart, i.e. db.schema.obj
-    pub views: Vec<String>,
+    pub views: Vec<Table>,
     pub if_restrict: bool,
     pub if_cascade: bool,
 }
@@ -36,13 +36,10 @@ impl DropViewStatement {
             tag_no_case("VIEW "),
             CommonParser::parse_if_exists,
             multispace0,
-            map(
-                many0(terminated(
-                    CommonParser::sql_identifier,
-                    opt(CommonParser::ws_sep_comma),
-                )),
-                |x| x.iter().map(|v| String::from(*v)).collect::<Vec<String>>(),
-            ),
+            many0(terminated(
+                Table::without_alias,
+                opt(CommonParser::ws_sep_comma),
+            )),
             opt(delimited(multispace1, tag_no_case("RESTRICT"), multispace0)),
             opt(delimited(multispace1, tag_no_case("CASCADE"), multispace0)),
             CommonParser::statement_terminator,
@@ -66,19 +63,24 @@ impl DropViewStatement {
 
 impl fmt::Display for DropViewStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "DROP VIEW")?;
+        write_sql!(f, "DROP VIEW")?;
         if self.if_exists {
-            write!(f, " IF EXISTS")?;
+            write_sql!(f, " IF EXISTS")?;
         }
 
-        let view_name = self.views.join(", ");
-        write!(f, " {}", view_name)?;
+        let view_name = self
+            .views
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<String>>()
+            .join(", ");
+        write_sql!(f, " {}", view_name)?;
 
         if self.if_restrict {
-            write!(f, " RESTRICT")?;
+            write_sql!(f, " RESTRICT")?;
         }
         if self.if_cascade {
-            write!(f, " CASCADE")?;
+            write_sql!(f, " CASCADE")?;
         }
         Ok(())
     }
@@ -86,6 +88,7 @@ impl fmt::Display for DropViewStatement {
 
 #[cfg(test)]
 mod tests {
+    use base::Table;
     use dds::drop_view::DropViewStatement;
 
     #[test]
@@ -101,31 +104,31 @@ mod tests {
         let exp_statements = [
             DropViewStatement {
                 if_exists: false,
-                views: vec!["view_name".to_string()],
+                views: vec![Table::from("view_name")],
                 if_restrict: false,
                 if_cascade: false,
             },
             DropViewStatement {
                 if_exists: true,
-                views: vec!["view_name".to_string()],
+                views: vec![Table::from("view_name")],
                 if_restrict: false,
                 if_cascade: false,
             },
             DropViewStatement {
                 if_exists: false,
-                views: vec!["view_name".to_string()],
+                views: vec![Table::from("view_name")],
                 if_restrict: false,
                 if_cascade: true,
             },
             DropViewStatement {
                 if_exists: false,
-                views: vec!["view_name1".to_string(), "view_name2".to_string()],
+                views: vec![Table::from("view_name1"), Table::from("view_name2")],
                 if_restrict: false,
                 if_cascade: false,
             },
             DropViewStatement {
                 if_exists: false,
-                views: vec!["view_name1".to_string(), "view_name2".to_string()],
+                views: vec![Table::from("view_name1"), Table::from("view_name2")],
                 if_restrict: true,
                 if_cascade: false,
             },
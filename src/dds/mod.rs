@@ -1,7 +1,21 @@
 pub use dds::alter_database::AlterDatabaseStatement;
+pub use dds::alter_event::AlterEventStatement;
+pub use dds::alter_server::AlterServerStatement;
 pub use dds::alter_table::AlterTableStatement;
+pub use dds::create_event::{
+    CreateEventStatement, EventSchedule, EventStatus, EventTimestamp, Interval, IntervalUnit,
+};
 pub use dds::create_index::CreateIndexStatement;
-pub use dds::create_table::CreateTableStatement;
+pub use dds::create_logfile_group::CreateLogfileGroupStatement;
+pub use dds::create_routine::{
+    CreateRoutineStatement, ParamMode, RoutineCharacteristic, RoutineParam, RoutineType,
+};
+pub use dds::create_server::{CreateServerStatement, ServerOption};
+pub use dds::create_spatial_reference_system::{
+    CreateSpatialReferenceSystemStatement, SrsAttribute,
+};
+pub use dds::create_table::{ColumnSummary, CreateTableStatement, ForeignKeySummary, TableSummary};
+pub use dds::create_tablespace::{CreateTablespaceStatement, TablespaceOption};
 pub use dds::drop_database::DropDatabaseStatement;
 pub use dds::drop_event::DropEventStatement;
 pub use dds::drop_function::DropFunctionStatement;
@@ -20,6 +34,7 @@ pub use dds::truncate_table::TruncateTableStatement;
 mod alter_database;
 mod alter_table;
 mod create_index;
+mod create_routine;
 mod create_table;
 mod drop_database;
 mod drop_index;
@@ -31,6 +46,10 @@ mod drop_view;
 
 mod drop_trigger;
 
+mod alter_server;
+mod create_server;
+mod create_spatial_reference_system;
+mod create_tablespace;
 mod drop_server;
 mod drop_spatial_reference_system;
 mod drop_tablespace;
@@ -38,6 +57,9 @@ mod drop_tablespace;
 mod drop_function;
 mod drop_procedure;
 
+mod create_logfile_group;
 mod drop_logfile_group;
 
+mod alter_event;
+mod create_event;
 mod drop_event;
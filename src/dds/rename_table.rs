@@ -47,24 +47,14 @@ impl RenameTableStatement {
 
 impl fmt::Display for RenameTableStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "RENAME TABLE ")?;
+        write_sql!(f, "RENAME TABLE ")?;
         let table_name = self
             .tables
             .iter()
-            .map(|(x, y)| {
-                let old = match &x.schema {
-                    Some(schema) => format!("{}.{}", schema, x.name),
-                    None => x.name.clone(),
-                };
-                let new = match &y.schema {
-                    Some(schema) => format!("{}.{}", schema, y.name),
-                    None => y.name.clone(),
-                };
-                format!("{} TO {}", old, new)
-            })
+            .map(|(old, new)| format_sql!("{} TO {}", old, new))
             .collect::<Vec<String>>()
             .join(", ");
-        write!(f, "{}", table_name)?;
+        write_sql!(f, "{}", table_name)?;
         Ok(())
     }
 }
@@ -90,11 +80,13 @@ mod tests {
                 name: String::from("tbl_name1"),
                 alias: None,
                 schema: None,
+                tablesample: None,
             },
             Table {
                 name: String::from("tbl_name2"),
                 alias: None,
                 schema: None,
+                tablesample: None,
             },
         )];
 
@@ -103,11 +95,13 @@ mod tests {
                 name: String::from("tbl_name1"),
                 alias: None,
                 schema: Some(String::from("db1")),
+                tablesample: None,
             },
             Table {
                 name: String::from("tbl_name2"),
                 alias: None,
                 schema: Some(String::from("db2")),
+                tablesample: None,
             },
         )];
 
@@ -117,11 +111,13 @@ mod tests {
                     name: String::from("tbl_name1"),
                     alias: None,
                     schema: None,
+                    tablesample: None,
                 },
                 Table {
                     name: String::from("tbl_name2"),
                     alias: None,
                     schema: None,
+                    tablesample: None,
                 },
             ),
             (
@@ -129,11 +125,13 @@ mod tests {
                     name: String::from("tbl_name3"),
                     alias: None,
                     schema: None,
+                    tablesample: None,
                 },
                 Table {
                     name: String::from("tbl_name4"),
                     alias: None,
                     schema: None,
+                    tablesample: None,
                 },
             ),
         ];
@@ -144,11 +142,13 @@ mod tests {
                     name: String::from("tbl_name1"),
                     alias: None,
                     schema: Some(String::from("db1")),
+                    tablesample: None,
                 },
                 Table {
                     name: String::from("tbl_name2"),
                     alias: None,
                     schema: Some(String::from("db2")),
+                    tablesample: None,
                 },
             ),
             (
@@ -156,11 +156,13 @@ mod tests {
                     name: String::from("tbl_name3"),
                     alias: None,
                     schema: Some(String::from("db3")),
+                    tablesample: None,
                 },
                 Table {
                     name: String::from("tbl_name4"),
                     alias: None,
                     schema: Some(String::from("db4")),
+                    tablesample: None,
                 },
             ),
         ];
@@ -182,11 +184,13 @@ mod tests {
                             name: String::from("tbl_name1"),
                             alias: None,
                             schema: Some(String::from("db1")),
+                            tablesample: None,
                         },
                         Table {
                             name: String::from("tbl_name2"),
                             alias: None,
                             schema: Some(String::from("db2")),
+                            tablesample: None,
                         },
                     ),
                     (
@@ -194,11 +198,13 @@ mod tests {
                             name: String::from("tbl_name3"),
                             alias: None,
                             schema: None,
+                            tablesample: None,
                         },
                         Table {
                             name: String::from("tbl_name4"),
                             alias: None,
                             schema: None,
+                            tablesample: None,
                         },
                     ),
                 ],
@@ -210,11 +216,13 @@ mod tests {
                             name: String::from("tbl_name1"),
                             alias: None,
                             schema: None,
+                            tablesample: None,
                         },
                         Table {
                             name: String::from("tbl_name2"),
                             alias: None,
                             schema: None,
+                            tablesample: None,
                         },
                     ),
                     (
@@ -222,11 +230,13 @@ mod tests {
                             name: String::from("tbl_name3"),
                             alias: None,
                             schema: Some(String::from("db3")),
+                            tablesample: None,
                         },
                         Table {
                             name: String::from("tbl_name4"),
                             alias: None,
                             schema: Some(String::from("db4")),
+                            tablesample: None,
                         },
                     ),
                 ],
@@ -242,4 +252,11 @@ mod tests {
             assert_eq!(res.unwrap().1, good_statements[i]);
         }
     }
+
+    #[test]
+    fn display_rename_table() {
+        let sql = "RENAME TABLE db1.`order` TO db2.orders, `my table` TO t";
+        let res = RenameTableStatement::parse(sql);
+        assert_eq!(res.unwrap().1.to_string(), sql);
+    }
 }
@@ -36,12 +36,7 @@ impl TruncateTableStatement {
 
 impl fmt::Display for TruncateTableStatement {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "TRUNCATE TABLE ")?;
-        if self.table.schema.is_some() {
-            write!(f, "{}.", self.table.schema.clone().unwrap())?;
-        }
-        write!(f, " {}", self.table.name.clone())?;
-        Ok(())
+        write_sql!(f, "TRUNCATE TABLE {}", self.table)
     }
 }
 
@@ -62,7 +57,7 @@ mod tests {
         let table_name = Table::from("table_name");
         let table_name_with_schema = Table::from(("db_name", "table_name"));
 
-        let exp_statements = vec![
+        let exp_statements = [
             TruncateTableStatement {
                 table: table_name.clone(),
             },
@@ -83,4 +78,19 @@ mod tests {
             assert_eq!(res.unwrap().1, exp_statements[i]);
         }
     }
+
+    #[test]
+    fn display_truncate_table() {
+        let sqls = [
+            (
+                "truncate db_name.table_name",
+                "TRUNCATE TABLE db_name.table_name",
+            ),
+            ("TRUNCATE TABLE `order`", "TRUNCATE TABLE `order`"),
+        ];
+        for (sql, exp) in sqls {
+            let res = TruncateTableStatement::parse(sql);
+            assert_eq!(res.unwrap().1.to_string(), exp);
+        }
+    }
 }
@@ -6,7 +6,7 @@ use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{multispace0, multispace1};
 use nom::combinator::{map, opt};
 use nom::multi::many1;
-use nom::sequence::{delimited, preceded, tuple};
+use nom::sequence::{delimited, pair, preceded, terminated, tuple};
 use nom::IResult;
 
 use base::error::ParseSQLError;
@@ -16,7 +16,7 @@ use dms::select::{LimitClause, SelectStatement};
 // TODO 用于 create 语句的 select
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub struct CompoundSelectStatement {
-    pub selects: Vec<(Option<CompoundSelectOperator>, SelectStatement)>,
+    pub selects: Vec<(Option<CompoundSelectOperator>, CompoundSelectOperand)>,
     pub order: Option<OrderClause>,
     pub limit: Option<LimitClause>,
 }
@@ -24,17 +24,40 @@ pub struct CompoundSelectStatement {
 impl CompoundSelectStatement {
     // Parse compound selection
     pub fn parse(i: &str) -> IResult<&str, CompoundSelectStatement, ParseSQLError<&str>> {
-        let (remaining_input, (first_select, other_selects, _, order, limit, _)) = tuple((
-            CommonParser::opt_delimited(tag("("), SelectStatement::nested_selection, tag(")")),
+        terminated(Self::nested_compound, CommonParser::statement_terminator)(i)
+    }
+
+    /// compound selection without the statement terminator, so it can be parenthesized
+    fn nested_compound(i: &str) -> IResult<&str, CompoundSelectStatement, ParseSQLError<&str>> {
+        CommonParser::nested(Self::compound)(i)
+    }
+
+    fn compound(i: &str) -> IResult<&str, CompoundSelectStatement, ParseSQLError<&str>> {
+        let (remaining_input, ((first_select, _), other_selects, _, order, limit)) = tuple((
+            CompoundSelectOperand::parse,
             many1(Self::other_selects),
             multispace0,
             opt(OrderClause::parse),
             opt(LimitClause::parse),
-            CommonParser::statement_terminator,
         ))(i)?;
 
         let mut selects = vec![(None, first_select)];
-        selects.extend(other_selects);
+        let mut order = order;
+        let mut limit = limit;
+        let last_parenthesized = other_selects.last().is_some_and(|x| x.2);
+        selects.extend(
+            other_selects
+                .into_iter()
+                .map(|(op, select, _)| (op, select)),
+        );
+        // a trailing ORDER BY or LIMIT belongs to the whole compound statement
+        // unless the last SELECT is parenthesized
+        if !last_parenthesized {
+            if let Some((_, CompoundSelectOperand::Select(last))) = selects.last_mut() {
+                order = order.or_else(|| last.order.take());
+                limit = limit.or_else(|| last.limit.take());
+            }
+        }
 
         Ok((
             remaining_input,
@@ -46,42 +69,102 @@ impl CompoundSelectStatement {
         ))
     }
 
+    /// `op operand`, and whether the operand is parenthesized
     fn other_selects(
         i: &str,
-    ) -> IResult<&str, (Option<CompoundSelectOperator>, SelectStatement), ParseSQLError<&str>> {
-        let (remaining_input, (_, op, _, select)) = tuple((
+    ) -> IResult<
+        &str,
+        (Option<CompoundSelectOperator>, CompoundSelectOperand, bool),
+        ParseSQLError<&str>,
+    > {
+        let (remaining_input, (_, op, _, (select, parenthesized))) = tuple((
             multispace0,
             CompoundSelectOperator::parse,
             multispace1,
-            CommonParser::opt_delimited(
-                tag("("),
-                delimited(multispace0, SelectStatement::nested_selection, multispace0),
-                tag(")"),
-            ),
+            CompoundSelectOperand::parse,
         ))(i)?;
 
-        Ok((remaining_input, (Some(op), select)))
+        Ok((remaining_input, (Some(op), select, parenthesized)))
     }
 }
 
 impl fmt::Display for CompoundSelectStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        for (ref op, ref sel) in &self.selects {
-            if op.is_some() {
-                write!(f, " {}", op.as_ref().unwrap())?;
+        for (i, (ref op, ref operand)) in self.selects.iter().enumerate() {
+            if i > 0 {
+                write_sql!(f, " ")?;
             }
-            write!(f, " {}", sel)?;
+            if let Some(ref op) = *op {
+                write_sql!(f, "{} ", op)?;
+            }
+            write_sql!(f, "{}", operand)?;
         }
-        if self.order.is_some() {
-            write!(f, " {}", self.order.as_ref().unwrap())?;
+        if let Some(ref order) = self.order {
+            write_sql!(f, " {}", order)?;
         }
-        if self.limit.is_some() {
-            write!(f, " {}", self.order.as_ref().unwrap())?;
+        if let Some(ref limit) = self.limit {
+            write_sql!(f, " {}", limit)?;
         }
         Ok(())
     }
 }
 
+/// an operand of a compound selection: a SELECT, or a parenthesized compound selection
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+pub enum CompoundSelectOperand {
+    Select(SelectStatement),
+    Compound(Box<CompoundSelectStatement>),
+}
+
+impl CompoundSelectOperand {
+    /// parse an operand, and whether it is parenthesized
+    fn parse(i: &str) -> IResult<&str, (CompoundSelectOperand, bool), ParseSQLError<&str>> {
+        alt((
+            map(
+                delimited(
+                    pair(tag("("), multispace0),
+                    CompoundSelectStatement::nested_compound,
+                    pair(multispace0, tag(")")),
+                ),
+                |compound| (CompoundSelectOperand::Compound(Box::new(compound)), true),
+            ),
+            map(
+                delimited(
+                    pair(tag("("), multispace0),
+                    SelectStatement::nested_selection,
+                    pair(multispace0, tag(")")),
+                ),
+                |select| (CompoundSelectOperand::Select(select), true),
+            ),
+            map(SelectStatement::nested_selection, |select| {
+                (CompoundSelectOperand::Select(select), false)
+            }),
+        ))(i)
+    }
+}
+
+impl From<SelectStatement> for CompoundSelectOperand {
+    fn from(select: SelectStatement) -> Self {
+        CompoundSelectOperand::Select(select)
+    }
+}
+
+impl fmt::Display for CompoundSelectOperand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            // ORDER BY or LIMIT of a single SELECT needs parentheses
+            CompoundSelectOperand::Select(ref select)
+                if select.order.is_some() || select.limit.is_some() =>
+            {
+                write_sql!(f, "({})", select)
+            }
+            CompoundSelectOperand::Select(ref select) => write_sql!(f, "{}", select),
+            CompoundSelectOperand::Compound(ref compound) => write_sql!(f, "({})", compound),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
 pub enum CompoundSelectOperator {
     Union,
@@ -128,10 +211,10 @@ impl CompoundSelectOperator {
 impl fmt::Display for CompoundSelectOperator {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            CompoundSelectOperator::Union => write!(f, "UNION"),
-            CompoundSelectOperator::DistinctUnion => write!(f, "UNION DISTINCT"),
-            CompoundSelectOperator::Intersect => write!(f, "INTERSECT"),
-            CompoundSelectOperator::Except => write!(f, "EXCEPT"),
+            CompoundSelectOperator::Union => write_sql!(f, "UNION ALL"),
+            CompoundSelectOperator::DistinctUnion => write_sql!(f, "UNION DISTINCT"),
+            CompoundSelectOperator::Intersect => write_sql!(f, "INTERSECT"),
+            CompoundSelectOperator::Except => write_sql!(f, "EXCEPT"),
         }
     }
 }
@@ -9,7 +9,7 @@ use nom::IResult;
 use base::condition::ConditionExpression;
 use base::error::ParseSQLError;
 use base::table::Table;
-use base::{CommonParser, DisplayUtil};
+use base::CommonParser;
 
 // FIXME TODO
 /// `DELETE [LOW_PRIORITY] [QUICK] [IGNORE] FROM tbl_name [[AS] tbl_alias]
@@ -43,13 +43,23 @@ impl DeleteStatement {
     }
 }
 
+impl DeleteStatement {
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn where_clause(&self) -> Option<&ConditionExpression> {
+        self.where_clause.as_ref()
+    }
+}
+
 impl fmt::Display for DeleteStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "DELETE FROM ")?;
-        write!(f, "{}", DisplayUtil::escape_if_keyword(&self.table.name))?;
+        write_sql!(f, "DELETE FROM ")?;
+        write_sql!(f, "{}", self.table)?;
         if let Some(ref where_clause) = self.where_clause {
-            write!(f, " WHERE ")?;
-            write!(f, "{}", where_clause)?;
+            write_sql!(f, " WHERE ")?;
+            write_sql!(f, "{}", where_clause)?;
         }
         Ok(())
     }
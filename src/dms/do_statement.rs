@@ -0,0 +1,70 @@
+use std::{fmt, str};
+
+use nom::bytes::complete::tag_no_case;
+use nom::character::complete::multispace1;
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, terminated};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::{CommonParser, FieldValueExpression};
+
+/// parse `DO expr [, expr] ...`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct DoStatement {
+    pub exprs: Vec<FieldValueExpression>,
+}
+
+impl DoStatement {
+    pub fn parse(i: &str) -> IResult<&str, DoStatement, ParseSQLError<&str>> {
+        let (remaining_input, exprs) = terminated(
+            preceded(
+                terminated(tag_no_case("DO"), multispace1),
+                separated_list1(CommonParser::ws_sep_comma, FieldValueExpression::parse),
+            ),
+            CommonParser::statement_terminator,
+        )(i)?;
+        Ok((remaining_input, DoStatement { exprs }))
+    }
+}
+
+impl fmt::Display for DoStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "DO ")?;
+        write_sql!(
+            f,
+            "{}",
+            self.exprs
+                .iter()
+                .map(|expr| expr.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base::{AssignmentExpression, FieldValueExpression, Literal, LiteralExpression};
+    use dms::DoStatement;
+
+    #[test]
+    fn do_sleep_and_assignment() {
+        let str = "DO SLEEP(1), @x := 2;";
+        let res = DoStatement::parse(str).unwrap().1;
+        assert_eq!(res.exprs.len(), 2);
+        assert_eq!(
+            res.exprs[1],
+            FieldValueExpression::Assignment(AssignmentExpression {
+                variable: "@x".to_owned(),
+                value: Box::new(FieldValueExpression::Literal(LiteralExpression {
+                    value: Literal::Integer(2),
+                    alias: None,
+                })),
+                alias: None,
+            })
+        );
+        assert_eq!(res.to_string(), "DO SLEEP(1), @x := 2");
+        assert_eq!(DoStatement::parse(&res.to_string()).unwrap().1, res);
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case};
+use nom::character::complete::{multispace0, multispace1};
+use nom::combinator::{map, opt, value};
+use nom::sequence::{delimited, preceded, terminated, tuple};
+use nom::IResult;
+
+use base::error::ParseSQLError;
+use base::CommonParser;
+use dms::{
+    CompoundSelectStatement, DeleteStatement, InsertStatement, SelectStatement, UpdateStatement,
+};
+
+/// parse `{EXPLAIN | DESCRIBE | DESC} [FORMAT = {TRADITIONAL | JSON | TREE}]
+///     {explainable_stmt | FOR CONNECTION connection_id}`
+///
+/// `explainable_stmt: {
+///     SELECT statement
+///   | DELETE statement
+///   | INSERT statement
+///   | UPDATE statement
+/// }`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ExplainStatement {
+    pub format: Option<ExplainFormat>,
+    pub target: ExplainTarget,
+}
+
+impl ExplainStatement {
+    pub fn parse(i: &str) -> IResult<&str, ExplainStatement, ParseSQLError<&str>> {
+        map(
+            tuple((
+                terminated(
+                    alt((
+                        tag_no_case("EXPLAIN"),
+                        tag_no_case("DESCRIBE"),
+                        tag_no_case("DESC"),
+                    )),
+                    multispace1,
+                ),
+                opt(terminated(
+                    preceded(
+                        tuple((tag_no_case("FORMAT"), multispace0, tag("="), multispace0)),
+                        ExplainFormat::parse,
+                    ),
+                    multispace1,
+                )),
+                ExplainTarget::parse,
+            )),
+            |(_, format, target)| ExplainStatement { format, target },
+        )(i)
+    }
+}
+
+impl fmt::Display for ExplainStatement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "EXPLAIN")?;
+        if let Some(ref format) = self.format {
+            write_sql!(f, " FORMAT={}", format)?;
+        }
+        write_sql!(f, " {}", self.target)
+    }
+}
+
+/// `TRADITIONAL | JSON | TREE`
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ExplainFormat {
+    Traditional,
+    Json,
+    Tree,
+}
+
+impl ExplainFormat {
+    fn parse(i: &str) -> IResult<&str, ExplainFormat, ParseSQLError<&str>> {
+        alt((
+            value(ExplainFormat::Traditional, tag_no_case("TRADITIONAL")),
+            value(ExplainFormat::Json, tag_no_case("JSON")),
+            value(ExplainFormat::Tree, tag_no_case("TREE")),
+        ))(i)
+    }
+}
+
+impl fmt::Display for ExplainFormat {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExplainFormat::Traditional => write_sql!(f, "TRADITIONAL"),
+            ExplainFormat::Json => write_sql!(f, "JSON"),
+            ExplainFormat::Tree => write_sql!(f, "TREE"),
+        }
+    }
+}
+
+/// the statement to explain, or the connection whose running statement to explain
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum ExplainTarget {
+    Select(SelectStatement),
+    CompoundSelect(CompoundSelectStatement),
+    Insert(InsertStatement),
+    Delete(DeleteStatement),
+    Update(UpdateStatement),
+    /// `FOR CONNECTION connection_id`
+    ForConnection(u64),
+}
+
+impl ExplainTarget {
+    fn parse(i: &str) -> IResult<&str, ExplainTarget, ParseSQLError<&str>> {
+        alt((
+            map(SelectStatement::parse, ExplainTarget::Select),
+            map(
+                CompoundSelectStatement::parse,
+                ExplainTarget::CompoundSelect,
+            ),
+            map(InsertStatement::parse, ExplainTarget::Insert),
+            map(DeleteStatement::parse, ExplainTarget::Delete),
+            map(UpdateStatement::parse, ExplainTarget::Update),
+            map(
+                delimited(
+                    tuple((
+                        tag_no_case("FOR"),
+                        multispace1,
+                        tag_no_case("CONNECTION"),
+                        multispace1,
+                    )),
+                    CommonParser::unsigned_number,
+                    CommonParser::statement_terminator,
+                ),
+                ExplainTarget::ForConnection,
+            ),
+        ))(i)
+    }
+}
+
+impl fmt::Display for ExplainTarget {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ExplainTarget::Select(ref select) => write_sql!(f, "{}", select),
+            ExplainTarget::CompoundSelect(ref compound) => write_sql!(f, "{}", compound),
+            ExplainTarget::Insert(ref insert) => write_sql!(f, "{}", insert),
+            ExplainTarget::Delete(ref delete) => write_sql!(f, "{}", delete),
+            ExplainTarget::Update(ref update) => write_sql!(f, "{}", update),
+            ExplainTarget::ForConnection(id) => write_sql!(f, "FOR CONNECTION {}", id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dms::explain::{ExplainFormat, ExplainStatement, ExplainTarget};
+
+    #[test]
+    fn explain_format_tree() {
+        let str = "EXPLAIN FORMAT=TREE SELECT 1";
+        let res = ExplainStatement::parse(str).unwrap().1;
+        assert_eq!(res.format, Some(ExplainFormat::Tree));
+        assert!(matches!(res.target, ExplainTarget::Select(_)));
+        assert_eq!(res.to_string(), str);
+
+        let str = "describe format = json UPDATE t SET a = 1;";
+        let res = ExplainStatement::parse(str).unwrap().1;
+        assert_eq!(res.format, Some(ExplainFormat::Json));
+        assert_eq!(res.to_string(), "EXPLAIN FORMAT=JSON UPDATE t SET a = 1");
+    }
+
+    #[test]
+    fn explain_for_connection() {
+        let str = "EXPLAIN FOR CONNECTION 5";
+        let res = ExplainStatement::parse(str);
+        let exp = ExplainStatement {
+            format: None,
+            target: ExplainTarget::ForConnection(5),
+        };
+        assert_eq!(res.unwrap().1, exp);
+        assert_eq!(exp.to_string(), str);
+
+        let str = "EXPLAIN FORMAT=TRADITIONAL FOR CONNECTION 42;";
+        let res = ExplainStatement::parse(str).unwrap().1;
+        assert_eq!(
+            res.to_string(),
+            "EXPLAIN FORMAT=TRADITIONAL FOR CONNECTION 42"
+        );
+    }
+}
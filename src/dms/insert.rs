@@ -1,51 +1,87 @@
 use std::fmt;
 use std::str;
 
+use nom::branch::alt;
 use nom::bytes::complete::{tag, tag_no_case};
 use nom::character::complete::{multispace0, multispace1};
-use nom::combinator::opt;
+use nom::combinator::{map, opt, value};
 use nom::multi::many1;
-use nom::sequence::{delimited, preceded, tuple};
+use nom::sequence::{delimited, pair, preceded, tuple};
 use nom::IResult;
 
 use base::column::Column;
-use base::error::ParseSQLError;
+use base::error::{ParseSQLError, ParseSQLErrorKind};
 use base::table::Table;
-use base::{CommonParser, DisplayUtil, FieldValueExpression, Literal};
+use base::{CommonParser, DisplayUtil, FieldDefinitionExpression, FieldValueExpression, Literal};
+use dms::SelectStatement;
 
+/// parse `{INSERT | REPLACE} [IGNORE] INTO tbl_name [(col_name [, col_name] ...)]
+///     {VALUES (value_list) [, (value_list)] ... [AS row_alias] | SELECT ...}
+///     [ON DUPLICATE KEY UPDATE assignment_list]`
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct InsertStatement {
     pub table: Table,
     pub fields: Option<Vec<Column>>,
+    /// rows of `VALUES`, empty when the rows come from [InsertStatement::select]
     pub data: Vec<Vec<Literal>>,
     pub ignore: bool,
     pub on_duplicate: Option<Vec<(Column, FieldValueExpression)>>,
+    /// `REPLACE` rather than `INSERT`
+    pub replace: bool,
+    /// `INSERT ... SELECT`
+    pub select: Option<SelectStatement>,
+    /// `VALUES (...) AS row_alias`, referenced as `row_alias.col` in `ON DUPLICATE KEY UPDATE`
+    pub row_alias: Option<String>,
 }
 
 impl InsertStatement {
     // Parse rule for a SQL insert query.
-    // TODO(malte): support REPLACE, nested selection, DEFAULT VALUES
+    // TODO(malte): support DEFAULT VALUES
     pub fn parse(i: &str) -> IResult<&str, InsertStatement, ParseSQLError<&str>> {
         let (
             remaining_input,
-            (_, ignore_res, _, _, _, table, _, fields, _, _, data, on_duplicate, _, _),
+            (
+                replace,
+                ignore_res,
+                _,
+                _,
+                _,
+                table,
+                _,
+                fields,
+                (data, row_alias, select),
+                on_duplicate,
+                _,
+                _,
+            ),
         ) = tuple((
-            tag_no_case("INSERT"),
+            alt((
+                value(false, tag_no_case("INSERT")),
+                value(true, tag_no_case("REPLACE")),
+            )),
             opt(preceded(multispace1, tag_no_case("IGNORE"))),
             multispace1,
             tag_no_case("INTO"),
             multispace1,
-            Table::schema_table_reference,
+            Table::without_alias,
             multispace0,
             opt(Self::fields),
-            tag_no_case("VALUES"),
-            multispace0,
-            many1(Self::data),
+            alt((
+                map(
+                    preceded(
+                        pair(tag_no_case("VALUES"), multispace0),
+                        pair(many1(Self::data), opt(Self::row_alias)),
+                    ),
+                    |(data, row_alias)| (data, row_alias, None),
+                ),
+                map(SelectStatement::nested_selection, |select| {
+                    (vec![], None, Some(select))
+                }),
+            )),
             opt(Self::on_duplicate),
             multispace0,
             CommonParser::statement_terminator,
         ))(i)?;
-        assert!(table.alias.is_none());
         let ignore = ignore_res.is_some();
 
         Ok((
@@ -56,10 +92,74 @@ impl InsertStatement {
                 data,
                 ignore,
                 on_duplicate,
+                replace,
+                select,
+                row_alias,
             },
         ))
     }
 
+    /// `AS row_alias` after the rows of `VALUES`
+    fn row_alias(i: &str) -> IResult<&str, String, ParseSQLError<&str>> {
+        map(
+            preceded(
+                tuple((multispace0, tag_no_case("AS"), multispace1)),
+                CommonParser::sql_identifier,
+            ),
+            String::from,
+        )(i)
+    }
+
+    /// Like [InsertStatement::parse], but fail when a column list is given and
+    /// a row of `VALUES`, or the field list of the `SELECT`, has a different
+    /// number of values. A `SELECT` projecting `*` is not checked.
+    pub fn parse_with_arity_check(i: &str) -> IResult<&str, InsertStatement, ParseSQLError<&str>> {
+        let (remaining_input, insert) = Self::parse(i)?;
+        match insert.arity_mismatch() {
+            None => Ok((remaining_input, insert)),
+            Some(message) => Err(nom::Err::Failure(ParseSQLError {
+                errors: vec![(i, ParseSQLErrorKind::Message(message))],
+            })),
+        }
+    }
+
+    /// Describe the first row, or the `SELECT`, whose number of values differs
+    /// from the column list, e.g. `3 columns but 2 values in row 1`.
+    fn arity_mismatch(&self) -> Option<String> {
+        let columns = self.fields.as_ref()?.len();
+        let count = |n: usize, noun: &str| match n {
+            1 => format!("1 {}", noun),
+            _ => format!("{} {}s", n, noun),
+        };
+        let mismatch = |values: usize, place: String| {
+            format!(
+                "column count doesn't match value count: {} but {} {}",
+                count(columns, "column"),
+                count(values, "value"),
+                place
+            )
+        };
+        match self.select {
+            Some(ref select) => {
+                let is_wildcard = |field: &FieldDefinitionExpression| {
+                    matches!(
+                        *field,
+                        FieldDefinitionExpression::All | FieldDefinitionExpression::AllInTable(_)
+                    )
+                };
+                if select.fields.iter().any(is_wildcard) || select.fields.len() == columns {
+                    return None;
+                }
+                Some(mismatch(select.fields.len(), String::from("in the SELECT")))
+            }
+            None => self
+                .data
+                .iter()
+                .position(|row| row.len() != columns)
+                .map(|idx| mismatch(self.data[idx].len(), format!("in row {}", idx + 1))),
+        }
+    }
+
     fn fields(i: &str) -> IResult<&str, Vec<Column>, ParseSQLError<&str>> {
         delimited(
             preceded(tag("("), multispace0),
@@ -70,9 +170,9 @@ impl InsertStatement {
 
     fn data(i: &str) -> IResult<&str, Vec<Literal>, ParseSQLError<&str>> {
         delimited(
-            tag("("),
+            pair(tag("("), multispace0),
             Literal::value_list,
-            preceded(tag(")"), opt(CommonParser::ws_sep_comma)),
+            tuple((multispace0, tag(")"), opt(CommonParser::ws_sep_comma))),
         )(i)
     }
 
@@ -97,38 +197,78 @@ impl InsertStatement {
     }
 }
 
+impl InsertStatement {
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn columns(&self) -> Option<&[Column]> {
+        self.fields.as_deref()
+    }
+
+    pub fn rows(&self) -> &[Vec<Literal>] {
+        &self.data
+    }
+
+    pub fn select(&self) -> Option<&SelectStatement> {
+        self.select.as_ref()
+    }
+}
+
 impl fmt::Display for InsertStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "INSERT INTO {}",
-            DisplayUtil::escape_if_keyword(&self.table.name)
-        )?;
+        match self.replace {
+            true => write_sql!(f, "REPLACE")?,
+            false => write_sql!(f, "INSERT")?,
+        }
+        if self.ignore {
+            write_sql!(f, " IGNORE")?;
+        }
+        write_sql!(f, " INTO {}", self.table)?;
         if let Some(ref fields) = self.fields {
-            write!(
+            write_sql!(
                 f,
                 " ({})",
                 fields
                     .iter()
-                    .map(|col| col.name.to_owned())
+                    .map(|col| DisplayUtil::escape_if_keyword(&col.name))
                     .collect::<Vec<_>>()
                     .join(", ")
             )?;
         }
-        write!(
-            f,
-            " VALUES {}",
-            self.data
-                .iter()
-                .map(|data| format!(
-                    "({})",
-                    data.iter()
-                        .map(|l| l.to_string())
-                        .collect::<Vec<_>>()
-                        .join(", ")
-                ))
-                .collect::<Vec<_>>()
-                .join(", ")
-        )
+        if let Some(ref select) = self.select {
+            write_sql!(f, " {}", select)?;
+        } else {
+            write_sql!(
+                f,
+                " VALUES {}",
+                self.data
+                    .iter()
+                    .map(|data| format!(
+                        "({})",
+                        data.iter()
+                            .map(|l| l.to_string())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+            if let Some(ref row_alias) = self.row_alias {
+                write_sql!(f, " AS {}", DisplayUtil::escape_if_keyword(row_alias))?;
+            }
+        }
+        if let Some(ref on_duplicate) = self.on_duplicate {
+            write_sql!(
+                f,
+                " ON DUPLICATE KEY UPDATE {}",
+                on_duplicate
+                    .iter()
+                    .map(|(col, value)| format!("{} = {}", col, value))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )?;
+        }
+        Ok(())
     }
 }
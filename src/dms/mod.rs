@@ -1,11 +1,20 @@
-pub use dms::compound_select::{CompoundSelectOperator, CompoundSelectStatement};
+pub use dms::compound_select::{
+    CompoundSelectOperand, CompoundSelectOperator, CompoundSelectStatement,
+};
 pub use dms::delete::DeleteStatement;
+pub use dms::do_statement::DoStatement;
+pub use dms::explain::{ExplainFormat, ExplainStatement, ExplainTarget};
 pub use dms::insert::InsertStatement;
-pub use dms::select::{BetweenAndClause, GroupByClause, LimitClause, SelectStatement};
+pub use dms::select::{
+    BetweenAndClause, GroupByClause, LimitClause, ProcedureClause, SelectStatement, TableReference,
+    TableSource,
+};
 pub use dms::update::UpdateStatement;
 
 mod compound_select;
 mod delete;
+mod do_statement;
+mod explain;
 mod insert;
 mod select;
 mod update;
@@ -1,81 +1,310 @@
 use std::fmt;
 use std::str;
 
-use nom::bytes::complete::{tag_no_case, take_till, take_until};
+use nom::branch::alt;
+use nom::bytes::complete::{tag, tag_no_case, take_till, take_until};
 use nom::character::complete::{multispace0, multispace1};
 use nom::combinator::{map, opt};
-use nom::multi::many0;
-use nom::sequence::{delimited, terminated, tuple};
+use nom::multi::{many0, separated_list0, separated_list1};
+use nom::sequence::{delimited, pair, preceded, separated_pair, terminated, tuple};
 use nom::IResult;
 
+use base::arithmetic::ArithmeticExpression;
 use base::column::Column;
-use base::condition::ConditionExpression;
+use base::condition::{ConditionBase, ConditionExpression, ConditionTree};
 use base::error::ParseSQLError;
 use base::table::Table;
 use base::{
-    CommonParser, FieldDefinitionExpression, JoinClause, JoinConstraint, JoinOperator,
-    JoinRightSide, OrderClause,
+    CommonParser, DisplayUtil, FieldDefinitionExpression, ItemPlaceholder, JoinClause,
+    JoinConstraint, JoinOperator, JoinRightSide, JsonTable, Literal, Operator, OrderClause,
+    WindowSpec,
 };
+use parser::ParseConfig;
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct SelectStatement {
-    pub tables: Vec<Table>,
+    /// comma-separated entries of the FROM clause, in the order they are written
+    pub tables: Vec<TableReference>,
     pub distinct: bool,
     pub fields: Vec<FieldDefinitionExpression>,
-    pub join: Vec<JoinClause>,
     pub where_clause: Option<ConditionExpression>,
     pub group_by: Option<GroupByClause>,
+    /// named windows of `WINDOW name AS (window_spec) [, name AS (window_spec)] ...`
+    pub window: Vec<(String, WindowSpec)>,
+    /// `QUALIFY condition`, only parsed when [crate::ParseConfig::qualify_clause] is set
+    pub qualify: Option<ConditionExpression>,
     pub order: Option<OrderClause>,
     pub limit: Option<LimitClause>,
+    /// legacy `PROCEDURE ANALYSE(...)`, removed in MySQL 8.0
+    pub procedure: Option<ProcedureClause>,
 }
 
 impl SelectStatement {
     // Parse rule for a SQL selection query.
     pub fn parse(i: &str) -> IResult<&str, SelectStatement, ParseSQLError<&str>> {
+        if let Some(simple) = Self::simple_selection(i) {
+            return Ok(simple);
+        }
         terminated(Self::nested_selection, CommonParser::statement_terminator)(i)
     }
 
+    /// Fast path for `SELECT {* | col [, col] ...} FROM tbl [WHERE col = value]`,
+    /// the shape of most point lookups, scanned without the backtracking of the
+    /// full grammar. Anything else, including reserved words used as names,
+    /// returns `None` and is left to [SelectStatement::nested_selection], which
+    /// gives the same result for every input accepted here.
+    fn simple_selection(i: &str) -> Option<(&str, SelectStatement)> {
+        let mut rest = Self::simple_keyword(i, "SELECT")?;
+        let mut fields = vec![];
+        if let Some(after_star) = rest.strip_prefix('*') {
+            fields.push(FieldDefinitionExpression::All);
+            rest = after_star;
+        } else {
+            loop {
+                let (after_name, name) = Self::simple_identifier(rest)?;
+                fields.push(FieldDefinitionExpression::Col(Column::from(name)));
+                rest = after_name;
+                match after_name
+                    .trim_start_matches(Self::is_space)
+                    .strip_prefix(',')
+                {
+                    Some(after_comma) => rest = after_comma.trim_start_matches(Self::is_space),
+                    None => break,
+                }
+            }
+        }
+
+        rest = Self::simple_keyword(Self::space1(rest)?, "FROM")?;
+        let (mut rest, name) = Self::simple_identifier(rest)?;
+        let table = Table {
+            name: String::from(name),
+            alias: None,
+            schema: None,
+            tablesample: None,
+        };
+
+        let mut where_clause = None;
+        if let Some(after_where) = Self::space1(rest).and_then(|r| Self::simple_keyword(r, "WHERE"))
+        {
+            let (after_name, name) = Self::simple_identifier(after_where)?;
+            let after_equal = after_name
+                .trim_start_matches(Self::is_space)
+                .strip_prefix('=')?
+                .trim_start_matches(Self::is_space);
+            let (after_value, value) = Self::simple_value(after_equal)?;
+            where_clause = Some(ConditionExpression::ComparisonOp(ConditionTree {
+                operator: Operator::Equal,
+                left: Box::new(ConditionExpression::Base(ConditionBase::Field(
+                    Column::from(name),
+                ))),
+                right: Box::new(ConditionExpression::Base(ConditionBase::Literal(value))),
+            }));
+            rest = after_value;
+        }
+
+        // an alias, a join or any other clause fails the terminator
+        let (remaining_input, _) = CommonParser::statement_terminator(rest).ok()?;
+        Some((
+            remaining_input,
+            SelectStatement {
+                tables: vec![table.into()],
+                fields,
+                where_clause,
+                ..Default::default()
+            },
+        ))
+    }
+
+    fn is_space(c: char) -> bool {
+        matches!(c, ' ' | '\t' | '\r' | '\n')
+    }
+
+    // at least one whitespace, as `multispace1`
+    fn space1(i: &str) -> Option<&str> {
+        let rest = i.trim_start_matches(Self::is_space);
+        (rest.len() < i.len()).then_some(rest)
+    }
+
+    // `keyword` followed by at least one whitespace
+    fn simple_keyword<'a>(i: &'a str, keyword: &str) -> Option<&'a str> {
+        let word = i.get(..keyword.len())?;
+        if !word.eq_ignore_ascii_case(keyword) {
+            return None;
+        }
+        Self::space1(&i[keyword.len()..])
+    }
+
+    // an unquoted name that no other rule of the full grammar could claim
+    fn simple_identifier(i: &str) -> Option<(&str, &str)> {
+        if !i.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            return None;
+        }
+        let end = i
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or(i.len());
+        let (word, rest) = i.split_at(end);
+        let boundary = rest.is_empty() || rest.starts_with(|c| Self::is_space(c) || c == ',');
+        let boundary = boundary || rest.starts_with(['=', ';', '\\']);
+        if !boundary || CommonParser::is_reserved_word(word) {
+            return None;
+        }
+        Some((rest, word))
+    }
+
+    // an integer, a `?` placeholder or a quoted string without escapes
+    fn simple_value(i: &str) -> Option<(&str, Literal)> {
+        let (rest, value) = if let Some(rest) = i.strip_prefix('?') {
+            (rest, Literal::Placeholder(ItemPlaceholder::QuestionMark))
+        } else if let Some(quoted) = i.strip_prefix('\'') {
+            let end = quoted.find(['\'', '\\'])?;
+            if !quoted[end..].starts_with('\'') || quoted[end + 1..].starts_with('\'') {
+                return None;
+            }
+            let value = Literal::String(String::from(&quoted[..end]));
+            (&quoted[end + 1..], value)
+        } else {
+            let end = i.find(|c: char| !c.is_ascii_digit()).unwrap_or(i.len());
+            let value = i[..end].parse().ok()?;
+            (&i[end..], Literal::Integer(value))
+        };
+        let boundary = rest.is_empty() || rest.starts_with(Self::is_space);
+        (boundary || rest.starts_with([';', '\\'])).then_some((rest, value))
+    }
+
     pub fn nested_selection(i: &str) -> IResult<&str, SelectStatement, ParseSQLError<&str>> {
+        CommonParser::nested(Self::selection)(i)
+    }
+
+    fn selection(i: &str) -> IResult<&str, SelectStatement, ParseSQLError<&str>> {
         let (
             remaining_input,
-            (_, _, distinct, _, fields, _, tables, join, where_clause, group_by, order, limit),
+            (
+                _,
+                _,
+                distinct,
+                _,
+                fields,
+                from,
+                where_clause,
+                group_by,
+                window,
+                qualify,
+                order,
+                limit,
+                procedure,
+            ),
         ) = tuple((
             tag_no_case("SELECT"),
             multispace1,
             opt(tag_no_case("DISTINCT")),
             multispace0,
             FieldDefinitionExpression::parse,
-            delimited(multispace0, tag_no_case("FROM"), multispace0),
-            Table::table_list,
-            many0(JoinClause::parse),
+            opt(preceded(
+                delimited(multispace0, tag_no_case("FROM"), multispace0),
+                Self::from_clause,
+            )),
             opt(ConditionExpression::parse),
             opt(GroupByClause::parse),
+            Self::window_clause,
+            Self::qualify_clause,
             opt(OrderClause::parse),
             opt(LimitClause::parse),
+            opt(ProcedureClause::parse),
         ))(i)?;
+        let tables = from.unwrap_or_default();
         Ok((
             remaining_input,
             SelectStatement {
                 tables,
                 distinct: distinct.is_some(),
                 fields,
-                join,
                 where_clause,
                 group_by,
+                window,
+                qualify,
                 order,
                 limit,
+                procedure,
             },
         ))
     }
+
+    /// `WINDOW name AS (window_spec) [, name AS (window_spec)] ...`
+    fn window_clause(i: &str) -> IResult<&str, Vec<(String, WindowSpec)>, ParseSQLError<&str>> {
+        map(
+            opt(preceded(
+                tuple((multispace0, tag_no_case("WINDOW"), multispace1)),
+                separated_list1(
+                    CommonParser::ws_sep_comma,
+                    separated_pair(
+                        WindowSpec::window_name,
+                        tuple((multispace1, tag_no_case("AS"), multispace0)),
+                        WindowSpec::parse,
+                    ),
+                ),
+            )),
+            Option::unwrap_or_default,
+        )(i)
+    }
+
+    /// `QUALIFY condition`, which MySQL lacks, so it is skipped unless enabled
+    fn qualify_clause(i: &str) -> IResult<&str, Option<ConditionExpression>, ParseSQLError<&str>> {
+        if !ParseConfig::with_current(|config| config.qualify_clause) {
+            return Ok((i, None));
+        }
+        opt(preceded(
+            tuple((multispace0, tag_no_case("QUALIFY"), multispace1)),
+            ConditionExpression::condition_expr,
+        ))(i)
+    }
+
+    // Parse the list of table references after FROM.
+    fn from_clause(i: &str) -> IResult<&str, Vec<TableReference>, ParseSQLError<&str>> {
+        many0(terminated(
+            TableReference::parse,
+            opt(CommonParser::ws_sep_comma),
+        ))(i)
+    }
+}
+
+impl SelectStatement {
+    pub fn tables(&self) -> &[TableReference] {
+        &self.tables
+    }
+
+    pub fn fields(&self) -> &[FieldDefinitionExpression] {
+        &self.fields
+    }
+
+    /// the joins of all entries of the FROM clause
+    pub fn joins(&self) -> impl Iterator<Item = &JoinClause> {
+        self.tables.iter().flat_map(|table| table.join.iter())
+    }
+
+    pub fn where_clause(&self) -> Option<&ConditionExpression> {
+        self.where_clause.as_ref()
+    }
+
+    pub fn group_by(&self) -> Option<&GroupByClause> {
+        self.group_by.as_ref()
+    }
+
+    pub fn order(&self) -> Option<&OrderClause> {
+        self.order.as_ref()
+    }
+
+    pub fn limit(&self) -> Option<&LimitClause> {
+        self.limit.as_ref()
+    }
 }
 
 impl fmt::Display for SelectStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "SELECT ")?;
+        write_sql!(f, "SELECT ")?;
         if self.distinct {
-            write!(f, "DISTINCT ")?;
+            write_sql!(f, "DISTINCT ")?;
         }
-        write!(
+        write_sql!(
             f,
             "{}",
             self.fields
@@ -86,8 +315,8 @@ impl fmt::Display for SelectStatement {
         )?;
 
         if !self.tables.is_empty() {
-            write!(f, " FROM ")?;
-            write!(
+            write_sql!(f, " FROM ")?;
+            write_sql!(
                 f,
                 "{}",
                 self.tables
@@ -97,29 +326,141 @@ impl fmt::Display for SelectStatement {
                     .join(", ")
             )?;
         }
-        for jc in &self.join {
-            write!(f, " {}", jc)?;
-        }
         if let Some(ref where_clause) = self.where_clause {
-            write!(f, " WHERE ")?;
-            write!(f, "{}", where_clause)?;
+            write_sql!(f, " WHERE ")?;
+            write_sql!(f, "{}", where_clause)?;
         }
         if let Some(ref group_by) = self.group_by {
-            write!(f, " {}", group_by)?;
+            write_sql!(f, " {}", group_by)?;
+        }
+        if !self.window.is_empty() {
+            let windows = self
+                .window
+                .iter()
+                .map(|(name, spec)| {
+                    format_sql!("{} AS {}", DisplayUtil::escape_if_keyword(name), spec)
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+            write_sql!(f, " WINDOW {}", windows)?;
+        }
+        if let Some(ref qualify) = self.qualify {
+            write_sql!(f, " QUALIFY {}", qualify)?;
         }
         if let Some(ref order) = self.order {
-            write!(f, " {}", order)?;
+            write_sql!(f, " {}", order)?;
         }
         if let Some(ref limit) = self.limit {
-            write!(f, " {}", limit)?;
+            write_sql!(f, " {}", limit)?;
+        }
+        if let Some(ref procedure) = self.procedure {
+            write_sql!(f, " {}", procedure)?;
         }
         Ok(())
     }
 }
 
+/// an entry of the comma-separated FROM list with the joins attached to it,
+/// as JOIN binds tighter than the comma: `FROM a, b JOIN c ON ...` is
+/// `FROM a, (b JOIN c ON ...)`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct TableReference {
+    pub source: TableSource,
+    pub join: Vec<JoinClause>,
+}
+
+impl TableReference {
+    pub fn parse(i: &str) -> IResult<&str, TableReference, ParseSQLError<&str>> {
+        map(
+            pair(TableSource::parse, many0(JoinClause::parse)),
+            |(source, join)| TableReference { source, join },
+        )(i)
+    }
+}
+
+impl From<TableSource> for TableReference {
+    fn from(source: TableSource) -> Self {
+        TableReference {
+            source,
+            join: vec![],
+        }
+    }
+}
+
+impl From<Table> for TableReference {
+    fn from(table: Table) -> Self {
+        TableSource::Table(table).into()
+    }
+}
+
+impl fmt::Display for TableReference {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "{}", self.source)?;
+        for jc in &self.join {
+            write_sql!(f, " {}", jc)?;
+        }
+        Ok(())
+    }
+}
+
+/// a table source in the FROM clause
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum TableSource {
+    /// `[schema.]table [[AS] alias]`
+    Table(Table),
+    /// `(subquery) [AS] alias`
+    Subquery(Box<SelectStatement>, String),
+    /// `JSON_TABLE(expr, path COLUMNS (column_list)) [AS] alias`
+    JsonTable(JsonTable, String),
+}
+
+impl TableSource {
+    pub fn parse(i: &str) -> IResult<&str, TableSource, ParseSQLError<&str>> {
+        alt((
+            map(
+                pair(
+                    delimited(
+                        pair(tag("("), multispace0),
+                        SelectStatement::nested_selection,
+                        pair(multispace0, tag(")")),
+                    ),
+                    CommonParser::as_alias,
+                ),
+                |(select, alias)| TableSource::Subquery(Box::new(select), String::from(alias)),
+            ),
+            map(
+                pair(JsonTable::parse, CommonParser::as_alias),
+                |(json_table, alias)| TableSource::JsonTable(json_table, String::from(alias)),
+            ),
+            map(Table::schema_table_reference, TableSource::Table),
+        ))(i)
+    }
+}
+
+impl From<Table> for TableSource {
+    fn from(table: Table) -> Self {
+        TableSource::Table(table)
+    }
+}
+
+impl fmt::Display for TableSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TableSource::Table(ref table) => write_sql!(f, "{}", table),
+            TableSource::Subquery(ref select, ref alias) => {
+                write_sql!(f, "({}) AS {}", select, alias)
+            }
+            TableSource::JsonTable(ref json_table, ref alias) => {
+                write_sql!(f, "{} AS {}", json_table, alias)
+            }
+        }
+    }
+}
+
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct GroupByClause {
-    pub columns: Vec<Column>,
+    /// grouping keys: columns, function calls or arithmetic expressions
+    pub columns: Vec<ConditionExpression>,
     pub having: Option<ConditionExpression>,
 }
 
@@ -130,18 +471,37 @@ impl GroupByClause {
             multispace0,
             tag_no_case("GROUP BY"),
             multispace1,
-            Column::field_list,
+            separated_list1(CommonParser::ws_sep_comma, Self::group_by_expr),
             opt(ConditionExpression::having_clause),
         ))(i)?;
+        ConditionExpression::reject_window_function(
+            i,
+            &columns,
+            "window functions are not allowed in GROUP BY",
+        )?;
 
         Ok((remaining_input, GroupByClause { columns, having }))
     }
+
+    fn group_by_expr(i: &str) -> IResult<&str, ConditionExpression, ParseSQLError<&str>> {
+        alt((
+            map(ArithmeticExpression::parse, |e| {
+                ConditionExpression::Arithmetic(Box::new(e))
+            }),
+            map(Literal::parse, |lit| {
+                ConditionExpression::Base(ConditionBase::Literal(lit))
+            }),
+            map(Column::without_alias, |c| {
+                ConditionExpression::Base(ConditionBase::Field(c))
+            }),
+        ))(i)
+    }
 }
 
 impl fmt::Display for GroupByClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "GROUP BY ")?;
-        write!(
+        write_sql!(f, "GROUP BY ")?;
+        write_sql!(
             f,
             "{}",
             self.columns
@@ -151,7 +511,7 @@ impl fmt::Display for GroupByClause {
                 .join(", ")
         )?;
         if let Some(ref having) = self.having {
-            write!(f, " HAVING {}", having)?;
+            write_sql!(f, " HAVING {}", having)?;
         }
         Ok(())
     }
@@ -160,6 +520,7 @@ impl fmt::Display for GroupByClause {
 // TODO need parse as detailed data type
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct BetweenAndClause {
+    pub column: Column,
     pub left: String,
     pub right: String,
 }
@@ -168,7 +529,7 @@ impl BetweenAndClause {
     pub fn parse(i: &str) -> IResult<&str, BetweenAndClause, ParseSQLError<&str>> {
         map(
             tuple((
-                CommonParser::sql_identifier,
+                Column::without_alias,
                 multispace1,
                 tag_no_case("BETWEEN"),
                 multispace1,
@@ -176,9 +537,10 @@ impl BetweenAndClause {
                 multispace1,
                 tag_no_case("AND"),
                 multispace1,
-                take_till(|c| c == ' '),
+                take_till(|c: char| c.is_whitespace() || c == ';' || c == ')'),
             )),
             |x| BetweenAndClause {
+                column: x.0,
                 left: String::from(x.4),
                 right: String::from(x.8),
             },
@@ -188,8 +550,8 @@ impl BetweenAndClause {
 
 impl fmt::Display for BetweenAndClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, " BETWEEN {}", self.left)?;
-        write!(f, " AND {}", self.right)?;
+        write_sql!(f, "{} BETWEEN {}", self.column, self.left)?;
+        write_sql!(f, " AND {}", self.right)?;
         Ok(())
     }
 }
@@ -201,13 +563,40 @@ pub struct LimitClause {
 }
 
 impl LimitClause {
+    /// `LIMIT row_count [OFFSET offset]` or `LIMIT offset, row_count`, or the
+    /// standard `OFFSET offset LIMIT row_count`
     pub fn parse(i: &str) -> IResult<&str, LimitClause, ParseSQLError<&str>> {
-        let (remaining_input, (_, _, _, limit, opt_offset)) = tuple((
+        alt((
+            Self::limit_first,
+            map(
+                tuple((
+                    Self::offset,
+                    multispace0,
+                    tag_no_case("LIMIT"),
+                    multispace1,
+                    CommonParser::unsigned_number,
+                )),
+                |(offset, _, _, _, limit)| LimitClause { limit, offset },
+            ),
+        ))(i)
+    }
+
+    fn limit_first(i: &str) -> IResult<&str, LimitClause, ParseSQLError<&str>> {
+        let (remaining_input, (_, _, _, (limit, opt_offset))) = tuple((
             multispace0,
             tag_no_case("LIMIT"),
             multispace1,
-            CommonParser::unsigned_number,
-            opt(Self::offset),
+            alt((
+                map(
+                    tuple((
+                        CommonParser::unsigned_number,
+                        CommonParser::ws_sep_comma,
+                        CommonParser::unsigned_number,
+                    )),
+                    |(offset, _, limit)| (limit, Some(offset)),
+                ),
+                pair(CommonParser::unsigned_number, opt(Self::offset)),
+            )),
         ))(i)?;
         let offset = opt_offset.unwrap_or(0);
 
@@ -228,14 +617,60 @@ impl LimitClause {
 
 impl fmt::Display for LimitClause {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "LIMIT {}", self.limit)?;
+        write_sql!(f, "LIMIT {}", self.limit)?;
         if self.offset > 0 {
-            write!(f, " OFFSET {}", self.offset)?;
+            write_sql!(f, " OFFSET {}", self.offset)?;
         }
         Ok(())
     }
 }
 
+/// `PROCEDURE procedure_name([argument[, argument] ...])`, in practice only `ANALYSE`
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ProcedureClause {
+    pub name: String,
+    pub arguments: Vec<Literal>,
+}
+
+impl ProcedureClause {
+    pub fn parse(i: &str) -> IResult<&str, ProcedureClause, ParseSQLError<&str>> {
+        map(
+            tuple((
+                multispace0,
+                tag_no_case("PROCEDURE"),
+                multispace1,
+                CommonParser::sql_identifier,
+                multispace0,
+                delimited(
+                    pair(tag("("), multispace0),
+                    separated_list0(CommonParser::ws_sep_comma, Literal::parse),
+                    pair(multispace0, tag(")")),
+                ),
+            )),
+            |(_, _, _, name, _, arguments)| ProcedureClause {
+                name: String::from(name),
+                arguments,
+            },
+        )(i)
+    }
+}
+
+impl fmt::Display for ProcedureClause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_sql!(f, "PROCEDURE {}(", self.name)?;
+        write_sql!(
+            f,
+            "{}",
+            self.arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        )?;
+        write_sql!(f, ")")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use base::arithmetic::{ArithmeticBase, ArithmeticExpression, ArithmeticOperator};
@@ -251,4 +686,51 @@ mod tests {
     use base::{Literal, OrderType};
 
     use super::*;
+
+    #[test]
+    fn simple_selection_matches_full_grammar() {
+        let full = |sql| {
+            terminated(
+                SelectStatement::nested_selection,
+                CommonParser::statement_terminator,
+            )(sql)
+            .ok()
+        };
+
+        let simple = [
+            "SELECT id, name FROM users WHERE id = 42",
+            "select * from users where name = 'bob';",
+            "SELECT a,b , c FROM t WHERE c = ?",
+            "SELECT id FROM t\n",
+            "SELECT id FROM t WHERE id = 1; SELECT 2",
+        ];
+        for sql in simple {
+            assert!(SelectStatement::simple_selection(sql).is_some(), "{}", sql);
+            assert_eq!(SelectStatement::parse(sql).ok(), full(sql), "{}", sql);
+        }
+
+        let fallback = [
+            "SELECT null FROM t",
+            "SELECT current_date FROM t",
+            "SELECT DISTINCT id FROM t",
+            "SELECT t.id FROM t",
+            "SELECT count(*) FROM t",
+            "SELECT id AS i FROM t",
+            "SELECT id FROM t u",
+            "SELECT id FROM db.t",
+            "SELECT id FROM t, u",
+            "SELECT id FROM t WHERE id = 1 LIMIT 1",
+            "SELECT id FROM t WHERE id = -1",
+            "SELECT id FROM t WHERE id = 1.5",
+            "SELECT id FROM t WHERE id = 0b1",
+            "SELECT id FROM t WHERE id = 99999999999999999999",
+            "SELECT id FROM t WHERE id = 'it''s'",
+            "SELECT id FROM t WHERE id = 'a\\'b'",
+            "SELECT id FROM t WHERE id = 1 AND a = 2",
+        ];
+        for sql in fallback {
+            assert!(SelectStatement::simple_selection(sql).is_none(), "{}", sql);
+            assert_eq!(SelectStatement::parse(sql).ok(), full(sql), "{}", sql);
+        }
+    }
 }
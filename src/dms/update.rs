@@ -10,7 +10,7 @@ use base::column::Column;
 use base::condition::ConditionExpression;
 use base::error::ParseSQLError;
 use base::table::Table;
-use base::{CommonParser, DisplayUtil, FieldValueExpression};
+use base::{CommonParser, FieldValueExpression};
 
 #[derive(Clone, Debug, Default, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub struct UpdateStatement {
@@ -24,7 +24,7 @@ impl UpdateStatement {
         let (remaining_input, (_, _, table, _, _, _, fields, _, where_clause, _)) = tuple((
             tag_no_case("UPDATE"),
             multispace1,
-            Table::table_reference,
+            Table::schema_table_reference,
             multispace1,
             tag_no_case("SET"),
             multispace1,
@@ -44,15 +44,25 @@ impl UpdateStatement {
     }
 }
 
+impl UpdateStatement {
+    pub fn table(&self) -> &Table {
+        &self.table
+    }
+
+    pub fn fields(&self) -> &[(Column, FieldValueExpression)] {
+        &self.fields
+    }
+
+    pub fn where_clause(&self) -> Option<&ConditionExpression> {
+        self.where_clause.as_ref()
+    }
+}
+
 impl fmt::Display for UpdateStatement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "UPDATE {} ",
-            DisplayUtil::escape_if_keyword(&self.table.name)
-        )?;
+        write_sql!(f, "UPDATE {} ", self.table)?;
         assert!(!self.fields.is_empty());
-        write!(
+        write_sql!(
             f,
             "SET {}",
             self.fields
@@ -62,8 +72,8 @@ impl fmt::Display for UpdateStatement {
                 .join(", ")
         )?;
         if let Some(ref where_clause) = self.where_clause {
-            write!(f, " WHERE ")?;
-            write!(f, "{}", where_clause)?;
+            write_sql!(f, " WHERE ")?;
+            write_sql!(f, "{}", where_clause)?;
         }
         Ok(())
     }
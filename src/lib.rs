@@ -54,6 +54,7 @@ extern crate serde_derive;
 
 pub use self::parser::*;
 
+#[macro_use]
 pub mod base;
 pub mod das;
 pub mod dds;
@@ -1,70 +1,70 @@
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::fmt;
 use std::io::BufRead;
-use std::str;
+use std::{mem, str};
 
-use das::SetStatement;
+use base::condition::ConditionExpression;
+use base::error::ParseSQLError;
+use base::visit::VisitLiteralsMut;
+use base::{CommonParser, DisplayUtil, ItemPlaceholder, Literal, ParseSQLErrorKind, Table};
+use das::{SetStatement, SetTransactionStatement, ShowStatement};
 use dds::{
-    AlterDatabaseStatement, AlterTableStatement, CreateIndexStatement, CreateTableStatement,
-    DropDatabaseStatement, DropEventStatement, DropFunctionStatement, DropIndexStatement,
-    DropLogfileGroupStatement, DropProcedureStatement, DropServerStatement,
-    DropSpatialReferenceSystemStatement, DropTableStatement, DropTablespaceStatement,
-    DropTriggerStatement, DropViewStatement, RenameTableStatement, TruncateTableStatement,
+    AlterDatabaseStatement, AlterEventStatement, AlterServerStatement, AlterTableStatement,
+    CreateEventStatement, CreateIndexStatement, CreateLogfileGroupStatement,
+    CreateRoutineStatement, CreateServerStatement, CreateSpatialReferenceSystemStatement,
+    CreateTableStatement, CreateTablespaceStatement, DropDatabaseStatement, DropEventStatement,
+    DropFunctionStatement, DropIndexStatement, DropLogfileGroupStatement, DropProcedureStatement,
+    DropServerStatement, DropSpatialReferenceSystemStatement, DropTableStatement,
+    DropTablespaceStatement, DropTriggerStatement, DropViewStatement, RenameTableStatement,
+    TruncateTableStatement,
 };
 use dms::{
-    CompoundSelectStatement, DeleteStatement, InsertStatement, SelectStatement, UpdateStatement,
+    CompoundSelectStatement, DeleteStatement, DoStatement, ExplainStatement, ExplainTarget,
+    InsertStatement, SelectStatement, TableSource, UpdateStatement,
 };
 use nom::branch::alt;
-use nom::combinator::map;
-use nom::Offset;
+use nom::bytes::complete::tag;
+use nom::character::complete::multispace0;
+use nom::combinator::{map, opt};
+use nom::error::ErrorKind;
+use nom::sequence::{terminated, tuple};
+use nom::{IResult, Offset};
 
 pub struct Parser;
 
 impl Parser {
-    pub fn parse(config: &ParseConfig, input: &str) -> Result<Statement, String> {
-        let input = input.trim();
-
-        let dds_parser = alt((
-            map(AlterDatabaseStatement::parse, Statement::AlterDatabase),
-            map(AlterTableStatement::parse, Statement::AlterTable),
-            map(CreateIndexStatement::parse, Statement::CreateIndex),
-            map(CreateTableStatement::parse, Statement::CreateTable),
-            map(DropDatabaseStatement::parse, Statement::DropDatabase),
-            map(DropEventStatement::parse, Statement::DropEvent),
-            map(DropFunctionStatement::parse, Statement::DropFunction),
-            map(DropIndexStatement::parse, Statement::DropIndex),
-            map(
-                DropLogfileGroupStatement::parse,
-                Statement::DropLogfileGroup,
-            ),
-            map(DropProcedureStatement::parse, Statement::DropProcedure),
-            map(DropServerStatement::parse, Statement::DropServer),
-            map(
-                DropSpatialReferenceSystemStatement::parse,
-                Statement::DropSpatialReferenceSystem,
-            ),
-            map(DropTableStatement::parse, Statement::DropTable),
-            map(DropTablespaceStatement::parse, Statement::DropTableSpace),
-            map(DropTriggerStatement::parse, Statement::DropTrigger),
-            map(DropViewStatement::parse, Statement::DropView),
-            map(RenameTableStatement::parse, Statement::RenameTable),
-            map(TruncateTableStatement::parse, Statement::TruncateTable),
-        ));
+    /// Input nesting subqueries, conditions, `CASE` or function calls deeper
+    /// than this is rejected, because the recursive descent parsers would
+    /// otherwise overflow the stack on hostile input.
+    pub const MAX_NESTING_DEPTH: usize = 32;
 
-        let das_parser = alt((map(SetStatement::parse, Statement::Set),));
+    /// Expressions whose tree is nested deeper than this are rejected, as
+    /// the tree is dropped and written by recursion. Long chains such as
+    /// `a AND b AND ...` nest one level per operand, unless
+    /// [ParseConfig::flatten_logical_ops] is set.
+    pub const MAX_EXPRESSION_DEPTH: usize = 1000;
 
-        let dms_parser = alt((
-            map(SelectStatement::parse, Statement::Select),
-            map(CompoundSelectStatement::parse, Statement::CompoundSelect),
-            map(InsertStatement::parse, Statement::Insert),
-            map(DeleteStatement::parse, Statement::Delete),
-            map(UpdateStatement::parse, Statement::Update),
-        ));
+    pub fn parse(config: &ParseConfig, input: &str) -> Result<Statement, String> {
+        let input = CommonParser::strip_comments(input);
+        let input = input.trim();
 
-        let mut parser = alt((dds_parser, dms_parser, das_parser));
+        // statement parsers may or may not consume their own terminator, so the
+        // dispatcher accepts one optional `;` or `\G` and requires nothing else
+        // after it
+        let mut parser = terminated(
+            |i| Self::statement(config, i),
+            tuple((
+                multispace0,
+                opt(alt((tag(";"), CommonParser::vertical_terminator))),
+                multispace0,
+                CommonParser::eof,
+            )),
+        );
 
-        match parser(input) {
+        match config.scope(|| parser(input)) {
             Ok(result) => Ok(result.1),
-            Err(nom::Err::Error(err)) => {
+            Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => {
                 if config.log_with_backtrace {
                     println!(">>>>>>>>>>>>>>>>>>>>");
                     for error in &err.errors {
@@ -74,26 +74,397 @@ impl Parser {
                 }
 
                 let msg = err.errors[0].0;
-                let err_msg = format!("failed to parse sql, error near `{}`", msg);
+                let err_msg = match err.errors.iter().find_map(|(_, kind)| match kind {
+                    ParseSQLErrorKind::Context(context) => Some(*context),
+                    ParseSQLErrorKind::Message(message) => Some(message.as_str()),
+                    _ => None,
+                }) {
+                    Some(context) => format!("failed to parse sql, {} near `{}`", context, msg),
+                    None => format!("failed to parse sql, error near `{}`", msg),
+                };
                 Err(err_msg)
             }
             _ => Err(String::from("failed to parse sql: other error")),
         }
     }
+
+    /// Parse a batch of statements, such as a dump being linted, without giving
+    /// up at the first one that fails to parse. Parsing resumes after the next
+    /// `;` outside quoted strings, identifiers and comments, so the errors hold
+    /// one entry per bad statement, in input order, with slices of `input`
+    /// showing where each one failed.
+    pub fn parse_statements_lenient<'a>(
+        config: &ParseConfig,
+        input: &'a str,
+    ) -> (Vec<Statement>, Vec<ParseSQLError<&'a str>>) {
+        // blanked rather than stripped so that offsets still match `input`
+        let blanked = CommonParser::blank_comments(input);
+        let ends = CommonParser::statement_ends(&blanked);
+
+        let mut parser = terminated(
+            |i| Self::statement(config, i),
+            tuple((
+                multispace0,
+                opt(alt((tag(";"), CommonParser::vertical_terminator))),
+                multispace0,
+            )),
+        );
+        let mut statements = Vec::new();
+        let mut errors = Vec::new();
+        config.scope(|| {
+            let mut pos = 0;
+            let mut idx = 0;
+            loop {
+                pos += blanked[pos..].len() - blanked[pos..].trim_start().len();
+                while idx < ends.len() && ends[idx] <= pos {
+                    idx += 1;
+                }
+                if idx == ends.len() {
+                    break;
+                }
+                let error = match parser(&blanked[pos..]) {
+                    Ok((rest, statement)) => {
+                        statements.push(statement);
+                        pos = blanked.offset(rest);
+                        continue;
+                    }
+                    Err(nom::Err::Error(err)) | Err(nom::Err::Failure(err)) => err,
+                    Err(nom::Err::Incomplete(_)) => ParseSQLError {
+                        errors: vec![(
+                            &blanked[pos..],
+                            ParseSQLErrorKind::Nom(ErrorKind::Complete),
+                        )],
+                    },
+                };
+                errors.push(ParseSQLError {
+                    errors: error
+                        .errors
+                        .into_iter()
+                        .map(|(at, kind)| (&input[blanked.offset(at)..], kind))
+                        .collect(),
+                });
+                pos = ends[idx];
+            }
+        });
+        (statements, errors)
+    }
+
+    /// A statement of any kind, with or without its terminator.
+    fn statement<'a>(
+        config: &ParseConfig,
+        i: &'a str,
+    ) -> IResult<&'a str, Statement, ParseSQLError<&'a str>> {
+        // grouped because `alt` takes at most 21 parsers
+        let dds_parser = alt((
+            alt((
+                map(AlterDatabaseStatement::parse, Statement::AlterDatabase),
+                map(AlterEventStatement::parse, Statement::AlterEvent),
+                map(AlterServerStatement::parse, Statement::AlterServer),
+                map(AlterTableStatement::parse, Statement::AlterTable),
+                map(CreateEventStatement::parse, Statement::CreateEvent),
+                map(CreateIndexStatement::parse, Statement::CreateIndex),
+                map(
+                    CreateLogfileGroupStatement::parse,
+                    Statement::CreateLogfileGroup,
+                ),
+                map(CreateRoutineStatement::parse, Statement::CreateRoutine),
+                map(CreateServerStatement::parse, Statement::CreateServer),
+                map(
+                    CreateSpatialReferenceSystemStatement::parse,
+                    Statement::CreateSpatialReferenceSystem,
+                ),
+                map(CreateTableStatement::parse, Statement::CreateTable),
+                map(
+                    CreateTablespaceStatement::parse,
+                    Statement::CreateTablespace,
+                ),
+                map(RenameTableStatement::parse, Statement::RenameTable),
+                map(TruncateTableStatement::parse, Statement::TruncateTable),
+            )),
+            alt((
+                map(DropDatabaseStatement::parse, Statement::DropDatabase),
+                map(DropEventStatement::parse, Statement::DropEvent),
+                map(DropFunctionStatement::parse, Statement::DropFunction),
+                map(DropIndexStatement::parse, Statement::DropIndex),
+                map(
+                    DropLogfileGroupStatement::parse,
+                    Statement::DropLogfileGroup,
+                ),
+                map(DropProcedureStatement::parse, Statement::DropProcedure),
+                map(DropServerStatement::parse, Statement::DropServer),
+                map(
+                    DropSpatialReferenceSystemStatement::parse,
+                    Statement::DropSpatialReferenceSystem,
+                ),
+                map(DropTableStatement::parse, Statement::DropTable),
+                map(DropTablespaceStatement::parse, Statement::DropTableSpace),
+                map(DropTriggerStatement::parse, Statement::DropTrigger),
+                map(DropViewStatement::parse, Statement::DropView),
+            )),
+        ));
+
+        let das_parser = alt((
+            map(SetTransactionStatement::parse, Statement::SetTransaction),
+            map(SetStatement::parse, Statement::Set),
+            map(ShowStatement::parse, Statement::Show),
+        ));
+
+        let insert_parser = match config.check_insert_arity {
+            true => InsertStatement::parse_with_arity_check,
+            false => InsertStatement::parse,
+        };
+        let dms_parser = alt((
+            map(SelectStatement::parse, Statement::Select),
+            map(CompoundSelectStatement::parse, Statement::CompoundSelect),
+            map(insert_parser, Statement::Insert),
+            map(DeleteStatement::parse, Statement::Delete),
+            map(UpdateStatement::parse, Statement::Update),
+            map(ExplainStatement::parse, Statement::Explain),
+            map(DoStatement::parse, Statement::Do),
+        ));
+
+        alt((dds_parser, dms_parser, das_parser))(i)
+    }
 }
 
-#[derive(Default)]
+/// Options of [Parser::parse] and [Parser::parse_statements_lenient].
+///
+/// Every option is off by default, which parses the MySQL grammar as is. The
+/// struct is non-exhaustive, so that options added later are no breaking
+/// change; build it with [ParseConfig::builder]. A statement's own `parse`
+/// follows the options of the enclosing [ParseConfig::scope], the defaults
+/// outside one.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
 pub struct ParseConfig {
+    /// Print every error collected by a failed [Parser::parse] to stdout,
+    /// innermost first, before the message is returned.
     pub log_with_backtrace: bool,
+    /// Words that are reserved by the parser but should be accepted as plain
+    /// identifiers, e.g. `plan` or `query`. Matching ignores case.
+    ///
+    /// This takes precedence over the built-in reserved word list. Allowing
+    /// words that start a clause, such as `FROM` or `WHERE`, makes such clauses
+    /// unparseable. Quoted identifiers are always accepted.
+    pub allow_identifiers: HashSet<String>,
+    /// Build a single [ConditionExpression::LogicalChain] for a sequence of
+    /// `AND`s or `OR`s, instead of a [ConditionExpression::LogicalOp] nested
+    /// once per operator. Long chains then take neither deep recursion to
+    /// display, compare or drop, nor a box per operator.
+    pub flatten_logical_ops: bool,
+    /// Reject an `INSERT` or `REPLACE` with a column list whose rows, or whose
+    /// `SELECT`, have a different number of values, see
+    /// [InsertStatement::parse_with_arity_check].
+    pub check_insert_arity: bool,
+    /// Accept the `QUALIFY condition` clause of other dialects after `HAVING`,
+    /// which filters rows once window functions are computed. MySQL has no
+    /// such clause, so by default `QUALIFY` is an ordinary identifier and a
+    /// query using the clause fails to parse.
+    pub qualify_clause: bool,
+    /// Accept the MariaDB extensions to the MySQL grammar, currently
+    /// `CREATE INDEX IF NOT EXISTS`, `DROP INDEX IF EXISTS` and a trailing
+    /// `RESTRICT` or `CASCADE` on `ALTER TABLE ... DROP COLUMN`.
+    pub mariadb: bool,
+    /// Accept the `TABLESAMPLE method (size) [REPEATABLE (seed)]` clause of
+    /// other dialects after a table reference, kept as raw text in
+    /// [Table::tablesample]. MySQL has no such clause, so by default a query
+    /// using it fails to parse.
+    pub tablesample: bool,
+}
+
+thread_local! {
+    /// options of the running parse, see [ParseConfig::scope]
+    static CURRENT_CONFIG: RefCell<ParseConfig> = RefCell::new(ParseConfig::default());
+}
+
+/// restores the options of the enclosing scope, even on panic
+struct ConfigScopeGuard(ParseConfig);
+
+impl Drop for ConfigScopeGuard {
+    fn drop(&mut self) {
+        let previous = mem::take(&mut self.0);
+        CURRENT_CONFIG.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+impl ParseConfig {
+    /// Run `f` with these options in effect on the current thread, so that
+    /// statement parsers called directly follow them as [Parser::parse] does.
+    ///
+    /// ```
+    /// use sqlparser_mysql::dms::SelectStatement;
+    /// use sqlparser_mysql::ParseConfig;
+    ///
+    /// let config = ParseConfig::builder().allow_identifiers(["plan"]).build();
+    /// let sql = "SELECT plan FROM t";
+    /// assert!(config.scope(|| SelectStatement::parse(sql)).is_ok());
+    /// assert!(SelectStatement::parse(sql).is_err());
+    /// ```
+    pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT_CONFIG.with(|current| current.replace(self.clone()));
+        let _guard = ConfigScopeGuard(previous);
+        f()
+    }
+
+    /// Read the options of the running parse.
+    pub(crate) fn with_current<T>(f: impl FnOnce(&ParseConfig) -> T) -> T {
+        CURRENT_CONFIG.with(|current| f(&current.borrow()))
+    }
+
+    /// A builder starting from the default options.
+    ///
+    /// ```
+    /// use sqlparser_mysql::{ParseConfig, Parser};
+    ///
+    /// let config = ParseConfig::builder()
+    ///     .allow_identifiers(["plan"])
+    ///     .qualify_clause(true)
+    ///     .build();
+    /// let sql = "SELECT plan FROM t QUALIFY plan = 1";
+    /// assert!(Parser::parse(&config, sql).is_ok());
+    /// assert!(Parser::parse(&ParseConfig::default(), sql).is_err());
+    /// ```
+    pub fn builder() -> ParseConfigBuilder {
+        ParseConfigBuilder::default()
+    }
+}
+
+/// Builder of a [ParseConfig], with a setter per field, see [ParseConfig::builder].
+#[derive(Clone, Debug, Default)]
+pub struct ParseConfigBuilder {
+    config: ParseConfig,
+}
+
+impl ParseConfigBuilder {
+    /// See [ParseConfig::log_with_backtrace].
+    pub fn log_with_backtrace(mut self, enabled: bool) -> Self {
+        self.config.log_with_backtrace = enabled;
+        self
+    }
+
+    /// Add to [ParseConfig::allow_identifiers].
+    pub fn allow_identifiers<I>(mut self, words: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.config
+            .allow_identifiers
+            .extend(words.into_iter().map(Into::into));
+        self
+    }
+
+    /// See [ParseConfig::flatten_logical_ops].
+    pub fn flatten_logical_ops(mut self, enabled: bool) -> Self {
+        self.config.flatten_logical_ops = enabled;
+        self
+    }
+
+    /// See [ParseConfig::check_insert_arity].
+    pub fn check_insert_arity(mut self, enabled: bool) -> Self {
+        self.config.check_insert_arity = enabled;
+        self
+    }
+
+    /// See [ParseConfig::qualify_clause].
+    pub fn qualify_clause(mut self, enabled: bool) -> Self {
+        self.config.qualify_clause = enabled;
+        self
+    }
+
+    /// See [ParseConfig::mariadb].
+    pub fn mariadb(mut self, enabled: bool) -> Self {
+        self.config.mariadb = enabled;
+        self
+    }
+
+    /// See [ParseConfig::tablesample].
+    pub fn tablesample(mut self, enabled: bool) -> Self {
+        self.config.tablesample = enabled;
+        self
+    }
+
+    pub fn build(self) -> ParseConfig {
+        self.config
+    }
+}
+
+/// Options for writing a [Statement] back as SQL text, see [Statement::display].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct DisplayConfig {
+    pub keyword_case: KeywordCase,
+}
+
+thread_local! {
+    /// options of the statement being written, see [DisplayConfig::scope]
+    static CURRENT_DISPLAY_CONFIG: RefCell<DisplayConfig> = RefCell::new(DisplayConfig::default());
+}
+
+/// restores the display options of the enclosing scope, even on panic
+struct DisplayScopeGuard(DisplayConfig);
+
+impl Drop for DisplayScopeGuard {
+    fn drop(&mut self) {
+        let previous = mem::take(&mut self.0);
+        CURRENT_DISPLAY_CONFIG.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+impl DisplayConfig {
+    /// Run `f` with these options in effect on the current thread, so that
+    /// the `Display` impls of AST nodes follow them as [Statement::display]
+    /// does.
+    ///
+    /// ```
+    /// use sqlparser_mysql::dms::SelectStatement;
+    /// use sqlparser_mysql::{DisplayConfig, KeywordCase};
+    ///
+    /// let config = DisplayConfig {
+    ///     keyword_case: KeywordCase::Lower,
+    /// };
+    /// let sql = "SELECT Total FROM t WHERE a IS NULL";
+    /// let select = SelectStatement::parse(sql).unwrap().1;
+    /// assert_eq!(
+    ///     config.scope(|| select.to_string()),
+    ///     "select Total from t where a is null"
+    /// );
+    /// ```
+    pub fn scope<T>(&self, f: impl FnOnce() -> T) -> T {
+        let previous = CURRENT_DISPLAY_CONFIG.with(|current| current.replace(self.clone()));
+        let _guard = DisplayScopeGuard(previous);
+        f()
+    }
+
+    /// Read the options of the statement being written.
+    pub(crate) fn with_current<T>(f: impl FnOnce(&DisplayConfig) -> T) -> T {
+        CURRENT_DISPLAY_CONFIG.with(|current| f(&current.borrow()))
+    }
 }
 
+/// Case of the keywords in SQL text written from an AST.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum KeywordCase {
+    #[default]
+    Upper,
+    Lower,
+}
+
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum Statement {
     // DDS
     AlterDatabase(AlterDatabaseStatement),
+    AlterEvent(AlterEventStatement),
+    AlterServer(AlterServerStatement),
     AlterTable(AlterTableStatement),
+    CreateEvent(CreateEventStatement),
     CreateIndex(CreateIndexStatement),
+    CreateLogfileGroup(CreateLogfileGroupStatement),
+    CreateRoutine(CreateRoutineStatement),
+    CreateServer(CreateServerStatement),
+    CreateSpatialReferenceSystem(CreateSpatialReferenceSystemStatement),
     CreateTable(CreateTableStatement),
+    CreateTablespace(CreateTablespaceStatement),
     DropDatabase(DropDatabaseStatement),
     DropEvent(DropEventStatement),
     DropFunction(DropFunctionStatement),
@@ -110,28 +481,670 @@ pub enum Statement {
     TruncateTable(TruncateTableStatement),
     // DAS
     Set(SetStatement),
+    SetTransaction(SetTransactionStatement),
+    Show(ShowStatement),
     // HISTORY
     Insert(InsertStatement),
     CompoundSelect(CompoundSelectStatement),
     Select(SelectStatement),
     Delete(DeleteStatement),
     Update(UpdateStatement),
+    Explain(ExplainStatement),
+    Do(DoStatement),
 }
 
 impl fmt::Display for Statement {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            // FIXME add all
-            Statement::Select(ref select) => write!(f, "{}", select),
-            Statement::Insert(ref insert) => write!(f, "{}", insert),
-            Statement::CreateTable(ref create) => write!(f, "{}", create),
-            Statement::Delete(ref delete) => write!(f, "{}", delete),
-            Statement::DropTable(ref drop) => write!(f, "{}", drop),
-            Statement::DropDatabase(ref drop) => write!(f, "{}", drop),
-            Statement::TruncateTable(ref drop) => write!(f, "{}", drop),
-            Statement::Update(ref update) => write!(f, "{}", update),
-            Statement::Set(ref set) => write!(f, "{}", set),
-            _ => unimplemented!(),
+            Statement::Select(ref select) => write_sql!(f, "{}", select),
+            Statement::CompoundSelect(ref compound) => write_sql!(f, "{}", compound),
+            Statement::Insert(ref insert) => write_sql!(f, "{}", insert),
+            Statement::AlterDatabase(ref alter) => write_sql!(f, "{}", alter),
+            Statement::AlterEvent(ref alter) => write_sql!(f, "{}", alter),
+            Statement::AlterTable(ref alter) => write_sql!(f, "{}", alter),
+            Statement::CreateIndex(ref create) => write_sql!(f, "{}", create),
+            Statement::CreateTable(ref create) => write_sql!(f, "{}", create),
+            Statement::CreateEvent(ref create) => write_sql!(f, "{}", create),
+            Statement::CreateRoutine(ref create) => write_sql!(f, "{}", create),
+            Statement::CreateServer(ref create) => write_sql!(f, "{}", create),
+            Statement::AlterServer(ref alter) => write_sql!(f, "{}", alter),
+            Statement::CreateSpatialReferenceSystem(ref create) => write_sql!(f, "{}", create),
+            Statement::CreateTablespace(ref create) => write_sql!(f, "{}", create),
+            Statement::CreateLogfileGroup(ref create) => write_sql!(f, "{}", create),
+            Statement::Delete(ref delete) => write_sql!(f, "{}", delete),
+            Statement::DropTable(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropDatabase(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropTableSpace(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropLogfileGroup(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropEvent(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropFunction(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropIndex(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropProcedure(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropServer(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropSpatialReferenceSystem(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropTrigger(ref drop) => write_sql!(f, "{}", drop),
+            Statement::DropView(ref drop) => write_sql!(f, "{}", drop),
+            Statement::RenameTable(ref rename) => write_sql!(f, "{}", rename),
+            Statement::TruncateTable(ref drop) => write_sql!(f, "{}", drop),
+            Statement::Update(ref update) => write_sql!(f, "{}", update),
+            Statement::Explain(ref explain) => write_sql!(f, "{}", explain),
+            Statement::Do(ref do_statement) => write_sql!(f, "{}", do_statement),
+            Statement::Set(ref set) => write_sql!(f, "{}", set),
+            Statement::SetTransaction(ref set) => write_sql!(f, "{}", set),
+            Statement::Show(ref show) => write_sql!(f, "{}", show),
+        }
+    }
+}
+
+impl Statement {
+    /// Write this statement as SQL text following `config`, e.g.
+    /// `statement.display(&config).to_string()`. The plain [fmt::Display] impl
+    /// uses the default config.
+    pub fn display<'a>(&'a self, config: &'a DisplayConfig) -> StatementDisplay<'a> {
+        StatementDisplay {
+            statement: self,
+            config,
+        }
+    }
+
+    /// Write this statement with every constant value replaced by a `?`
+    /// placeholder, e.g. for a prepared statement cache, and return the
+    /// replaced values in the order their placeholders appear in the SQL.
+    ///
+    /// `NULL`, existing placeholders, keyword functions such as
+    /// `CURRENT_TIMESTAMP` and positions such as `GROUP BY 1` are kept. The
+    /// bounds of `BETWEEN` are kept as text and left inline too. Only
+    /// `SELECT`, `INSERT`, `UPDATE`, `DELETE` and their `EXPLAIN` have values
+    /// extracted, other statements are written unchanged.
+    pub fn to_parameterized_sql(&self) -> (String, Vec<Literal>) {
+        let mut statement = self.clone();
+        let mut parameters = Vec::new();
+        let mut replace = |literal: &mut Literal| match literal {
+            Literal::Bool(_)
+            | Literal::Integer(_)
+            | Literal::UnsignedInteger(_)
+            | Literal::FixedPoint(_)
+            | Literal::String(_)
+            | Literal::Blob(_)
+            | Literal::BitString(_) => {
+                let placeholder = Literal::Placeholder(ItemPlaceholder::QuestionMark);
+                parameters.push(std::mem::replace(literal, placeholder));
+            }
+            _ => {}
+        };
+        match statement {
+            Statement::Select(ref mut select) => select.visit_literals_mut(&mut replace),
+            Statement::CompoundSelect(ref mut compound) => {
+                compound.visit_literals_mut(&mut replace)
+            }
+            Statement::Insert(ref mut insert) => insert.visit_literals_mut(&mut replace),
+            Statement::Update(ref mut update) => update.visit_literals_mut(&mut replace),
+            Statement::Delete(ref mut delete) => delete.visit_literals_mut(&mut replace),
+            Statement::Explain(ref mut explain) => explain.visit_literals_mut(&mut replace),
+            _ => {}
+        }
+        (statement.to_string(), parameters)
+    }
+
+    /// The `SELECT` of a plain select statement.
+    pub fn as_select(&self) -> Option<&SelectStatement> {
+        match *self {
+            Statement::Select(ref select) => Some(select),
+            _ => None,
+        }
+    }
+
+    /// The `WHERE` condition of a `SELECT`, `UPDATE` or `DELETE`, also when
+    /// it is the target of an `EXPLAIN`.
+    pub fn where_clause(&self) -> Option<&ConditionExpression> {
+        match *self {
+            Statement::Select(ref select) => select.where_clause(),
+            Statement::Update(ref update) => update.where_clause(),
+            Statement::Delete(ref delete) => delete.where_clause(),
+            Statement::Explain(ref explain) => match explain.target {
+                ExplainTarget::Select(ref select) => select.where_clause(),
+                ExplainTarget::Update(ref update) => update.where_clause(),
+                ExplainTarget::Delete(ref delete) => delete.where_clause(),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// The tables a `SELECT`, `INSERT`, `UPDATE` or `DELETE` names directly,
+    /// i.e. its `FROM` list or target table, also when it is the target of an
+    /// `EXPLAIN`. Joined and derived tables are not included.
+    pub fn tables(&self) -> Vec<&Table> {
+        match *self {
+            Statement::Select(ref select) => Self::select_tables(select),
+            Statement::Insert(ref insert) => vec![insert.table()],
+            Statement::Update(ref update) => vec![update.table()],
+            Statement::Delete(ref delete) => vec![delete.table()],
+            Statement::Explain(ref explain) => match explain.target {
+                ExplainTarget::Select(ref select) => Self::select_tables(select),
+                ExplainTarget::Insert(ref insert) => vec![insert.table()],
+                ExplainTarget::Update(ref update) => vec![update.table()],
+                ExplainTarget::Delete(ref delete) => vec![delete.table()],
+                _ => vec![],
+            },
+            _ => vec![],
+        }
+    }
+
+    fn select_tables(select: &SelectStatement) -> Vec<&Table> {
+        select
+            .tables()
+            .iter()
+            .filter_map(|table| match table.source {
+                TableSource::Table(ref table) => Some(table),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// The kind of this statement, without the rest of its AST.
+    pub fn kind(&self) -> StatementKind {
+        match *self {
+            Statement::AlterDatabase(_) => StatementKind::AlterDatabase,
+            Statement::AlterEvent(_) => StatementKind::AlterEvent,
+            Statement::AlterServer(_) => StatementKind::AlterServer,
+            Statement::AlterTable(_) => StatementKind::AlterTable,
+            Statement::CreateEvent(_) => StatementKind::CreateEvent,
+            Statement::CreateIndex(_) => StatementKind::CreateIndex,
+            Statement::CreateLogfileGroup(_) => StatementKind::CreateLogfileGroup,
+            Statement::CreateRoutine(_) => StatementKind::CreateRoutine,
+            Statement::CreateServer(_) => StatementKind::CreateServer,
+            Statement::CreateSpatialReferenceSystem(_) => {
+                StatementKind::CreateSpatialReferenceSystem
+            }
+            Statement::CreateTable(_) => StatementKind::CreateTable,
+            Statement::CreateTablespace(_) => StatementKind::CreateTablespace,
+            Statement::DropDatabase(_) => StatementKind::DropDatabase,
+            Statement::DropEvent(_) => StatementKind::DropEvent,
+            Statement::DropFunction(_) => StatementKind::DropFunction,
+            Statement::DropIndex(_) => StatementKind::DropIndex,
+            Statement::DropLogfileGroup(_) => StatementKind::DropLogfileGroup,
+            Statement::DropProcedure(_) => StatementKind::DropProcedure,
+            Statement::DropServer(_) => StatementKind::DropServer,
+            Statement::DropSpatialReferenceSystem(_) => StatementKind::DropSpatialReferenceSystem,
+            Statement::DropTable(_) => StatementKind::DropTable,
+            Statement::DropTableSpace(_) => StatementKind::DropTableSpace,
+            Statement::DropTrigger(_) => StatementKind::DropTrigger,
+            Statement::DropView(_) => StatementKind::DropView,
+            Statement::RenameTable(_) => StatementKind::RenameTable,
+            Statement::TruncateTable(_) => StatementKind::TruncateTable,
+            Statement::Set(_) => StatementKind::Set,
+            Statement::SetTransaction(_) => StatementKind::SetTransaction,
+            Statement::Show(_) => StatementKind::Show,
+            Statement::Insert(_) => StatementKind::Insert,
+            Statement::CompoundSelect(_) => StatementKind::CompoundSelect,
+            Statement::Select(_) => StatementKind::Select,
+            Statement::Delete(_) => StatementKind::Delete,
+            Statement::Update(_) => StatementKind::Update,
+            Statement::Explain(_) => StatementKind::Explain,
+            Statement::Do(_) => StatementKind::Do,
+        }
+    }
+}
+
+/// [fmt::Display] wrapper returned by [Statement::display].
+pub struct StatementDisplay<'a> {
+    statement: &'a Statement,
+    config: &'a DisplayConfig,
+}
+
+impl<'a> fmt::Display for StatementDisplay<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.config.scope(|| write!(f, "{}", self.statement))
+    }
+}
+
+/// Field-less tag of a [Statement], cheap to copy into logs and metrics.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub enum StatementKind {
+    // DDS
+    AlterDatabase,
+    AlterEvent,
+    AlterServer,
+    AlterTable,
+    CreateEvent,
+    CreateIndex,
+    CreateLogfileGroup,
+    CreateRoutine,
+    CreateServer,
+    CreateSpatialReferenceSystem,
+    CreateTable,
+    CreateTablespace,
+    DropDatabase,
+    DropEvent,
+    DropFunction,
+    DropIndex,
+    DropLogfileGroup,
+    DropProcedure,
+    DropServer,
+    DropSpatialReferenceSystem,
+    DropTable,
+    DropTableSpace,
+    DropTrigger,
+    DropView,
+    RenameTable,
+    TruncateTable,
+    // DAS
+    Set,
+    SetTransaction,
+    Show,
+    // HISTORY
+    Insert,
+    CompoundSelect,
+    Select,
+    Delete,
+    Update,
+    Explain,
+    Do,
+}
+
+#[cfg(test)]
+mod tests {
+    use base::condition::ConditionExpression;
+    use base::{Literal, Operator};
+    use parser::{DisplayConfig, KeywordCase, ParseConfig, Parser, Statement, StatementKind};
+
+    #[test]
+    fn optional_statement_terminator() {
+        let parts = [
+            "ALTER DATABASE db CHARACTER SET utf8mb4",
+            "ALTER TABLE t ADD COLUMN b INT",
+            "CREATE EVENT e ON SCHEDULE EVERY 1 DAY DO DELETE FROM t",
+            "CREATE INDEX idx ON t (a)",
+            "CREATE PROCEDURE p() BEGIN SELECT 1; END",
+            "CREATE SERVER s FOREIGN DATA WRAPPER mysql OPTIONS (HOST 'h')",
+            "ALTER SERVER s OPTIONS (PORT 3307)",
+            "CREATE SPATIAL REFERENCE SYSTEM 4120 NAME 'Greek'",
+            "CREATE TABLE t (a INT)",
+            "CREATE TABLESPACE ts ADD DATAFILE 'ts.ibd' ENGINE = InnoDB",
+            "CREATE LOGFILE GROUP lg ADD UNDOFILE 'u.log' ENGINE = NDB",
+            "DROP DATABASE db",
+            "DROP EVENT e",
+            "DROP FUNCTION f",
+            "DROP INDEX idx ON t",
+            "DROP LOGFILE GROUP lg ENGINE = NDB",
+            "DROP PROCEDURE p",
+            "DROP SERVER s",
+            "DROP SPATIAL REFERENCE SYSTEM 4120",
+            "DROP TABLE t",
+            "DROP TABLESPACE ts",
+            "DROP TRIGGER tr",
+            "DROP VIEW v",
+            "RENAME TABLE a TO b",
+            "TRUNCATE TABLE t",
+            "SET @a = 1",
+            "SET TRANSACTION ISOLATION LEVEL READ COMMITTED",
+            "SHOW GLOBAL VARIABLES LIKE 'max%'",
+            "INSERT INTO t VALUES (1)",
+            "SELECT a FROM t UNION SELECT a FROM u",
+            "SELECT a FROM t",
+            "DELETE FROM t",
+            "UPDATE t SET a = 1",
+            "EXPLAIN FORMAT=TREE SELECT 1",
+            "DO SLEEP(1)",
+        ];
+        let config = ParseConfig::default();
+        for part in parts {
+            let bare = Parser::parse(&config, part);
+            assert!(bare.is_ok(), "{}", part);
+            let bare = bare.unwrap();
+            for sql in [
+                format!("{};", part),
+                format!("  {} ;\n ", part),
+                format!("{}\\G", part),
+            ] {
+                let res = Parser::parse(&config, &sql);
+                assert_eq!(res, Ok(bare.clone()), "{}", sql);
+            }
+        }
+    }
+
+    #[test]
+    fn vertical_terminator() {
+        let config = ParseConfig::default();
+        let exp = Parser::parse(&config, "SELECT 1").unwrap();
+        assert_eq!(Parser::parse(&config, "SELECT 1\\G"), Ok(exp.clone()));
+        assert_eq!(Parser::parse(&config, "SELECT 1 \\g\n"), Ok(exp));
+    }
+
+    #[test]
+    fn qualify_clause() {
+        let sql = "SELECT a, b FROM t GROUP BY a HAVING count(*) > 1 QUALIFY rn = 1 ORDER BY a ASC";
+        let config = ParseConfig {
+            qualify_clause: true,
+            ..Default::default()
+        };
+        let res = Parser::parse(&config, sql).unwrap();
+        match res {
+            Statement::Select(ref select) => assert!(select.qualify.is_some()),
+            ref other => panic!("unexpected statement {:?}", other),
+        }
+        assert_eq!(res.to_string(), sql);
+
+        let res = Parser::parse(&config, "SELECT a FROM t QUALIFY rn = 1").unwrap();
+        assert_eq!(res.to_string(), "SELECT a FROM t QUALIFY rn = 1");
+
+        let config = ParseConfig::default();
+        assert!(Parser::parse(&config, sql).is_err());
+        assert!(Parser::parse(&config, "SELECT a FROM t QUALIFY rn = 1").is_err());
+        assert!(Parser::parse(&config, "SELECT qualify FROM t qualify").is_ok());
+    }
+
+    #[test]
+    fn parse_config_builder() {
+        let config = ParseConfig::builder().build();
+        assert!(!config.log_with_backtrace);
+        assert!(config.allow_identifiers.is_empty());
+        assert!(!config.flatten_logical_ops);
+        assert!(!config.check_insert_arity);
+        assert!(!config.qualify_clause);
+        assert!(!config.mariadb);
+        assert!(!config.tablesample);
+
+        let config = ParseConfig::builder()
+            .log_with_backtrace(true)
+            .allow_identifiers(vec![String::from("plan")])
+            .allow_identifiers(["query"])
+            .flatten_logical_ops(true)
+            .check_insert_arity(true)
+            .qualify_clause(true)
+            .mariadb(true)
+            .tablesample(true)
+            .build();
+        assert!(config.log_with_backtrace);
+        assert_eq!(config.allow_identifiers.len(), 2);
+        assert!(config.allow_identifiers.contains("query"));
+        assert!(config.flatten_logical_ops);
+        assert!(config.check_insert_arity);
+        assert!(config.qualify_clause);
+        assert!(config.mariadb);
+        assert!(config.tablesample);
+    }
+
+    #[test]
+    fn tablesample_clause() {
+        let sql = "SELECT a FROM t AS x TABLESAMPLE SYSTEM (10) \
+            INNER JOIN u TABLESAMPLE BERNOULLI (5) REPEATABLE (42) ON x.id = u.id";
+        let config = ParseConfig {
+            tablesample: true,
+            ..Default::default()
+        };
+        let res = Parser::parse(&config, sql).unwrap();
+        let tables = res.tables();
+        assert_eq!(tables[0].alias, Some(String::from("x")));
+        assert_eq!(tables[0].tablesample, Some(String::from("SYSTEM (10)")));
+        assert_eq!(res.to_string(), sql);
+
+        let config = ParseConfig::default();
+        assert!(Parser::parse(&config, sql).is_err());
+        assert!(Parser::parse(&config, "SELECT a FROM t TABLESAMPLE SYSTEM (10)").is_err());
+        assert!(Parser::parse(&config, "SELECT a FROM t tablesample").is_ok());
+    }
+
+    #[test]
+    fn mariadb_index_existence_clauses() {
+        let create = "CREATE UNIQUE INDEX IF NOT EXISTS idx ON t (a)";
+        let drop = "DROP INDEX IF EXISTS idx ON t";
+        let config = ParseConfig {
+            mariadb: true,
+            ..Default::default()
+        };
+        match Parser::parse(&config, create).unwrap() {
+            Statement::CreateIndex(ref create_index) => {
+                assert!(create_index.if_not_exists);
+                assert_eq!(create_index.to_string(), create);
+            }
+            ref other => panic!("unexpected statement {:?}", other),
+        }
+        match Parser::parse(&config, drop).unwrap() {
+            Statement::DropIndex(ref drop_index) => {
+                assert!(drop_index.if_exists);
+                assert_eq!(drop_index.to_string(), drop);
+            }
+            ref other => panic!("unexpected statement {:?}", other),
+        }
+        match Parser::parse(&config, "DROP INDEX idx ON t").unwrap() {
+            Statement::DropIndex(ref drop_index) => assert!(!drop_index.if_exists),
+            ref other => panic!("unexpected statement {:?}", other),
+        }
+
+        let config = ParseConfig::default();
+        assert!(Parser::parse(&config, create).is_err());
+        assert!(Parser::parse(&config, drop).is_err());
+    }
+
+    #[test]
+    fn borrowing_accessors() {
+        let config = ParseConfig::default();
+        let statement = Parser::parse(&config, "SELECT a FROM t, u WHERE a = 1 LIMIT 5").unwrap();
+        let names: Vec<&str> = statement.tables().iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["t", "u"]);
+        assert!(matches!(
+            statement.where_clause(),
+            Some(ConditionExpression::ComparisonOp(_))
+        ));
+        let select = statement.as_select().unwrap();
+        assert_eq!(select.fields().len(), 1);
+        assert_eq!(select.joins().count(), 0);
+        assert!(select.order().is_none());
+        assert_eq!(select.limit().map(|l| l.limit), Some(5));
+
+        let statement = Parser::parse(&config, "EXPLAIN DELETE FROM t WHERE a = 1").unwrap();
+        assert!(statement.where_clause().is_some());
+        assert_eq!(statement.tables()[0].name, "t");
+
+        let statement = Parser::parse(&config, "EXPLAIN SELECT a FROM t, u").unwrap();
+        let names: Vec<&str> = statement.tables().iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, ["t", "u"]);
+
+        let statement = Parser::parse(&config, "UPDATE t SET a = 1").unwrap();
+        assert_eq!(statement.tables()[0].name, "t");
+        assert!(statement.where_clause().is_none());
+        assert!(statement.as_select().is_none());
+    }
+
+    #[test]
+    fn parse_statements_lenient() {
+        let config = ParseConfig::default();
+        let sql = "SELECT 1;\n\
+            SELEC /* typo; */ oops FROM t;\n\
+            DELETE FROM t WHERE a = ';';\n\
+            UPDATE t SET a = 1";
+        let (statements, errors) = Parser::parse_statements_lenient(&config, sql);
+        let kinds: Vec<StatementKind> = statements.iter().map(Statement::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                StatementKind::Select,
+                StatementKind::Delete,
+                StatementKind::Update
+            ]
+        );
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].errors[0].0.starts_with("SELEC /* typo; */ oops"));
+
+        let deep = format!("SELECT {}1{}", "(".repeat(40), ")".repeat(40));
+        let sql = format!("SELECT 1; {}; SELECT 2", deep);
+        let (statements, errors) = Parser::parse_statements_lenient(&config, &sql);
+        assert_eq!(statements.len(), 2);
+        assert_eq!(errors.len(), 1);
+
+        let (statements, errors) = Parser::parse_statements_lenient(&config, " ;\n");
+        assert!(statements.is_empty());
+        assert_eq!(errors.len(), 1);
+
+        let sql = "CREATE FUNCTION f() RETURNS INT RETURN 1; SELECT 2;\n\
+            CREATE EVENT e ON SCHEDULE EVERY 1 DAY DO DELETE FROM t; SELECT 3";
+        let (statements, errors) = Parser::parse_statements_lenient(&config, sql);
+        let kinds: Vec<StatementKind> = statements.iter().map(Statement::kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                StatementKind::CreateRoutine,
+                StatementKind::Select,
+                StatementKind::CreateEvent,
+                StatementKind::Select
+            ]
+        );
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn to_parameterized_sql() {
+        let config = ParseConfig::default();
+        let sql = "SELECT * FROM t WHERE a = 1 AND b = 'x'";
+        let res = Parser::parse(&config, sql).unwrap().to_parameterized_sql();
+        assert_eq!(
+            res,
+            (
+                "SELECT * FROM t WHERE a = ? AND b = ?".to_string(),
+                vec![Literal::Integer(1), Literal::String("x".to_string())]
+            )
+        );
+
+        let sql = "SELECT a + 2, lower('A') FROM t \
+            WHERE b IN (3, NULL, ?) AND c IS NULL AND d > (SELECT max(e) FROM u WHERE f = 4)";
+        let (sql, parameters) = Parser::parse(&config, sql).unwrap().to_parameterized_sql();
+        assert_eq!(
+            sql,
+            "SELECT a + ?, lower(?) FROM t \
+            WHERE b IN (?, NULL, ?) AND c IS NULL AND d > (SELECT max(e) FROM u WHERE f = ?)"
+        );
+        let exp: Vec<Literal> = vec![2.into(), "A".into(), 3.into(), 4.into()];
+        assert_eq!(parameters, exp);
+
+        let sql = "SELECT a, count(*) FROM t WHERE b BETWEEN 1 AND 2 \
+            GROUP BY 1, a + 5 HAVING count(*) > 3";
+        let (sql, parameters) = Parser::parse(&config, sql).unwrap().to_parameterized_sql();
+        assert_eq!(
+            sql,
+            "SELECT a, count(*) FROM t WHERE b BETWEEN 1 AND 2 \
+            GROUP BY 1, a + ? HAVING count(*) > ?"
+        );
+        let exp: Vec<Literal> = vec![5.into(), 3.into()];
+        assert_eq!(parameters, exp);
+
+        let sql = "INSERT INTO t (a, b) VALUES (1, 'x'), (2, CURRENT_TIMESTAMP) \
+            ON DUPLICATE KEY UPDATE b = 'y'";
+        let (sql, parameters) = Parser::parse(&config, sql).unwrap().to_parameterized_sql();
+        assert_eq!(
+            sql,
+            "INSERT INTO t (a, b) VALUES (?, ?), (?, CURRENT_TIMESTAMP) \
+            ON DUPLICATE KEY UPDATE b = ?"
+        );
+        let exp: Vec<Literal> = vec![1.into(), "x".into(), 2.into(), "y".into()];
+        assert_eq!(parameters, exp);
+    }
+
+    #[test]
+    fn flatten_logical_ops() {
+        let conditions = (0..101)
+            .map(|i| format!("a{} = {}", i, i))
+            .collect::<Vec<_>>();
+        let sql = format!("SELECT * FROM t WHERE {}", conditions.join(" AND "));
+        let config = ParseConfig {
+            flatten_logical_ops: true,
+            ..Default::default()
+        };
+        let res = Parser::parse(&config, &sql).unwrap();
+        match res {
+            Statement::Select(ref select) => match select.where_clause {
+                Some(ConditionExpression::LogicalChain(Operator::And, ref operands)) => {
+                    assert_eq!(operands.len(), 101);
+                    assert!(operands
+                        .iter()
+                        .all(|e| matches!(e, ConditionExpression::ComparisonOp(_))));
+                }
+                ref other => panic!("unexpected where clause {:?}", other),
+            },
+            ref other => panic!("unexpected statement {:?}", other),
+        }
+        assert_eq!(res.to_string(), sql);
+
+        let sql = "SELECT * FROM t WHERE a = 1 AND b = 2 OR c = 3 AND d = 4 AND e = 5";
+        let res = Parser::parse(&config, sql).unwrap();
+        assert_eq!(res.to_string(), sql);
+        let nested = Parser::parse(&ParseConfig::default(), sql).unwrap();
+        assert_ne!(res, nested);
+    }
+
+    #[test]
+    fn reject_input_after_terminator() {
+        let config = ParseConfig::default();
+        assert!(Parser::parse(&config, "SELECT a FROM t; garbage").is_err());
+        assert!(Parser::parse(&config, "DROP TABLE t; DROP TABLE u").is_err());
+    }
+
+    #[test]
+    fn statement_kind() {
+        let parts = [
+            "SELECT a FROM t",
+            "INSERT INTO t VALUES (1)",
+            "UPDATE t SET a = 1",
+            "DELETE FROM t WHERE a = 1",
+            "CREATE TABLE t (a INT)",
+            "ALTER TABLE t ADD COLUMN b INT",
+            "SELECT a FROM t UNION SELECT a FROM u",
+            "EXPLAIN FOR CONNECTION 5",
+        ];
+        let exps = [
+            StatementKind::Select,
+            StatementKind::Insert,
+            StatementKind::Update,
+            StatementKind::Delete,
+            StatementKind::CreateTable,
+            StatementKind::AlterTable,
+            StatementKind::CompoundSelect,
+            StatementKind::Explain,
+        ];
+        let config = ParseConfig::default();
+        for i in 0..parts.len() {
+            let res = Parser::parse(&config, parts[i]);
+            assert_eq!(res.unwrap().kind(), exps[i]);
+        }
+    }
+
+    #[test]
+    fn keyword_case() {
+        let parts = [
+            (
+                "SELECT COUNT(*) AS Total FROM `Order` WHERE NAME = 'SELECT' ORDER BY id DESC",
+                "select count(*) as Total from `Order` where NAME = 'SELECT' order by id desc",
+            ),
+            (
+                "INSERT INTO t (a, b) VALUES (1, 'It''s NULL')",
+                "insert into t (a, b) values (1, 'It''s NULL')",
+            ),
+            (
+                "UPDATE t SET a = NULL WHERE b > 1 AND c LIKE '%x'",
+                "update t set a = null where b > 1 and c like '%x'",
+            ),
+            // names spelled like keywords keep their case, keywords the
+            // parser does not reserve are still written in keyword case
+            (
+                "SELECT STATUS, RANK() OVER (PARTITION BY a ROWS UNBOUNDED PRECEDING) FROM t",
+                "select STATUS, RANK() over (partition by a rows unbounded preceding) from t",
+            ),
+            (
+                "CREATE TABLE t (a INT) ENCRYPTION 'Y'",
+                "create table t (a int(32)) encryption 'Y'",
+            ),
+            ("SHOW STATUS LIKE 'Up%'", "show status like 'Up%'"),
+        ];
+        let config = ParseConfig::default();
+        let upper = DisplayConfig::default();
+        let lower = DisplayConfig {
+            keyword_case: KeywordCase::Lower,
+        };
+        for (sql, exp) in parts {
+            let statement = Parser::parse(&config, sql).unwrap();
+            assert_eq!(statement.display(&upper).to_string(), statement.to_string());
+            assert_eq!(statement.display(&lower).to_string(), exp);
         }
     }
 }
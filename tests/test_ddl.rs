@@ -1,6 +1,8 @@
 extern crate sqlparser_mysql;
 
+use sqlparser_mysql::base::Table;
 use sqlparser_mysql::dds::{AlterTableStatement, CreateTableStatement};
+use sqlparser_mysql::{ParseConfig, Parser, Statement};
 
 // FIXME should assert_eq(parse_result, exp)
 
@@ -13,6 +15,7 @@ fn parse_create_table() {
         "CREATE TABLE employee (id INT, name VARCHAR(100), department_id INT, PRIMARY KEY(id), FOREIGN KEY (department_id) REFERENCES department(id))",
         "CREATE TABLE my_table (id INT AUTO_INCREMENT PRIMARY KEY, name VARCHAR(100), age INT)",
         "CREATE TEMPORARY TABLE temp_table (id INT, score DECIMAL(5, 2))",
+        "CREATE TABLE events (id INT, created_at DATETIME DEFAULT LOCALTIMESTAMP, updated_at TIMESTAMP(3) DEFAULT CURRENT_TIMESTAMP(3) ON UPDATE CURRENT_TIMESTAMP(3))",
         "CREATE TABLE IF NOT EXISTS my_table (id INT AUTO_INCREMENT PRIMARY KEY, name VARCHAR(100), age INT)",
         "CREATE TABLE department (id INT AUTO_INCREMENT, name VARCHAR(100), PRIMARY KEY(id))",
         "CREATE TABLE product (id INT AUTO_INCREMENT PRIMARY KEY, name VARCHAR(100), price DECIMAL(10,2), category_id INT, INDEX(category_id))",
@@ -117,3 +120,131 @@ fn parse_alter_table() {
         assert!(res.is_ok());
     }
 }
+
+#[test]
+fn foreign_key_reference_columns() {
+    let config = ParseConfig::default();
+
+    let ok_sqls = [
+        "CREATE TABLE c (a INT, b INT, FOREIGN KEY (a, b) REFERENCES p (x, y))",
+        "ALTER TABLE c ADD CONSTRAINT fk_1 FOREIGN KEY (a, b) REFERENCES p (x, y)",
+    ];
+    for sql in ok_sqls {
+        assert!(Parser::parse(&config, sql).is_ok(), "{}", sql);
+    }
+
+    let ordered_sqls = [
+        "CREATE TABLE c (a INT, b INT, FOREIGN KEY (a, b) REFERENCES p (x DESC, y))",
+        "ALTER TABLE c ADD FOREIGN KEY (a) REFERENCES p (x ASC)",
+    ];
+    for sql in ordered_sqls {
+        let err = Parser::parse(&config, sql).unwrap_err();
+        assert!(
+            err.contains("ASC or DESC is not allowed in REFERENCES"),
+            "{}",
+            err
+        );
+    }
+}
+
+#[test]
+fn cross_schema_table_names() {
+    let config = ParseConfig::default();
+    let table = |schema: &str, name: &str| Table {
+        name: name.to_string(),
+        alias: None,
+        schema: Some(schema.to_string()),
+        tablesample: None,
+    };
+
+    let sql = "DROP TABLE db1.a, db2.b";
+    match Parser::parse(&config, sql).unwrap() {
+        Statement::DropTable(drop) => {
+            assert_eq!(drop.tables, vec![table("db1", "a"), table("db2", "b")]);
+            assert_eq!(drop.to_string(), sql);
+        }
+        other => panic!("unexpected statement {:?}", other),
+    }
+
+    let sql = "RENAME TABLE db1.a TO db2.b, db2.c TO db1.c";
+    match Parser::parse(&config, sql).unwrap() {
+        Statement::RenameTable(rename) => {
+            assert_eq!(
+                rename.tables,
+                vec![
+                    (table("db1", "a"), table("db2", "b")),
+                    (table("db2", "c"), table("db1", "c")),
+                ]
+            );
+            assert_eq!(rename.to_string(), sql);
+        }
+        other => panic!("unexpected statement {:?}", other),
+    }
+    // aliases are not allowed in a rename
+    assert!(Parser::parse(&config, "RENAME TABLE db1.a AS x TO db2.b").is_err());
+
+    for sql in [
+        "ALTER TABLE db1.a RENAME TO db2.b",
+        "ALTER TABLE db1.a RENAME AS db2.b",
+        "ALTER TABLE db1.a RENAME db2.b",
+    ] {
+        match Parser::parse(&config, sql).unwrap() {
+            Statement::AlterTable(alter) => {
+                assert_eq!(alter.table, table("db1", "a"));
+                let display = alter.to_string();
+                assert!(display.ends_with(" RENAME TO db2.b"), "{}", display);
+                assert_eq!(AlterTableStatement::parse(&display).unwrap().1, alter);
+            }
+            other => panic!("unexpected statement {:?}", other),
+        }
+    }
+
+    match Parser::parse(&config, "TRUNCATE TABLE db1.a").unwrap() {
+        Statement::TruncateTable(truncate) => assert_eq!(truncate.table, table("db1", "a")),
+        other => panic!("unexpected statement {:?}", other),
+    }
+
+    let sql = "DROP VIEW db1.v, db2.`select`";
+    match Parser::parse(&config, sql).unwrap() {
+        Statement::DropView(drop) => {
+            assert_eq!(drop.views, vec![table("db1", "v"), table("db2", "select")]);
+            assert_eq!(drop.to_string(), sql);
+        }
+        other => panic!("unexpected statement {:?}", other),
+    }
+}
+
+#[test]
+fn doubled_backquote_in_quoted_names() {
+    let sql = "CREATE TABLE `we``ird` (`a``b` INT)";
+    let res = CreateTableStatement::parse(sql).unwrap().1;
+    assert_eq!(res.table.name, "we`ird");
+}
+
+#[test]
+fn executable_comments_keep_their_body() {
+    let config = ParseConfig::default();
+    let sql = "ALTER TABLE t /*!50100 PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10), \
+        PARTITION p1 VALUES LESS THAN (MAXVALUE)) */";
+    let res = Parser::parse(&config, sql);
+    let exp = Parser::parse(
+        &config,
+        "ALTER TABLE t PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10), \
+        PARTITION p1 VALUES LESS THAN (MAXVALUE))",
+    );
+    assert!(matches!(res, Ok(Statement::AlterTable(_))));
+    assert_eq!(res, exp);
+
+    let sql =
+        "CREATE TABLE t (a INT) ENGINE=InnoDB\n/*!50100 PARTITION BY HASH (a)\nPARTITIONS 4 */";
+    let res = Parser::parse(&config, sql);
+    let exp = Parser::parse(
+        &config,
+        "CREATE TABLE t (a INT) ENGINE=InnoDB PARTITION BY HASH (a) PARTITIONS 4",
+    );
+    assert!(matches!(res, Ok(Statement::CreateTable(_))));
+    assert_eq!(res, exp);
+
+    let res = Parser::parse(&config, "SELECT a/*!, b */ FROM t");
+    assert_eq!(res.unwrap().to_string(), "SELECT a, b FROM t");
+}
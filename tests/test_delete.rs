@@ -59,3 +59,10 @@ fn format_delete() {
     let res = DeleteStatement::parse(str);
     assert_eq!(format!("{}", res.unwrap().1), expected);
 }
+
+#[test]
+fn format_delete_schema() {
+    let str = "DELETE FROM db1.users WHERE id = 1";
+    let res = DeleteStatement::parse(str);
+    assert_eq!(str, format!("{}", res.unwrap().1));
+}
@@ -86,3 +86,28 @@ fn format_delete_with_where_clause() {
     assert_eq!(expected0, format!("{}", res0.unwrap()));
     assert_eq!(expected1, format!("{}", res1.unwrap()));
 }
+
+#[test]
+fn round_trip_dml() {
+    let parts = [
+        "INSERT INTO users VALUES (?, ?)",
+        "INSERT INTO users (`key`, `order`) VALUES (1, NULL)",
+        "INSERT IGNORE INTO db1.users (id, name) VALUES (1, 'a'), (2, 'b') \
+         ON DUPLICATE KEY UPDATE name = 'c', id = id + 1",
+        "UPDATE db1.users SET name = 'x', score = score * 2 WHERE id = 1 AND name LIKE 'a%'",
+        "UPDATE users SET `key` = 1 WHERE `order` > 2 OR deleted_at IS NOT NULL",
+        "DELETE FROM users",
+        "DELETE FROM db1.users WHERE id IN (1, 2, 3) OR name IS NULL",
+    ];
+    let config = ParseConfig::default();
+    for part in parts {
+        let res = Parser::parse(&config, part);
+        assert!(res.is_ok(), "{}", part);
+        let statement = res.unwrap();
+        assert_eq!(part, format!("{}", statement));
+        assert_eq!(
+            Parser::parse(&config, &statement.to_string()),
+            Ok(statement)
+        );
+    }
+}
@@ -1,6 +1,7 @@
 extern crate sqlparser_mysql;
 
 use sqlparser_mysql::base::arithmetic::{ArithmeticBase, ArithmeticExpression, ArithmeticOperator};
+use sqlparser_mysql::base::column::FunctionExpression;
 use sqlparser_mysql::base::{Column, FieldValueExpression, ItemPlaceholder, Literal, Table};
 use sqlparser_mysql::dms::InsertStatement;
 use sqlparser_mysql::{ParseConfig, Parser, Statement};
@@ -87,7 +88,7 @@ fn complex_insert() {
                 42.into(),
                 "test".into(),
                 "test".into(),
-                Literal::CurrentTimestamp,
+                Literal::CurrentTimestamp(None),
             ],],
             ..Default::default()
         }
@@ -213,3 +214,151 @@ fn insert_with_leading_value_whitespace() {
         }
     );
 }
+
+#[test]
+fn insert_default_row() {
+    let parts = [
+        "INSERT INTO users () VALUES ()",
+        "INSERT INTO users( ) VALUES( );",
+    ];
+    let expected = InsertStatement {
+        table: Table::from("users"),
+        fields: Some(vec![]),
+        data: vec![vec![]],
+        ..Default::default()
+    };
+    for part in parts {
+        let res = InsertStatement::parse(part);
+        assert_eq!(res.unwrap().1, expected);
+    }
+    assert_eq!(parts[0], format!("{}", expected));
+}
+
+#[test]
+fn insert_schema_qualified_table() {
+    let str = "INSERT INTO db1.users (id) VALUES (42)";
+
+    let res = InsertStatement::parse(str);
+    let stmt = res.unwrap().1;
+    assert_eq!(
+        stmt,
+        InsertStatement {
+            table: Table::from(("db1", "users")),
+            fields: Some(vec![Column::from("id")]),
+            data: vec![vec![42.into()]],
+            ..Default::default()
+        }
+    );
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn replace_and_insert_select() {
+    let str = "REPLACE INTO users (id, name) SELECT id, name FROM staff WHERE active = 1";
+    let res = InsertStatement::parse(str);
+    let stmt = res.unwrap().1;
+    assert!(stmt.replace);
+    assert!(stmt.data.is_empty());
+    assert_eq!(stmt.select.as_ref().unwrap().fields.len(), 2);
+    assert_eq!(str, format!("{}", stmt));
+
+    let str = "INSERT INTO users SELECT * FROM staff ON DUPLICATE KEY UPDATE name = 'x'";
+    let res = InsertStatement::parse(str);
+    assert_eq!(str, format!("{}", res.unwrap().1));
+
+    let str = "replace into users values (1, 'a');";
+    let res = InsertStatement::parse(str);
+    assert_eq!(
+        "REPLACE INTO users VALUES (1, 'a')",
+        format!("{}", res.unwrap().1)
+    );
+}
+
+#[test]
+fn insert_arity_check() {
+    let mismatched = [
+        "INSERT INTO users (id, name) SELECT id FROM staff",
+        "REPLACE INTO users (id) SELECT id, name FROM staff",
+        "INSERT INTO users (id, name) VALUES (1, 'a'), (2)",
+    ];
+    let config = ParseConfig::builder().check_insert_arity(true).build();
+    for str in mismatched {
+        assert!(InsertStatement::parse(str).is_ok());
+        assert!(Parser::parse(&ParseConfig::default(), str).is_ok());
+        let res = Parser::parse(&config, str);
+        assert!(res
+            .unwrap_err()
+            .contains("column count doesn't match value count"));
+    }
+
+    let matching = [
+        "INSERT INTO users (id, name) SELECT id, name FROM staff",
+        "INSERT INTO users (id, name) SELECT * FROM staff",
+        "INSERT INTO users SELECT id FROM staff",
+        "INSERT INTO users (id, name) VALUES (1, 'a'), (2, 'b')",
+    ];
+    for str in matching {
+        assert!(Parser::parse(&config, str).is_ok());
+    }
+}
+
+#[test]
+fn insert_arity_mismatch_is_described() {
+    let config = ParseConfig::builder().check_insert_arity(true).build();
+    let cases = [
+        (
+            "INSERT INTO users (id, name, email) VALUES (1, 'a', 'a@x'), (2, 'b')",
+            "3 columns but 2 values in row 2",
+        ),
+        (
+            "INSERT INTO users (id, name) VALUES (1, 'a', 'a@x')",
+            "2 columns but 3 values in row 1",
+        ),
+        (
+            "INSERT INTO users (id, name) VALUES (1)",
+            "2 columns but 1 value in row 1",
+        ),
+        (
+            "INSERT INTO users (id) SELECT id, name FROM staff",
+            "1 column but 2 values in the SELECT",
+        ),
+    ];
+    for (str, message) in cases {
+        let err = Parser::parse(&config, str).unwrap_err();
+        assert!(err.contains(message), "`{}` gave `{}`", str, err);
+    }
+}
+
+#[test]
+fn on_duplicate_values_function() {
+    let str = "INSERT INTO t (a, b) VALUES (1, 2) ON DUPLICATE KEY UPDATE a = VALUES(a), b = VALUES(b) + 1";
+    let res = InsertStatement::parse(str);
+    let stmt = res.unwrap().1;
+    let on_duplicate = stmt.on_duplicate.as_ref().unwrap();
+    match on_duplicate[0].1 {
+        FieldValueExpression::Column(ref col) => assert_eq!(
+            col.function.as_deref(),
+            Some(&FunctionExpression::Values(Column::from("a")))
+        ),
+        ref other => panic!("expected VALUES(a), got {:?}", other),
+    }
+    assert_eq!(stmt.row_alias, None);
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn on_duplicate_row_alias() {
+    let str = "INSERT INTO t (a, b) VALUES (1, 2), (3, 4) AS new ON DUPLICATE KEY UPDATE a = new.a";
+    let res = InsertStatement::parse(str);
+    let stmt = res.unwrap().1;
+    assert_eq!(stmt.row_alias, Some("new".to_string()));
+    assert_eq!(
+        stmt.on_duplicate,
+        Some(vec![(
+            Column::from("a"),
+            FieldValueExpression::Column(Column::from("new.a"))
+        )])
+    );
+    assert_eq!(str, format!("{}", stmt));
+    assert_eq!(InsertStatement::parse(&stmt.to_string()).unwrap().1, stmt);
+}
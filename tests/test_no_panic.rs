@@ -0,0 +1,244 @@
+extern crate sqlparser_mysql;
+
+use std::panic;
+
+use sqlparser_mysql::{ParseConfig, Parser};
+
+/// well-formed statements used as seeds for the mutations below
+const SEEDS: &[&str] = &[
+    "SELECT a, b FROM t AS x WHERE a = 1 AND b IN (1, 2) ORDER BY a DESC LIMIT 10, 5",
+    "SELECT COUNT(*), JSON_EXTRACT(doc, '$.a') FROM t GROUP BY c HAVING COUNT(*) > 1",
+    "SELECT * FROM a JOIN b ON a.id = b.id LEFT JOIN c USING (id) WHERE x LIKE '%y'",
+    "INSERT INTO t (a, b) VALUES (1, 'x'), (2, NULL) ON DUPLICATE KEY UPDATE a = 3",
+    "UPDATE t SET data = JSON_SET(data, '$.k', 1), n = n + 1 WHERE id = ?",
+    "DELETE FROM t WHERE a BETWEEN 1.5 AND 2.25",
+    "CREATE TABLE t (id INT(11) UNSIGNED NOT NULL AUTO_INCREMENT, name VARCHAR(255) DEFAULT 'a', \
+     amount DECIMAL(10, 2) DEFAULT 1.5, PRIMARY KEY (id), KEY idx_name (name(10))) \
+     ENGINE=InnoDB AUTO_INCREMENT=100 AVG_ROW_LENGTH=20 MAX_ROWS=1000",
+    "ALTER TABLE t ADD COLUMN c CHAR(10), MODIFY COLUMN d BIGINT(20), DROP INDEX i",
+    "CREATE INDEX idx ON t (a(10) DESC, b)",
+    "SET GLOBAL TRANSACTION ISOLATION LEVEL READ COMMITTED",
+    "SHOW WARNINGS LIMIT 10, 5",
+    "EXPLAIN FORMAT=JSON SELECT :1, $2 FROM t",
+    "CREATE EVENT e ON SCHEDULE EVERY 1 HOUR STARTS '2024-01-01' DO DELETE FROM t",
+    "ALTER EVENT e ON COMPLETION PRESERVE ENABLE",
+];
+
+/// tokens that tend to push parsers into numeric and nested code paths
+const TOKENS: &[&str] = &[
+    "99999999999999999999999",
+    "-99999999999999999999",
+    "4294967296",
+    "65536",
+    "256",
+    "1.99999999999",
+    ":99999999999",
+    "$99999999999",
+    "(",
+    ")",
+    ",",
+    "'",
+    "\"",
+    "`",
+    "/*",
+    "*/",
+    "\\G",
+    ";",
+    " ",
+    "é",
+    "\u{0}",
+    "SELECT",
+    "FROM",
+    "WHERE",
+    "AND",
+    "OR",
+    "NOT",
+    "CASE",
+    "WHEN",
+    "THEN",
+    "END",
+    "VARCHAR",
+    "INT",
+    "DECIMAL",
+    "AUTO_INCREMENT=",
+    "LIMIT",
+];
+
+/// tiny deterministic xorshift generator, so failures are reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+
+    fn char(&mut self) -> char {
+        match self.below(4) {
+            0 => char::from_u32(self.below(0x80) as u32).unwrap(),
+            1 => char::from_u32(0x80 + self.below(0x780) as u32).unwrap(),
+            _ => char::from(b' ' + self.below(95) as u8),
+        }
+    }
+}
+
+fn assert_no_panic(config: &ParseConfig, sql: &str) {
+    let res = panic::catch_unwind(panic::AssertUnwindSafe(|| Parser::parse(config, sql)));
+    assert!(res.is_ok(), "parser panicked on input {:?}", sql);
+}
+
+fn mutate(rng: &mut Rng, seed: &str) -> String {
+    let mut chars: Vec<char> = seed.chars().collect();
+    for _ in 0..=rng.below(3) {
+        let pos = rng.below(chars.len() + 1);
+        match rng.below(4) {
+            0 => chars.insert(pos, rng.char()),
+            1 if pos < chars.len() => {
+                chars.remove(pos);
+            }
+            2 => {
+                let token = TOKENS[rng.below(TOKENS.len())];
+                for (offset, c) in token.chars().enumerate() {
+                    chars.insert(pos + offset, c);
+                }
+            }
+            _ => chars.truncate(pos),
+        }
+    }
+    chars.into_iter().collect()
+}
+
+#[test]
+fn truncated_statements_do_not_panic() {
+    let config = ParseConfig::default();
+    for seed in SEEDS {
+        for (idx, _) in seed.char_indices() {
+            assert_no_panic(&config, &seed[..idx]);
+        }
+    }
+}
+
+#[test]
+fn mutated_statements_do_not_panic() {
+    let config = ParseConfig::default();
+    let mut rng = Rng(0x2545_f491_4f6c_dd1d);
+    for _ in 0..2000 {
+        let seed = SEEDS[rng.below(SEEDS.len())];
+        assert_no_panic(&config, &mutate(&mut rng, seed));
+    }
+}
+
+#[test]
+fn random_strings_do_not_panic() {
+    let config = ParseConfig::default();
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..2000 {
+        let mut sql = String::new();
+        for _ in 0..rng.below(24) {
+            match rng.below(2) {
+                0 => sql.push_str(TOKENS[rng.below(TOKENS.len())]),
+                _ => sql.push(rng.char()),
+            }
+        }
+        assert_no_panic(&config, &sql);
+    }
+}
+
+#[test]
+fn oversized_numbers_are_errors() {
+    let config = ParseConfig::default();
+    let statements = [
+        "SELECT * FROM t LIMIT 99999999999999999999999",
+        "SELECT 99999999999999999999999",
+        "SELECT 1.99999999999",
+        "SELECT :99999999999",
+        "CREATE TABLE t (a VARCHAR(65536))",
+        "CREATE TABLE t (a DECIMAL(256, 2))",
+        "CREATE TABLE t (a INT DEFAULT 99999999999999999999999)",
+        "CREATE TABLE t (a INT) AUTO_INCREMENT=99999999999999999999999",
+        "CREATE TABLE t (a INT) AVG_ROW_LENGTH=abc",
+        "CREATE INDEX idx ON t (a(99999999999999999999999))",
+    ];
+    for sql in statements {
+        let res = panic::catch_unwind(|| Parser::parse(&config, sql));
+        assert!(
+            matches!(res, Ok(Err(_))),
+            "expected an error for {:?}, got {:?}",
+            sql,
+            res
+        );
+    }
+}
+
+#[test]
+fn deep_nesting_is_an_error() {
+    let config = ParseConfig::default();
+    let depth = Parser::MAX_NESTING_DEPTH;
+
+    let sql = format!(
+        "SELECT {}1{}",
+        "lower(".repeat(depth / 2),
+        ")".repeat(depth / 2)
+    );
+    assert!(Parser::parse(&config, &sql).is_ok());
+
+    let openings = ["(", "lower(", "(SELECT a FROM t WHERE a IN "];
+    for opening in openings {
+        let sql = format!(
+            "SELECT * FROM t WHERE a IN {}1{}",
+            opening.repeat(10 * depth),
+            ")".repeat(10 * depth)
+        );
+        assert!(Parser::parse(&config, &sql).is_err());
+    }
+
+    // a long run of NOTs needs no parentheses, but still counts towards the depth
+    let sql = format!("SELECT * FROM t WHERE {}a = 1", "NOT ".repeat(100));
+    assert!(Parser::parse(&config, &sql).is_ok());
+    let sql = format!("SELECT * FROM t WHERE {}a = 1", "NOT ".repeat(10_000));
+    assert!(Parser::parse(&config, &sql).is_err());
+}
+
+#[test]
+fn deep_case_is_an_error() {
+    let config = ParseConfig::default();
+    let depth = 10 * Parser::MAX_NESTING_DEPTH;
+    let sql = format!(
+        "SELECT {}1{}",
+        "CASE WHEN a THEN ".repeat(depth),
+        " END".repeat(depth)
+    );
+    let res = std::thread::Builder::new()
+        .stack_size(2 << 20)
+        .spawn(move || Parser::parse(&config, &sql).is_err())
+        .unwrap()
+        .join();
+    assert!(res.unwrap());
+}
+
+#[test]
+fn long_logical_chains_do_not_overflow() {
+    for operator in [" AND ", " OR ", " + ", " = "] {
+        let config = ParseConfig::default();
+        let sql = format!(
+            "SELECT * FROM t WHERE a = 1{}",
+            format!("{}a", operator).repeat(200_000)
+        );
+        let res = std::thread::Builder::new()
+            .stack_size(2 << 20)
+            .spawn(move || Parser::parse(&config, &sql).is_err())
+            .unwrap()
+            .join();
+        assert!(res.unwrap(), "{}", operator);
+    }
+
+    let config = ParseConfig::default();
+    let sql = format!("SELECT * FROM t WHERE a = 1{}", " AND a = 1".repeat(500));
+    assert!(Parser::parse(&config, &sql).is_ok());
+}
@@ -0,0 +1,201 @@
+extern crate sqlparser_mysql;
+
+use sqlparser_mysql::{ParseConfig, Parser};
+
+/// Statements already in the canonical form written by `Display`, one or more
+/// per supported statement kind.
+const CANONICAL: &[&str] = &[
+    // SELECT
+    "SELECT * FROM users",
+    "SELECT DISTINCT a, b AS c FROM t AS x WHERE a = 1 AND b IN (1, 2) ORDER BY a DESC LIMIT 10 OFFSET 5",
+    "SELECT a, count(*) FROM t GROUP BY a HAVING count(*) > 1",
+    "SELECT t.a, u.b FROM t INNER JOIN u ON t.id = u.id LEFT JOIN v ON v.id = u.id",
+    "SELECT * FROM a JOIN (b, c) ON a.x = b.x",
+    "SELECT * FROM a JOIN b ON a.x = b.x, c LEFT JOIN d ON c.y = d.y",
+    "SELECT a FROM t WHERE b IN (SELECT b FROM u) AND c IS NOT NULL",
+    "SELECT * FROM t WHERE a = NULL OR a != NULL OR a IS NULL",
+    "SELECT a FROM t WHERE b BETWEEN 1 AND 10 OR c LIKE 'x%'",
+    "SELECT a FROM t WHERE (a BETWEEN 1 AND 2) = 1",
+    "SELECT a FROM t WHERE EXISTS (SELECT 1 FROM u WHERE u.a = t.a)",
+    "SELECT a FROM t WHERE NOT EXISTS (SELECT 1 FROM u)",
+    "SELECT a FROM t WHERE b NOT IN (1, 2) ORDER BY a ASC LIMIT 1",
+    "SELECT * FROM (SELECT a FROM t) AS d",
+    "SELECT * FROM (SELECT a FROM t) AS d, u JOIN v ON v.id = u.id",
+    "SELECT * FROM u, JSON_TABLE(u.doc, '$[*]' COLUMNS (id FOR ORDINALITY)) AS jt, v",
+    "SELECT a + b * c, (a + b) * c AS x FROM t",
+    "SELECT CASE WHEN a > 1 THEN 1 ELSE 2 END FROM t",
+    "SELECT a FROM t UNION ALL SELECT b FROM u",
+    "SELECT doc->>'$.name' FROM t WHERE doc->'$.id' = 1",
+    "SELECT RANK() OVER (PARTITION BY dept ORDER BY salary DESC) AS r, sum(x) OVER w FROM emp",
+    "SELECT a, RANK() OVER w FROM t GROUP BY a WINDOW w AS (ORDER BY a ASC), w2 AS (w ROWS 1 PRECEDING) ORDER BY a ASC",
+    // INSERT and REPLACE
+    "INSERT INTO t (a, b) VALUES (1, 'x'), (2, 'y')",
+    "INSERT INTO t (a) VALUES (1) ON DUPLICATE KEY UPDATE a = 2",
+    "INSERT INTO t (a) SELECT a FROM u",
+    "INSERT IGNORE INTO t (a) VALUES (1)",
+    "REPLACE INTO t (a) VALUES (1)",
+    // UPDATE and DELETE
+    "UPDATE t SET a = 1, b = 'x' WHERE c = 2",
+    "DELETE FROM t WHERE a = 1",
+    "DELETE FROM t",
+    // CREATE TABLE
+    "CREATE TABLE t (id INT(32) NOT NULL AUTO_INCREMENT, name VARCHAR(255), PRIMARY KEY (id))",
+    "CREATE TABLE t (a INT(32), b INT(32), KEY idx_b (b), UNIQUE KEY uk (a), FOREIGN KEY (b) REFERENCES u (id))",
+    "CREATE TABLE IF NOT EXISTS t (a INT(32) DEFAULT 1, b TEXT) ENGINE InnoDB",
+    "CREATE TEMPORARY TABLE t (a INT(32))",
+    "CREATE TABLE t LIKE u",
+    "CREATE TABLE t (a TIMESTAMP DEFAULT CURRENT_TIMESTAMP(6) ON UPDATE CURRENT_TIMESTAMP(6))",
+    "SELECT CURRENT_TIME(3), LOCALTIMESTAMP, UTC_TIMESTAMP(6) FROM t",
+    "CREATE TABLE t (a INT(32)) PARTITION BY HASH (a) PARTITIONS 4",
+    "CREATE TABLE t (a INT(32)) ENGINE InnoDB PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10), PARTITION p1 VALUES LESS THAN (MAXVALUE))",
+    "CREATE TABLE t (a INT(32)) ENGINE InnoDB AUTO_INCREMENT 1000 CHARSET utf8mb4 COLLATE utf8mb4_bin",
+    "CREATE TABLE t (a INT(32)) ROW_FORMAT DYNAMIC KEY_BLOCK_SIZE 8 COMPRESSION 'ZLIB' ENCRYPTION 'Y'",
+    "CREATE TABLE t (a INT(32)) AVG_ROW_LENGTH 100 MAX_ROWS 10 MIN_ROWS 1 CHECKSUM 1 DELAY_KEY_WRITE 0",
+    "CREATE TABLE t (a INT(32)) PACK_KEYS 1 STATS_PERSISTENT 0 STATS_AUTO_RECALC DEFAULT STATS_SAMPLE_PAGES 10",
+    "CREATE TABLE t (a INT(32)) DATA DIRECTORY '/d' INDEX DIRECTORY '/i' CONNECTION 'mysql://u@h/db/t'",
+    "CREATE TABLE t (a INT(32)) ENGINE MERGE UNION (u, v) INSERT_METHOD LAST",
+    "CREATE TABLE t (a INT(32)) ENGINE_ATTRIBUTE '{}' TABLESPACE ts STORAGE DISK",
+    // ALTER TABLE
+    "ALTER TABLE t ADD COLUMN c INT(32)",
+    "ALTER TABLE t DROP COLUMN c",
+    "ALTER TABLE t ADD INDEX idx_a (a)",
+    "ALTER TABLE t ADD PRIMARY KEY (a)",
+    "ALTER TABLE t DROP PRIMARY KEY, DROP INDEX idx",
+    "ALTER TABLE t CHANGE a b INT(32)",
+    "ALTER TABLE t MODIFY c INT(32) FIRST",
+    "ALTER TABLE t RENAME COLUMN a TO b",
+    "ALTER TABLE t RENAME TO u",
+    "ALTER TABLE t ADD FOREIGN KEY (b) REFERENCES u (id)",
+    "ALTER TABLE t ALGORITHM INPLACE, LOCK NONE",
+    "ALTER TABLE t CONVERT TO CHARACTER SET utf8mb4",
+    "ALTER TABLE t ALTER COLUMN a SET DEFAULT 1",
+    "ALTER TABLE t PARTITION BY RANGE (a) (PARTITION p0 VALUES LESS THAN (10), PARTITION p1 VALUES LESS THAN (MAXVALUE))",
+    "ALTER TABLE t ADD PARTITION (PARTITION p2 VALUES LESS THAN (20))",
+    // other DDL
+    "ALTER DATABASE db CHARACTER SET utf8mb4",
+    "CREATE INDEX idx ON t (a)",
+    "CREATE UNIQUE INDEX idx ON t (a, b)",
+    "CREATE FULLTEXT INDEX idx ON t (a)",
+    "CREATE EVENT IF NOT EXISTS e ON SCHEDULE EVERY 1 DAY DO DELETE FROM t",
+    "ALTER EVENT e DISABLE",
+    "ALTER EVENT e ON SCHEDULE AT CURRENT_TIMESTAMP + INTERVAL 1 HOUR RENAME TO f DO DELETE FROM t",
+    "CREATE FUNCTION f(s CHAR(20)) RETURNS CHAR(50) RETURN CONCAT('Hello, ', s)",
+    "CREATE SERVER s FOREIGN DATA WRAPPER mysql OPTIONS (HOST 'h', PORT 3306)",
+    "ALTER SERVER s OPTIONS (USER 'sally')",
+    "CREATE SPATIAL REFERENCE SYSTEM 4120 NAME 'Greek'",
+    "CREATE TABLESPACE ts ADD DATAFILE 'ts.ibd' ENGINE = InnoDB",
+    "CREATE LOGFILE GROUP lg ADD UNDOFILE 'undo.log' ENGINE = NDB",
+    "RENAME TABLE t TO u, v TO w",
+    "TRUNCATE TABLE t",
+    "DROP TABLE IF EXISTS t, u",
+    "DROP TABLE t CASCADE",
+    "DROP TEMPORARY TABLE t",
+    "DROP DATABASE db",
+    "DROP INDEX idx ON t",
+    "DROP VIEW v",
+    "DROP EVENT e",
+    "DROP FUNCTION f",
+    "DROP PROCEDURE p",
+    "DROP SERVER s",
+    "DROP TRIGGER tr",
+    "DROP TABLESPACE ts",
+    "DROP LOGFILE GROUP lg ENGINE = NDB",
+    "DROP SPATIAL REFERENCE SYSTEM 4120",
+    // administration and utility statements
+    "SET @a = 1",
+    "SET sql_mode = 'ANSI'",
+    "SET TRANSACTION ISOLATION LEVEL READ COMMITTED",
+    "SET SESSION TRANSACTION READ ONLY",
+    "SHOW GLOBAL VARIABLES LIKE 'max%'",
+    "SHOW WARNINGS LIMIT 5",
+    "EXPLAIN SELECT a FROM t",
+    "EXPLAIN FORMAT=JSON UPDATE t SET a = 1",
+    "EXPLAIN FOR CONNECTION 5",
+    "DO SLEEP(1)",
+];
+
+/// Statements and the canonical form `Display` writes them in.
+const NORMALIZED: &[(&str, &str)] = &[
+    (
+        "select * from users limit 10, 5;",
+        "SELECT * FROM users LIMIT 5 OFFSET 10",
+    ),
+    (
+        "SELECT a FROM t OFFSET 5 LIMIT 10",
+        "SELECT a FROM t LIMIT 10 OFFSET 5",
+    ),
+    (
+        "SELECT MAX(o_id)-3333 FROM orders",
+        "SELECT max(o_id) - 3333 FROM orders",
+    ),
+    (
+        "SELECT a FROM t ORDER BY CASE WHEN a > 1 THEN 1 ELSE 2 END",
+        "SELECT a FROM t ORDER BY CASE WHEN a > 1 THEN 1 ELSE 2 END ASC",
+    ),
+    (
+        "(SELECT a FROM t) UNION (SELECT a FROM u) ORDER BY a DESC LIMIT 3",
+        "SELECT a FROM t UNION DISTINCT SELECT a FROM u ORDER BY a DESC LIMIT 3",
+    ),
+    (
+        "CREATE TABLE t (id INT NOT NULL AUTO_INCREMENT, PRIMARY KEY (id)) ENGINE=InnoDB",
+        "CREATE TABLE t (id INT(32) NOT NULL AUTO_INCREMENT, PRIMARY KEY (id)) ENGINE InnoDB",
+    ),
+    (
+        "CREATE TABLE t (a INT PRIMARY KEY) DEFAULT CHARSET=utf8mb4 COMMENT='x'",
+        "CREATE TABLE t (a INT(32) PRIMARY KEY) CHARSET utf8mb4 COMMENT 'x'",
+    ),
+    (
+        "CREATE TABLE t (id INT) ENGINE=InnoDB AUTO_INCREMENT=1000 ROW_FORMAT=DYNAMIC",
+        "CREATE TABLE t (id INT(32)) ENGINE InnoDB AUTO_INCREMENT 1000 ROW_FORMAT DYNAMIC",
+    ),
+    (
+        "CREATE TABLE t AS SELECT a FROM u",
+        "CREATE TABLE t SELECT a FROM u",
+    ),
+    (
+        "ALTER TABLE t MODIFY COLUMN c INT FIRST",
+        "ALTER TABLE t MODIFY c INT(32) FIRST",
+    ),
+    (
+        "ALTER TABLE t ALGORITHM=INPLACE LOCK=NONE",
+        "ALTER TABLE t ALGORITHM INPLACE, LOCK NONE",
+    ),
+    (
+        "ALTER TABLE t ENGINE = InnoDB",
+        "ALTER TABLE t ENGINE InnoDB",
+    ),
+    (
+        "DROP LOGFILE GROUP lg ENGINE=NDB",
+        "DROP LOGFILE GROUP lg ENGINE = NDB",
+    ),
+    (
+        "CREATE PROCEDURE p(IN a INT) BEGIN SELECT a; END",
+        "CREATE PROCEDURE p(IN a INT(32)) BEGIN SELECT a; END",
+    ),
+];
+
+fn assert_round_trip(sql: &str, canonical: &str) {
+    let config = ParseConfig::default();
+    let statement = Parser::parse(&config, sql)
+        .unwrap_or_else(|err| panic!("failed to parse `{}`: {}", sql, err));
+    let written = statement.to_string();
+    assert_eq!(written, canonical, "written form of `{}`", sql);
+    let reparsed = Parser::parse(&config, &written)
+        .unwrap_or_else(|err| panic!("failed to parse written `{}`: {}", written, err));
+    assert_eq!(reparsed, statement, "reparsed `{}`", written);
+}
+
+#[test]
+fn canonical_statements_round_trip() {
+    for sql in CANONICAL {
+        assert_round_trip(sql, sql);
+    }
+}
+
+#[test]
+fn statements_are_written_in_canonical_form() {
+    for (sql, canonical) in NORMALIZED {
+        assert_round_trip(sql, canonical);
+        assert_round_trip(canonical, canonical);
+    }
+}
@@ -6,15 +6,17 @@ use sqlparser_mysql::base::condition::ConditionBase::LiteralList;
 use sqlparser_mysql::base::condition::ConditionExpression::{Base, ComparisonOp, LogicalOp};
 use sqlparser_mysql::base::condition::{ConditionBase, ConditionExpression, ConditionTree};
 use sqlparser_mysql::base::{
-    CaseWhenExpression, Column, ColumnOrLiteral, FieldDefinitionExpression, FieldValueExpression,
-    ItemPlaceholder, JoinClause, JoinConstraint, JoinOperator, JoinRightSide, Literal, Operator,
-    OrderClause, OrderType, Table,
+    AssignmentExpression, CaseWhenExpression, Column, ColumnOrLiteral, DataType,
+    FieldDefinitionExpression, FieldValueExpression, ItemPlaceholder, JoinClause, JoinConstraint,
+    JoinOperator, JoinRightSide, JsonTable, JsonTableColumn, Literal, LiteralExpression, Operator,
+    OrderClause, OrderType, SystemVariable, Table, WindowSpec,
 };
+use sqlparser_mysql::das::VariableScope;
 use sqlparser_mysql::dms::{
-    BetweenAndClause, CompoundSelectOperator, CompoundSelectStatement, GroupByClause, LimitClause,
-    SelectStatement,
+    BetweenAndClause, CompoundSelectOperand, CompoundSelectOperator, CompoundSelectStatement,
+    GroupByClause, LimitClause, ProcedureClause, SelectStatement, TableReference, TableSource,
 };
-use sqlparser_mysql::{ParseConfig, Parser};
+use sqlparser_mysql::{ParseConfig, Parser, Statement};
 
 #[test]
 fn display_select_query() {
@@ -64,7 +66,7 @@ fn union() {
     let res2 = CompoundSelectStatement::parse(qstr2);
 
     let first_select = SelectStatement {
-        tables: vec![Table::from("Vote")],
+        tables: vec![Table::from("Vote").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("id")),
             FieldDefinitionExpression::Value(FieldValueExpression::Literal(
@@ -74,7 +76,7 @@ fn union() {
         ..Default::default()
     };
     let second_select = SelectStatement {
-        tables: vec![Table::from("Rating")],
+        tables: vec![Table::from("Rating").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("id")),
             FieldDefinitionExpression::Col(Column::from("stars")),
@@ -83,8 +85,11 @@ fn union() {
     };
     let expected = CompoundSelectStatement {
         selects: vec![
-            (None, first_select),
-            (Some(CompoundSelectOperator::DistinctUnion), second_select),
+            (None, first_select.into()),
+            (
+                Some(CompoundSelectOperator::DistinctUnion),
+                second_select.into(),
+            ),
         ],
         order: None,
         limit: None,
@@ -131,7 +136,7 @@ fn multi_union() {
     let res = CompoundSelectStatement::parse(qstr);
 
     let first_select = SelectStatement {
-        tables: vec![Table::from("Vote")],
+        tables: vec![Table::from("Vote").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("id")),
             FieldDefinitionExpression::Value(FieldValueExpression::Literal(
@@ -141,7 +146,7 @@ fn multi_union() {
         ..Default::default()
     };
     let second_select = SelectStatement {
-        tables: vec![Table::from("Rating")],
+        tables: vec![Table::from("Rating").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("id")),
             FieldDefinitionExpression::Col(Column::from("stars")),
@@ -149,7 +154,7 @@ fn multi_union() {
         ..Default::default()
     };
     let third_select = SelectStatement {
-        tables: vec![Table::from("Vote")],
+        tables: vec![Table::from("Vote").into()],
         fields: vec![
             FieldDefinitionExpression::Value(FieldValueExpression::Literal(
                 Literal::Integer(42).into(),
@@ -163,9 +168,15 @@ fn multi_union() {
 
     let expected = CompoundSelectStatement {
         selects: vec![
-            (None, first_select),
-            (Some(CompoundSelectOperator::DistinctUnion), second_select),
-            (Some(CompoundSelectOperator::DistinctUnion), third_select),
+            (None, first_select.into()),
+            (
+                Some(CompoundSelectOperator::DistinctUnion),
+                second_select.into(),
+            ),
+            (
+                Some(CompoundSelectOperator::DistinctUnion),
+                third_select.into(),
+            ),
         ],
         order: None,
         limit: None,
@@ -180,7 +191,7 @@ fn union_all() {
     let res = CompoundSelectStatement::parse(qstr);
 
     let first_select = SelectStatement {
-        tables: vec![Table::from("Vote")],
+        tables: vec![Table::from("Vote").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("id")),
             FieldDefinitionExpression::Value(FieldValueExpression::Literal(
@@ -190,7 +201,7 @@ fn union_all() {
         ..Default::default()
     };
     let second_select = SelectStatement {
-        tables: vec![Table::from("Rating")],
+        tables: vec![Table::from("Rating").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("id")),
             FieldDefinitionExpression::Col(Column::from("stars")),
@@ -199,8 +210,8 @@ fn union_all() {
     };
     let expected = CompoundSelectStatement {
         selects: vec![
-            (None, first_select),
-            (Some(CompoundSelectOperator::Union), second_select),
+            (None, first_select.into()),
+            (Some(CompoundSelectOperator::Union), second_select.into()),
         ],
         order: None,
         limit: None,
@@ -209,12 +220,81 @@ fn union_all() {
     assert_eq!(res.unwrap().1, expected);
 }
 
+#[test]
+fn nested_union() {
+    let qstr = "(SELECT a FROM t UNION ALL SELECT a FROM u) UNION SELECT a FROM v ORDER BY a ASC";
+    let res = CompoundSelectStatement::parse(qstr).unwrap().1;
+
+    let select = |table: &str| SelectStatement {
+        tables: vec![Table::from(table).into()],
+        fields: FieldDefinitionExpression::from_column_str(&["a"]),
+        ..Default::default()
+    };
+    let inner = CompoundSelectStatement {
+        selects: vec![
+            (None, select("t").into()),
+            (Some(CompoundSelectOperator::Union), select("u").into()),
+        ],
+        order: None,
+        limit: None,
+    };
+    let expected = CompoundSelectStatement {
+        selects: vec![
+            (None, CompoundSelectOperand::Compound(Box::new(inner))),
+            (
+                Some(CompoundSelectOperator::DistinctUnion),
+                select("v").into(),
+            ),
+        ],
+        order: Some(OrderClause {
+            columns: vec![(Column::from("a"), OrderType::Asc)],
+        }),
+        limit: None,
+    };
+    assert_eq!(res, expected);
+    assert_eq!(
+        res.to_string(),
+        "(SELECT a FROM t UNION ALL SELECT a FROM u) UNION DISTINCT SELECT a FROM v ORDER BY a ASC"
+    );
+
+    // a nested compound as the last operand keeps its own ORDER BY and LIMIT
+    let qstr = "SELECT a FROM t EXCEPT (SELECT a FROM u UNION ALL SELECT a FROM v LIMIT 1)";
+    let res = CompoundSelectStatement::parse(qstr).unwrap().1;
+    assert_eq!(res.limit, None);
+    match res.selects[1].1 {
+        CompoundSelectOperand::Compound(ref compound) => {
+            assert_eq!(
+                compound.limit,
+                Some(LimitClause {
+                    limit: 1,
+                    offset: 0
+                })
+            )
+        }
+        ref operand => panic!("unexpected operand {:?}", operand),
+    }
+    assert_eq!(res.to_string(), qstr);
+    assert_eq!(
+        CompoundSelectStatement::parse(&res.to_string()).unwrap().1,
+        res
+    );
+}
+
 /////////////// SELECT
 #[test]
 fn between_and() {
     let str = "age between 10 and 20";
     let res = BetweenAndClause::parse(str);
-    println!("{:?}", res);
+    let clause = res.unwrap().1;
+    assert_eq!(
+        clause,
+        BetweenAndClause {
+            column: Column::from("age"),
+            left: String::from("10"),
+            right: String::from("20"),
+        }
+    );
+    assert_eq!(clause.to_string(), "age BETWEEN 10 AND 20");
 }
 
 #[test]
@@ -225,7 +305,7 @@ fn simple_select() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users")],
+            tables: vec![Table::from("users").into()],
             fields: FieldDefinitionExpression::from_column_str(&["id", "name"]),
             ..Default::default()
         }
@@ -240,7 +320,7 @@ fn more_involved_select() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users")],
+            tables: vec![Table::from("users").into()],
             fields: FieldDefinitionExpression::from_column_str(&["users.id", "users.name"]),
             ..Default::default()
         }
@@ -259,7 +339,7 @@ fn select_literals() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users")],
+            tables: vec![Table::from("users").into()],
             fields: vec![
                 FieldDefinitionExpression::Value(FieldValueExpression::Literal(
                     Literal::Null.into(),
@@ -271,7 +351,7 @@ fn select_literals() {
                     Literal::String("foo".to_owned()).into(),
                 )),
                 FieldDefinitionExpression::Value(FieldValueExpression::Literal(
-                    Literal::CurrentTime.into(),
+                    Literal::CurrentTime(None).into(),
                 )),
             ],
             ..Default::default()
@@ -287,7 +367,7 @@ fn select_all() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users")],
+            tables: vec![Table::from("users").into()],
             fields: vec![FieldDefinitionExpression::All],
             ..Default::default()
         }
@@ -302,7 +382,7 @@ fn select_all_in_table() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users"), Table::from("votes")],
+            tables: vec![Table::from("users").into(), Table::from("votes").into()],
             fields: vec![FieldDefinitionExpression::AllInTable(String::from("users"))],
             ..Default::default()
         }
@@ -317,7 +397,7 @@ fn spaces_optional() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users")],
+            tables: vec![Table::from("users").into()],
             fields: FieldDefinitionExpression::from_column_str(&["id", "name"]),
             ..Default::default()
         }
@@ -384,7 +464,7 @@ fn where_clause_with_variable_placeholder(str: &str, literal: Literal) {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("ContactInfo")],
+            tables: vec![Table::from("ContactInfo").into()],
             fields: vec![FieldDefinitionExpression::All],
             where_clause: expected_where_cond,
             ..Default::default()
@@ -396,6 +476,7 @@ fn where_clause_with_variable_placeholder(str: &str, literal: Literal) {
 fn limit_clause() {
     let str1 = "select * from users limit 10\n";
     let str2 = "select * from users limit 10 offset 10\n";
+    let str3 = "select * from users limit 10, 10\n";
 
     let expected_lim1 = LimitClause {
         limit: 10,
@@ -408,8 +489,30 @@ fn limit_clause() {
 
     let res1 = SelectStatement::parse(str1);
     let res2 = SelectStatement::parse(str2);
+    let res3 = SelectStatement::parse(str3);
     assert_eq!(res1.unwrap().1.limit, Some(expected_lim1));
-    assert_eq!(res2.unwrap().1.limit, Some(expected_lim2));
+    assert_eq!(res2.unwrap().1.limit, Some(expected_lim2.clone()));
+    assert_eq!(res3.unwrap().1.limit, Some(expected_lim2));
+}
+
+#[test]
+fn offset_before_limit() {
+    let res = SelectStatement::parse("SELECT * FROM users ORDER BY id OFFSET 5 LIMIT 10");
+    let select = res.unwrap().1;
+    assert_eq!(
+        select.limit,
+        Some(LimitClause {
+            limit: 10,
+            offset: 5,
+        })
+    );
+    assert_eq!(
+        select.to_string(),
+        "SELECT * FROM users ORDER BY id ASC LIMIT 10 OFFSET 5"
+    );
+
+    assert!(SelectStatement::parse("SELECT * FROM users OFFSET 5").is_err());
+    assert!(SelectStatement::parse("SELECT * FROM users OFFSET 5 LIMIT 10 OFFSET 3").is_err());
 }
 
 #[test]
@@ -421,11 +524,12 @@ fn table_alias() {
     assert_eq!(
         res1.unwrap().1,
         SelectStatement {
-            tables: vec![Table {
+            tables: vec![TableReference::from(Table {
                 name: String::from("PaperTag"),
                 alias: Some(String::from("t")),
                 schema: None,
-            },],
+                tablesample: None,
+            })],
             fields: vec![FieldDefinitionExpression::All],
             ..Default::default()
         }
@@ -442,11 +546,12 @@ fn table_schema() {
     assert_eq!(
         res1.unwrap().1,
         SelectStatement {
-            tables: vec![Table {
+            tables: vec![TableReference::from(Table {
                 name: String::from("PaperTag"),
                 alias: Some(String::from("t")),
                 schema: Some(String::from("db1")),
-            },],
+                tablesample: None,
+            })],
             fields: vec![FieldDefinitionExpression::All],
             ..Default::default()
         }
@@ -464,11 +569,12 @@ fn column_alias() {
     assert_eq!(
         res1.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("PaperTag")],
+            tables: vec![Table::from("PaperTag").into()],
             fields: vec![FieldDefinitionExpression::Col(Column {
                 name: String::from("name"),
                 alias: Some(String::from("TagName")),
                 table: None,
+                schema: None,
                 function: None,
             }),],
             ..Default::default()
@@ -478,11 +584,12 @@ fn column_alias() {
     assert_eq!(
         res2.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("PaperTag")],
+            tables: vec![Table::from("PaperTag").into()],
             fields: vec![FieldDefinitionExpression::Col(Column {
                 name: String::from("name"),
                 alias: Some(String::from("TagName")),
                 table: Some(String::from("PaperTag")),
+                schema: None,
                 function: None,
             }),],
             ..Default::default()
@@ -499,11 +606,12 @@ fn column_alias_no_as() {
     assert_eq!(
         res1.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("PaperTag")],
+            tables: vec![Table::from("PaperTag").into()],
             fields: vec![FieldDefinitionExpression::Col(Column {
                 name: String::from("name"),
                 alias: Some(String::from("TagName")),
                 table: None,
+                schema: None,
                 function: None,
             }),],
             ..Default::default()
@@ -513,11 +621,12 @@ fn column_alias_no_as() {
     assert_eq!(
         res2.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("PaperTag")],
+            tables: vec![Table::from("PaperTag").into()],
             fields: vec![FieldDefinitionExpression::Col(Column {
                 name: String::from("name"),
                 alias: Some(String::from("TagName")),
                 table: Some(String::from("PaperTag")),
+                schema: None,
                 function: None,
             }),],
             ..Default::default()
@@ -541,7 +650,7 @@ fn distinct() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("PaperTag")],
+            tables: vec![Table::from("PaperTag").into()],
             distinct: true,
             fields: FieldDefinitionExpression::from_column_str(&["tag"]),
             where_clause: expected_where_cond,
@@ -580,7 +689,7 @@ fn simple_condition_expr() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("PaperStorage")],
+            tables: vec![Table::from("PaperStorage").into()],
             fields: FieldDefinitionExpression::from_column_str(&["infoJson"]),
             where_clause: expected_where_cond,
             ..Default::default()
@@ -609,7 +718,7 @@ fn where_and_limit_clauses() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("users")],
+            tables: vec![Table::from("users").into()],
             fields: vec![FieldDefinitionExpression::All],
             where_clause: expected_where_cond,
             limit: expected_lim,
@@ -627,11 +736,12 @@ fn aggregation_column() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("address")],
+            tables: vec![Table::from("address").into()],
             fields: vec![FieldDefinitionExpression::Col(Column {
                 name: String::from("max(addr_id)"),
                 alias: None,
                 table: None,
+                schema: None,
                 function: Some(Box::new(agg_expr)),
             }),],
             ..Default::default()
@@ -646,11 +756,12 @@ fn aggregation_column_with_alias() {
     let res = SelectStatement::parse(str);
     let agg_expr = FunctionExpression::Max(FunctionArgument::Column(Column::from("addr_id")));
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("address")],
+        tables: vec![Table::from("address").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: String::from("max_addr"),
             alias: Some(String::from("max_addr")),
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         ..Default::default()
@@ -665,15 +776,16 @@ fn count_all() {
     let res = SelectStatement::parse(str);
     let agg_expr = FunctionExpression::CountStar;
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("votes")],
+        tables: vec![Table::from("votes").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: String::from("count(*)"),
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         group_by: Some(GroupByClause {
-            columns: vec![Column::from("aid")],
+            columns: vec![Base(ConditionBase::Field(Column::from("aid")))],
             having: None,
         }),
         ..Default::default()
@@ -689,15 +801,16 @@ fn count_distinct() {
     let agg_expr =
         FunctionExpression::Count(FunctionArgument::Column(Column::from("vote_id")), true);
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("votes")],
+        tables: vec![Table::from("votes").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: String::from("count(distinct vote_id)"),
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         group_by: Some(GroupByClause {
-            columns: vec![Column::from("aid")],
+            columns: vec![Base(ConditionBase::Field(Column::from("aid")))],
             having: None,
         }),
         ..Default::default()
@@ -717,22 +830,25 @@ fn count_filter() {
     });
     let agg_expr = FunctionExpression::Count(
         FunctionArgument::Conditional(CaseWhenExpression {
-            then_expr: ColumnOrLiteral::Column(Column::from("vote_id")),
+            branches: vec![(
+                filter_cond,
+                ColumnOrLiteral::Column(Column::from("vote_id")),
+            )],
             else_expr: None,
-            condition: filter_cond,
         }),
         false,
     );
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("votes")],
+        tables: vec![Table::from("votes").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: format!("{}", agg_expr),
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         group_by: Some(GroupByClause {
-            columns: vec![Column::from("aid")],
+            columns: vec![Base(ConditionBase::Field(Column::from("aid")))],
             having: None,
         }),
         ..Default::default()
@@ -753,22 +869,25 @@ fn sum_filter() {
     });
     let agg_expr = FunctionExpression::Sum(
         FunctionArgument::Conditional(CaseWhenExpression {
-            then_expr: ColumnOrLiteral::Column(Column::from("vote_id")),
+            branches: vec![(
+                filter_cond,
+                ColumnOrLiteral::Column(Column::from("vote_id")),
+            )],
             else_expr: None,
-            condition: filter_cond,
         }),
         false,
     );
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("votes")],
+        tables: vec![Table::from("votes").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: format!("{}", agg_expr),
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         group_by: Some(GroupByClause {
-            columns: vec![Column::from("aid")],
+            columns: vec![Base(ConditionBase::Field(Column::from("aid")))],
             having: None,
         }),
         ..Default::default()
@@ -789,22 +908,25 @@ fn sum_filter_else_literal() {
     });
     let agg_expr = FunctionExpression::Sum(
         FunctionArgument::Conditional(CaseWhenExpression {
-            then_expr: ColumnOrLiteral::Column(Column::from("vote_id")),
+            branches: vec![(
+                filter_cond,
+                ColumnOrLiteral::Column(Column::from("vote_id")),
+            )],
             else_expr: Some(ColumnOrLiteral::Literal(Literal::Integer(6))),
-            condition: filter_cond,
         }),
         false,
     );
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("votes")],
+        tables: vec![Table::from("votes").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: format!("{}", agg_expr),
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         group_by: Some(GroupByClause {
-            columns: vec![Column::from("aid")],
+            columns: vec![Base(ConditionBase::Field(Column::from("aid")))],
             having: None,
         }),
         ..Default::default()
@@ -825,7 +947,7 @@ fn count_filter_lobsters() {
         left: Box::new(ComparisonOp(ConditionTree {
             left: Box::new(Base(ConditionBase::Field(Column::from("votes.story_id")))),
             right: Box::new(Base(ConditionBase::Literal(Literal::Null))),
-            operator: Operator::Equal,
+            operator: Operator::Is,
         })),
         right: Box::new(ComparisonOp(ConditionTree {
             left: Box::new(Base(ConditionBase::Field(Column::from("votes.vote")))),
@@ -836,22 +958,25 @@ fn count_filter_lobsters() {
     });
     let agg_expr = FunctionExpression::Count(
         FunctionArgument::Conditional(CaseWhenExpression {
-            then_expr: ColumnOrLiteral::Column(Column::from("votes.vote")),
+            branches: vec![(
+                filter_cond,
+                ColumnOrLiteral::Column(Column::from("votes.vote")),
+            )],
             else_expr: None,
-            condition: filter_cond,
         }),
         false,
     );
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("votes")],
+        tables: vec![Table::from("votes").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: String::from("votes"),
             alias: Some(String::from("votes")),
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         group_by: Some(GroupByClause {
-            columns: vec![Column::from("votes.comment_id")],
+            columns: vec![Base(ConditionBase::Field(Column::from("votes.comment_id")))],
             having: None,
         }),
         ..Default::default()
@@ -859,6 +984,143 @@ fn count_filter_lobsters() {
     assert_eq!(res.unwrap().1, expected_stmt);
 }
 
+#[test]
+fn case_order_key() {
+    let str = "SELECT id FROM items ORDER BY CASE WHEN status = 'new' THEN 1 ELSE 2 END, id DESC";
+    let res = SelectStatement::parse(str).unwrap().1;
+
+    let (key, order) = &res.order.as_ref().unwrap().columns[0];
+    assert_eq!(*order, OrderType::Asc);
+    let case_when = match key.function.as_deref() {
+        Some(FunctionExpression::Case(case_when)) => case_when,
+        other => panic!("expected a CASE order key, got {:?}", other),
+    };
+    assert_eq!(case_when.branches[0].1, ColumnOrLiteral::Literal(1.into()));
+    assert_eq!(
+        case_when.else_expr,
+        Some(ColumnOrLiteral::Literal(2.into()))
+    );
+    assert_eq!(
+        res.to_string(),
+        "SELECT id FROM items \
+        ORDER BY CASE WHEN status = 'new' THEN 1 ELSE 2 END ASC, id DESC"
+    );
+
+    let str = "SELECT id FROM items \
+        ORDER BY CASE WHEN status = 'new' THEN 1 WHEN status = 'old' THEN rank + 1 ELSE 3 END ASC";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let key = &res.order.as_ref().unwrap().columns[0].0;
+    let case_when = match key.function.as_deref() {
+        Some(FunctionExpression::Case(case_when)) => case_when,
+        other => panic!("expected a CASE order key, got {:?}", other),
+    };
+    assert_eq!(case_when.branches.len(), 2);
+    assert!(matches!(
+        case_when.branches[1].1,
+        ColumnOrLiteral::Arithmetic(_)
+    ));
+    assert_eq!(res.to_string(), str);
+}
+
+#[test]
+fn case_comparison_operand() {
+    let str = "SELECT id FROM items WHERE CASE WHEN a > b THEN a ELSE b END = 1";
+    let res = SelectStatement::parse(str).unwrap().1;
+
+    let case_when = CaseWhenExpression {
+        branches: vec![(
+            ComparisonOp(ConditionTree {
+                left: Box::new(Base(ConditionBase::Field(Column::from("a")))),
+                right: Box::new(Base(ConditionBase::Field(Column::from("b")))),
+                operator: Operator::Greater,
+            }),
+            ColumnOrLiteral::Column(Column::from("a")),
+        )],
+        else_expr: Some(ColumnOrLiteral::Column(Column::from("b"))),
+    };
+    let case_column = Column {
+        name: String::from("CASE WHEN a > b THEN a ELSE b END"),
+        alias: None,
+        table: None,
+        schema: None,
+        function: Some(Box::new(FunctionExpression::Case(case_when))),
+    };
+    assert_eq!(
+        res.where_clause,
+        Some(ComparisonOp(ConditionTree {
+            left: Box::new(Base(ConditionBase::Field(case_column))),
+            right: Box::new(Base(ConditionBase::Literal(1.into()))),
+            operator: Operator::Equal,
+        }))
+    );
+    assert_eq!(res.to_string(), str);
+
+    let str = "SELECT id FROM items \
+        WHERE CASE WHEN a > b THEN a WHEN a < b THEN b - a ELSE 0 END > 1";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let case_when = match res.where_clause {
+        Some(ComparisonOp(ConditionTree { ref left, .. })) => match **left {
+            Base(ConditionBase::Field(ref column)) => match column.function.as_deref() {
+                Some(FunctionExpression::Case(case_when)) => case_when.clone(),
+                ref other => panic!("expected a CASE operand, got {:?}", other),
+            },
+            ref other => panic!("expected a CASE operand, got {:?}", other),
+        },
+        ref other => panic!("expected a comparison, got {:?}", other),
+    };
+    assert_eq!(case_when.branches.len(), 2);
+    assert_eq!(
+        case_when.else_expr,
+        Some(ColumnOrLiteral::Literal(0.into()))
+    );
+    assert_eq!(res.to_string(), str);
+}
+
+#[test]
+fn json_extraction_binds_tighter_than_comparison() {
+    let str = "SELECT id FROM items WHERE a->'$.x' = b->'$.y'";
+    let res = SelectStatement::parse(str).unwrap().1;
+
+    let extraction = |name: &str, path: &str| Column {
+        name: format!("{}->'{}'", name, path),
+        alias: None,
+        table: None,
+        schema: None,
+        function: Some(Box::new(FunctionExpression::JsonExtract(
+            Column::from(name),
+            String::from(path),
+        ))),
+    };
+    assert_eq!(
+        res.where_clause,
+        Some(ComparisonOp(ConditionTree {
+            left: Box::new(Base(ConditionBase::Field(extraction("a", "$.x")))),
+            right: Box::new(Base(ConditionBase::Field(extraction("b", "$.y")))),
+            operator: Operator::Equal,
+        }))
+    );
+    assert_eq!(res.to_string(), str);
+
+    let str = "SELECT t.doc->>'$.name' AS name FROM t WHERE doc -> '$.a' = 'x'";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let field = match res.fields[0] {
+        FieldDefinitionExpression::Col(ref column) => column.clone(),
+        _ => panic!("expected a column"),
+    };
+    assert_eq!(field.alias, Some(String::from("name")));
+    assert_eq!(
+        field.function,
+        Some(Box::new(FunctionExpression::JsonUnquoteExtract(
+            Column::from("t.doc"),
+            String::from("$.name"),
+        )))
+    );
+    assert_eq!(
+        res.to_string(),
+        "SELECT t.doc->>'$.name' AS name FROM t WHERE doc->'$.a' = 'x'"
+    );
+}
+
 #[test]
 fn generic_function_query() {
     let str = "SELECT coalesce(a, b,c) as x,d FROM sometable;";
@@ -872,36 +1134,41 @@ fn generic_function_query() {
                     name: String::from("a"),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: None,
                 }),
                 FunctionArgument::Column(Column {
                     name: String::from("b"),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: None,
                 }),
                 FunctionArgument::Column(Column {
                     name: String::from("c"),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: None,
                 }),
             ],
         },
     );
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("sometable")],
+        tables: vec![Table::from("sometable").into()],
         fields: vec![
             FieldDefinitionExpression::Col(Column {
                 name: String::from("x"),
                 alias: Some(String::from("x")),
                 table: None,
+                schema: None,
                 function: Some(Box::new(agg_expr)),
             }),
             FieldDefinitionExpression::Col(Column {
                 name: String::from("d"),
                 alias: None,
                 table: None,
+                schema: None,
                 function: None,
             }),
         ],
@@ -934,7 +1201,7 @@ fn moderately_complex_selection() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("item"), Table::from("author")],
+            tables: vec![Table::from("item").into(), Table::from("author").into()],
             fields: vec![FieldDefinitionExpression::All],
             where_clause: expected_where_cond,
             order: Some(OrderClause {
@@ -949,19 +1216,72 @@ fn moderately_complex_selection() {
     );
 }
 
+#[test]
+fn nested_selection_with_trailing_order_and_limit() {
+    let str =
+        "SELECT * FROM item WHERE item.i_a_id IN (SELECT a_id FROM author WHERE a_lname = ?) \
+               ORDER BY item.i_title DESC LIMIT 10";
+
+    let res = SelectStatement::parse(str).unwrap();
+    assert_eq!(res.0, "");
+    let select = res.1;
+    let nested = match select.where_clause {
+        Some(ComparisonOp(ConditionTree {
+            operator: Operator::In,
+            ref right,
+            ..
+        })) => match **right {
+            Base(ConditionBase::NestedSelect(ref nested)) => nested.clone(),
+            ref other => panic!("expected a nested select, got {:?}", other),
+        },
+        ref other => panic!("expected IN, got {:?}", other),
+    };
+    assert_eq!(nested.order, None);
+    assert_eq!(nested.limit, None);
+    assert_eq!(
+        select.order,
+        Some(OrderClause {
+            columns: vec![("item.i_title".into(), OrderType::Desc)],
+        })
+    );
+    assert_eq!(
+        select.limit,
+        Some(LimitClause {
+            limit: 10,
+            offset: 0,
+        })
+    );
+    assert_eq!(select.to_string(), str);
+
+    // a nested select may have its own ORDER BY and LIMIT
+    let str = "SELECT a FROM t WHERE x IN (SELECT y FROM u ORDER BY y ASC LIMIT 5) \
+               ORDER BY a ASC LIMIT 10 OFFSET 2";
+    let select = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(
+        select.limit,
+        Some(LimitClause {
+            limit: 10,
+            offset: 2,
+        })
+    );
+    assert_eq!(select.to_string(), str);
+}
+
 #[test]
 fn simple_joins() {
     let str = "select paperId from PaperConflict join PCMember using (contactId);";
 
     let res = SelectStatement::parse(str);
     let expected_stmt = SelectStatement {
-        tables: vec![Table::from("PaperConflict")],
-        fields: FieldDefinitionExpression::from_column_str(&["paperId"]),
-        join: vec![JoinClause {
-            operator: JoinOperator::Join,
-            right: JoinRightSide::Table(Table::from("PCMember")),
-            constraint: JoinConstraint::Using(vec![Column::from("contactId")]),
+        tables: vec![TableReference {
+            source: Table::from("PaperConflict").into(),
+            join: vec![JoinClause {
+                operator: JoinOperator::Join,
+                right: JoinRightSide::Table(Table::from("PCMember")),
+                constraint: JoinConstraint::Using(vec![Column::from("contactId")]),
+            }],
         }],
+        fields: FieldDefinitionExpression::from_column_str(&["paperId"]),
         ..Default::default()
     };
     assert_eq!(res.unwrap().1, expected_stmt);
@@ -987,13 +1307,15 @@ fn simple_joins() {
     };
     let join_cond = ConditionExpression::ComparisonOp(ct);
     let expected = SelectStatement {
-        tables: vec![Table::from("PCMember")],
-        fields: FieldDefinitionExpression::from_column_str(&["PCMember.contactId"]),
-        join: vec![JoinClause {
-            operator: JoinOperator::Join,
-            right: JoinRightSide::Table(Table::from("PaperReview")),
-            constraint: JoinConstraint::On(join_cond),
+        tables: vec![TableReference {
+            source: Table::from("PCMember").into(),
+            join: vec![JoinClause {
+                operator: JoinOperator::Join,
+                right: JoinRightSide::Table(Table::from("PaperReview")),
+                constraint: JoinConstraint::On(join_cond),
+            }],
         }],
+        fields: FieldDefinitionExpression::from_column_str(&["PCMember.contactId"]),
         order: Some(OrderClause {
             columns: vec![("contactId".into(), OrderType::Asc)],
         }),
@@ -1047,19 +1369,21 @@ fn multi_join() {
     assert_eq!(
         res.unwrap().1,
         SelectStatement {
-            tables: vec![Table::from("ContactInfo")],
+            tables: vec![TableReference {
+                source: Table::from("ContactInfo").into(),
+                join: vec![
+                    mkjoin("PaperReview", "contactId"),
+                    mkjoin("PaperConflict", "contactId"),
+                    mkjoin("PCMember", "contactId"),
+                    mkjoin("ChairAssistant", "contactId"),
+                    mkjoin("Chair", "contactId"),
+                ],
+            }],
             fields: FieldDefinitionExpression::from_column_str(&[
                 "PCMember.contactId",
                 "ChairAssistant.contactId",
                 "Chair.contactId"
             ]),
-            join: vec![
-                mkjoin("PaperReview", "contactId"),
-                mkjoin("PaperConflict", "contactId"),
-                mkjoin("PCMember", "contactId"),
-                mkjoin("ChairAssistant", "contactId"),
-                mkjoin("Chair", "contactId"),
-            ],
             where_clause: expected_where_cond,
             ..Default::default()
         }
@@ -1082,7 +1406,10 @@ fn nested_select() {
     });
 
     let inner_select = SelectStatement {
-        tables: vec![Table::from("orders"), Table::from("order_line")],
+        tables: vec![
+            Table::from("orders").into(),
+            Table::from("order_line").into(),
+        ],
         fields: FieldDefinitionExpression::from_column_str(&["o_c_id"]),
         where_clause: Some(inner_where_clause),
         ..Default::default()
@@ -1095,7 +1422,10 @@ fn nested_select() {
     });
 
     let outer_select = SelectStatement {
-        tables: vec![Table::from("orders"), Table::from("order_line")],
+        tables: vec![
+            Table::from("orders").into(),
+            Table::from("order_line").into(),
+        ],
         fields: FieldDefinitionExpression::from_column_str(&["ol_i_id"]),
         where_clause: Some(outer_where_clause),
         ..Default::default()
@@ -1115,11 +1445,12 @@ fn recursive_nested_select() {
 
     let agg_expr = FunctionExpression::Max(FunctionArgument::Column(Column::from("o_id")));
     let recursive_select = SelectStatement {
-        tables: vec![Table::from("orders")],
+        tables: vec![Table::from("orders").into()],
         fields: vec![FieldDefinitionExpression::Col(Column {
             name: String::from("max(o_id)"),
             alias: None,
             table: None,
+            schema: None,
             function: Some(Box::new(agg_expr)),
         })],
         ..Default::default()
@@ -1148,7 +1479,10 @@ fn recursive_nested_select() {
     });
 
     let inner_select = SelectStatement {
-        tables: vec![Table::from("orders"), Table::from("order_line")],
+        tables: vec![
+            Table::from("orders").into(),
+            Table::from("order_line").into(),
+        ],
         fields: FieldDefinitionExpression::from_column_str(&["o_c_id"]),
         where_clause: Some(inner_where_clause),
         ..Default::default()
@@ -1161,7 +1495,10 @@ fn recursive_nested_select() {
     });
 
     let outer_select = SelectStatement {
-        tables: vec![Table::from("orders"), Table::from("order_line")],
+        tables: vec![
+            Table::from("orders").into(),
+            Table::from("order_line").into(),
+        ],
         fields: FieldDefinitionExpression::from_column_str(&["ol_i_id"]),
         where_clause: Some(outer_where_clause),
         ..Default::default()
@@ -1170,6 +1507,45 @@ fn recursive_nested_select() {
     assert_eq!(res.unwrap().1, outer_select);
 }
 
+#[test]
+fn comma_list_with_join() {
+    let str = "SELECT * FROM a, b JOIN c ON b.id = c.bid";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let join = JoinClause {
+        operator: JoinOperator::Join,
+        right: JoinRightSide::Table(Table::from("c")),
+        constraint: JoinConstraint::On(ComparisonOp(ConditionTree {
+            operator: Operator::Equal,
+            left: Box::new(Base(ConditionBase::Field(Column::from("b.id")))),
+            right: Box::new(Base(ConditionBase::Field(Column::from("c.bid")))),
+        })),
+    };
+    assert_eq!(
+        res,
+        SelectStatement {
+            tables: vec![
+                Table::from("a").into(),
+                TableReference {
+                    source: Table::from("b").into(),
+                    join: vec![join],
+                },
+            ],
+            fields: vec![FieldDefinitionExpression::All],
+            ..Default::default()
+        }
+    );
+    assert_eq!(res.to_string(), str);
+    assert_eq!(SelectStatement::parse(&res.to_string()).unwrap().1, res);
+
+    // joins stay with their entry when more entries follow
+    let str = "SELECT * FROM a JOIN b ON a.x = b.x, c";
+    let res = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(res.tables.len(), 2);
+    assert_eq!(res.tables[0].join.len(), 1);
+    assert_eq!(res.tables[1], Table::from("c").into());
+    assert_eq!(res.to_string(), str);
+}
+
 #[test]
 fn join_against_nested_select() {
     let t0 = "(SELECT ol_i_id FROM order_line)";
@@ -1191,23 +1567,25 @@ fn join_against_nested_select() {
 
     // N.B.: Don't alias the inner select to `inner`, which is, well, a SQL keyword!
     let inner_select = SelectStatement {
-        tables: vec![Table::from("order_line")],
+        tables: vec![Table::from("order_line").into()],
         fields: FieldDefinitionExpression::from_column_str(&["ol_i_id"]),
         ..Default::default()
     };
 
     let outer_select = SelectStatement {
-        tables: vec![Table::from("orders")],
-        fields: FieldDefinitionExpression::from_column_str(&["o_id", "ol_i_id"]),
-        join: vec![JoinClause {
-            operator: JoinOperator::Join,
-            right: JoinRightSide::NestedSelect(Box::new(inner_select), Some("ids".into())),
-            constraint: JoinConstraint::On(ComparisonOp(ConditionTree {
-                operator: Operator::Equal,
-                left: Box::new(Base(ConditionBase::Field(Column::from("orders.o_id")))),
-                right: Box::new(Base(ConditionBase::Field(Column::from("ids.ol_i_id")))),
-            })),
+        tables: vec![TableReference {
+            source: Table::from("orders").into(),
+            join: vec![JoinClause {
+                operator: JoinOperator::Join,
+                right: JoinRightSide::NestedSelect(Box::new(inner_select), Some("ids".into())),
+                constraint: JoinConstraint::On(ComparisonOp(ConditionTree {
+                    operator: Operator::Equal,
+                    left: Box::new(Base(ConditionBase::Field(Column::from("orders.o_id")))),
+                    right: Box::new(Base(ConditionBase::Field(Column::from("ids.ol_i_id")))),
+                })),
+            }],
         }],
+        fields: FieldDefinitionExpression::from_column_str(&["o_id", "ol_i_id"]),
         ..Default::default()
     };
 
@@ -1220,7 +1598,7 @@ fn project_arithmetic_expressions() {
     let res = SelectStatement::parse(qstr);
 
     let expected = SelectStatement {
-        tables: vec![Table::from("orders")],
+        tables: vec![Table::from("orders").into()],
         fields: vec![FieldDefinitionExpression::Value(
             FieldValueExpression::Arithmetic(ArithmeticExpression::new(
                 ArithmeticOperator::Subtract,
@@ -1228,6 +1606,7 @@ fn project_arithmetic_expressions() {
                     name: String::from("max(o_id)"),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: Some(Box::new(FunctionExpression::Max(FunctionArgument::Column(
                         "o_id".into(),
                     )))),
@@ -1248,7 +1627,7 @@ fn project_arithmetic_expressions_with_aliases() {
     let res = SelectStatement::parse(qstr);
 
     let expected = SelectStatement {
-        tables: vec![Table::from("orders")],
+        tables: vec![Table::from("orders").into()],
         fields: vec![FieldDefinitionExpression::Value(
             FieldValueExpression::Arithmetic(ArithmeticExpression::new(
                 ArithmeticOperator::Multiply,
@@ -1256,6 +1635,7 @@ fn project_arithmetic_expressions_with_aliases() {
                     name: String::from("max(o_id)"),
                     alias: None,
                     table: None,
+                    schema: None,
                     function: Some(Box::new(FunctionExpression::Max(FunctionArgument::Column(
                         "o_id".into(),
                     )))),
@@ -1270,6 +1650,28 @@ fn project_arithmetic_expressions_with_aliases() {
     assert_eq!(res.unwrap().1, expected);
 }
 
+#[test]
+fn arithmetic_projection_round_trip() {
+    let qstrs = [
+        "SELECT max(o_id) - 3333 FROM orders",
+        "SELECT max(o_id) * 2 AS double_max FROM orders",
+        "SELECT a + b * c, (a + b) * c AS x FROM t",
+        "SELECT a - (b - c), t.a / 2 AS half FROM t",
+        "SELECT count(*) * 2 FROM t",
+    ];
+    for qstr in qstrs {
+        let res = SelectStatement::parse(qstr).unwrap().1;
+        assert_eq!(res.to_string(), qstr);
+    }
+
+    // the operators are always written with one space on each side
+    let res = SelectStatement::parse("SELECT MAX(o_id)-3333 FROM orders;");
+    assert_eq!(
+        res.unwrap().1.to_string(),
+        "SELECT max(o_id) - 3333 FROM orders"
+    );
+}
+
 #[test]
 fn where_in_clause() {
     let qstr = "SELECT `auth_permission`.`content_type_id`, `auth_permission`.`codename`
@@ -1288,27 +1690,631 @@ fn where_in_clause() {
     }));
 
     let expected = SelectStatement {
-        tables: vec![Table::from("auth_permission")],
+        tables: vec![TableReference {
+            source: Table::from("auth_permission").into(),
+            join: vec![JoinClause {
+                operator: JoinOperator::Join,
+                right: JoinRightSide::Table(Table::from("django_content_type")),
+                constraint: JoinConstraint::On(ComparisonOp(ConditionTree {
+                    operator: Operator::Equal,
+                    left: Box::new(Base(ConditionBase::Field(Column::from(
+                        "auth_permission.content_type_id",
+                    )))),
+                    right: Box::new(Base(ConditionBase::Field(Column::from(
+                        "django_content_type.id",
+                    )))),
+                })),
+            }],
+        }],
         fields: vec![
             FieldDefinitionExpression::Col(Column::from("auth_permission.content_type_id")),
             FieldDefinitionExpression::Col(Column::from("auth_permission.codename")),
         ],
-        join: vec![JoinClause {
-            operator: JoinOperator::Join,
-            right: JoinRightSide::Table(Table::from("django_content_type")),
-            constraint: JoinConstraint::On(ComparisonOp(ConditionTree {
-                operator: Operator::Equal,
-                left: Box::new(Base(ConditionBase::Field(Column::from(
-                    "auth_permission.content_type_id",
-                )))),
-                right: Box::new(Base(ConditionBase::Field(Column::from(
-                    "django_content_type.id",
-                )))),
-            })),
-        }],
         where_clause: expected_where_clause,
         ..Default::default()
     };
 
     assert_eq!(res.unwrap().1, expected);
 }
+
+#[test]
+fn select_keyword_functions() {
+    let str = "SELECT CURRENT_DATE, CURRENT_USER";
+    let res = SelectStatement::parse(str);
+
+    let expected = SelectStatement {
+        fields: vec![
+            FieldDefinitionExpression::Value(FieldValueExpression::Literal(
+                Literal::CurrentDate.into(),
+            )),
+            FieldDefinitionExpression::Value(FieldValueExpression::Literal(
+                Literal::CurrentUser.into(),
+            )),
+        ],
+        ..Default::default()
+    };
+    assert_eq!(res.unwrap().1, expected);
+
+    let config = ParseConfig::default();
+    let str1 = "SELECT LOCALTIME, LOCALTIMESTAMP, UTC_TIMESTAMP FROM users";
+    let res1 = Parser::parse(&config, str1);
+    assert!(res1.is_ok());
+    assert_eq!(str1, format!("{}", res1.unwrap()));
+}
+
+#[test]
+fn select_binary_and_row() {
+    let config = ParseConfig::default();
+    let str0 = "SELECT * FROM users WHERE BINARY name = 'X'";
+    let str1 = "SELECT ROW(1, 2)";
+
+    let res0 = Parser::parse(&config, str0);
+    let res1 = Parser::parse(&config, str1);
+    assert!(res0.is_ok());
+    assert!(res1.is_ok());
+    assert_eq!(str0, format!("{}", res0.unwrap()));
+    assert_eq!(str1, format!("{}", res1.unwrap()));
+
+    let res2 = SelectStatement::parse(str1);
+    let expected = SelectStatement {
+        fields: vec![FieldDefinitionExpression::Value(FieldValueExpression::Row(
+            vec![
+                ConditionExpression::Base(ConditionBase::Literal(Literal::Integer(1))),
+                ConditionExpression::Base(ConditionBase::Literal(Literal::Integer(2))),
+            ],
+        ))],
+        ..Default::default()
+    };
+    assert_eq!(res2.unwrap().1, expected);
+}
+
+#[test]
+fn derived_table_with_order_and_limit() {
+    let str = "SELECT * FROM (SELECT a FROM t ORDER BY a ASC LIMIT 10) AS d";
+    let res = SelectStatement::parse(str);
+
+    let inner = SelectStatement {
+        tables: vec![Table::from("t").into()],
+        fields: vec![FieldDefinitionExpression::Col(Column::from("a"))],
+        order: Some(OrderClause {
+            columns: vec![(Column::from("a"), OrderType::Asc)],
+        }),
+        limit: Some(LimitClause {
+            limit: 10,
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    let expected = SelectStatement {
+        tables: vec![TableSource::Subquery(Box::new(inner), "d".to_string()).into()],
+        fields: vec![FieldDefinitionExpression::All],
+        ..Default::default()
+    };
+    let stmt = res.unwrap().1;
+    assert_eq!(stmt, expected);
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn in_subquery_with_limit() {
+    let str = "SELECT * FROM t WHERE x IN (SELECT y FROM u LIMIT 5)";
+    let res = SelectStatement::parse(str);
+
+    let inner = SelectStatement {
+        tables: vec![Table::from("u").into()],
+        fields: vec![FieldDefinitionExpression::Col(Column::from("y"))],
+        limit: Some(LimitClause {
+            limit: 5,
+            offset: 0,
+        }),
+        ..Default::default()
+    };
+    let expected = SelectStatement {
+        tables: vec![Table::from("t").into()],
+        fields: vec![FieldDefinitionExpression::All],
+        where_clause: Some(ComparisonOp(ConditionTree {
+            operator: Operator::In,
+            left: Box::new(Base(ConditionBase::Field(Column::from("x")))),
+            right: Box::new(Base(ConditionBase::NestedSelect(Box::new(inner)))),
+        })),
+        ..Default::default()
+    };
+    let stmt = res.unwrap().1;
+    assert_eq!(stmt, expected);
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn allow_reserved_word_as_identifier() {
+    let str = "SELECT plan FROM t";
+
+    let config = ParseConfig::default();
+    assert!(Parser::parse(&config, str).is_err());
+
+    let mut config = ParseConfig::default();
+    config.allow_identifiers.insert("plan".to_string());
+    let res = Parser::parse(&config, str);
+    assert!(res.is_ok());
+    match res.unwrap() {
+        Statement::Select(select) => {
+            assert_eq!(
+                select.fields,
+                vec![FieldDefinitionExpression::Col(Column::from("plan"))]
+            );
+        }
+        _ => panic!("expected a select statement"),
+    }
+}
+
+#[test]
+fn json_table_in_from_clause() {
+    let str = "SELECT jt.id, jt.name FROM JSON_TABLE('[{\"name\": \"a\"}]', '$[*]' \
+        COLUMNS (id FOR ORDINALITY, name VARCHAR(40) PATH '$.name')) AS jt";
+    let res = SelectStatement::parse(str);
+
+    let json_table = JsonTable {
+        expr: ConditionExpression::Base(ConditionBase::Literal(Literal::String(
+            "[{\"name\": \"a\"}]".to_string(),
+        ))),
+        path: "$[*]".to_string(),
+        columns: vec![
+            JsonTableColumn::ForOrdinality {
+                name: "id".to_string(),
+            },
+            JsonTableColumn::Path {
+                name: "name".to_string(),
+                data_type: DataType::Varchar(40),
+                path: "$.name".to_string(),
+                on_empty: None,
+                on_error: None,
+            },
+        ],
+    };
+    let expected = SelectStatement {
+        tables: vec![TableSource::JsonTable(json_table, "jt".to_string()).into()],
+        fields: vec![
+            FieldDefinitionExpression::Col(Column::from("jt.id")),
+            FieldDefinitionExpression::Col(Column::from("jt.name")),
+        ],
+        ..Default::default()
+    };
+    let stmt = res.unwrap().1;
+    assert_eq!(stmt, expected);
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn correlated_scalar_subquery_field() {
+    let str = "SELECT a, (SELECT count(*) FROM b WHERE b.a_id = a.id) AS cnt FROM a";
+    let res = SelectStatement::parse(str);
+
+    let inner = SelectStatement {
+        tables: vec![Table::from("b").into()],
+        fields: vec![FieldDefinitionExpression::Col(Column {
+            name: String::from("count(*)"),
+            alias: None,
+            table: None,
+            schema: None,
+            function: Some(Box::new(FunctionExpression::CountStar)),
+        })],
+        where_clause: Some(ComparisonOp(ConditionTree {
+            left: Box::new(Base(ConditionBase::Field(Column::from("b.a_id")))),
+            right: Box::new(Base(ConditionBase::Field(Column::from("a.id")))),
+            operator: Operator::Equal,
+        })),
+        ..Default::default()
+    };
+    let expected = SelectStatement {
+        tables: vec![Table::from("a").into()],
+        fields: vec![
+            FieldDefinitionExpression::Col(Column::from("a")),
+            FieldDefinitionExpression::Subquery(Box::new(inner), Some("cnt".to_string())),
+        ],
+        ..Default::default()
+    };
+    let stmt = res.unwrap().1;
+    assert_eq!(stmt, expected);
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn wildcard_versus_multiplication() {
+    let parts = [
+        "SELECT a * b FROM t",
+        "SELECT a.* FROM a",
+        "SELECT *, a * 2 FROM t",
+        "SELECT a.*, b * c FROM a",
+    ];
+    let multiply = |l: ArithmeticBase, r: ArithmeticBase| {
+        FieldDefinitionExpression::Value(FieldValueExpression::Arithmetic(
+            ArithmeticExpression::new(ArithmeticOperator::Multiply, l, r, None),
+        ))
+    };
+    let exps = [
+        vec![multiply(
+            ArithmeticBase::Column("a".into()),
+            ArithmeticBase::Column("b".into()),
+        )],
+        vec![FieldDefinitionExpression::AllInTable("a".to_string())],
+        vec![
+            FieldDefinitionExpression::All,
+            multiply(
+                ArithmeticBase::Column("a".into()),
+                ArithmeticBase::Scalar(2.into()),
+            ),
+        ],
+        vec![
+            FieldDefinitionExpression::AllInTable("a".to_string()),
+            multiply(
+                ArithmeticBase::Column("b".into()),
+                ArithmeticBase::Column("c".into()),
+            ),
+        ],
+    ];
+    for i in 0..parts.len() {
+        let stmt = SelectStatement::parse(parts[i]).unwrap().1;
+        assert_eq!(stmt.fields, exps[i]);
+        assert_eq!(parts[i], format!("{}", stmt));
+    }
+}
+
+#[test]
+fn group_by_expressions() {
+    let str = "SELECT region, count(*) FROM events GROUP BY DATE(ts), region, score + 1";
+
+    let res = SelectStatement::parse(str);
+    let date = Column {
+        name: String::from("DATE(ts)"),
+        alias: None,
+        table: None,
+        schema: None,
+        function: Some(Box::new(FunctionExpression::Generic(
+            "DATE".to_string(),
+            FunctionArguments {
+                arguments: vec![FunctionArgument::Column(Column::from("ts"))],
+            },
+        ))),
+    };
+    let score = ArithmeticExpression::new(
+        ArithmeticOperator::Add,
+        ArithmeticBase::Column(Column::from("score")),
+        ArithmeticBase::Scalar(Literal::Integer(1)),
+        None,
+    );
+    let stmt = res.unwrap().1;
+    assert_eq!(
+        stmt.group_by,
+        Some(GroupByClause {
+            columns: vec![
+                Base(ConditionBase::Field(date)),
+                Base(ConditionBase::Field(Column::from("region"))),
+                ConditionExpression::Arithmetic(Box::new(score)),
+            ],
+            having: None,
+        })
+    );
+    assert_eq!(
+        stmt.to_string(),
+        "SELECT region, count(*) FROM events GROUP BY DATE(ts), region, score + 1"
+    );
+}
+
+#[test]
+fn order_by_select_alias() {
+    let config = ParseConfig::default();
+
+    let str = "SELECT a + b AS s FROM t ORDER BY s";
+    let res = Parser::parse(&config, str);
+    let Ok(Statement::Select(select)) = res else {
+        panic!("{:?}", res);
+    };
+    assert_eq!(
+        select.order,
+        Some(OrderClause {
+            columns: vec![(Column::from("s"), OrderType::Asc)],
+        })
+    );
+    assert_eq!(
+        select.to_string(),
+        "SELECT a + b AS s FROM t ORDER BY s ASC"
+    );
+
+    let str = "SELECT a AS s FROM t UNION ALL SELECT b FROM u ORDER BY s DESC LIMIT 3";
+    let res = Parser::parse(&config, str);
+    let Ok(Statement::CompoundSelect(compound)) = res else {
+        panic!("{:?}", res);
+    };
+    assert_eq!(
+        compound.order,
+        Some(OrderClause {
+            columns: vec![(Column::from("s"), OrderType::Desc)],
+        })
+    );
+    assert_eq!(
+        compound.limit,
+        Some(LimitClause {
+            limit: 3,
+            offset: 0
+        })
+    );
+    assert!(compound
+        .selects
+        .iter()
+        .all(|(_, sel)| matches!(sel, CompoundSelectOperand::Select(sel) if sel.order.is_none())));
+    assert_eq!(compound.to_string(), str);
+
+    let str = "SELECT a AS s FROM t UNION DISTINCT (SELECT b FROM u ORDER BY b ASC LIMIT 3)";
+    let res = Parser::parse(&config, str);
+    let Ok(Statement::CompoundSelect(compound)) = res else {
+        panic!("{:?}", res);
+    };
+    assert_eq!(compound.order, None);
+    assert_eq!(compound.to_string(), str);
+}
+
+#[test]
+fn select_system_variables() {
+    let str = "SELECT @@version";
+    let res = SelectStatement::parse(str);
+    let system_variable = |scope: Option<(VariableScope, &str)>, name: &str| {
+        FieldDefinitionExpression::Value(FieldValueExpression::Literal(LiteralExpression {
+            value: Literal::SystemVariable(SystemVariable {
+                scope: scope.map(|(scope, spelling)| (scope, spelling.to_string())),
+                name: name.to_string(),
+            }),
+            alias: None,
+        }))
+    };
+    let stmt = res.unwrap().1;
+    assert_eq!(stmt.fields, vec![system_variable(None, "version")]);
+    assert_eq!(stmt.to_string(), str);
+
+    let str = "SELECT @@global.x, @@session.autocommit FROM t WHERE @@x = 1";
+    let res = SelectStatement::parse(str);
+    let stmt = res.unwrap().1;
+    assert_eq!(
+        stmt.fields,
+        vec![
+            system_variable(Some((VariableScope::Global, "global")), "x"),
+            system_variable(Some((VariableScope::Session, "session")), "autocommit"),
+        ]
+    );
+    let display = stmt.to_string();
+    assert_eq!(display, str);
+    assert_eq!(SelectStatement::parse(&display).unwrap().1, stmt);
+}
+
+#[test]
+fn select_procedure_analyse() {
+    let str = "SELECT a, b FROM t WHERE a > 1 LIMIT 10 PROCEDURE ANALYSE(10, 2000)";
+    let res = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(
+        res.procedure,
+        Some(ProcedureClause {
+            name: "ANALYSE".to_string(),
+            arguments: vec![Literal::Integer(10), Literal::Integer(2000)],
+        })
+    );
+    assert_eq!(
+        res.limit,
+        Some(LimitClause {
+            limit: 10,
+            offset: 0
+        })
+    );
+    assert_eq!(
+        res.to_string(),
+        "SELECT a, b FROM t WHERE a > 1 LIMIT 10 PROCEDURE ANALYSE(10, 2000)"
+    );
+
+    let str = "SELECT * FROM t PROCEDURE ANALYSE()";
+    let res = Parser::parse(&ParseConfig::default(), str);
+    match res.unwrap() {
+        Statement::Select(select) => {
+            assert_eq!(select.procedure.unwrap().to_string(), "PROCEDURE ANALYSE()")
+        }
+        statement => panic!("unexpected statement {:?}", statement),
+    }
+}
+
+#[test]
+fn in_list_with_null_and_placeholder() {
+    let str = "SELECT * FROM t WHERE a IN (1, NULL, ?)";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let exp = ComparisonOp(ConditionTree {
+        operator: Operator::In,
+        left: Box::new(Base(ConditionBase::Field(Column::from("a")))),
+        right: Box::new(Base(LiteralList(vec![
+            Literal::Integer(1),
+            Literal::Null,
+            Literal::Placeholder(ItemPlaceholder::QuestionMark),
+        ]))),
+    });
+    assert_eq!(res.where_clause, Some(exp));
+    assert_eq!(res.to_string(), str);
+    assert_eq!(SelectStatement::parse(&res.to_string()).unwrap().1, res);
+
+    let str = "SELECT nullable FROM t";
+    let res = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(
+        res.fields,
+        FieldDefinitionExpression::from_column_str(&["nullable"])
+    );
+}
+
+#[test]
+fn scalar_subquery_comparison() {
+    let str = "SELECT * FROM t WHERE max_id = (SELECT max(id) FROM t)";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let subquery = SelectStatement::parse("SELECT max(id) FROM t").unwrap().1;
+    let exp = ComparisonOp(ConditionTree {
+        operator: Operator::Equal,
+        left: Box::new(Base(ConditionBase::Field(Column::from("max_id")))),
+        right: Box::new(Base(ConditionBase::NestedSelect(Box::new(subquery)))),
+    });
+    assert_eq!(res.where_clause, Some(exp));
+    assert_eq!(res.to_string(), str);
+    assert_eq!(SelectStatement::parse(&res.to_string()).unwrap().1, res);
+
+    let str = "SELECT * FROM t WHERE a > (SELECT avg(b) FROM u WHERE u.c = t.c) AND d = 1";
+    let res = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(res.to_string(), str);
+    assert_eq!(SelectStatement::parse(&res.to_string()).unwrap().1, res);
+}
+
+#[test]
+fn user_variable_running_total() {
+    let str = "SELECT @n := @n + 1 AS rownum FROM t";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let exp =
+        FieldDefinitionExpression::Value(FieldValueExpression::Assignment(AssignmentExpression {
+            variable: "@n".to_owned(),
+            value: Box::new(FieldValueExpression::Arithmetic(ArithmeticExpression::new(
+                ArithmeticOperator::Add,
+                ArithmeticBase::Column("@n".into()),
+                ArithmeticBase::Scalar(1.into()),
+                None,
+            ))),
+            alias: Some("rownum".to_owned()),
+        }));
+    assert_eq!(res.fields, vec![exp]);
+    assert_eq!(res.to_string(), str);
+    assert_eq!(SelectStatement::parse(&res.to_string()).unwrap().1, res);
+
+    let str = "SELECT @n := @n + 1 AS `order` FROM t";
+    let res = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(res.to_string(), str);
+}
+
+#[test]
+fn window_functions() {
+    let str = "SELECT ROW_NUMBER() OVER (PARTITION BY dept ORDER BY salary DESC) FROM emp";
+    let res = SelectStatement::parse(str).unwrap().1;
+    let function = match res.fields[0] {
+        FieldDefinitionExpression::Col(ref column) => column.function.clone(),
+        _ => panic!("expected a column"),
+    };
+    assert_eq!(
+        function,
+        Some(Box::new(FunctionExpression::Window {
+            func: Box::new(FunctionExpression::Generic(
+                "ROW_NUMBER".to_owned(),
+                FunctionArguments { arguments: vec![] },
+            )),
+            window_name: None,
+            partition_by: vec![Column::from("dept")],
+            order_by: Some(OrderClause {
+                columns: vec![(Column::from("salary"), OrderType::Desc)],
+            }),
+            frame: None,
+        }))
+    );
+    assert_eq!(res.to_string(), str);
+
+    let sqls = [
+        "SELECT RANK() OVER (ORDER BY salary DESC) AS r FROM emp",
+        "SELECT DENSE_RANK() OVER (PARTITION BY a, b ORDER BY c ASC) FROM t",
+        "SELECT LAG(salary, 1) OVER (ORDER BY hired ASC) FROM emp",
+        "SELECT sum(x) OVER w FROM t",
+        "SELECT count(*) OVER () FROM t",
+        "SELECT sum(x) OVER (PARTITION BY a ORDER BY b ASC ROWS BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW) FROM t",
+        "SELECT avg(x) OVER (w RANGE 2 PRECEDING) FROM t",
+    ];
+    for sql in sqls {
+        let res = SelectStatement::parse(sql).unwrap().1;
+        assert_eq!(res.to_string(), sql);
+        assert_eq!(SelectStatement::parse(&res.to_string()).unwrap().1, res);
+    }
+
+    // `over` only starts a window as a whole word
+    let res = SelectStatement::parse("SELECT sum(x) over_total FROM t")
+        .unwrap()
+        .1;
+    assert_eq!(res.to_string(), "SELECT sum(x) AS over_total FROM t");
+
+    // window function names are not reserved words
+    let res = SelectStatement::parse("SELECT rank, groups FROM t")
+        .unwrap()
+        .1;
+    assert_eq!(
+        res.fields,
+        vec![
+            FieldDefinitionExpression::Col(Column::from("rank")),
+            FieldDefinitionExpression::Col(Column::from("groups")),
+        ]
+    );
+}
+
+#[test]
+fn named_window_clause() {
+    let str = "SELECT a, RANK() OVER w FROM t WINDOW w AS (ORDER BY a)";
+    let res = SelectStatement::parse(str).unwrap().1;
+    assert_eq!(
+        res.window,
+        vec![(
+            "w".to_string(),
+            WindowSpec {
+                order_by: Some(OrderClause {
+                    columns: vec![(Column::from("a"), OrderType::Asc)],
+                }),
+                ..Default::default()
+            }
+        )]
+    );
+    assert_eq!(
+        res.to_string(),
+        "SELECT a, RANK() OVER w FROM t WINDOW w AS (ORDER BY a ASC)"
+    );
+
+    // WINDOW is reserved, so it is not taken for a table alias
+    assert!(SelectStatement::parse("SELECT a FROM t WINDOW w").is_err());
+}
+
+#[test]
+fn window_function_positions() {
+    let config = ParseConfig::default();
+    let allowed = [
+        "SELECT a, ROW_NUMBER() OVER (ORDER BY a ASC) FROM t",
+        "SELECT a FROM t ORDER BY RANK() OVER (ORDER BY a ASC) DESC",
+        // a subquery is a query of its own
+        "SELECT a FROM t WHERE a IN (SELECT ROW_NUMBER() OVER () FROM u)",
+    ];
+    for sql in allowed {
+        assert_eq!(Parser::parse(&config, sql).unwrap().to_string(), sql);
+    }
+
+    let rejected = [
+        ("SELECT a FROM t WHERE ROW_NUMBER() OVER () = 1", "WHERE"),
+        (
+            "SELECT a FROM t GROUP BY RANK() OVER (ORDER BY a)",
+            "GROUP BY",
+        ),
+        (
+            "SELECT a FROM t GROUP BY a HAVING sum(a) OVER () > 1",
+            "HAVING",
+        ),
+        ("DELETE FROM t WHERE a + ROW_NUMBER() OVER () > 1", "WHERE"),
+    ];
+    for (sql, clause) in rejected {
+        let err = Parser::parse(&config, sql).unwrap_err();
+        assert!(
+            err.contains(&format!("window functions are not allowed in {}", clause)),
+            "{}: {}",
+            sql,
+            err
+        );
+    }
+
+    let sql = "SELECT a FROM t QUALIFY ROW_NUMBER() OVER (PARTITION BY a) = 1";
+    let config = ParseConfig::builder().qualify_clause(true).build();
+    assert_eq!(Parser::parse(&config, sql).unwrap().to_string(), sql);
+}
+
+#[test]
+fn doubled_backquote_in_quoted_column() {
+    let res = SelectStatement::parse("SELECT `a``b` FROM t").unwrap().1;
+    assert_eq!(
+        res.fields,
+        vec![FieldDefinitionExpression::Col(Column::from("`a``b`"))]
+    );
+}
@@ -8,6 +8,7 @@ use sqlparser_mysql::base::{
     Table,
 };
 use sqlparser_mysql::dms::UpdateStatement;
+use sqlparser_mysql::{ParseConfig, Parser, Statement};
 
 /////////////// UPDATE
 #[test]
@@ -155,3 +156,55 @@ fn update_with_arithmetic() {
         }
     );
 }
+
+#[test]
+fn update_schema_qualified_table() {
+    let str = "UPDATE db1.users SET id = 42";
+
+    let res = UpdateStatement::parse(str);
+    let stmt = res.unwrap().1;
+    assert_eq!(
+        stmt,
+        UpdateStatement {
+            table: Table::from(("db1", "users")),
+            fields: vec![(
+                Column::from("id"),
+                FieldValueExpression::Literal(LiteralExpression::from(Literal::from(42))),
+            )],
+            ..Default::default()
+        }
+    );
+    assert_eq!(str, format!("{}", stmt));
+}
+
+#[test]
+fn update_with_optimizer_hint() {
+    let str =
+        "UPDATE /*+ NO_MERGE(users) */ users SET id = 42 /* set id */ WHERE name = 'a /* b */'";
+
+    let config = ParseConfig::default();
+    let res = Parser::parse(&config, str);
+    let exp = Parser::parse(&config, "UPDATE users SET id = 42 WHERE name = 'a /* b */'");
+    assert!(matches!(res, Ok(Statement::Update(_))));
+    assert_eq!(res, exp);
+}
+
+#[test]
+fn update_with_function_call_value() {
+    let str = "UPDATE t SET data = JSON_SET(data, '$.k', 1) WHERE id = 1";
+
+    let res = UpdateStatement::parse(str);
+    let stmt = res.unwrap().1;
+    match stmt.fields[0].1 {
+        FieldValueExpression::Column(ref col) => {
+            assert_eq!(col.name, "JSON_SET(data, '$.k', 1)");
+            assert!(col.function.is_some());
+        }
+        ref other => panic!("expected a function call, got {:?}", other),
+    }
+    assert_eq!(str, format!("{}", stmt));
+
+    let str = "UPDATE t SET a = b, c = lower(d)";
+    let res = UpdateStatement::parse(str);
+    assert_eq!(str, format!("{}", res.unwrap().1));
+}